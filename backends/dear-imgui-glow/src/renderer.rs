@@ -1161,13 +1161,7 @@ impl GlowRenderer {
         }
 
         // Collect update rects; prefer explicit Updates[] then fallback to single UpdateRect
-        let mut rects: Vec<dear_imgui_rs::texture::TextureRect> = texture_data.updates().collect();
-        if rects.is_empty() {
-            let r = texture_data.update_rect();
-            if r.w > 0 && r.h > 0 {
-                rects.push(r);
-            }
-        }
+        let rects = texture_data.update_rects();
 
         if rects.is_empty() {
             // Nothing to update; mark OK and return
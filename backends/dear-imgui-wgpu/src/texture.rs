@@ -166,13 +166,7 @@ impl WgpuTextureManager {
 
         // Collect update rectangles; prefer explicit Updates[] if present,
         // otherwise fallback to single UpdateRect.
-        let mut rects: Vec<dear_imgui_rs::texture::TextureRect> = texture_data.updates().collect();
-        if rects.is_empty() {
-            let r = texture_data.update_rect();
-            if r.w > 0 && r.h > 0 {
-                rects.push(r);
-            }
-        }
+        let rects = texture_data.update_rects();
         if rects.is_empty() {
             return Ok(false);
         }
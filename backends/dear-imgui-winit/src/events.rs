@@ -3,7 +3,7 @@
 //! This module contains event processing logic for various winit events
 //! including keyboard, mouse, touch, and IME events.
 
-use dear_imgui_rs::Context;
+use dear_imgui_rs::{Context, MouseSource};
 use winit::event::{DeviceEvent, ElementState, Ime, KeyEvent, MouseScrollDelta, TouchPhase};
 
 use std::cell::RefCell;
@@ -69,9 +69,9 @@ pub fn handle_mouse_button(
 ) -> bool {
     if let Some(imgui_button) = to_imgui_mouse_button(button) {
         let pressed = state == ElementState::Pressed;
-        imgui_ctx
-            .io_mut()
-            .add_mouse_button_event(imgui_button, pressed);
+        let io = imgui_ctx.io_mut();
+        io.add_mouse_source_event(MouseSource::Mouse);
+        io.add_mouse_button_event(imgui_button, pressed);
         return imgui_ctx.io().want_capture_mouse();
     }
     false
@@ -79,9 +79,9 @@ pub fn handle_mouse_button(
 
 /// Handle cursor movement events
 pub fn handle_cursor_moved(position: [f64; 2], imgui_ctx: &mut Context) -> bool {
-    imgui_ctx
-        .io_mut()
-        .add_mouse_pos_event([position[0] as f32, position[1] as f32]);
+    let io = imgui_ctx.io_mut();
+    io.add_mouse_source_event(MouseSource::Mouse);
+    io.add_mouse_pos_event([position[0] as f32, position[1] as f32]);
     imgui_ctx.io().want_capture_mouse()
 }
 
@@ -141,31 +141,27 @@ pub fn handle_touch_event(touch: &winit::event::Touch, _window: &Window, _imgui_
                     // Capture this touch as the active pointer
                     *active.borrow_mut() = Some(id);
                     let pos = touch.location.to_logical::<f64>(_window.scale_factor());
-                    _imgui_ctx
-                        .io_mut()
-                        .add_mouse_pos_event([pos.x as f32, pos.y as f32]);
-                    _imgui_ctx
-                        .io_mut()
-                        .add_mouse_button_event(dear_imgui_rs::input::MouseButton::Left, true);
+                    let io = _imgui_ctx.io_mut();
+                    io.add_mouse_source_event(MouseSource::TouchScreen);
+                    io.add_mouse_pos_event([pos.x as f32, pos.y as f32]);
+                    io.add_mouse_button_event(dear_imgui_rs::input::MouseButton::Left, true);
                 }
             }
             TouchPhase::Moved => {
                 if active_id == Some(id) {
                     let pos = touch.location.to_logical::<f64>(_window.scale_factor());
-                    _imgui_ctx
-                        .io_mut()
-                        .add_mouse_pos_event([pos.x as f32, pos.y as f32]);
+                    let io = _imgui_ctx.io_mut();
+                    io.add_mouse_source_event(MouseSource::TouchScreen);
+                    io.add_mouse_pos_event([pos.x as f32, pos.y as f32]);
                 }
             }
             TouchPhase::Ended | TouchPhase::Cancelled => {
                 if active_id == Some(id) {
                     let pos = touch.location.to_logical::<f64>(_window.scale_factor());
-                    _imgui_ctx
-                        .io_mut()
-                        .add_mouse_pos_event([pos.x as f32, pos.y as f32]);
-                    _imgui_ctx
-                        .io_mut()
-                        .add_mouse_button_event(dear_imgui_rs::input::MouseButton::Left, false);
+                    let io = _imgui_ctx.io_mut();
+                    io.add_mouse_source_event(MouseSource::TouchScreen);
+                    io.add_mouse_pos_event([pos.x as f32, pos.y as f32]);
+                    io.add_mouse_button_event(dear_imgui_rs::input::MouseButton::Left, false);
                     *active.borrow_mut() = None;
                 }
             }
@@ -170,9 +170,8 @@ pub fn init_multi_viewport_support(ctx: &mut Context, main_window: &Window) {
 }
 
 /// Set up monitors list for multi-viewport support using a reference window
-unsafe fn setup_monitors_with_window(window: &Window, _ctx: &mut Context) {
+unsafe fn setup_monitors_with_window(window: &Window, ctx: &mut Context) {
     // Build monitor list from winit and feed into ImGuiPlatformIO.Monitors.
-    // We allocate storage using ImGui's allocator to keep ownership consistent.
     let monitors: Vec<dear_imgui_rs::sys::ImGuiPlatformMonitor> = {
         let mut out = Vec::new();
         let mut iter = window.available_monitors();
@@ -215,33 +214,7 @@ unsafe fn setup_monitors_with_window(window: &Window, _ctx: &mut Context) {
         out
     };
 
-    let pio = dear_imgui_rs::sys::igGetPlatformIO_Nil();
-    let vec = unsafe { &mut (*pio).Monitors };
-
-    // Free existing storage if any (owned by ImGui allocator)
-    if vec.Capacity > 0 && !vec.Data.is_null() {
-        dear_imgui_rs::sys::igMemFree(vec.Data as *mut _);
-        vec.Data = std::ptr::null_mut();
-        vec.Size = 0;
-        vec.Capacity = 0;
-    }
-
-    let count = monitors.len();
-    let bytes = count * std::mem::size_of::<dear_imgui_rs::sys::ImGuiPlatformMonitor>();
-    let data_ptr = if bytes > 0 {
-        dear_imgui_rs::sys::igMemAlloc(bytes) as *mut dear_imgui_rs::sys::ImGuiPlatformMonitor
-    } else {
-        std::ptr::null_mut()
-    };
-
-    if !data_ptr.is_null() {
-        for (i, m) in monitors.iter().enumerate() {
-            *data_ptr.add(i) = *m;
-        }
-        vec.Data = data_ptr;
-        vec.Size = count as i32;
-        vec.Capacity = count as i32;
-    }
+    ctx.platform_io_mut().set_monitors(&monitors);
 }
 
 /// Try to route a winit event to the correct ImGui viewport window
@@ -44,6 +44,10 @@ impl BuildConfig {
                 .split(',')
                 .any(|f| f == "crt-static")
     }
+    /// `wasm32-unknown-emscripten`: cimgui is compiled into the same module (single-module)
+    fn is_emscripten(&self) -> bool {
+        self.target_os == "emscripten"
+    }
     fn cimgui_root(&self) -> PathBuf {
         self.manifest_dir.join("third-party/cimgui")
     }
@@ -63,9 +67,11 @@ fn main() {
     // Re-run triggers
     println!("cargo:rerun-if-changed=build.rs");
     println!("cargo:rerun-if-env-changed=IMGUI_SYS_LIB_DIR");
+    println!("cargo:rerun-if-env-changed=DEAR_IMGUI_PREBUILT_DIR");
     println!("cargo:rerun-if-env-changed=IMGUI_SYS_SKIP_CC");
     println!("cargo:rerun-if-env-changed=IMGUI_SYS_FORCE_BUILD");
     println!("cargo:rerun-if-env-changed=IMGUI_SYS_PREBUILT_URL");
+    println!("cargo:rerun-if-env-changed=IMGUI_SYS_PREBUILT_TIMEOUT_SECS");
     println!("cargo:rerun-if-env-changed=IMGUI_SYS_USE_CMAKE");
     println!("cargo:rerun-if-env-changed=CARGO_NET_OFFLINE");
 
@@ -75,8 +81,20 @@ fn main() {
         return;
     }
 
-    // Native: always generate bindings from cimgui
-    generate_bindings_native(&cfg);
+    // `pregenerated-bindings`: without the cimgui headers (e.g. a crates.io build using a
+    // prebuilt library), fall back to the checked-in bindings instead of running bindgen.
+    let header_free =
+        cfg!(feature = "pregenerated-bindings") && !cfg.cimgui_root().join("cimgui.h").exists();
+    if header_free {
+        if !use_pregenerated_bindings(&cfg.out_dir) {
+            panic!(
+                "dear-imgui-sys: feature `pregenerated-bindings` is enabled but src/bindings_pregenerated.rs is not available"
+            );
+        }
+    } else {
+        // Native: always generate bindings from cimgui
+        generate_bindings_native(&cfg);
+    }
 
     // Build strategy selection via features + env var override
     // Force native build when explicitly requested or when sandboxed
@@ -85,22 +103,36 @@ fn main() {
         cfg!(feature = "build-from-source") || env::var("IMGUI_SYS_FORCE_BUILD").is_ok();
 
     // Try prebuilt dear_imgui first (static lib) unless force_build
-    let linked_prebuilt = if force_build {
-        false
+    let mut provenance = if force_build {
+        None
     } else {
         try_link_prebuilt_all(&cfg)
     };
+    let linked_prebuilt = provenance.is_some();
+    if header_free && !linked_prebuilt && env::var("IMGUI_SYS_SKIP_CC").is_err() {
+        panic!(
+            "dear-imgui-sys: C/C++ sources are missing and no prebuilt library was linked; enable the `prebuilt` feature or set IMGUI_SYS_LIB_DIR, IMGUI_SYS_PREBUILT_URL or DEAR_IMGUI_PREBUILT_DIR"
+        );
+    }
 
     // Build from sources when needed
     if !linked_prebuilt && env::var("IMGUI_SYS_SKIP_CC").is_err() {
         if cfg.target_arch == "wasm32" {
             // If targeting Emscripten, attempt to compile C/C++ (requires emsdk toolchain)
-            if cfg.target_env == "emscripten" {
-                build_with_cc_wasm(&cfg);
+            if cfg.is_emscripten() {
+                let files = build_with_cc_wasm(&cfg);
+                provenance = Some(build_support::LibProvenance::Source {
+                    tool: "cc",
+                    files: Some(files),
+                });
             } else {
                 // Unknown-unknown skeleton: compile only when explicitly requested
                 if env::var("IMGUI_SYS_WASM_CC").is_ok() {
-                    build_with_cc_wasm(&cfg);
+                    let files = build_with_cc_wasm(&cfg);
+                    provenance = Some(build_support::LibProvenance::Source {
+                        tool: "cc",
+                        files: Some(files),
+                    });
                 } else {
                     println!(
                         "cargo:warning=WASM (unknown) skeleton: skipping native C/C++ build (set IMGUI_SYS_WASM_CC=1 to enable)"
@@ -114,8 +146,16 @@ fn main() {
                 && build_with_cmake(&cfg.manifest_dir)
             {
                 // CMake path prints link flags and search paths
+                provenance = Some(build_support::LibProvenance::Source {
+                    tool: "cmake",
+                    files: None,
+                });
             } else {
-                build_with_cc_cfg(&cfg);
+                let files = build_with_cc_cfg(&cfg);
+                provenance = Some(build_support::LibProvenance::Source {
+                    tool: "cc",
+                    files: Some(files),
+                });
             }
         }
     } else if !linked_prebuilt {
@@ -125,6 +165,10 @@ fn main() {
         println!("cargo:warning=WASM target is not supported.");
     }
 
+    if let Some(provenance) = &provenance {
+        build_support::report_provenance(&cfg.out_dir, "dear_imgui", provenance);
+    }
+
     // Export include paths/defines for extensions
     export_include_paths(&cfg);
 }
@@ -227,8 +271,8 @@ fn generate_bindings_native(cfg: &BuildConfig) {
     sanitize_bindings_file(&out);
 }
 
-fn try_link_prebuilt_all(cfg: &BuildConfig) -> bool {
-    let mut linked = false;
+fn try_link_prebuilt_all(cfg: &BuildConfig) -> Option<build_support::LibProvenance> {
+    let mut linked = None;
     if cfg.target_arch != "wasm32" {
         if let Some(lib_dir) = env::var_os("IMGUI_SYS_LIB_DIR") {
             let lib_dir = PathBuf::from(lib_dir);
@@ -237,10 +281,29 @@ fn try_link_prebuilt_all(cfg: &BuildConfig) -> bool {
                     "cargo:warning=Using prebuilt dear_imgui from {}",
                     lib_dir.display()
                 );
-                linked = true;
+                linked = Some(build_support::LibProvenance::PrebuiltLocal {
+                    origin: lib_dir.display().to_string(),
+                });
             }
         }
-        if !linked && let Some(url) = env::var_os("IMGUI_SYS_PREBUILT_URL") {
+        if linked.is_none()
+            && env::var_os("IMGUI_SYS_PREBUILT_URL").is_none()
+            && let Some(lib_dir) =
+                build_support::shared_prebuilt_lib_dir(&expected_lib_name(&cfg.target_env))
+            && try_link_prebuilt(&lib_dir, &cfg.target_env)
+        {
+            println!(
+                "cargo:warning=Using prebuilt dear_imgui from {} ({})",
+                lib_dir.display(),
+                build_support::SHARED_PREBUILT_DIR_ENV
+            );
+            linked = Some(build_support::LibProvenance::PrebuiltLocal {
+                origin: lib_dir.display().to_string(),
+            });
+        }
+        if linked.is_none()
+            && let Some(url) = env::var_os("IMGUI_SYS_PREBUILT_URL")
+        {
             let cache_root = prebuilt_cache_root(cfg);
             if let Ok(lib_dir) =
                 try_download_prebuilt(&cache_root, &url.to_string_lossy(), &cfg.target_env)
@@ -250,7 +313,9 @@ fn try_link_prebuilt_all(cfg: &BuildConfig) -> bool {
                     "cargo:warning=Downloaded and using prebuilt dear_imgui from {}",
                     lib_dir.display()
                 );
-                linked = true;
+                linked = Some(build_support::LibProvenance::PrebuiltDownloaded {
+                    origin: url.to_string_lossy().into_owned(),
+                });
             }
         }
         // Only attempt automatic release download when explicitly enabled.
@@ -260,7 +325,7 @@ fn try_link_prebuilt_all(cfg: &BuildConfig) -> bool {
             Some("1") | Some("true") | Some("yes")
         );
         let allow_auto_prebuilt = allow_feature || allow_env;
-        if !linked && allow_auto_prebuilt {
+        if linked.is_none() && allow_auto_prebuilt {
             let source = match (allow_feature, allow_env) {
                 (true, true) => "feature+env",
                 (true, false) => "feature",
@@ -272,17 +337,17 @@ fn try_link_prebuilt_all(cfg: &BuildConfig) -> bool {
                 "cargo:warning=auto-prebuilt enabled (dear-imgui-sys): source={}, repo={}/{}",
                 source, owner, repo
             );
-            if let Some(lib_dir) = try_download_prebuilt_from_release(cfg)
+            if let Some((lib_dir, provenance)) = try_download_prebuilt_from_release(cfg)
                 && try_link_prebuilt(&lib_dir, &cfg.target_env)
             {
                 println!(
                     "cargo:warning=Downloaded and using prebuilt dear_imgui from release at {}",
                     lib_dir.display()
                 );
-                linked = true;
+                linked = Some(provenance);
             }
         }
-        if !linked {
+        if linked.is_none() {
             let repo_prebuilt = cfg
                 .manifest_dir
                 .join("third-party")
@@ -293,14 +358,16 @@ fn try_link_prebuilt_all(cfg: &BuildConfig) -> bool {
                     "cargo:warning=Using repo prebuilt dear_imgui from {}",
                     repo_prebuilt.display()
                 );
-                linked = true;
+                linked = Some(build_support::LibProvenance::PrebuiltLocal {
+                    origin: repo_prebuilt.display().to_string(),
+                });
             }
         }
     }
     linked
 }
 
-fn build_with_cc_cfg(cfg: &BuildConfig) {
+fn build_with_cc_cfg(cfg: &BuildConfig) -> usize {
     let mut build = cc::Build::new();
     build.cpp(true).std("c++17");
     let cimgui_root = cfg.cimgui_root();
@@ -348,7 +415,9 @@ fn build_with_cc_cfg(cfg: &BuildConfig) {
         }
         build.file(cfg.imgui_src().join("misc/freetype/imgui_freetype.cpp"));
     }
+    let files = build.get_files().count();
     build.compile("dear_imgui");
+    files
 }
 
 fn export_include_paths(cfg: &BuildConfig) {
@@ -404,7 +473,7 @@ fn try_link_prebuilt(dir: &Path, target_env: &str) -> bool {
 
 // Minimal WASM (skeleton) build: compile cimgui + imgui with WASM-friendly defines.
 // This will be extended with proper toolchain flags in future iterations.
-fn build_with_cc_wasm(cfg: &BuildConfig) {
+fn build_with_cc_wasm(cfg: &BuildConfig) -> usize {
     let mut build = cc::Build::new();
     build.cpp(true).std("c++17");
     let cimgui_root = cfg.cimgui_root();
@@ -418,8 +487,10 @@ fn build_with_cc_wasm(cfg: &BuildConfig) {
     build.file(imgui_src.join("imgui_demo.cpp"));
     build.file(cimgui_root.join("cimgui.cpp"));
 
-    // If EMSDK is available, prefer its upstream clang++ for wasm32-unknown-unknown objects
-    if let Ok(emsdk) = std::env::var("EMSDK") {
+    if cfg.is_emscripten() {
+        // cc already drives em++ for this target, which brings its own sysroot
+    } else if let Ok(emsdk) = std::env::var("EMSDK") {
+        // If EMSDK is available, prefer its upstream clang++ for wasm32-unknown-unknown objects
         let mut clangpp = PathBuf::from(emsdk.clone());
         // EMSDK/upstream/bin/clang++
         clangpp.push("upstream");
@@ -459,10 +530,13 @@ fn build_with_cc_wasm(cfg: &BuildConfig) {
     build.flag_if_supported("-fno-exceptions");
     build.flag_if_supported("-fno-rtti");
 
-    // Do not link the C++ standard library for wasm32-unknown-unknown
+    // Do not link the C++ standard library explicitly: wasm32-unknown-unknown has none, and
+    // emcc adds libc++ itself when linking an Emscripten module
     build.cpp_link_stdlib(None);
 
+    let files = build.get_files().count();
     build.compile("dear_imgui");
+    files
 }
 
 fn try_download_prebuilt(
@@ -472,10 +546,19 @@ fn try_download_prebuilt(
 ) -> Result<PathBuf, String> {
     let lib_name = expected_lib_name(target_env);
     println!("cargo:warning=Downloading prebuilt dear_imgui from {}", url);
-    build_support::download_prebuilt(cache_root, url, lib_name.as_str(), target_env)
+    let timeout = build_support::prebuilt_timeout_from_env("IMGUI_SYS_PREBUILT_TIMEOUT_SECS");
+    build_support::download_prebuilt_with_timeout(
+        cache_root,
+        url,
+        lib_name.as_str(),
+        target_env,
+        timeout,
+    )
 }
 
-fn try_download_prebuilt_from_release(cfg: &BuildConfig) -> Option<PathBuf> {
+fn try_download_prebuilt_from_release(
+    cfg: &BuildConfig,
+) -> Option<(PathBuf, build_support::LibProvenance)> {
     if build_support::is_offline() {
         return None;
     }
@@ -541,7 +624,12 @@ fn try_download_prebuilt_from_release(cfg: &BuildConfig) -> Option<PathBuf> {
                     &cache_root,
                     expected_lib_name(&cfg.target_env).as_str(),
                 ) {
-                    return Some(lib_dir);
+                    return Some((
+                        lib_dir,
+                        build_support::LibProvenance::PrebuiltLocal {
+                            origin: archive_path.display().to_string(),
+                        },
+                    ));
                 }
             }
         }
@@ -551,7 +639,10 @@ fn try_download_prebuilt_from_release(cfg: &BuildConfig) -> Option<PathBuf> {
     let urls = build_support::release_candidate_urls_env(&tags, &candidates);
     for url in urls {
         if let Ok(lib_dir) = try_download_prebuilt(&cache_root, &url, &cfg.target_env) {
-            return Some(lib_dir);
+            return Some((
+                lib_dir,
+                build_support::LibProvenance::PrebuiltDownloaded { origin: url },
+            ));
         }
     }
     None
@@ -647,6 +738,22 @@ fn use_pregenerated_bindings(out_dir: &Path) -> bool {
         .map(|v| v == "1")
         .unwrap_or(false);
     let is_wasm = std::env::var("CARGO_CFG_TARGET_ARCH").as_deref() == Ok("wasm32");
+    // Emscripten links cimgui into the Rust module: reuse the wasm32 layout but drop the
+    // `imgui-sys-v0` import module so symbols resolve at link time.
+    let is_emscripten = std::env::var("CARGO_CFG_TARGET_OS").as_deref() == Ok("emscripten");
+    if is_emscripten {
+        let preg = Path::new("src").join("wasm_bindings_pregenerated.rs");
+        if let Ok(content) = std::fs::read_to_string(&preg) {
+            let sanitized = strip_wasm_import_module(&sanitize_bindings_string(&content));
+            if std::fs::write(out_dir.join("bindings.rs"), sanitized).is_ok() {
+                println!(
+                    "cargo:warning=Using pregenerated bindings (emscripten, single module): {}",
+                    preg.display()
+                );
+                return true;
+            }
+        }
+    }
     let candidates = if is_wasm && !single_module {
         vec![
             Path::new("src").join("wasm_bindings_pregenerated.rs"),
@@ -679,6 +786,18 @@ fn use_pregenerated_bindings(out_dir: &Path) -> bool {
     false
 }
 
+fn strip_wasm_import_module(content: &str) -> String {
+    let mut out = String::with_capacity(content.len());
+    for line in content.lines() {
+        if line.trim_start().starts_with("#[link(wasm_import_module") {
+            continue;
+        }
+        out.push_str(line);
+        out.push('\n');
+    }
+    out
+}
+
 fn sanitize_bindings_file(path: &Path) {
     if let Ok(content) = std::fs::read_to_string(path) {
         let sanitized = sanitize_bindings_string(&content);
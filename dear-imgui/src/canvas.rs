@@ -0,0 +1,368 @@
+//! Pan/zoom canvas
+//!
+//! [`Canvas`] reserves a rectangular region that can be panned by dragging and zoomed
+//! with the mouse wheel, the building block behind node editors, map viewers and
+//! diagram tools. The pan offset and zoom live in a user-owned [`CanvasState`]; each
+//! frame the canvas hands out a [`CanvasTransform`] for converting between world and
+//! screen coordinates, along with a draw list clipped to the canvas.
+//!
+//! ```no_run
+//! # use dear_imgui_rs::*;
+//! # let mut ctx = Context::create();
+//! # let ui = ctx.frame();
+//! let mut state = CanvasState::new();
+//! ui.canvas("##diagram", &mut state).grid(32.0).build(|canvas| {
+//!     let t = canvas.transform();
+//!     // Draw a 100x60 world-space box at the origin
+//!     canvas
+//!         .draw_list()
+//!         .add_rect(t.world_to_screen([0.0, 0.0]), t.world_to_screen([100.0, 60.0]), [1.0, 0.6, 0.2, 1.0])
+//!         .build();
+//!     if let Some(p) = canvas.mouse_world_pos() {
+//!         ui.set_item_tooltip(format!("{:.1}, {:.1}", p[0], p[1]));
+//!     }
+//! });
+//! ```
+//!
+use crate::draw::DrawListMut;
+use crate::input::MouseButton;
+use crate::sys;
+use crate::widget::ButtonFlags;
+use crate::{StyleColor, Ui};
+
+/// Zoom factor applied per mouse wheel notch
+const ZOOM_STEP: f32 = 1.1;
+/// Grid lines closer than this many pixels are not drawn
+const MIN_GRID_SPACING_PX: f32 = 4.0;
+
+/// Pan offset and zoom of a [`Canvas`], kept by the caller across frames
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CanvasState {
+    /// Screen-space offset, in pixels, of the world origin from the canvas' top-left corner
+    pub offset: [f32; 2],
+    /// Pixels per world unit
+    pub zoom: f32,
+}
+
+impl Default for CanvasState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CanvasState {
+    /// Creates a state with the world origin at the top-left corner and a zoom of 1
+    pub fn new() -> Self {
+        Self {
+            offset: [0.0, 0.0],
+            zoom: 1.0,
+        }
+    }
+
+    /// Resets pan and zoom to their initial values
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// Mapping between world and screen coordinates for one frame of a canvas
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CanvasTransform {
+    min: [f32; 2],
+    max: [f32; 2],
+    origin: [f32; 2],
+    zoom: f32,
+}
+
+impl CanvasTransform {
+    /// Converts a world-space point to screen space
+    pub fn world_to_screen(&self, p: [f32; 2]) -> [f32; 2] {
+        [
+            self.origin[0] + p[0] * self.zoom,
+            self.origin[1] + p[1] * self.zoom,
+        ]
+    }
+
+    /// Converts a screen-space point to world space
+    pub fn screen_to_world(&self, p: [f32; 2]) -> [f32; 2] {
+        [
+            (p[0] - self.origin[0]) / self.zoom,
+            (p[1] - self.origin[1]) / self.zoom,
+        ]
+    }
+
+    /// Converts a world-space length (radius, thickness, ...) to pixels
+    pub fn world_to_screen_len(&self, len: f32) -> f32 {
+        len * self.zoom
+    }
+
+    /// Pixels per world unit
+    pub fn zoom(&self) -> f32 {
+        self.zoom
+    }
+
+    /// Screen-space position of the world origin
+    pub fn origin(&self) -> [f32; 2] {
+        self.origin
+    }
+
+    /// Canvas rectangle as `(min, max)` (screen space)
+    pub fn rect(&self) -> ([f32; 2], [f32; 2]) {
+        (self.min, self.max)
+    }
+
+    /// Visible world-space rectangle as `(min, max)`
+    pub fn visible_world_rect(&self) -> ([f32; 2], [f32; 2]) {
+        (
+            self.screen_to_world(self.min),
+            self.screen_to_world(self.max),
+        )
+    }
+}
+
+/// # Canvas
+impl Ui {
+    /// Creates a pan/zoom canvas whose view is stored in `state`.
+    ///
+    /// Call [`build`](Canvas::build) to reserve the region and draw its contents.
+    pub fn canvas<'s>(&self, id: impl Into<String>, state: &'s mut CanvasState) -> Canvas<'_, 's> {
+        self.debug_assert_current();
+        Canvas::new(self, id, state)
+    }
+}
+
+/// Builder for a pan/zoom canvas.
+///
+/// By default the canvas fills the available content region, pans with a right-button
+/// drag, zooms around the mouse cursor with the wheel and draws a framed background.
+#[must_use]
+pub struct Canvas<'ui, 's> {
+    ui: &'ui Ui,
+    id: String,
+    state: &'s mut CanvasState,
+    size: [f32; 2],
+    pan_button: MouseButton,
+    zoom_range: (f32, f32),
+    grid: Option<f32>,
+    background: bool,
+}
+
+impl<'ui, 's> Canvas<'ui, 's> {
+    /// Creates a canvas builder
+    pub fn new(ui: &'ui Ui, id: impl Into<String>, state: &'s mut CanvasState) -> Self {
+        Self {
+            ui,
+            id: id.into(),
+            state,
+            size: [0.0, 0.0],
+            pan_button: MouseButton::Right,
+            zoom_range: (0.1, 10.0),
+            grid: None,
+            background: true,
+        }
+    }
+
+    /// Sets the canvas size.
+    ///
+    /// Zero or negative components fill the available space along that axis.
+    pub fn size(mut self, size: [f32; 2]) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the mouse button that pans the view when dragged
+    pub fn pan_button(mut self, button: MouseButton) -> Self {
+        self.pan_button = button;
+        self
+    }
+
+    /// Sets the allowed zoom range, in pixels per world unit
+    pub fn zoom_range(mut self, min: f32, max: f32) -> Self {
+        self.zoom_range = (min.min(max), min.max(max));
+        self
+    }
+
+    /// Draws grid lines every `step` world units
+    pub fn grid(mut self, step: f32) -> Self {
+        self.grid = (step > 0.0).then_some(step);
+        self
+    }
+
+    /// Sets whether the framed background is drawn
+    pub fn background(mut self, background: bool) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// Reserves the canvas, applies pan/zoom input and calls `f` to draw its contents.
+    ///
+    /// Returns the frame's transform, e.g. for hit-testing after the canvas is drawn.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a [`DrawListMut`](crate::DrawListMut) for the window draw list is alive,
+    /// e.g. when canvases are nested.
+    pub fn build<F>(self, f: F) -> CanvasTransform
+    where
+        F: FnOnce(&CanvasFrame<'_>),
+    {
+        let ui = self.ui;
+        let avail = ui.content_region_avail();
+        let size = [
+            if self.size[0] > 0.0 {
+                self.size[0]
+            } else {
+                avail[0]
+            }
+            .max(1.0),
+            if self.size[1] > 0.0 {
+                self.size[1]
+            } else {
+                avail[1]
+            }
+            .max(1.0),
+        ];
+        let min = ui.cursor_screen_pos();
+        let max = [min[0] + size[0], min[1] + size[1]];
+
+        ui.invisible_button_flags(
+            &self.id,
+            size,
+            ButtonFlags::MOUSE_BUTTON_LEFT
+                | ButtonFlags::MOUSE_BUTTON_RIGHT
+                | ButtonFlags::MOUSE_BUTTON_MIDDLE,
+        );
+        let hovered = ui.is_item_hovered();
+        let active = ui.is_item_active();
+
+        let state = self.state;
+        if active && ui.is_mouse_down(self.pan_button) {
+            let delta = ui.io().mouse_delta();
+            state.offset[0] += delta[0];
+            state.offset[1] += delta[1];
+        }
+        if hovered {
+            // Keep the wheel from also scrolling the parent window
+            unsafe { sys::igSetItemKeyOwner_Nil(sys::ImGuiKey_MouseWheelY) };
+            let wheel = ui.io().mouse_wheel();
+            if wheel != 0.0 {
+                let mouse = ui.io().mouse_pos();
+                let old_zoom = state.zoom;
+                let new_zoom =
+                    (old_zoom * ZOOM_STEP.powf(wheel)).clamp(self.zoom_range.0, self.zoom_range.1);
+                // Keep the world point under the cursor fixed on screen
+                for axis in 0..2 {
+                    let local = mouse[axis] - min[axis];
+                    let world = (local - state.offset[axis]) / old_zoom;
+                    state.offset[axis] = local - world * new_zoom;
+                }
+                state.zoom = new_zoom;
+            }
+        }
+
+        let transform = CanvasTransform {
+            min,
+            max,
+            origin: [min[0] + state.offset[0], min[1] + state.offset[1]],
+            zoom: state.zoom,
+        };
+
+        let draw_list = crate::DrawListMut::window_for_widget(ui, "canvas");
+        draw_list.push_clip_rect(min, max, true);
+        if self.background {
+            draw_list
+                .add_rect(min, max, ui.get_color_u32(StyleColor::FrameBg))
+                .filled(true)
+                .build();
+        }
+        if let Some(step) = self.grid {
+            draw_grid(
+                &draw_list,
+                &transform,
+                step,
+                ui.get_color_u32(StyleColor::Border),
+            );
+        }
+
+        let frame = CanvasFrame {
+            draw_list,
+            transform,
+            hovered,
+            active,
+            mouse_pos: ui.io().mouse_pos(),
+        };
+        f(&frame);
+        frame.draw_list.pop_clip_rect();
+
+        if self.background {
+            frame
+                .draw_list
+                .add_rect(min, max, ui.get_color_u32(StyleColor::Border))
+                .build();
+        }
+        transform
+    }
+}
+
+/// A canvas being drawn, passed to the [`Canvas::build`] closure
+pub struct CanvasFrame<'ui> {
+    draw_list: DrawListMut<'ui>,
+    transform: CanvasTransform,
+    hovered: bool,
+    active: bool,
+    mouse_pos: [f32; 2],
+}
+
+impl<'ui> CanvasFrame<'ui> {
+    /// Window draw list, clipped to the canvas rectangle.
+    ///
+    /// Only one [`DrawListMut`] can exist at a time, so draw through this one rather
+    /// than [`Ui::get_window_draw_list`] while inside the canvas. Use
+    /// [`channels_split`](DrawListMut::channels_split) on it to layer contents.
+    pub fn draw_list(&self) -> &DrawListMut<'ui> {
+        &self.draw_list
+    }
+
+    /// World/screen mapping for this frame
+    pub fn transform(&self) -> CanvasTransform {
+        self.transform
+    }
+
+    /// Returns true if the mouse is over the canvas
+    pub fn is_hovered(&self) -> bool {
+        self.hovered
+    }
+
+    /// Returns true while any mouse button is held on the canvas
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Mouse position in world coordinates, if the canvas is hovered
+    pub fn mouse_world_pos(&self) -> Option<[f32; 2]> {
+        self.hovered
+            .then(|| self.transform.screen_to_world(self.mouse_pos))
+    }
+}
+
+fn draw_grid(draw_list: &DrawListMut<'_>, t: &CanvasTransform, step: f32, color: u32) {
+    let spacing = t.world_to_screen_len(step);
+    if spacing < MIN_GRID_SPACING_PX {
+        return;
+    }
+    let (min, max) = t.rect();
+    for axis in 0..2 {
+        // First line at or before the canvas edge, aligned to the world grid
+        let mut pos =
+            t.origin()[axis] + ((min[axis] - t.origin()[axis]) / spacing).floor() * spacing;
+        while pos < max[axis] {
+            let (a, b) = if axis == 0 {
+                ([pos, min[1]], [pos, max[1]])
+            } else {
+                ([min[0], pos], [max[0], pos])
+            };
+            draw_list.add_line(a, b, color).build();
+            pos += spacing;
+        }
+    }
+}
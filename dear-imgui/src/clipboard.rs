@@ -42,6 +42,28 @@ impl ClipboardContext {
     }
 }
 
+/// Clipboard backend built from a pair of closures, see [`Context::set_clipboard_handlers`]
+///
+/// [`Context::set_clipboard_handlers`]: crate::Context::set_clipboard_handlers
+pub(crate) struct FnClipboardBackend<G, S> {
+    pub(crate) get: G,
+    pub(crate) set: S,
+}
+
+impl<G, S> ClipboardBackend for FnClipboardBackend<G, S>
+where
+    G: FnMut() -> Option<String> + 'static,
+    S: FnMut(&str) + 'static,
+{
+    fn get(&mut self) -> Option<String> {
+        (self.get)()
+    }
+
+    fn set(&mut self, value: &str) {
+        (self.set)(value)
+    }
+}
+
 /// Non-functioning placeholder clipboard backend
 pub struct DummyClipboardBackend;
 
@@ -64,8 +86,12 @@ pub(crate) unsafe extern "C" fn get_clipboard_text(
 
         let ctx = unsafe { &mut *(user_data as *mut ClipboardContext) };
         match ctx.backend.get() {
-            Some(text) => {
-                ctx.last_value = CString::new(text).unwrap();
+            Some(mut text) => {
+                // C strings end at the first nul, so drop anything after it
+                if let Some(nul) = text.find('\0') {
+                    text.truncate(nul);
+                }
+                ctx.last_value = CString::new(text).unwrap_or_default();
                 ctx.last_value.as_ptr()
             }
             None => ptr::null(),
@@ -85,8 +111,8 @@ pub(crate) unsafe extern "C" fn set_clipboard_text(
         let user_data = unsafe { (*crate::sys::igGetPlatformIO_Nil()).Platform_ClipboardUserData };
 
         let ctx = unsafe { &mut *(user_data as *mut ClipboardContext) };
-        let text = unsafe { CStr::from_ptr(text) }.to_owned();
-        ctx.backend.set(text.to_str().unwrap());
+        let text = unsafe { CStr::from_ptr(text) }.to_string_lossy();
+        ctx.backend.set(&text);
     });
     result.unwrap_or_else(|_| {
         eprintln!("Clipboard setter panicked");
@@ -94,6 +120,31 @@ pub(crate) unsafe extern "C" fn set_clipboard_text(
     });
 }
 
+impl crate::Ui {
+    /// Returns the current clipboard contents, or `None` if the clipboard is empty
+    #[doc(alias = "GetClipboardText")]
+    pub fn clipboard_text(&self) -> Option<String> {
+        self.debug_assert_current();
+        let text = unsafe { crate::sys::igGetClipboardText() };
+        if text.is_null() {
+            None
+        } else {
+            Some(
+                unsafe { CStr::from_ptr(text) }
+                    .to_string_lossy()
+                    .into_owned(),
+            )
+        }
+    }
+
+    /// Sets the clipboard contents
+    #[doc(alias = "SetClipboardText")]
+    pub fn set_clipboard_text(&self, text: impl AsRef<str>) {
+        self.debug_assert_current();
+        unsafe { crate::sys::igSetClipboardText(self.scratch_txt(text)) }
+    }
+}
+
 impl fmt::Debug for ClipboardContext {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("ClipboardContext")
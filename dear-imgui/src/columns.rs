@@ -49,6 +49,7 @@ impl Ui {
     /// * `border` - Whether to draw borders between columns
     #[doc(alias = "Columns")]
     pub fn columns(&self, count: i32, id: impl AsRef<str>, border: bool) {
+        self.debug_assert_current();
         unsafe { sys::igColumns(count, self.scratch_txt(id), border) }
     }
 
@@ -60,12 +61,14 @@ impl Ui {
     /// * `flags` - Column flags
     #[doc(alias = "BeginColumns")]
     pub fn begin_columns(&self, id: impl AsRef<str>, count: i32, flags: OldColumnFlags) {
+        self.debug_assert_current();
         unsafe { sys::igBeginColumns(self.scratch_txt(id), count, flags.bits()) }
     }
 
     /// End columns layout.
     #[doc(alias = "EndColumns")]
     pub fn end_columns(&self) {
+        self.debug_assert_current();
         unsafe { sys::igEndColumns() }
     }
 
@@ -74,66 +77,77 @@ impl Ui {
     /// If the current row is finished, switches to first column of the next row
     #[doc(alias = "NextColumn")]
     pub fn next_column(&self) {
+        self.debug_assert_current();
         unsafe { sys::igNextColumn() }
     }
 
     /// Returns the index of the current column
     #[doc(alias = "GetColumnIndex")]
     pub fn current_column_index(&self) -> i32 {
+        self.debug_assert_current();
         unsafe { sys::igGetColumnIndex() }
     }
 
     /// Returns the width of the current column (in pixels)
     #[doc(alias = "GetColumnWidth")]
     pub fn current_column_width(&self) -> f32 {
+        self.debug_assert_current();
         unsafe { sys::igGetColumnWidth(-1) }
     }
 
     /// Returns the width of the given column (in pixels)
     #[doc(alias = "GetColumnWidth")]
     pub fn column_width(&self, column_index: i32) -> f32 {
+        self.debug_assert_current();
         unsafe { sys::igGetColumnWidth(column_index) }
     }
 
     /// Sets the width of the current column (in pixels)
     #[doc(alias = "SetColumnWidth")]
     pub fn set_current_column_width(&self, width: f32) {
+        self.debug_assert_current();
         unsafe { sys::igSetColumnWidth(-1, width) };
     }
 
     /// Sets the width of the given column (in pixels)
     #[doc(alias = "SetColumnWidth")]
     pub fn set_column_width(&self, column_index: i32, width: f32) {
+        self.debug_assert_current();
         unsafe { sys::igSetColumnWidth(column_index, width) };
     }
 
     /// Returns the offset of the current column (in pixels from the left side of the content region)
     #[doc(alias = "GetColumnOffset")]
     pub fn current_column_offset(&self) -> f32 {
+        self.debug_assert_current();
         unsafe { sys::igGetColumnOffset(-1) }
     }
 
     /// Returns the offset of the given column (in pixels from the left side of the content region)
     #[doc(alias = "GetColumnOffset")]
     pub fn column_offset(&self, column_index: i32) -> f32 {
+        self.debug_assert_current();
         unsafe { sys::igGetColumnOffset(column_index) }
     }
 
     /// Sets the offset of the current column (in pixels from the left side of the content region)
     #[doc(alias = "SetColumnOffset")]
     pub fn set_current_column_offset(&self, offset_x: f32) {
+        self.debug_assert_current();
         unsafe { sys::igSetColumnOffset(-1, offset_x) };
     }
 
     /// Sets the offset of the given column (in pixels from the left side of the content region)
     #[doc(alias = "SetColumnOffset")]
     pub fn set_column_offset(&self, column_index: i32, offset_x: f32) {
+        self.debug_assert_current();
         unsafe { sys::igSetColumnOffset(column_index, offset_x) };
     }
 
     /// Returns the current amount of columns
     #[doc(alias = "GetColumnsCount")]
     pub fn column_count(&self) -> i32 {
+        self.debug_assert_current();
         unsafe { sys::igGetColumnsCount() }
     }
 
@@ -145,24 +159,28 @@ impl Ui {
     /// This is useful for custom drawing within columns.
     #[doc(alias = "PushColumnClipRect")]
     pub fn push_column_clip_rect(&self, column_index: i32) {
+        self.debug_assert_current();
         unsafe { sys::igPushColumnClipRect(column_index) }
     }
 
     /// Push columns background for drawing.
     #[doc(alias = "PushColumnsBackground")]
     pub fn push_columns_background(&self) {
+        self.debug_assert_current();
         unsafe { sys::igPushColumnsBackground() }
     }
 
     /// Pop columns background.
     #[doc(alias = "PopColumnsBackground")]
     pub fn pop_columns_background(&self) {
+        self.debug_assert_current();
         unsafe { sys::igPopColumnsBackground() }
     }
 
     /// Get columns ID for the given string ID and count.
     #[doc(alias = "GetColumnsID")]
     pub fn get_columns_id(&self, str_id: impl AsRef<str>, count: i32) -> u32 {
+        self.debug_assert_current();
         unsafe { sys::igGetColumnsID(self.scratch_txt(str_id), count) }
     }
 
@@ -173,6 +191,7 @@ impl Ui {
     /// Check if any column is being resized.
     /// Note: This is a placeholder implementation as the underlying C++ function is not available
     pub fn is_any_column_resizing(&self) -> bool {
+        self.debug_assert_current();
         // TODO: Implement when the proper C++ binding is available
         // The ImGui_GetCurrentWindow function is not available in our bindings
         false
@@ -180,6 +199,7 @@ impl Ui {
 
     /// Get the total width of all columns.
     pub fn get_columns_total_width(&self) -> f32 {
+        self.debug_assert_current();
         let count = self.column_count();
         if count <= 0 {
             return 0.0;
@@ -194,6 +214,7 @@ impl Ui {
 
     /// Set all columns to equal width.
     pub fn set_columns_equal_width(&self) {
+        self.debug_assert_current();
         let count = self.column_count();
         if count <= 1 {
             return;
@@ -209,6 +230,7 @@ impl Ui {
 
     /// Get column width as a percentage of total width.
     pub fn get_column_width_percentage(&self, column_index: i32) -> f32 {
+        self.debug_assert_current();
         let total_width = self.get_columns_total_width();
         if total_width <= 0.0 {
             return 0.0;
@@ -220,6 +242,7 @@ impl Ui {
 
     /// Set column width as a percentage of total width.
     pub fn set_column_width_percentage(&self, column_index: i32, percentage: f32) {
+        self.debug_assert_current();
         let total_width = self.get_columns_total_width();
         if total_width <= 0.0 {
             return;
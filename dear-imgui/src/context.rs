@@ -7,7 +7,7 @@
 //!
 use parking_lot::ReentrantMutex;
 use std::cell::UnsafeCell;
-use std::ffi::CString;
+use std::ffi::{CString, c_void};
 use std::ops::Drop;
 use std::path::PathBuf;
 use std::ptr;
@@ -27,6 +27,10 @@ use crate::sys;
 /// If you need more than one context, you can use suspended contexts. As long as only one context
 /// is active at a time, it's possible to have multiple independent contexts.
 ///
+/// Alternatively, detach the current context with [`Context::clear_current`], create the next one,
+/// and switch between them explicitly with [`Context::set_current`]. In debug builds, using a
+/// context's `Ui` while another context is current panics instead of drawing into the wrong one.
+///
 /// # Examples
 ///
 /// Creating a new active context:
@@ -57,12 +61,16 @@ pub struct Context {
     // imgui a mutable pointer to it.
     clipboard_ctx: Box<UnsafeCell<ClipboardContext>>,
     ui: crate::ui::Ui,
+    redraw: RedrawTracker,
 }
 
 // This mutex needs to be used to guard all public functions that can affect the underlying
 // Dear ImGui active context
 static CTX_MUTEX: ReentrantMutex<()> = parking_lot::const_reentrant_mutex(());
 
+/// Frames rendered after input by default, see [`Context::set_redraw_frames_after_input`]
+const DEFAULT_REDRAW_FRAMES_AFTER_INPUT: u32 = 3;
+
 fn clear_current_context() {
     unsafe {
         sys::igSetCurrentContext(ptr::null_mut());
@@ -102,6 +110,14 @@ impl Context {
             .expect("Failed to create Dear ImGui context")
     }
 
+    /// Returns a builder for configuring a context before it is created.
+    ///
+    /// Use this when the allocator, ini/log filenames or config flags must be in place
+    /// before the first frame; otherwise [`Context::create`] is enough.
+    pub fn builder() -> ContextBuilder {
+        ContextBuilder::default()
+    }
+
     // removed legacy create_or_panic variants (use create()/try_create())
 
     fn try_create_internal(
@@ -139,7 +155,8 @@ impl Context {
             platform_name: None,
             renderer_name: None,
             clipboard_ctx: Box::new(UnsafeCell::new(ClipboardContext::dummy())),
-            ui: crate::ui::Ui::new(),
+            ui: crate::ui::Ui::new(raw),
+            redraw: RedrawTracker::new(),
         })
     }
 
@@ -184,10 +201,12 @@ impl Context {
     /// Creates a new frame and returns a Ui object for building the interface
     pub fn frame(&mut self) -> &mut crate::ui::Ui {
         let _guard = CTX_MUTEX.lock();
+        self.ui.debug_assert_current();
 
         unsafe {
             sys::igNewFrame();
         }
+        self.redraw.new_frame();
         &mut self.ui
     }
 
@@ -206,12 +225,34 @@ impl Context {
     /// The returned draw data contains all the information needed to render the frame.
     pub fn render(&mut self) -> &crate::render::DrawData {
         let _guard = CTX_MUTEX.lock();
+        self.ui.debug_assert_current();
         unsafe {
             sys::igRender();
             &*(sys::igGetDrawData() as *const crate::render::DrawData)
         }
     }
 
+    /// Renders the frame through a [`NullRenderer`](crate::render::NullRenderer)
+    ///
+    /// Finalizes the frame like [`Context::render`], acknowledges texture requests
+    /// without a GPU and returns the draw data for inspection. The output covers
+    /// `io.display_size()`. Create the context with [`ContextBuilder::headless`] (and
+    /// [`ContextBuilder::display_size`]) so the renderer flags are set before the first
+    /// frame.
+    pub fn render_headless(&mut self) -> &crate::render::DrawData {
+        use crate::ImGuiRenderer;
+
+        debug_assert!(
+            self.io()
+                .backend_flags()
+                .contains(crate::render::NullRenderer::BACKEND_FLAGS),
+            "render_headless needs a context created with ContextBuilder::headless()"
+        );
+        let draw_data = self.render();
+        let Ok(()) = crate::render::NullRenderer.render(draw_data);
+        draw_data
+    }
+
     /// Gets the draw data for the current frame
     ///
     /// This returns the draw data without calling render. Only valid after
@@ -231,6 +272,11 @@ impl Context {
 
     /// Sets the INI filename for settings persistence
     ///
+    /// Passing `None` disables Dear ImGui's automatic reads and writes of the settings
+    /// file. Persist the settings yourself with [`Context::save_ini_settings`] and
+    /// [`Context::load_ini_settings`], polling [`Io::want_save_ini_settings`] to know when
+    /// they changed.
+    ///
     /// # Errors
     ///
     /// Returns an error if the filename contains null bytes
@@ -370,35 +416,68 @@ impl Context {
         crate::viewport_backend::utils::enable_viewport_flags(self.io_mut());
     }
 
-    /// Update platform windows
+    /// Updates the OS windows backing secondary viewports
+    ///
+    /// Call once per frame when `ConfigFlags::VIEWPORTS_ENABLE` is set, after
+    /// [`render`](Self::render). It creates, moves, resizes and destroys platform windows
+    /// through the [`PlatformIo`](crate::platform_io::PlatformIo) callbacks, which the
+    /// platform and renderer backends must have installed. Without it, windows dragged
+    /// outside the main viewport never get an OS window.
+    ///
+    /// The per-frame order is:
     ///
-    /// This function should be called every frame when multi-viewport is enabled.
-    /// It updates all platform windows and handles viewport management.
+    /// 1. [`frame`](Self::frame), build the UI, then [`render`](Self::render)
+    /// 2. Render the main viewport's draw data with the renderer backend
+    /// 3. `update_platform_windows()`
+    /// 4. [`render_platform_windows_default`](Self::render_platform_windows_default), or a
+    ///    backend's own loop over the secondary viewports
+    /// 5. Present/swap the main window
+    ///
+    /// Backends whose swap chains do not tolerate it may present the main window before
+    /// step 3 instead.
+    #[doc(alias = "UpdatePlatformWindows")]
     #[cfg(feature = "multi-viewport")]
     pub fn update_platform_windows(&mut self) {
         let _guard = CTX_MUTEX.lock();
         unsafe {
-            // Ensure main viewport is properly set up before updating platform windows
-            let main_viewport = sys::igGetMainViewport();
-            if !main_viewport.is_null() && (*main_viewport).PlatformHandle.is_null() {
-                eprintln!("update_platform_windows: main viewport not set up, setting it up now");
-                // The main viewport needs to be set up - this should be done by the backend
-                // For now, we'll just log this and continue
-            }
-
             sys::igUpdatePlatformWindows();
         }
     }
 
-    /// Render platform windows with default implementation
+    /// Renders and presents every secondary viewport
     ///
-    /// This function renders all platform windows using the default implementation.
-    /// It calls the platform and renderer backends to render each viewport.
+    /// Calls the `Platform_RenderWindow`, `Renderer_RenderWindow`, `Platform_SwapBuffers` and
+    /// `Renderer_SwapBuffers` callbacks for each visible secondary viewport. Must follow
+    /// [`update_platform_windows`](Self::update_platform_windows) in the same frame.
+    #[doc(alias = "RenderPlatformWindowsDefault")]
     #[cfg(feature = "multi-viewport")]
     pub fn render_platform_windows_default(&mut self) {
+        unsafe {
+            self.render_platform_windows_default_with_args(
+                std::ptr::null_mut(),
+                std::ptr::null_mut(),
+            );
+        }
+    }
+
+    /// Like [`render_platform_windows_default`](Self::render_platform_windows_default), but
+    /// forwards `platform_arg` to the platform callbacks and `renderer_arg` to the renderer
+    /// callbacks, so backends can route per-frame state (e.g. a command encoder) to them.
+    ///
+    /// # Safety
+    ///
+    /// The arguments must be what the installed callbacks expect, and stay valid until
+    /// this call returns.
+    #[doc(alias = "RenderPlatformWindowsDefault")]
+    #[cfg(feature = "multi-viewport")]
+    pub unsafe fn render_platform_windows_default_with_args(
+        &mut self,
+        platform_arg: *mut std::ffi::c_void,
+        renderer_arg: *mut std::ffi::c_void,
+    ) {
         let _guard = CTX_MUTEX.lock();
         unsafe {
-            sys::igRenderPlatformWindowsDefault(std::ptr::null_mut(), std::ptr::null_mut());
+            sys::igRenderPlatformWindowsDefault(platform_arg, renderer_arg);
         }
     }
 
@@ -430,6 +509,33 @@ impl Context {
         self.raw == ctx
     }
 
+    /// Makes this context the current Dear ImGui context.
+    ///
+    /// All subsequent Dear ImGui calls (including those made through this context's `Ui`)
+    /// target this context until another one is made current. In debug builds, calling a
+    /// method on a `Ui` whose context is not current panics.
+    #[doc(alias = "SetCurrentContext")]
+    pub fn set_current(&self) {
+        let _guard = CTX_MUTEX.lock();
+        unsafe {
+            sys::igSetCurrentContext(self.raw);
+        }
+    }
+
+    /// Returns `true` if this context is the current Dear ImGui context
+    pub fn is_current(&self) -> bool {
+        let _guard = CTX_MUTEX.lock();
+        self.is_current_context()
+    }
+
+    /// Detaches whichever context is current, so that another one can be created.
+    ///
+    /// Existing contexts are kept alive; re-attach one with [`Context::set_current`].
+    pub fn clear_current() {
+        let _guard = CTX_MUTEX.lock();
+        clear_current_context();
+    }
+
     /// Push a font onto the font stack
     pub fn push_font(&mut self, font: &Font) {
         let _guard = CTX_MUTEX.lock();
@@ -477,13 +583,15 @@ impl Context {
     pub fn font_atlas_mut(&mut self) -> FontAtlas {
         let _guard = CTX_MUTEX.lock();
 
-        // For WASM, return a null atlas to avoid pointer issues
-        #[cfg(target_arch = "wasm32")]
+        // Import-style WASM (wasm32-unknown-unknown + provider module): return a null atlas to
+        // avoid pointer issues. Emscripten links cimgui into the same module, so the real
+        // atlas pointer is valid there.
+        #[cfg(all(target_arch = "wasm32", not(target_os = "emscripten")))]
         {
             return unsafe { FontAtlas::from_raw(ptr::null_mut()) };
         }
 
-        #[cfg(not(target_arch = "wasm32"))]
+        #[cfg(any(not(target_arch = "wasm32"), target_os = "emscripten"))]
         unsafe {
             let io = sys::igGetIO_Nil();
             let atlas_ptr = (*io).Fonts;
@@ -504,6 +612,9 @@ impl Context {
     }
 
     /// Loads settings from a string slice containing settings in .Ini file format
+    ///
+    /// Call this after creating the context and before the first frame, so windows pick
+    /// up their saved position and size when they are first created.
     #[doc(alias = "LoadIniSettingsFromMemory")]
     pub fn load_ini_settings(&mut self, data: &str) {
         let _guard = CTX_MUTEX.lock();
@@ -513,6 +624,11 @@ impl Context {
     }
 
     /// Saves settings to a mutable string buffer in .Ini file format
+    ///
+    /// When no INI filename is set, Dear ImGui raises [`Io::want_save_ini_settings`] once
+    /// settings have been dirty for [`Io::ini_saving_rate`] seconds instead of writing a
+    /// file. Saving clears that flag, so the usual pattern is to check it after each frame
+    /// and save when it is set.
     #[doc(alias = "SaveIniSettingsToMemory")]
     pub fn save_ini_settings(&mut self, buf: &mut String) {
         let _guard = CTX_MUTEX.lock();
@@ -525,7 +641,21 @@ impl Context {
         }
     }
 
+    /// Saves settings in .Ini file format into a new string
+    ///
+    /// See [`Context::save_ini_settings`].
+    #[doc(alias = "SaveIniSettingsToMemory")]
+    pub fn save_ini_settings_to_string(&mut self) -> String {
+        let mut buf = String::new();
+        self.save_ini_settings(&mut buf);
+        buf
+    }
+
     /// Sets the clipboard backend used for clipboard operations
+    ///
+    /// Until a backend is set, Dear ImGui's built-in clipboard is used: the OS clipboard
+    /// on Windows and an in-process buffer elsewhere. The text returned by the backend is
+    /// kept alive by the context until the next clipboard read.
     pub fn set_clipboard_backend<T: ClipboardBackend>(&mut self, backend: T) {
         let clipboard_ctx: Box<UnsafeCell<_>> =
             Box::new(UnsafeCell::new(ClipboardContext::new(backend)));
@@ -540,6 +670,104 @@ impl Context {
 
         self.clipboard_ctx = clipboard_ctx;
     }
+
+    /// Sets clipboard handlers from a getter and a setter closure
+    ///
+    /// Shorthand for [`Context::set_clipboard_backend`] when a full
+    /// [`ClipboardBackend`] type is not needed, e.g. to route the clipboard through a
+    /// browser API on WASM or through a buffer in headless tests.
+    pub fn set_clipboard_handlers<G, S>(&mut self, get: G, set: S)
+    where
+        G: FnMut() -> Option<String> + 'static,
+        S: FnMut(&str) + 'static,
+    {
+        self.set_clipboard_backend(crate::clipboard::FnClipboardBackend { get, set });
+    }
+}
+
+/// # Idle / redraw heuristic
+impl Context {
+    /// Returns true if the UI may look different on the next frame.
+    ///
+    /// Lets event-driven applications stop rendering while nothing happens: after each
+    /// rendered frame, keep the loop running only while this returns true, and otherwise
+    /// sleep until the next OS event. Dear ImGui does not track this itself, so this is a
+    /// **heuristic**. It returns true when:
+    ///
+    /// - fewer than [`set_redraw_frames_after_input`](Self::set_redraw_frames_after_input)
+    ///   frames have passed since a frame received input (mouse movement, buttons, wheel,
+    ///   keys, text) or since [`request_redraw`](Self::request_redraw), giving layout
+    ///   changes such as window auto-fit or popups opening time to settle
+    /// - an item is active (a drag in progress, a text field with a blinking cursor)
+    /// - an item is hovered and its tooltip delay (`style.hover_delay_normal`) has not
+    ///   elapsed since the last input
+    ///
+    /// Input that arrives between frames is only seen by the next frame, so any OS event
+    /// should still trigger a frame. Anything that changes on its own (application data,
+    /// custom animations, [`Ui::time`](crate::Ui::time)-driven widgets such as spinners)
+    /// is invisible to the heuristic: call [`request_redraw`](Self::request_redraw).
+    pub fn wants_redraw(&self) -> bool {
+        let _guard = CTX_MUTEX.lock();
+        if self.redraw.frames_left > 0 {
+            return true;
+        }
+        unsafe {
+            let since_input = sys::igGetTime() - self.redraw.last_input_time;
+            let hover_delay = (*sys::igGetStyle()).HoverDelayNormal as f64;
+            sys::igIsAnyItemActive() || (sys::igIsAnyItemHovered() && since_input < hover_delay)
+        }
+    }
+
+    /// Forces [`wants_redraw`](Self::wants_redraw) to return true for the next `frames`
+    /// frames, e.g. after application data shown in the UI changed.
+    pub fn request_redraw(&mut self, frames: u32) {
+        self.redraw.frames_left = self.redraw.frames_left.max(frames);
+    }
+
+    /// Sets how many frames [`wants_redraw`](Self::wants_redraw) keeps returning true after
+    /// a frame that received input (default: 3).
+    pub fn set_redraw_frames_after_input(&mut self, frames: u32) {
+        self.redraw.frames_after_input = frames;
+    }
+}
+
+/// Input activity bookkeeping behind [`Context::wants_redraw`]
+#[derive(Debug)]
+struct RedrawTracker {
+    frames_after_input: u32,
+    frames_left: u32,
+    last_input_time: f64,
+    last_mouse_pos: [f32; 2],
+}
+
+impl RedrawTracker {
+    fn new() -> Self {
+        Self {
+            frames_after_input: DEFAULT_REDRAW_FRAMES_AFTER_INPUT,
+            frames_left: DEFAULT_REDRAW_FRAMES_AFTER_INPUT,
+            last_input_time: 0.0,
+            last_mouse_pos: [f32::MIN; 2],
+        }
+    }
+
+    /// Updates the counters from the input processed by the frame that just started
+    fn new_frame(&mut self) {
+        let io = unsafe { &*sys::igGetIO_Nil() };
+        let mouse_pos = [io.MousePos.x, io.MousePos.y];
+        let had_input = mouse_pos != self.last_mouse_pos
+            || io.MouseDown.iter().any(|&down| down)
+            || io.MouseWheel != 0.0
+            || io.MouseWheelH != 0.0
+            || io.KeysData.iter().any(|key| key.Down)
+            || io.InputQueueCharacters.Size > 0;
+        self.last_mouse_pos = mouse_pos;
+        if had_input {
+            self.frames_left = self.frames_after_input;
+            self.last_input_time = unsafe { sys::igGetTime() };
+        } else {
+            self.frames_left = self.frames_left.saturating_sub(1);
+        }
+    }
 }
 
 impl Drop for Context {
@@ -556,6 +784,144 @@ impl Drop for Context {
     }
 }
 
+/// Allocation callback passed to `igSetAllocatorFunctions`
+pub type AllocFn = unsafe extern "C" fn(size: usize, user_data: *mut c_void) -> *mut c_void;
+
+/// Deallocation callback passed to `igSetAllocatorFunctions`
+pub type FreeFn = unsafe extern "C" fn(ptr: *mut c_void, user_data: *mut c_void);
+
+/// Configures a [`Context`] before `CreateContext` is called.
+///
+/// # Examples
+///
+/// ```
+/// use dear_imgui_rs::{ConfigFlags, Context};
+///
+/// let ctx = Context::builder()
+///     .no_ini()
+///     .config_flags(ConfigFlags::NAV_ENABLE_KEYBOARD)
+///     .build()
+///     .unwrap();
+/// assert!(ctx.io().config_flags().contains(ConfigFlags::NAV_ENABLE_KEYBOARD));
+/// ```
+#[derive(Debug, Default)]
+pub struct ContextBuilder {
+    shared_font_atlas: Option<SharedFontAtlas>,
+    allocator: Option<(AllocFn, FreeFn, *mut c_void)>,
+    ini_filename: Option<Option<PathBuf>>,
+    log_filename: Option<Option<PathBuf>>,
+    config_flags: Option<crate::io::ConfigFlags>,
+    display_size: Option<[f32; 2]>,
+    headless: bool,
+}
+
+impl ContextBuilder {
+    /// Shares an existing font atlas with the new context
+    pub fn shared_font_atlas(mut self, shared_font_atlas: SharedFontAtlas) -> Self {
+        self.shared_font_atlas = Some(shared_font_atlas);
+        self
+    }
+
+    /// Installs custom allocator functions before the context is created.
+    ///
+    /// Dear ImGui keeps a single, process-wide allocator, so this affects every context
+    /// created afterwards and all memory Dear ImGui allocates from then on.
+    ///
+    /// # Safety
+    ///
+    /// - `alloc` and `free` must be `extern "C"` functions that stay valid for as long as
+    ///   any context (and any memory allocated through them) is alive.
+    /// - `user_data` is passed verbatim to both functions and must outlive the context.
+    /// - Memory returned by `alloc` must be releasable by `free`. Do not switch allocators
+    ///   while a context created with a different allocator is still alive.
+    pub unsafe fn allocator(
+        mut self,
+        alloc: AllocFn,
+        free: FreeFn,
+        user_data: *mut c_void,
+    ) -> Self {
+        self.allocator = Some((alloc, free, user_data));
+        self
+    }
+
+    /// Sets the INI filename, or disables settings persistence with `None`
+    pub fn ini_filename<P: Into<PathBuf>>(mut self, filename: Option<P>) -> Self {
+        self.ini_filename = Some(filename.map(Into::into));
+        self
+    }
+
+    /// Disables loading and saving `imgui.ini`
+    pub fn no_ini(self) -> Self {
+        self.ini_filename(None::<PathBuf>)
+    }
+
+    /// Sets the log filename used by `LogToFile`, or clears it with `None`
+    pub fn log_filename<P: Into<PathBuf>>(mut self, filename: Option<P>) -> Self {
+        self.log_filename = Some(filename.map(Into::into));
+        self
+    }
+
+    /// Sets the initial configuration flags
+    pub fn config_flags(mut self, flags: crate::io::ConfigFlags) -> Self {
+        self.config_flags = Some(flags);
+        self
+    }
+
+    /// Sets the initial display size, e.g. the output size of a headless context
+    pub fn display_size(mut self, size: [f32; 2]) -> Self {
+        self.display_size = Some(size);
+        self
+    }
+
+    /// Reports a [`NullRenderer`](crate::render::NullRenderer) as the renderer backend
+    ///
+    /// Sets the renderer backend flags before the first frame, as Dear ImGui requires, so
+    /// frames can be finished with [`Context::render_headless`] without a GPU.
+    pub fn headless(mut self) -> Self {
+        self.headless = true;
+        self
+    }
+
+    /// Creates the context and makes it current.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if another context is already active, creation fails, or a
+    /// filename contains null bytes.
+    pub fn build(self) -> crate::error::ImGuiResult<Context> {
+        let _guard = CTX_MUTEX.lock();
+
+        // Changing the allocator under a live context would free its memory with the wrong
+        // functions, so bail out before touching it.
+        if !no_current_context() {
+            return Err(crate::error::ImGuiError::ContextAlreadyActive);
+        }
+
+        if let Some((alloc, free, user_data)) = self.allocator {
+            unsafe { sys::igSetAllocatorFunctions(Some(alloc), Some(free), user_data) };
+        }
+
+        let mut ctx = Context::try_create_internal(self.shared_font_atlas)?;
+        if let Some(filename) = self.ini_filename {
+            ctx.set_ini_filename(filename)?;
+        }
+        if let Some(filename) = self.log_filename {
+            ctx.set_log_filename(filename)?;
+        }
+        if let Some(flags) = self.config_flags {
+            ctx.io_mut().set_config_flags(flags);
+        }
+        if let Some(size) = self.display_size {
+            ctx.io_mut().set_display_size(size);
+        }
+        if self.headless {
+            let io = ctx.io_mut();
+            io.set_backend_flags(io.backend_flags() | crate::render::NullRenderer::BACKEND_FLAGS);
+        }
+        Ok(ctx)
+    }
+}
+
 /// A suspended Dear ImGui context
 ///
 /// A suspended context retains its state, but is not usable without activating it first.
@@ -613,7 +979,8 @@ impl SuspendedContext {
             platform_name: None,
             renderer_name: None,
             clipboard_ctx: Box::new(UnsafeCell::new(ClipboardContext::dummy())),
-            ui: crate::ui::Ui::new(),
+            ui: crate::ui::Ui::new(raw),
+            redraw: RedrawTracker::new(),
         };
 
         // If the context was activated during creation, deactivate it
@@ -628,6 +995,7 @@ impl SuspendedContext {
     ///
     /// If there is no active context, this suspended context is activated and `Ok` is returned.
     /// If there is already an active context, nothing happens and `Err` is returned.
+    #[allow(clippy::result_large_err)]
     pub fn activate(self) -> Result<Context, SuspendedContext> {
         let _guard = CTX_MUTEX.lock();
         if no_current_context() {
@@ -8,6 +8,12 @@
 //!
 //! Docking is always enabled in this crate; no feature flag required.
 //!
+//! Build the layout before submitting the dockspace and the windows it docks, i.e. before
+//! `Ui::dock_space*` and outside those windows' `Begin`/`End`, typically only when
+//! [`DockBuilder::node_exists`] reports the dockspace node missing. This keeps the layout
+//! as the first-run default while letting users rearrange it (and the ini file persist
+//! it) afterwards.
+//!
 //! # Basic Usage
 //!
 //! ```no_run
@@ -16,7 +22,8 @@
 //! # let ui = ctx.frame();
 //! // Create a dockspace
 //! let dockspace_id = ui.get_id("MyDockspace");
-//! DockBuilder::add_node(dockspace_id, DockNodeFlags::NONE);
+//! DockBuilder::add_node(dockspace_id, DockNodeFlags::DOCK_SPACE);
+//! DockBuilder::set_node_size(dockspace_id, [800.0, 600.0]);
 //!
 //! // Split the dockspace: 30% left panel, 70% remaining
 //! let (left_panel, main_area) = DockBuilder::split_node(
@@ -217,6 +224,10 @@ impl DockBuilder {
 
     /// Adds a new dock node
     ///
+    /// Pass [`DockNodeFlags::DOCK_SPACE`](crate::DockNodeFlags::DOCK_SPACE) when the node is
+    /// the root of a dockspace layout, then size it with [`set_node_size`](Self::set_node_size)
+    /// before splitting it.
+    ///
     /// # Parameters
     ///
     /// * `node_id` - The ID for the new dock node (use 0 to auto-generate)
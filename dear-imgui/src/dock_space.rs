@@ -54,6 +54,12 @@ bitflags::bitflags! {
         const AUTO_HIDE_TAB_BAR = sys::ImGuiDockNodeFlags_AutoHideTabBar as i32;
         /// Disable undocking this node.
         const NO_UNDOCKING = sys::ImGuiDockNodeFlags_NoUndocking as i32;
+        /// The node is a dockspace rather than a floating dock tree.
+        ///
+        /// Only meant for [`DockBuilder::add_node`](crate::DockBuilder::add_node), so the node
+        /// behaves as the dockspace later submitted with the same ID; do not pass it to
+        /// [`Ui::dock_space_with_class`].
+        const DOCK_SPACE = sys::ImGuiDockNodeFlags_DockSpace as i32;
     }
 }
 
@@ -166,6 +172,7 @@ impl Ui {
         dockspace_id: Id,
         flags: DockNodeFlags,
     ) -> Id {
+        self.debug_assert_current();
         unsafe {
             Id::from(sys::igDockSpaceOverViewport(
                 dockspace_id.into(),
@@ -176,6 +183,49 @@ impl Ui {
         }
     }
 
+    /// Creates a dockspace covering the given viewport
+    ///
+    /// Like [`dockspace_over_main_viewport_with_flags`](Self::dockspace_over_main_viewport_with_flags),
+    /// but for any viewport, e.g. a secondary platform window when multi-viewports are enabled.
+    ///
+    /// # Parameters
+    ///
+    /// * `dockspace_id` - The ID for the dockspace (use 0 to auto-generate)
+    /// * `viewport` - The viewport to cover
+    /// * `flags` - Dock node flags
+    ///
+    /// # Returns
+    ///
+    /// The ID of the created dockspace
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// let viewport = ui.main_viewport();
+    /// let dockspace_id =
+    ///     ui.dockspace_over_viewport(0, viewport, DockNodeFlags::PASSTHRU_CENTRAL_NODE);
+    /// ```
+    #[doc(alias = "DockSpaceOverViewport")]
+    pub fn dockspace_over_viewport(
+        &self,
+        dockspace_id: Id,
+        viewport: &crate::platform_io::Viewport,
+        flags: DockNodeFlags,
+    ) -> Id {
+        self.debug_assert_current();
+        unsafe {
+            Id::from(sys::igDockSpaceOverViewport(
+                dockspace_id.into(),
+                viewport.as_raw(),
+                flags.bits(),
+                ptr::null(),
+            ))
+        }
+    }
+
     /// Creates a dockspace over the main viewport with default settings
     ///
     /// This is a convenience function that creates a dockspace covering the entire main viewport
@@ -195,6 +245,7 @@ impl Ui {
     /// ```
     #[doc(alias = "DockSpaceOverViewport")]
     pub fn dockspace_over_main_viewport(&self) -> Id {
+        self.debug_assert_current();
         self.dockspace_over_main_viewport_with_flags(
             Id::from(0u32),
             DockNodeFlags::PASSTHRU_CENTRAL_NODE,
@@ -235,17 +286,17 @@ impl Ui {
         flags: DockNodeFlags,
         window_class: Option<&WindowClass>,
     ) -> Id {
+        self.debug_assert_current();
         unsafe {
             let size_vec = sys::ImVec2 {
                 x: size[0],
                 y: size[1],
             };
-            let window_class_ptr = if let Some(wc) = window_class {
-                let imgui_wc = wc.to_imgui();
-                &imgui_wc as *const _
-            } else {
-                ptr::null()
-            };
+            // Keep the converted class alive until DockSpace has copied it
+            let imgui_wc = window_class.map(WindowClass::to_imgui);
+            let window_class_ptr = imgui_wc
+                .as_ref()
+                .map_or(ptr::null(), |wc| wc as *const sys::ImGuiWindowClass);
             Id::from(sys::igDockSpace(
                 id.into(),
                 size_vec,
@@ -276,9 +327,39 @@ impl Ui {
     /// ```
     #[doc(alias = "DockSpace")]
     pub fn dock_space(&self, id: Id, size: [f32; 2]) -> Id {
+        self.debug_assert_current();
         self.dock_space_with_class(id, size, DockNodeFlags::NONE, None)
     }
 
+    /// Creates a dockspace with the specified ID, size and flags
+    ///
+    /// With [`DockNodeFlags::PASSTHRU_CENTRAL_NODE`], an empty central node is left
+    /// transparent and lets inputs through, so content drawn behind the dockspace (e.g. a 3D
+    /// viewport) stays visible and interactive.
+    ///
+    /// # Returns
+    ///
+    /// The ID of the created dockspace, usable with [`DockBuilder`](crate::DockBuilder)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// let id = ui.get_id("Workspace");
+    /// ui.dock_space_with_flags(
+    ///     id,
+    ///     [800.0, 600.0],
+    ///     DockNodeFlags::PASSTHRU_CENTRAL_NODE | DockNodeFlags::AUTO_HIDE_TAB_BAR,
+    /// );
+    /// ```
+    #[doc(alias = "DockSpace")]
+    pub fn dock_space_with_flags(&self, id: Id, size: [f32; 2], flags: DockNodeFlags) -> Id {
+        self.debug_assert_current();
+        self.dock_space_with_class(id, size, flags, None)
+    }
+
     /// Sets the dock ID for the next window with condition
     ///
     /// This function must be called before creating a window to dock it to a specific dock node.
@@ -302,6 +383,7 @@ impl Ui {
     /// ```
     #[doc(alias = "SetNextWindowDockID")]
     pub fn set_next_window_dock_id_with_cond(&self, dock_id: Id, cond: crate::Condition) {
+        self.debug_assert_current();
         unsafe {
             sys::igSetNextWindowDockID(dock_id.into(), cond as i32);
         }
@@ -330,6 +412,7 @@ impl Ui {
     /// ```
     #[doc(alias = "SetNextWindowDockID")]
     pub fn set_next_window_dock_id(&self, dock_id: Id) {
+        self.debug_assert_current();
         self.set_next_window_dock_id_with_cond(dock_id, crate::Condition::Always)
     }
 
@@ -355,6 +438,7 @@ impl Ui {
     /// ```
     #[doc(alias = "SetNextWindowClass")]
     pub fn set_next_window_class(&self, window_class: &WindowClass) {
+        self.debug_assert_current();
         unsafe {
             let imgui_wc = window_class.to_imgui();
             sys::igSetNextWindowClass(&imgui_wc as *const _);
@@ -384,6 +468,7 @@ impl Ui {
     /// ```
     #[doc(alias = "GetWindowDockID")]
     pub fn get_window_dock_id(&self) -> Id {
+        self.debug_assert_current();
         unsafe { Id::from(sys::igGetWindowDockID()) }
     }
 
@@ -409,6 +494,7 @@ impl Ui {
     /// ```
     #[doc(alias = "IsWindowDocked")]
     pub fn is_window_docked(&self) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsWindowDocked() }
     }
 }
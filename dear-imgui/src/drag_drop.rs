@@ -91,6 +91,7 @@ impl Ui {
     /// }
     /// ```
     pub fn drag_drop_source_config<T: AsRef<str>>(&self, name: T) -> DragDropSource<'_, T> {
+        self.debug_assert_current();
         DragDropSource {
             name,
             flags: DragDropFlags::NONE,
@@ -119,6 +120,7 @@ impl Ui {
     /// ```
     #[doc(alias = "BeginDragDropTarget")]
     pub fn drag_drop_target(&self) -> Option<DragDropTarget<'_>> {
+        self.debug_assert_current();
         let should_begin = unsafe { sys::igBeginDragDropTarget() };
         if should_begin {
             Some(DragDropTarget(self))
@@ -266,15 +266,16 @@ impl Iterator for DrawCmdIterator<'_> {
                     cmd.ClipRect.z,
                     cmd.ClipRect.w,
                 ],
-                texture_id: TextureId::from(unsafe {
-                    sys::ImDrawCmd_GetTexID(cmd as *const _ as *mut sys::ImDrawCmd)
-                }),
+                texture_id: crate::render::draw_data::effective_texture_id(cmd),
                 vtx_offset: cmd.VtxOffset as usize,
                 idx_offset: cmd.IdxOffset as usize,
             };
 
             match cmd.UserCallback {
-                Some(raw_callback) if raw_callback as usize == usize::MAX => {
+                Some(raw_callback)
+                    if raw_callback as usize
+                        == crate::render::draw_data::RESET_RENDER_STATE as usize =>
+                {
                     DrawCmd::ResetRenderState
                 }
                 Some(raw_callback) => DrawCmd::RawCallback {
@@ -392,25 +393,104 @@ impl DrawListMut<'_> {
         }
     }
 
-    pub(crate) fn background(_ui: &crate::Ui) -> Self {
+    /// Window draw list for a custom widget that draws itself, such as [`Ui::spinner`]
+    ///
+    /// Panics with a message naming `widget` if the caller still holds the window draw
+    /// list, e.g. when the widget is submitted inside a [`Ui::canvas`] closure.
+    ///
+    /// [`Ui::spinner`]: crate::Ui::spinner
+    /// [`Ui::canvas`]: crate::Ui::canvas
+    pub(crate) fn window_for_widget(ui: &crate::Ui, widget: &str) -> Self {
+        assert!(
+            !DRAW_LIST_LOADED_WINDOW.load(std::sync::atomic::Ordering::Acquire),
+            "`{widget}` draws with the window draw list, which is already held by a DrawListMut; \
+             drop it (or leave the canvas closure) before submitting the widget"
+        );
+        Self::window(ui)
+    }
+
+    /// Background draw list of `viewport`, or of the main viewport when null
+    pub(crate) fn background(_ui: &crate::Ui, viewport: *mut sys::ImGuiViewport) -> Self {
         Self::lock_draw_list(DrawListType::Background);
         Self {
-            draw_list: unsafe { sys::igGetBackgroundDrawList(std::ptr::null_mut()) },
+            draw_list: unsafe { sys::igGetBackgroundDrawList(viewport) },
             draw_list_type: DrawListType::Background,
             _phantom: PhantomData,
         }
     }
 
-    pub(crate) fn foreground(_ui: &crate::Ui) -> Self {
+    /// Foreground draw list of `viewport`, or of the main viewport when null
+    pub(crate) fn foreground(_ui: &crate::Ui, viewport: *mut sys::ImGuiViewport) -> Self {
         Self::lock_draw_list(DrawListType::Foreground);
         Self {
-            draw_list: unsafe { sys::igGetForegroundDrawList_ViewportPtr(std::ptr::null_mut()) },
+            draw_list: unsafe { sys::igGetForegroundDrawList_ViewportPtr(viewport) },
             draw_list_type: DrawListType::Foreground,
             _phantom: PhantomData,
         }
     }
 }
 
+/// Window draw list paired with the rectangle of the item emitted just before it was obtained.
+///
+/// Returned by [`Ui::draw_list_for_item`](crate::Ui::draw_list_for_item) for drawing decorations
+/// such as badges or highlights over a widget. Dereferences to [`DrawListMut`].
+pub struct ItemDrawList<'ui> {
+    draw_list: DrawListMut<'ui>,
+    min: [f32; 2],
+    max: [f32; 2],
+}
+
+impl<'ui> ItemDrawList<'ui> {
+    pub(crate) fn new(draw_list: DrawListMut<'ui>, min: [f32; 2], max: [f32; 2]) -> Self {
+        Self {
+            draw_list,
+            min,
+            max,
+        }
+    }
+
+    /// Upper-left corner of the item (screen space)
+    pub fn min(&self) -> [f32; 2] {
+        self.min
+    }
+
+    /// Lower-right corner of the item (screen space)
+    pub fn max(&self) -> [f32; 2] {
+        self.max
+    }
+
+    /// Item rectangle as `(min, max)` (screen space)
+    pub fn rect(&self) -> ([f32; 2], [f32; 2]) {
+        (self.min, self.max)
+    }
+
+    /// Size of the item
+    pub fn size(&self) -> [f32; 2] {
+        [self.max[0] - self.min[0], self.max[1] - self.min[1]]
+    }
+
+    /// Center of the item (screen space)
+    pub fn center(&self) -> [f32; 2] {
+        [
+            (self.min[0] + self.max[0]) * 0.5,
+            (self.min[1] + self.max[1]) * 0.5,
+        ]
+    }
+
+    /// Returns the underlying draw list
+    pub fn into_inner(self) -> DrawListMut<'ui> {
+        self.draw_list
+    }
+}
+
+impl<'ui> std::ops::Deref for ItemDrawList<'ui> {
+    type Target = DrawListMut<'ui>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.draw_list
+    }
+}
+
 /// Drawing functions
 impl<'ui> DrawListMut<'ui> {
     /// Split draw into multiple channels and merge automatically at the end of the closure.
@@ -423,6 +503,21 @@ impl<'ui> DrawListMut<'ui> {
         });
         unsafe { sys::ImDrawList_ChannelsMerge(self.draw_list) };
     }
+
+    /// Split draw into multiple channels, returning a token that merges them when dropped.
+    ///
+    /// Unlike [`channels_split`](Self::channels_split), the split can span code that
+    /// does not fit in a closure, e.g. emitting node contents first and then drawing
+    /// their backgrounds on a lower channel.
+    #[doc(alias = "ChannelsSplit")]
+    pub fn begin_channels_split(&self, channels_count: u32) -> ChannelsToken<'_> {
+        unsafe { sys::ImDrawList_ChannelsSplit(self.draw_list, channels_count as i32) };
+        ChannelsToken {
+            draw_list: self,
+            channels_count,
+            merged: false,
+        }
+    }
     /// Returns a line from point `p1` to `p2` with color `c`.
     pub fn add_line<C>(
         &'ui self,
@@ -756,6 +851,8 @@ impl<'ui> DrawListMut<'ui> {
     /// Draw text with an explicit font and optional fine CPU clip rectangle.
     ///
     /// This mirrors Dear ImGui's `ImDrawList::AddText(ImFont*, ...)` overload.
+    /// A `font_size` of `0.0` uses the current font size; a `wrap_width` of `0.0`
+    /// disables wrapping.
     #[doc(alias = "AddText")]
     pub fn add_text_with_font(
         &self,
@@ -766,12 +863,76 @@ impl<'ui> DrawListMut<'ui> {
         text: impl AsRef<str>,
         wrap_width: f32,
         cpu_fine_clip_rect: Option<[f32; 4]>,
+    ) {
+        self.add_text_font_ptr(
+            font.raw(),
+            font_size,
+            pos.into(),
+            col.into(),
+            text.as_ref(),
+            wrap_width,
+            cpu_fine_clip_rect,
+        );
+    }
+
+    /// Draw text with a font from the atlas, identified by its [`FontId`](crate::fonts::FontId).
+    ///
+    /// Same as [`add_text_with_font`](Self::add_text_with_font), for callers that keep the id
+    /// returned by [`FontAtlas::add_font`](crate::fonts::FontAtlas::add_font) rather than a
+    /// `&Font`. Useful for canvas and diagram labels drawn at arbitrary scales.
+    ///
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # let mut ctx = Context::create();
+    /// # let font_data_sources = [];
+    /// let label_font = ctx.fonts().add_font(&font_data_sources);
+    /// # let ui = ctx.frame();
+    /// let zoom = 2.5;
+    /// ui.get_window_draw_list().add_text_with_font_id(
+    ///     label_font,
+    ///     13.0 * zoom,
+    ///     [40.0, 40.0],
+    ///     [1.0, 1.0, 1.0, 1.0],
+    ///     "Node A",
+    ///     0.0,
+    ///     None,
+    /// );
+    /// ```
+    #[doc(alias = "AddText")]
+    #[allow(clippy::too_many_arguments)]
+    pub fn add_text_with_font_id(
+        &self,
+        font: crate::fonts::FontId,
+        font_size: f32,
+        pos: impl Into<sys::ImVec2>,
+        col: impl Into<ImColor32>,
+        text: impl AsRef<str>,
+        wrap_width: f32,
+        cpu_fine_clip_rect: Option<[f32; 4]>,
+    ) {
+        self.add_text_font_ptr(
+            font.0 as *mut sys::ImFont,
+            font_size,
+            pos.into(),
+            col.into(),
+            text.as_ref(),
+            wrap_width,
+            cpu_fine_clip_rect,
+        );
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn add_text_font_ptr(
+        &self,
+        font_ptr: *mut sys::ImFont,
+        font_size: f32,
+        pos: sys::ImVec2,
+        col: ImColor32,
+        text: &str,
+        wrap_width: f32,
+        cpu_fine_clip_rect: Option<[f32; 4]>,
     ) {
         use std::os::raw::c_char;
-        let text = text.as_ref();
-        let pos: sys::ImVec2 = pos.into();
-        let col = col.into();
-        let font_ptr = font.raw();
 
         let clip_vec4 = cpu_fine_clip_rect.map(|r| sys::ImVec4 {
             x: r[0],
@@ -1225,6 +1386,18 @@ impl<'ui> DrawListMut<'ui> {
         unsafe { sys::ImDrawList_AddCallback(self.draw_list, callback, userdata, userdata_size) }
     }
 
+    /// Insert a request for the renderer to restore its default render state.
+    ///
+    /// Use after callbacks that changed shaders, blending or other GPU state.
+    #[doc(alias = "ImDrawCallback_ResetRenderState")]
+    pub fn add_reset_render_state(&self) {
+        unsafe {
+            let reset: sys::ImDrawCallback =
+                std::mem::transmute(crate::render::draw_data::RESET_RENDER_STATE);
+            sys::ImDrawList_AddCallback(self.draw_list, reset, std::ptr::null_mut(), 0);
+        }
+    }
+
     /// Insert a new draw command (forces a new draw call boundary).
     #[doc(alias = "AddDrawCmd")]
     pub fn add_draw_cmd(&self) {
@@ -1262,6 +1435,56 @@ impl ChannelsSplit<'_> {
     }
 }
 
+/// Tracks a draw list channel split that must be merged by calling `.merge()`
+///
+/// Created by [`DrawListMut::begin_channels_split`]. Dropping the token merges the
+/// channels; merging happens exactly once either way.
+#[must_use]
+pub struct ChannelsToken<'a> {
+    draw_list: &'a DrawListMut<'a>,
+    channels_count: u32,
+    merged: bool,
+}
+
+impl ChannelsToken<'_> {
+    /// Change current channel.
+    ///
+    /// Out-of-range indices trigger a debug assertion and are otherwise ignored.
+    #[doc(alias = "ChannelsSetCurrent")]
+    pub fn set_current(&self, channel_index: u32) {
+        debug_assert!(
+            channel_index < self.channels_count,
+            "Channel index {} out of range {}",
+            channel_index,
+            self.channels_count
+        );
+        if channel_index < self.channels_count {
+            unsafe {
+                sys::ImDrawList_ChannelsSetCurrent(self.draw_list.draw_list, channel_index as i32)
+            };
+        }
+    }
+
+    /// Merges the channels back into the draw list, in channel order
+    #[doc(alias = "ChannelsMerge")]
+    pub fn merge(mut self) {
+        self.merge_once();
+    }
+
+    fn merge_once(&mut self) {
+        if !self.merged {
+            self.merged = true;
+            unsafe { sys::ImDrawList_ChannelsMerge(self.draw_list.draw_list) };
+        }
+    }
+}
+
+impl Drop for ChannelsToken<'_> {
+    fn drop(&mut self) {
+        self.merge_once();
+    }
+}
+
 /// A safe builder for registering a Rust callback to be executed during draw.
 #[must_use = "call .build() to register the callback"]
 pub struct Callback<'ui, F> {
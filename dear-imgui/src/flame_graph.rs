@@ -0,0 +1,336 @@
+//! Flame graph
+//!
+//! [`FlameGraph`] draws profiling spans as stacked, labelled rectangles: one row per
+//! call depth, with X as time. Hovering a span shows its name and timing, clicking it
+//! zooms the view to that span, the mouse wheel zooms around the cursor and dragging
+//! with the right button pans. Double-clicking the background resets the view, which
+//! lives in a user-owned [`FlameGraphState`].
+//!
+//! ```no_run
+//! # use dear_imgui_rs::*;
+//! # let mut ctx = Context::create();
+//! # let ui = ctx.frame();
+//! let spans = [
+//!     FlameSpan::new("frame", 0.0, 16.0, 0),
+//!     FlameSpan::new("update", 0.0, 6.0, 1),
+//!     FlameSpan::new("render", 6.0, 9.5, 1),
+//!     FlameSpan::new("upload", 6.5, 2.0, 2),
+//! ];
+//! let mut state = FlameGraphState::new();
+//! if let Some(i) = ui.flame_graph("##profile", &spans, &mut state).build() {
+//!     ui.text(format!("hovering {}", spans[i].name));
+//! }
+//! ```
+//!
+use crate::input::MouseButton;
+use crate::sys;
+use crate::{StyleColor, Ui};
+
+/// Zoom factor applied per mouse wheel notch
+const ZOOM_STEP: f64 = 1.25;
+/// Spans narrower than this many pixels are not drawn
+const MIN_SPAN_WIDTH_PX: f32 = 1.0;
+/// Horizontal padding of span labels, in pixels
+const LABEL_PADDING_PX: f32 = 4.0;
+/// Narrowest view the wheel zooms to, as a fraction of the time range of all spans
+const MIN_VIEW_FRACTION: f64 = 1e-9;
+
+/// One timed span of a [`FlameGraph`]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FlameSpan<'a> {
+    /// Label drawn inside the span and shown in its tooltip
+    pub name: &'a str,
+    /// Start time
+    pub start: f64,
+    /// Length of the span, in the same unit as `start`
+    pub duration: f64,
+    /// Call depth: 0 for the outermost spans, drawn as the top row
+    pub depth: usize,
+}
+
+impl<'a> FlameSpan<'a> {
+    /// Creates a span
+    pub fn new(name: &'a str, start: f64, duration: f64, depth: usize) -> Self {
+        Self {
+            name,
+            start,
+            duration,
+            depth,
+        }
+    }
+
+    /// Time at which the span ends
+    pub fn end(&self) -> f64 {
+        self.start + self.duration
+    }
+}
+
+/// Visible time range of a [`FlameGraph`], kept by the caller across frames
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct FlameGraphState {
+    /// Visible `[start, end]` time range; `None` fits all spans
+    pub view: Option<[f64; 2]>,
+    panning: bool,
+}
+
+impl FlameGraphState {
+    /// Creates a state that shows all spans
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Zooms back out to show all spans
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+}
+
+/// # Flame graph
+impl Ui {
+    /// Creates a flame graph of `spans` whose view is stored in `state`.
+    ///
+    /// Call [`build`](FlameGraph::build) to draw it.
+    pub fn flame_graph<'a, 's>(
+        &self,
+        id: impl Into<String>,
+        spans: &'a [FlameSpan<'a>],
+        state: &'s mut FlameGraphState,
+    ) -> FlameGraph<'_, 'a, 's> {
+        self.debug_assert_current();
+        FlameGraph::new(self, id, spans, state)
+    }
+}
+
+/// Builder for a flame graph.
+///
+/// By default the graph fills the available width and is exactly as tall as its
+/// deepest row; rows are one frame height tall.
+#[must_use]
+pub struct FlameGraph<'ui, 'a, 's> {
+    ui: &'ui Ui,
+    id: String,
+    spans: &'a [FlameSpan<'a>],
+    state: &'s mut FlameGraphState,
+    size: [f32; 2],
+    row_height: Option<f32>,
+}
+
+impl<'ui, 'a, 's> FlameGraph<'ui, 'a, 's> {
+    /// Creates a flame graph builder
+    pub fn new(
+        ui: &'ui Ui,
+        id: impl Into<String>,
+        spans: &'a [FlameSpan<'a>],
+        state: &'s mut FlameGraphState,
+    ) -> Self {
+        Self {
+            ui,
+            id: id.into(),
+            spans,
+            state,
+            size: [0.0, 0.0],
+            row_height: None,
+        }
+    }
+
+    /// Sets the graph size.
+    ///
+    /// A zero or negative width fills the available width; a zero or negative height
+    /// fits all rows.
+    pub fn size(mut self, size: [f32; 2]) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the height of a row, in pixels
+    pub fn row_height(mut self, row_height: f32) -> Self {
+        self.row_height = (row_height > 0.0).then_some(row_height);
+        self
+    }
+
+    /// Draws the graph and applies hover, click-to-zoom and pan/zoom input.
+    ///
+    /// Returns the index in `spans` of the hovered span, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a [`DrawListMut`](crate::DrawListMut) for the window draw list is alive.
+    pub fn build(self) -> Option<usize> {
+        let ui = self.ui;
+        let spans = self.spans;
+        let state = self.state;
+        let row_height = self.row_height.unwrap_or_else(|| ui.frame_height());
+        let rows = spans.iter().map(|s| s.depth + 1).max().unwrap_or(1);
+        let size = [
+            if self.size[0] > 0.0 {
+                self.size[0]
+            } else {
+                ui.content_region_avail()[0]
+            }
+            .max(1.0),
+            if self.size[1] > 0.0 {
+                self.size[1]
+            } else {
+                rows as f32 * row_height
+            }
+            .max(1.0),
+        ];
+        let min = ui.cursor_screen_pos();
+        let max = [min[0] + size[0], min[1] + size[1]];
+
+        let _id = ui.push_id(self.id.as_str());
+        // Background item for layout; spans submitted on top of it take the hover
+        ui.set_next_item_allow_overlap();
+        ui.invisible_button("##background", size);
+        let hovering = ui.is_window_hovered() && ui.is_mouse_hovering_rect(min, max);
+
+        let full = full_range(spans);
+        let mut view = state.view.unwrap_or(full);
+        if hovering {
+            // Keep the wheel from also scrolling the parent window
+            unsafe { sys::igSetItemKeyOwner_Nil(sys::ImGuiKey_MouseWheelY) };
+            let wheel = ui.io().mouse_wheel();
+            if wheel != 0.0 {
+                let t = ((ui.io().mouse_pos()[0] - min[0]) / size[0]) as f64;
+                let min_width = (full[1] - full[0]) * MIN_VIEW_FRACTION;
+                view = zoom_view(view, t, ZOOM_STEP.powf(-wheel as f64), min_width);
+                state.view = Some(view);
+            }
+            if ui.is_mouse_clicked(MouseButton::Right) {
+                state.panning = true;
+            }
+        }
+        if !ui.is_mouse_down(MouseButton::Right) {
+            state.panning = false;
+        }
+        if state.panning {
+            let dx = ui.io().mouse_delta()[0];
+            if dx != 0.0 {
+                view = pan_view(view, dx, size[0]);
+                state.view = Some(view);
+            }
+        }
+
+        let to_x = |t: f64| min[0] + ((t - view[0]) / (view[1] - view[0])) as f32 * size[0];
+        let mut hovered = None;
+        let mut clicked = None;
+        let draw_list = crate::DrawListMut::window_for_widget(ui, "flame_graph");
+        draw_list.push_clip_rect(min, max, true);
+        draw_list
+            .add_rect(min, max, ui.get_color_u32(StyleColor::FrameBg))
+            .filled(true)
+            .build();
+        let text_color = ui.get_color_u32(StyleColor::Text);
+        let text_offset = (row_height - ui.text_line_height()) * 0.5;
+        for (index, span) in spans.iter().enumerate() {
+            let x0 = to_x(span.start).max(min[0]);
+            let x1 = to_x(span.end()).min(max[0]);
+            let y0 = min[1] + span.depth as f32 * row_height;
+            if x1 - x0 < MIN_SPAN_WIDTH_PX || y0 >= max[1] {
+                continue;
+            }
+            let y1 = (y0 + row_height).min(max[1]);
+            let (a, b) = ([x0, y0], [x1, y1]);
+
+            ui.set_cursor_screen_pos(a);
+            let _span_id = ui.push_id(index);
+            if ui.invisible_button("##span", [x1 - x0, y1 - y0]) {
+                clicked = Some(index);
+            }
+            let is_hovered = ui.is_item_hovered();
+            if is_hovered {
+                hovered = Some(index);
+            }
+
+            draw_list
+                .add_rect(a, b, span_color(span.name))
+                .filled(true)
+                .build();
+            draw_list
+                .add_rect(a, b, ui.get_color_u32(StyleColor::WindowBg))
+                .build();
+            if is_hovered {
+                draw_list.add_rect(a, b, text_color).thickness(2.0).build();
+            }
+            draw_list.push_clip_rect(a, b, true);
+            draw_list.add_text(
+                [x0 + LABEL_PADDING_PX, y0 + text_offset],
+                [0.0, 0.0, 0.0, 1.0],
+                span.name,
+            );
+            draw_list.pop_clip_rect();
+        }
+        draw_list.pop_clip_rect();
+        drop(draw_list);
+        // Leave the cursor below the graph, as a single item of `size` would
+        ui.set_cursor_screen_pos(min);
+        ui.dummy(size);
+
+        if let Some(index) = clicked.filter(|&i| spans[i].duration > 0.0) {
+            let span = &spans[index];
+            state.view = Some([span.start, span.end()]);
+        } else if hovered.is_none() && hovering && ui.is_mouse_double_clicked(MouseButton::Left) {
+            state.view = None;
+        }
+
+        if let Some(index) = hovered {
+            let span = &spans[index];
+            ui.tooltip(|| {
+                ui.text(span.name);
+                ui.text(format!("start: {:.3}", span.start));
+                ui.text(format!("duration: {:.3}", span.duration));
+                if full[1] > full[0] {
+                    let share = span.duration / (full[1] - full[0]) * 100.0;
+                    ui.text(format!("{share:.1}% of total"));
+                }
+            });
+        }
+        hovered
+    }
+}
+
+/// Time range covering all spans, never empty
+fn full_range(spans: &[FlameSpan<'_>]) -> [f64; 2] {
+    let range = spans
+        .iter()
+        .map(|s| [s.start, s.end()])
+        .reduce(|a, b| [a[0].min(b[0]), a[1].max(b[1])])
+        .unwrap_or([0.0, 1.0]);
+    if range[1] > range[0] {
+        range
+    } else {
+        [range[0], range[0] + 1.0]
+    }
+}
+
+/// Scales `view` by `factor` around the point at fraction `t` of its width, keeping it
+/// at least `min_width` wide
+fn zoom_view(view: [f64; 2], t: f64, factor: f64, min_width: f64) -> [f64; 2] {
+    let anchor = view[0] + (view[1] - view[0]) * t;
+    let width = ((view[1] - view[0]) * factor).max(min_width);
+    let start = anchor - width * t;
+    [start, start + width]
+}
+
+/// Shifts `view` so the content follows a mouse drag of `dx` pixels over `width` pixels
+fn pan_view(view: [f64; 2], dx: f32, width: f32) -> [f64; 2] {
+    let shift = (view[1] - view[0]) * (dx / width) as f64;
+    [view[0] - shift, view[1] - shift]
+}
+
+/// Warm color derived from the span name, so a function keeps its color across frames
+fn span_color(name: &str) -> [f32; 4] {
+    // FNV-1a
+    let hash = name.bytes().fold(0x811c_9dc5_u32, |h, b| {
+        (h ^ b as u32).wrapping_mul(0x0100_0193)
+    });
+    let v1 = (hash & 0xff) as f32 / 255.0;
+    let v2 = ((hash >> 8) & 0xff) as f32 / 255.0;
+    let v3 = ((hash >> 16) & 0xff) as f32 / 255.0;
+    [
+        (205.0 + 50.0 * v3) / 255.0,
+        (230.0 * v1) / 255.0,
+        (55.0 * v2) / 255.0,
+        1.0,
+    ]
+}
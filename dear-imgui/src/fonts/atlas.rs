@@ -76,6 +76,12 @@ impl FontLoader {
 }
 
 /// Font loader flags for controlling font loading behavior
+///
+/// These mirror `ImGuiFreeTypeLoaderFlags` and only take effect with the FreeType
+/// loader, which Dear ImGui selects automatically when the `freetype` feature is
+/// enabled; the default stb_truetype loader ignores them. Use
+/// [`FontAtlas::set_freetype_loader_flags`] to get an error instead when the feature
+/// is missing.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct FontLoaderFlags(pub u32);
 
@@ -83,17 +89,40 @@ impl FontLoaderFlags {
     /// No special flags
     pub const NONE: Self = Self(0);
 
-    /// Load color glyphs (requires FreeType backend)
-    pub const LOAD_COLOR: Self = Self(1 << 0);
+    /// Disable hinting
+    pub const NO_HINTING: Self = Self(1 << 0);
+
+    /// Disable auto-hinting, using the font's native hinter where available
+    pub const NO_AUTOHINT: Self = Self(1 << 1);
 
     /// Force auto-hinting
-    pub const FORCE_AUTOHINT: Self = Self(1 << 1);
+    pub const FORCE_AUTOHINT: Self = Self(1 << 2);
 
-    /// Disable hinting
-    pub const NO_HINTING: Self = Self(1 << 2);
+    /// Light hinting: vertical only, keeps glyph shapes closer to the design
+    pub const LIGHT_HINTING: Self = Self(1 << 3);
+
+    /// Strong hinting for monochrome output
+    pub const MONO_HINTING: Self = Self(1 << 4);
+
+    /// Artificially embolden the font
+    pub const BOLD: Self = Self(1 << 5);
+
+    /// Artificially slant the font
+    pub const OBLIQUE: Self = Self(1 << 6);
 
-    /// Disable auto-hinting
-    pub const NO_AUTOHINT: Self = Self(1 << 3);
+    /// Disable anti-aliasing, best combined with `MONO_HINTING`
+    pub const MONOCHROME: Self = Self(1 << 7);
+
+    /// Load color glyphs, e.g. color emoji
+    pub const LOAD_COLOR: Self = Self(1 << 8);
+
+    /// Load bitmap glyphs embedded in the font
+    pub const BITMAP: Self = Self(1 << 9);
+
+    /// Returns `true` if all flags in `other` are set
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
 }
 
 impl std::ops::BitOr for FontLoaderFlags {
@@ -109,6 +138,25 @@ impl std::ops::BitOrAssign for FontLoaderFlags {
     }
 }
 
+bitflags::bitflags! {
+    /// Flags controlling how the atlas texture is built
+    ///
+    /// The rasterizer is not chosen here: Dear ImGui uses FreeType when the `freetype`
+    /// feature is enabled and stb_truetype otherwise.
+    #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct FontAtlasFlags: i32 {
+        /// No flags
+        const NONE = sys::ImFontAtlasFlags_None as i32;
+        /// Don't round the texture height to the next power of two
+        const NO_POWER_OF_TWO_HEIGHT = sys::ImFontAtlasFlags_NoPowerOfTwoHeight as i32;
+        /// Don't pack software mouse cursor shapes into the atlas
+        const NO_MOUSE_CURSORS = sys::ImFontAtlasFlags_NoMouseCursors as i32;
+        /// Don't pack thick line textures, e.g. for renderers using point sampling
+        const NO_BAKED_LINES = sys::ImFontAtlasFlags_NoBakedLines as i32;
+    }
+}
+
 /// A shared font atlas that can be used across multiple contexts
 ///
 /// This allows multiple ImGui contexts to share the same font atlas,
@@ -150,6 +198,36 @@ impl Drop for SharedFontAtlas {
     }
 }
 
+/// Identifier of a custom rect packed into a [`FontAtlas`]
+///
+/// Not `Copy` so it cannot be used again after [`FontAtlas::remove_custom_rect`].
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct CustomRectId(sys::ImFontAtlasRectId);
+
+impl CustomRectId {
+    /// Returns the raw `ImFontAtlasRectId`
+    pub fn raw(&self) -> sys::ImFontAtlasRectId {
+        self.0
+    }
+}
+
+/// Location of a custom rect within the atlas texture
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct FontAtlasRect {
+    /// Left edge in pixels
+    pub x: u16,
+    /// Top edge in pixels
+    pub y: u16,
+    /// Width in pixels
+    pub width: u16,
+    /// Height in pixels
+    pub height: u16,
+    /// UV of the upper-left corner
+    pub uv0: [f32; 2],
+    /// UV of the lower-right corner
+    pub uv1: [f32; 2],
+}
+
 impl FontAtlas {
     /// Creates a new font atlas with default settings
     pub fn new() -> Self {
@@ -218,7 +296,41 @@ impl FontAtlas {
         unsafe { FontLoaderFlags((*self.raw).FontLoaderFlags) }
     }
 
+    /// Sets global FreeType loader flags, e.g. light hinting or color glyphs
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the crate was built without the `freetype` feature, in which
+    /// case the flags would be silently ignored by the stb_truetype loader.
+    pub fn set_freetype_loader_flags(
+        &mut self,
+        flags: FontLoaderFlags,
+    ) -> crate::error::ImGuiResult<()> {
+        if !crate::HAS_FREETYPE {
+            return Err(crate::error::ImGuiError::configuration(
+                "FreeType loader flags require the `freetype` feature",
+            ));
+        }
+        self.set_font_loader_flags(flags);
+        Ok(())
+    }
+
+    /// Sets atlas build flags
+    pub fn set_flags(&mut self, flags: FontAtlasFlags) {
+        unsafe {
+            (*self.raw).Flags = flags.bits();
+        }
+    }
+
+    /// Gets atlas build flags
+    pub fn flags(&self) -> FontAtlasFlags {
+        unsafe { FontAtlasFlags::from_bits_truncate((*self.raw).Flags) }
+    }
+
     /// Add a font to the atlas using FontSource
+    ///
+    /// Every source after the first is merged into the first one, e.g. to add icons to a text
+    /// font, so the returned font draws glyphs from all of them.
     #[doc(alias = "AddFont")]
     pub fn add_font(&mut self, font_sources: &[FontSource<'_>]) -> crate::fonts::FontId {
         let (head, tail) = font_sources.split_first().unwrap();
@@ -232,16 +344,24 @@ impl FontAtlas {
     fn add_font_internal(
         &mut self,
         font_source: &FontSource<'_>,
-        _merge_mode: bool,
+        merge_mode: bool,
     ) -> crate::fonts::FontId {
+        let config = |config: &Option<FontConfig>| {
+            let cfg = config.clone().unwrap_or_default();
+            if merge_mode {
+                cfg.merge_mode(true)
+            } else {
+                cfg
+            }
+        };
         match font_source {
             FontSource::DefaultFontData {
                 size_pixels,
-                config,
+                config: config_in,
             } => {
                 // For v1.92+, we can use dynamic sizing by passing 0.0
                 let size = size_pixels.unwrap_or(0.0);
-                let mut cfg = config.clone().unwrap_or_default();
+                let mut cfg = config(config_in);
                 if size > 0.0 {
                     cfg = cfg.size_pixels(size);
                 }
@@ -251,10 +371,10 @@ impl FontAtlas {
             FontSource::TtfData {
                 data,
                 size_pixels,
-                config,
+                config: config_in,
             } => {
                 let size = size_pixels.unwrap_or(0.0);
-                let mut cfg = config.clone().unwrap_or_default();
+                let mut cfg = config(config_in);
                 if size > 0.0 {
                     cfg = cfg.size_pixels(size);
                 }
@@ -266,10 +386,10 @@ impl FontAtlas {
             FontSource::TtfFile {
                 path,
                 size_pixels,
-                config,
+                config: config_in,
             } => {
                 let size = size_pixels.unwrap_or(0.0);
-                let mut cfg = config.clone().unwrap_or_default();
+                let mut cfg = config(config_in);
                 if size > 0.0 {
                     cfg = cfg.size_pixels(size);
                 }
@@ -301,6 +421,13 @@ impl FontAtlas {
     }
 
     /// Add a font from a TTF file
+    ///
+    /// `glyph_ranges` must be zero-terminated and outlive the font, see
+    /// [`GlyphRangesBuilder::build`](crate::GlyphRangesBuilder::build).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `glyph_ranges` is not zero-terminated.
     #[doc(alias = "AddFontFromFileTTF")]
     pub fn add_font_from_file_ttf(
         &mut self,
@@ -312,7 +439,7 @@ impl FontAtlas {
         unsafe {
             let filename_cstr = std::ffi::CString::new(filename).ok()?;
             let cfg_ptr = font_cfg.map_or(ptr::null(), |cfg| cfg.raw());
-            let ranges_ptr = glyph_ranges.map_or(ptr::null(), |ranges| ranges.as_ptr());
+            let ranges_ptr = glyph_ranges_ptr(glyph_ranges);
 
             let font_ptr = sys::ImFontAtlas_AddFontFromFileTTF(
                 self.raw,
@@ -331,6 +458,14 @@ impl FontAtlas {
     }
 
     /// Add a font from memory (TTF data)
+    ///
+    /// The data is copied into a buffer owned by the atlas, since glyphs are loaded from it
+    /// on demand for as long as the font exists. `glyph_ranges` must be zero-terminated and
+    /// outlive the font.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `glyph_ranges` is not zero-terminated.
     #[doc(alias = "AddFontFromMemoryTTF")]
     pub fn add_font_from_memory_ttf(
         &mut self,
@@ -340,15 +475,20 @@ impl FontAtlas {
         glyph_ranges: Option<&[sys::ImWchar]>,
     ) -> Option<&mut Font> {
         unsafe {
-            let cfg_ptr = font_cfg.map_or(ptr::null(), |cfg| cfg.raw());
-            let ranges_ptr = glyph_ranges.map_or(ptr::null(), |ranges| ranges.as_ptr());
+            let mut cfg = font_cfg.cloned().unwrap_or_default();
+            cfg.raw.FontDataOwnedByAtlas = true;
+            let ranges_ptr = glyph_ranges_ptr(glyph_ranges);
+
+            // Allocated with ImGui's allocator, which the atlas frees it with
+            let data_ptr = sys::igMemAlloc(font_data.len()) as *mut u8;
+            ptr::copy_nonoverlapping(font_data.as_ptr(), data_ptr, font_data.len());
 
             let font_ptr = sys::ImFontAtlas_AddFontFromMemoryTTF(
                 self.raw,
-                font_data.as_ptr() as *mut std::os::raw::c_void,
+                data_ptr as *mut std::os::raw::c_void,
                 font_data.len() as i32,
                 size_pixels,
-                cfg_ptr,
+                cfg.raw(),
                 ranges_ptr,
             );
 
@@ -379,12 +519,16 @@ impl FontAtlas {
     }
 
     /// Clear only the texture data (keep fonts)
+    ///
+    /// Frees the CPU-side pixels, e.g. once [`FontAtlas::build_rgba32`] output has been
+    /// uploaded and [`FontAtlas::set_texture_id`] recorded the GPU handle.
     #[doc(alias = "ClearTexData")]
     pub fn clear_tex_data(&mut self) {
         unsafe { sys::ImFontAtlas_ClearTexData(self.raw) }
     }
 
-    /// Get default glyph ranges (Basic Latin + Latin Supplement)
+    /// Get default glyph ranges (Basic Latin + Latin Supplement), including the
+    /// terminating zero
     #[doc(alias = "GetGlyphRangesDefault")]
     pub fn get_glyph_ranges_default(&self) -> &[sys::ImWchar] {
         unsafe {
@@ -392,12 +536,11 @@ impl FontAtlas {
             if ptr.is_null() {
                 &[]
             } else {
-                // Count the ranges (terminated by 0)
                 let mut len = 0;
                 while *ptr.add(len) != 0 {
                     len += 1;
                 }
-                std::slice::from_raw_parts(ptr, len)
+                std::slice::from_raw_parts(ptr, len + 1)
             }
         }
     }
@@ -482,6 +625,160 @@ impl FontAtlas {
         }
     }
 
+    /// Builds the atlas as RGBA32 and returns `(width, height, pixels)`
+    ///
+    /// Pixels are 4 bytes each, with white RGB for glyphs. Meant for renderers without
+    /// `BackendFlags::RENDERER_HAS_TEXTURES` that upload the whole atlas once; renderers
+    /// with it receive the atlas through `DrawData::textures()` instead. Rebuilds the
+    /// atlas if it was built in another format or its pixels were cleared.
+    #[doc(alias = "GetTexDataAsRGBA32")]
+    pub fn build_rgba32(&mut self) -> Option<(u32, u32, &[u8])> {
+        self.build_tex_data(crate::texture::TextureFormat::RGBA32)
+    }
+
+    /// Builds the atlas as 8-bit alpha and returns `(width, height, pixels)`
+    ///
+    /// See [`FontAtlas::build_rgba32`].
+    #[doc(alias = "GetTexDataAsAlpha8")]
+    pub fn build_alpha8(&mut self) -> Option<(u32, u32, &[u8])> {
+        self.build_tex_data(crate::texture::TextureFormat::Alpha8)
+    }
+
+    fn build_tex_data(
+        &mut self,
+        format: crate::texture::TextureFormat,
+    ) -> Option<(u32, u32, &[u8])> {
+        if self.raw.is_null() {
+            return None;
+        }
+        unsafe {
+            let raw = self.raw;
+            let tex = (*raw).TexData;
+            let ready = (*raw).TexIsBuilt
+                && !tex.is_null()
+                && !(*tex).Pixels.is_null()
+                && (*tex).Format == format.into();
+            if !ready {
+                (*raw).TexDesiredFormat = format.into();
+                if (*raw).Builder.is_null() {
+                    self.build();
+                } else {
+                    // Recreate the texture in the desired format with fresh pixels
+                    sys::igImFontAtlasBuildClear(raw);
+                    sys::igImFontAtlasBuildMain(raw);
+                    sys::igImFontAtlasBuildUpdatePointers(raw);
+                }
+            }
+            let tex = (*raw).TexData;
+            if tex.is_null() || (*tex).Format != format.into() {
+                return None;
+            }
+            let tex = crate::texture::TextureData::from_raw(tex);
+            let (width, height) = (tex.width() as u32, tex.height() as u32);
+            tex.pixels().map(|pixels| (width, height, pixels))
+        }
+    }
+
+    /// Packs a `width` x `height` rectangle into the atlas texture for custom images
+    ///
+    /// Returns `None` if the rectangle could not be packed. Renderers without
+    /// `BackendFlags::RENDERER_HAS_TEXTURES` upload the atlas once, so add custom rects
+    /// before building and uploading it. Otherwise the atlas may repack and move the
+    /// rect as fonts grow: look up [`FontAtlas::custom_rect`] each frame rather than
+    /// caching UVs.
+    #[doc(alias = "AddCustomRect")]
+    pub fn add_custom_rect(&mut self, width: u16, height: u16) -> Option<CustomRectId> {
+        let id = unsafe {
+            sys::ImFontAtlas_AddCustomRect(
+                self.raw,
+                width as i32,
+                height as i32,
+                std::ptr::null_mut(),
+            )
+        };
+        // ImFontAtlasRectId_Invalid
+        (id != -1).then_some(CustomRectId(id))
+    }
+
+    /// Removes a custom rect, freeing its space in the atlas
+    #[doc(alias = "RemoveCustomRect")]
+    pub fn remove_custom_rect(&mut self, id: CustomRectId) {
+        unsafe { sys::ImFontAtlas_RemoveCustomRect(self.raw, id.0) }
+    }
+
+    /// Current position and UVs of a custom rect
+    #[doc(alias = "GetCustomRect")]
+    pub fn custom_rect(&self, id: &CustomRectId) -> Option<FontAtlasRect> {
+        let mut rect = sys::ImFontAtlasRect::default();
+        let found = unsafe { sys::ImFontAtlas_GetCustomRect(self.raw, id.0, &mut rect) };
+        found.then_some(FontAtlasRect {
+            x: rect.x,
+            y: rect.y,
+            width: rect.w,
+            height: rect.h,
+            uv0: [rect.uv0.x, rect.uv0.y],
+            uv1: [rect.uv1.x, rect.uv1.y],
+        })
+    }
+
+    /// UV coordinates `(uv0, uv1)` of a custom rect, e.g. for `Ui::image` calls
+    pub fn custom_rect_uv(&self, id: &CustomRectId) -> Option<([f32; 2], [f32; 2])> {
+        self.custom_rect(id).map(|rect| (rect.uv0, rect.uv1))
+    }
+
+    /// Copies `pixels` into a custom rect and queues the region for upload
+    ///
+    /// `pixels` holds the rect's rows top to bottom in the atlas texture format (4 bytes
+    /// per pixel for RGBA32, 1 for Alpha8).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rect is unknown, the atlas has no pixels, or `pixels`
+    /// does not match the rect size.
+    pub fn write_custom_rect(
+        &mut self,
+        id: &CustomRectId,
+        pixels: &[u8],
+    ) -> crate::error::ImGuiResult<()> {
+        use crate::error::ImGuiError;
+        let rect = self
+            .custom_rect(id)
+            .ok_or_else(|| ImGuiError::invalid_operation("unknown custom rect"))?;
+        let tex_ptr = self.get_tex_data();
+        if tex_ptr.is_null() || unsafe { (*tex_ptr).Pixels.is_null() } {
+            return Err(ImGuiError::invalid_operation("font atlas has no pixels"));
+        }
+        let tex = unsafe { crate::texture::TextureData::from_raw(tex_ptr) };
+        let bpp = tex.bytes_per_pixel() as usize;
+        let row_len = rect.width as usize * bpp;
+        if pixels.len() != row_len * rect.height as usize {
+            return Err(ImGuiError::invalid_operation(format!(
+                "expected {} bytes for a {}x{} custom rect, got {}",
+                row_len * rect.height as usize,
+                rect.width,
+                rect.height,
+                pixels.len()
+            )));
+        }
+        let pitch = tex.pitch() as usize;
+        let base = unsafe { (*tex_ptr).Pixels };
+        for (row, src) in pixels.chunks_exact(row_len).enumerate() {
+            let offset = (rect.y as usize + row) * pitch + rect.x as usize * bpp;
+            unsafe { std::ptr::copy_nonoverlapping(src.as_ptr(), base.add(offset), row_len) };
+        }
+        unsafe {
+            sys::igImFontAtlasTextureBlockQueueUpload(
+                self.raw,
+                tex_ptr,
+                rect.x as i32,
+                rect.y as i32,
+                rect.width as i32,
+                rect.height as i32,
+            );
+        }
+        Ok(())
+    }
+
     /// Get texture reference for the font atlas
     ///
     /// Note: Our Dear ImGui version uses ImTextureRef instead of a simple texture ID
@@ -508,6 +805,7 @@ impl FontAtlas {
 
     /// Convenience: set atlas texture id and mark status OK
     /// Also updates TexRef so draw commands use this texture id.
+    #[doc(alias = "SetTexID")]
     pub fn set_texture_id(&mut self, tex_id: crate::texture::TextureId) {
         // Update TexRef used by draw commands
         let tex_ref = sys::ImTextureRef {
@@ -547,6 +845,18 @@ impl FontAtlas {
     }
 }
 
+/// Pointer to optional glyph ranges, checking that Dear ImGui will find their terminator
+fn glyph_ranges_ptr(glyph_ranges: Option<&[sys::ImWchar]>) -> *const sys::ImWchar {
+    glyph_ranges.map_or(ptr::null(), |ranges| {
+        assert_eq!(
+            ranges.last(),
+            Some(&0),
+            "glyph ranges must be zero-terminated"
+        );
+        ranges.as_ptr()
+    })
+}
+
 impl Default for FontAtlas {
     fn default() -> Self {
         Self::new()
@@ -599,6 +909,12 @@ impl FontConfig {
     }
 
     /// Set whether to merge this font with the previous one
+    ///
+    /// A merged font adds its glyphs to the previously added font instead of creating a new
+    /// one, e.g. to show icons inline with text. Use the same size as the base font (or
+    /// adjust with [`glyph_offset`](Self::glyph_offset)) so the glyphs line up, and restrict
+    /// it with [`glyph_ranges`](Self::glyph_ranges) so it only contributes the glyphs it is
+    /// added for.
     pub fn merge_mode(mut self, merge: bool) -> Self {
         self.raw.MergeMode = merge;
         self
@@ -612,11 +928,27 @@ impl FontConfig {
         self
     }
 
-    /// Set glyph ranges to exclude from this font
+    /// Set the zero-terminated glyph ranges this font provides, e.g.
+    /// [`GlyphRanges::JAPANESE`](crate::GlyphRanges::JAPANESE) or leaked
+    /// [`GlyphRangesBuilder`](crate::GlyphRangesBuilder) output
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ranges` is not zero-terminated.
+    pub fn glyph_ranges(mut self, ranges: &'static [sys::ImWchar]) -> Self {
+        self.raw.GlyphRanges = glyph_ranges_ptr(Some(ranges));
+        self
+    }
+
+    /// Set zero-terminated glyph ranges to exclude from this font
     ///
     /// Useful when merging fonts to avoid overlapping glyphs.
-    pub fn glyph_exclude_ranges(mut self, ranges: &[u32]) -> Self {
-        self.raw.GlyphExcludeRanges = ranges.as_ptr() as *const sys::ImWchar;
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ranges` is not zero-terminated.
+    pub fn glyph_exclude_ranges(mut self, ranges: &'static [sys::ImWchar]) -> Self {
+        self.raw.GlyphExcludeRanges = glyph_ranges_ptr(Some(ranges));
         self
     }
 
@@ -3,7 +3,7 @@
 //! This module provides the Font type which represents a single font instance
 //! with its associated runtime data and rendering operations.
 
-use super::FontId;
+use super::{FontId, Glyph};
 use crate::sys;
 
 /// A font instance with runtime data
@@ -52,6 +52,32 @@ impl Font {
         unsafe { sys::ImFont_IsGlyphInFont(self.raw(), c as u16) }
     }
 
+    /// Looks up the glyph for `c` at the font's default size, loading it on demand
+    ///
+    /// Returns `None` if the font has no glyph for `c` (the fallback glyph is not
+    /// substituted). Must be called while the font's atlas is built, e.g. during a frame.
+    #[doc(alias = "FindGlyph")]
+    pub fn find_glyph(&self, c: char) -> Option<Glyph> {
+        self.find_glyph_at_size(unsafe { (*self.raw()).LegacySize }, c)
+    }
+
+    /// Looks up the glyph for `c` baked at `size` pixels
+    ///
+    /// Advance, bounds and UVs all depend on the size, so use the size text is drawn at.
+    #[doc(alias = "FindGlyphNoFallback")]
+    pub fn find_glyph_at_size(&self, size: f32, c: char) -> Option<Glyph> {
+        // Codepoints outside the ImWchar range can't have a glyph
+        let c = sys::ImWchar::try_from(u32::from(c)).ok()?;
+        unsafe {
+            let baked = sys::ImFont_GetFontBaked(self.raw(), size, -1.0);
+            if baked.is_null() {
+                return None;
+            }
+            let glyph = sys::ImFontBaked_FindGlyphNoFallback(baked, c);
+            (!glyph.is_null()).then(|| Glyph::from_raw(*glyph))
+        }
+    }
+
     /// Calculate text size for the given text
     #[doc(alias = "CalcTextSizeA")]
     pub fn calc_text_size(
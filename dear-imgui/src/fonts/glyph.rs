@@ -41,7 +41,7 @@ impl Glyph {
         ([self.raw.U0, self.raw.V0], [self.raw.U1, self.raw.V1])
     }
 
-    /// Get the glyph position and size
+    /// Get the glyph's visible bounds as `(min, max)`, relative to the pen position
     pub fn position_and_size(&self) -> ([f32; 2], [f32; 2]) {
         ([self.raw.X0, self.raw.Y0], [self.raw.X1, self.raw.Y1])
     }
@@ -43,6 +43,16 @@ impl GlyphRangesBuilder {
         }
     }
 
+    /// Add a single character
+    ///
+    /// Characters outside the Basic Multilingual Plane (above U+FFFF) are ignored.
+    #[doc(alias = "AddChar")]
+    pub fn add_char(&mut self, c: char) {
+        if let Ok(c) = sys::ImWchar::try_from(u32::from(c)) {
+            unsafe { sys::ImFontGlyphRangesBuilder_AddChar(self.raw, c) }
+        }
+    }
+
     /// Add text to the builder (all characters in the text will be included)
     #[doc(alias = "AddText")]
     pub fn add_text(&mut self, text: &str) {
@@ -53,33 +63,46 @@ impl GlyphRangesBuilder {
         }
     }
 
-    /// Add a range of characters
+    /// Add inclusive `(first, last)` ranges of code points
+    ///
+    /// Code points above U+FFFF are ignored.
     #[doc(alias = "AddRanges")]
-    pub fn add_ranges(&mut self, ranges: &[u32]) {
-        unsafe {
-            sys::ImFontGlyphRangesBuilder_AddRanges(
-                self.raw,
-                ranges.as_ptr() as *const sys::ImWchar,
-            );
+    pub fn add_ranges(&mut self, ranges: &[(u32, u32)]) {
+        let mut raw: Vec<sys::ImWchar> = Vec::with_capacity(ranges.len() * 2 + 1);
+        for &(first, last) in ranges {
+            if first > last || first == 0 || first > u32::from(sys::ImWchar::MAX) {
+                continue;
+            }
+            raw.push(first as sys::ImWchar);
+            raw.push(last.min(u32::from(sys::ImWchar::MAX)) as sys::ImWchar);
         }
+        raw.push(0);
+        unsafe { sys::ImFontGlyphRangesBuilder_AddRanges(self.raw, raw.as_ptr()) }
     }
 
-    /// Build the final ranges array
+    /// Build the final zero-terminated ranges, ready to pass when adding a font
+    ///
+    /// Dear ImGui reads the ranges whenever it loads glyphs for the font, so keep the
+    /// returned vector alive for as long as the font stays in the atlas.
     #[doc(alias = "BuildRanges")]
-    pub fn build_ranges(&mut self) -> Vec<u32> {
+    pub fn build(&mut self) -> Vec<sys::ImWchar> {
         unsafe {
             let mut out_ranges = std::mem::zeroed::<sys::ImVector_ImWchar>();
             sys::ImFontGlyphRangesBuilder_BuildRanges(self.raw, &mut out_ranges);
-
-            // Convert ImVector to Vec
-            let len = out_ranges.Size as usize;
-            let mut result = Vec::with_capacity(len);
-            for i in 0..len {
-                result.push(*out_ranges.Data.add(i) as u32);
-            }
+            let result = if out_ranges.Data.is_null() {
+                vec![0]
+            } else {
+                std::slice::from_raw_parts(out_ranges.Data, out_ranges.Size as usize).to_vec()
+            };
+            sys::ImVector_ImWchar_UnInit(&mut out_ranges);
             result
         }
     }
+
+    /// Build the final ranges array as `u32` code points, including the terminating zero
+    pub fn build_ranges(&mut self) -> Vec<u32> {
+        self.build().into_iter().map(u32::from).collect()
+    }
 }
 
 impl Default for GlyphRangesBuilder {
@@ -88,6 +111,12 @@ impl Default for GlyphRangesBuilder {
     }
 }
 
+impl Drop for GlyphRangesBuilder {
+    fn drop(&mut self) {
+        unsafe { sys::ImFontGlyphRangesBuilder_destroy(self.raw) }
+    }
+}
+
 /// Predefined glyph ranges for common character sets
 ///
 /// **Note**: These ranges are still useful with Dear ImGui 1.92+ for:
@@ -97,17 +126,19 @@ impl Default for GlyphRangesBuilder {
 ///
 /// For most use cases, you can now omit glyph ranges and let the dynamic
 /// font system load glyphs on-demand.
+///
+/// Each set is zero-terminated and can be passed directly when adding a font.
 pub struct GlyphRanges;
 
 impl GlyphRanges {
     /// Basic Latin + Latin Supplement (default)
-    pub const DEFAULT: &'static [u32] = &[
+    pub const DEFAULT: &'static [sys::ImWchar] = &[
         0x0020, 0x00FF, // Basic Latin + Latin Supplement
         0,
     ];
 
     /// Korean characters
-    pub const KOREAN: &'static [u32] = &[
+    pub const KOREAN: &'static [sys::ImWchar] = &[
         0x0020, 0x00FF, // Basic Latin + Latin Supplement
         0x3131, 0x3163, // Korean alphabets
         0xAC00, 0xD7A3, // Korean characters
@@ -115,7 +146,7 @@ impl GlyphRanges {
     ];
 
     /// Japanese Hiragana + Katakana + Half-Width characters
-    pub const JAPANESE: &'static [u32] = &[
+    pub const JAPANESE: &'static [sys::ImWchar] = &[
         0x0020, 0x00FF, // Basic Latin + Latin Supplement
         0x3000, 0x30FF, // CJK Symbols and Punctuations, Hiragana, Katakana
         0x31F0, 0x31FF, // Katakana Phonetic Extensions
@@ -124,18 +155,30 @@ impl GlyphRanges {
     ];
 
     /// Chinese Simplified common characters
-    pub const CHINESE_SIMPLIFIED_COMMON: &'static [u32] = &[
+    pub const CHINESE_SIMPLIFIED_COMMON: &'static [sys::ImWchar] = &[
+        0x0020, 0x00FF, // Basic Latin + Latin Supplement
+        0x2000, 0x206F, // General Punctuation
+        0x3000, 0x30FF, // CJK Symbols and Punctuations, Hiragana, Katakana
+        0x31F0, 0x31FF, // Katakana Phonetic Extensions
+        0xFF00, 0xFFEF, // Half-width characters
+        0x4E00, 0x9FAF, // CJK Ideograms
+        0,
+    ];
+
+    /// Full Chinese character set (Simplified and Traditional CJK ideograms)
+    pub const CHINESE_FULL: &'static [sys::ImWchar] = &[
         0x0020, 0x00FF, // Basic Latin + Latin Supplement
         0x2000, 0x206F, // General Punctuation
         0x3000, 0x30FF, // CJK Symbols and Punctuations, Hiragana, Katakana
         0x31F0, 0x31FF, // Katakana Phonetic Extensions
         0xFF00, 0xFFEF, // Half-width characters
+        0xFFFD, 0xFFFD, // Invalid
         0x4E00, 0x9FAF, // CJK Ideograms
         0,
     ];
 
     /// Chinese Traditional common characters
-    pub const CHINESE_TRADITIONAL_COMMON: &'static [u32] = &[
+    pub const CHINESE_TRADITIONAL_COMMON: &'static [sys::ImWchar] = &[
         0x0020, 0x00FF, // Basic Latin + Latin Supplement
         0x2000, 0x206F, // General Punctuation
         0x3000, 0x30FF, // CJK Symbols and Punctuations, Hiragana, Katakana
@@ -146,7 +189,7 @@ impl GlyphRanges {
     ];
 
     /// Cyrillic characters
-    pub const CYRILLIC: &'static [u32] = &[
+    pub const CYRILLIC: &'static [sys::ImWchar] = &[
         0x0020, 0x00FF, // Basic Latin + Latin Supplement
         0x0400, 0x052F, // Cyrillic + Cyrillic Supplement
         0x2DE0, 0x2DFF, // Cyrillic Extended-A
@@ -155,14 +198,14 @@ impl GlyphRanges {
     ];
 
     /// Thai characters
-    pub const THAI: &'static [u32] = &[
+    pub const THAI: &'static [sys::ImWchar] = &[
         0x0020, 0x00FF, // Basic Latin + Latin Supplement
         0x0E00, 0x0E7F, // Thai
         0,
     ];
 
     /// Vietnamese characters
-    pub const VIETNAMESE: &'static [u32] = &[
+    pub const VIETNAMESE: &'static [sys::ImWchar] = &[
         0x0020, 0x00FF, // Basic Latin + Latin Supplement
         0x0102, 0x0103, // Ă ă
         0x0110, 0x0111, // Đ đ
@@ -31,20 +31,60 @@ impl Ui {
     /// Returns the current font
     #[doc(alias = "GetFont")]
     pub fn current_font(&self) -> &Font {
+        self.debug_assert_current();
         unsafe { Font::from_raw(crate::sys::igGetFont() as *const _) }
     }
 
     /// Returns the current font size (= height in pixels) with font scale applied
     #[doc(alias = "GetFontSize")]
     pub fn current_font_size(&self) -> f32 {
+        self.debug_assert_current();
         unsafe { crate::sys::igGetFontSize() }
     }
 
+    /// Returns the size `text` takes up when drawn in the current font
+    #[doc(alias = "CalcTextSize")]
+    pub fn calc_text_size(&self, text: impl AsRef<str>) -> [f32; 2] {
+        self.debug_assert_current();
+        self.calc_text_size_with_opts(text, false, -1.0)
+    }
+
+    /// Returns the size `text` takes up when drawn in the current font
+    ///
+    /// With `hide_text_after_double_hash`, anything from a `##` ID suffix onwards is not
+    /// measured. A positive `wrap_width` wraps lines at that width, as [`Ui::text_wrapped`]
+    /// would; pass a negative value to disable wrapping.
+    #[doc(alias = "CalcTextSize")]
+    pub fn calc_text_size_with_opts(
+        &self,
+        text: impl AsRef<str>,
+        hide_text_after_double_hash: bool,
+        wrap_width: f32,
+    ) -> [f32; 2] {
+        self.debug_assert_current();
+        let text = text.as_ref();
+        let mut out = crate::sys::ImVec2 { x: 0.0, y: 0.0 };
+        unsafe {
+            // Passing the end pointer measures the exact byte range, without a NUL copy
+            let start = text.as_ptr() as *const std::os::raw::c_char;
+            let end = start.add(text.len());
+            crate::sys::igCalcTextSize(
+                &mut out,
+                start,
+                end,
+                hide_text_after_double_hash,
+                wrap_width,
+            );
+        }
+        [out.x, out.y]
+    }
+
     /// Push a font with dynamic size support (v1.92+ feature)
     ///
     /// This allows changing font size at runtime without pre-loading different sizes.
     /// Pass None for font to use the current font with the new size.
     pub fn push_font_with_size(&self, font: Option<&Font>, size: f32) {
+        self.debug_assert_current();
         unsafe {
             let font_ptr = font.map_or(std::ptr::null_mut(), |f| f.raw());
             crate::sys::igPushFont(font_ptr, size);
@@ -56,6 +96,7 @@ impl Ui {
     where
         F: FnOnce() -> R,
     {
+        self.debug_assert_current();
         self.push_font_with_size(font, size);
         let result = f();
         unsafe {
@@ -69,6 +110,7 @@ impl Ui {
     /// Useful for drawing custom shapes with the draw list API.
     #[doc(alias = "GetFontTexUvWhitePixel")]
     pub fn font_tex_uv_white_pixel(&self) -> [f32; 2] {
+        self.debug_assert_current();
         unsafe {
             let mut uv = crate::sys::ImVec2 { x: 0.0, y: 0.0 };
             crate::sys::igGetFontTexUvWhitePixel(&mut uv);
@@ -82,6 +124,7 @@ impl Ui {
     /// Font scaling should be handled through font size instead.
     #[doc(alias = "SetWindowFontScale")]
     pub fn set_window_font_scale(&self, _scale: f32) {
+        self.debug_assert_current();
         // TODO: Implement when SetWindowFontScale is available in our Dear ImGui version
         // unsafe { crate::sys::igSetWindowFontScale(scale) }
     }
@@ -30,6 +30,21 @@ pub enum MouseButton {
     Extra2 = 4,
 }
 
+/// Kind of device driving the mouse events
+///
+/// Dear ImGui uses this to adjust behaviors, e.g. touch input skips hovering delays.
+#[repr(i32)]
+#[derive(Copy, Clone, Debug, Default, Hash, Eq, PartialEq)]
+pub enum MouseSource {
+    /// A regular mouse or trackpad
+    #[default]
+    Mouse = sys::ImGuiMouseSource_Mouse as i32,
+    /// A touch screen
+    TouchScreen = sys::ImGuiMouseSource_TouchScreen as i32,
+    /// A pen or stylus
+    Pen = sys::ImGuiMouseSource_Pen as i32,
+}
+
 /// Mouse cursor types
 #[repr(i32)]
 #[derive(Copy, Clone, Debug, Hash, Eq, PartialEq)]
@@ -279,6 +294,157 @@ pub enum Key {
 
     /// OEM 102 key (ISO < > |)
     Oem102 = sys::ImGuiKey_Oem102 as i32,
+
+    /// Ctrl modifier (Cmd on macOS when `ConfigMacOSXBehaviors` is set)
+    ModCtrl = sys::ImGuiMod_Ctrl as i32,
+    /// Shift modifier
+    ModShift = sys::ImGuiMod_Shift as i32,
+    /// Alt modifier
+    ModAlt = sys::ImGuiMod_Alt as i32,
+    /// Super modifier (Windows/Cmd key)
+    ModSuper = sys::ImGuiMod_Super as i32,
+
+    /// Start (Menu on Xbox, + on Switch)
+    GamepadStart = sys::ImGuiKey_GamepadStart as i32,
+    /// Back (View on Xbox, - on Switch)
+    GamepadBack = sys::ImGuiKey_GamepadBack as i32,
+    /// Left face button (X on Xbox, Y on Switch, Square on PlayStation)
+    GamepadFaceLeft = sys::ImGuiKey_GamepadFaceLeft as i32,
+    /// Right face button (B on Xbox, A on Switch, Circle on PlayStation)
+    GamepadFaceRight = sys::ImGuiKey_GamepadFaceRight as i32,
+    /// Top face button (Y on Xbox, X on Switch, Triangle on PlayStation)
+    GamepadFaceUp = sys::ImGuiKey_GamepadFaceUp as i32,
+    /// Bottom face button (A on Xbox, B on Switch, Cross on PlayStation)
+    GamepadFaceDown = sys::ImGuiKey_GamepadFaceDown as i32,
+    /// D-pad left
+    GamepadDpadLeft = sys::ImGuiKey_GamepadDpadLeft as i32,
+    /// D-pad right
+    GamepadDpadRight = sys::ImGuiKey_GamepadDpadRight as i32,
+    /// D-pad up
+    GamepadDpadUp = sys::ImGuiKey_GamepadDpadUp as i32,
+    /// D-pad down
+    GamepadDpadDown = sys::ImGuiKey_GamepadDpadDown as i32,
+    /// Left bumper
+    GamepadL1 = sys::ImGuiKey_GamepadL1 as i32,
+    /// Right bumper
+    GamepadR1 = sys::ImGuiKey_GamepadR1 as i32,
+    /// Left trigger (analog)
+    GamepadL2 = sys::ImGuiKey_GamepadL2 as i32,
+    /// Right trigger (analog)
+    GamepadR2 = sys::ImGuiKey_GamepadR2 as i32,
+    /// Left stick press
+    GamepadL3 = sys::ImGuiKey_GamepadL3 as i32,
+    /// Right stick press
+    GamepadR3 = sys::ImGuiKey_GamepadR3 as i32,
+    /// Left stick left (analog)
+    GamepadLStickLeft = sys::ImGuiKey_GamepadLStickLeft as i32,
+    /// Left stick right (analog)
+    GamepadLStickRight = sys::ImGuiKey_GamepadLStickRight as i32,
+    /// Left stick up (analog)
+    GamepadLStickUp = sys::ImGuiKey_GamepadLStickUp as i32,
+    /// Left stick down (analog)
+    GamepadLStickDown = sys::ImGuiKey_GamepadLStickDown as i32,
+    /// Right stick left (analog)
+    GamepadRStickLeft = sys::ImGuiKey_GamepadRStickLeft as i32,
+    /// Right stick right (analog)
+    GamepadRStickRight = sys::ImGuiKey_GamepadRStickRight as i32,
+    /// Right stick up (analog)
+    GamepadRStickUp = sys::ImGuiKey_GamepadRStickUp as i32,
+    /// Right stick down (analog)
+    GamepadRStickDown = sys::ImGuiKey_GamepadRStickDown as i32,
+}
+
+/// Snapshot of a standard gamepad, fed to Dear ImGui with [`Io::add_gamepad_events`]
+///
+/// Stick axes range from -1.0 to 1.0 with +X pointing right and +Y pointing down;
+/// triggers range from 0.0 to 1.0. Gamepad navigation additionally needs
+/// `ConfigFlags::NAV_ENABLE_GAMEPAD` and `BackendFlags::HAS_GAMEPAD`.
+///
+/// [`Io::add_gamepad_events`]: crate::Io::add_gamepad_events
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct GamepadState {
+    /// Left stick position
+    pub left_stick: [f32; 2],
+    /// Right stick position
+    pub right_stick: [f32; 2],
+    /// Left trigger
+    pub left_trigger: f32,
+    /// Right trigger
+    pub right_trigger: f32,
+    /// D-pad left
+    pub dpad_left: bool,
+    /// D-pad right
+    pub dpad_right: bool,
+    /// D-pad up
+    pub dpad_up: bool,
+    /// D-pad down
+    pub dpad_down: bool,
+    /// Left face button (activates text input / on-screen keyboard)
+    pub face_left: bool,
+    /// Right face button (cancel / close)
+    pub face_right: bool,
+    /// Top face button (menu / toggle windowing)
+    pub face_up: bool,
+    /// Bottom face button (activate / open)
+    pub face_down: bool,
+    /// Left bumper
+    pub l1: bool,
+    /// Right bumper
+    pub r1: bool,
+    /// Left stick press
+    pub l3: bool,
+    /// Right stick press
+    pub r3: bool,
+    /// Start button
+    pub start: bool,
+    /// Back button
+    pub back: bool,
+}
+
+impl GamepadState {
+    /// Stick deflection below which the stick reports no input
+    pub const STICK_DEAD_ZONE: f32 = 0.25;
+    /// Analog value above which an analog key also reports being down
+    pub const ANALOG_DOWN_THRESHOLD: f32 = 0.1;
+
+    /// Every key Dear ImGui reads from a gamepad, with its digital state and analog value
+    pub fn key_events(&self) -> [(Key, bool, f32); 24] {
+        let button = |down: bool| (down, if down { 1.0 } else { 0.0 });
+        let analog = |value: f32, start: f32| {
+            let v = ((value - start) / (1.0 - start)).clamp(0.0, 1.0);
+            (v > Self::ANALOG_DOWN_THRESHOLD, v)
+        };
+        let stick = |value: f32| analog(value, Self::STICK_DEAD_ZONE);
+        let [lx, ly] = self.left_stick;
+        let [rx, ry] = self.right_stick;
+        let events = [
+            (Key::GamepadStart, button(self.start)),
+            (Key::GamepadBack, button(self.back)),
+            (Key::GamepadFaceLeft, button(self.face_left)),
+            (Key::GamepadFaceRight, button(self.face_right)),
+            (Key::GamepadFaceUp, button(self.face_up)),
+            (Key::GamepadFaceDown, button(self.face_down)),
+            (Key::GamepadDpadLeft, button(self.dpad_left)),
+            (Key::GamepadDpadRight, button(self.dpad_right)),
+            (Key::GamepadDpadUp, button(self.dpad_up)),
+            (Key::GamepadDpadDown, button(self.dpad_down)),
+            (Key::GamepadL1, button(self.l1)),
+            (Key::GamepadR1, button(self.r1)),
+            (Key::GamepadL2, analog(self.left_trigger, 0.0)),
+            (Key::GamepadR2, analog(self.right_trigger, 0.0)),
+            (Key::GamepadL3, button(self.l3)),
+            (Key::GamepadR3, button(self.r3)),
+            (Key::GamepadLStickLeft, stick(-lx)),
+            (Key::GamepadLStickRight, stick(lx)),
+            (Key::GamepadLStickUp, stick(-ly)),
+            (Key::GamepadLStickDown, stick(ly)),
+            (Key::GamepadRStickLeft, stick(-rx)),
+            (Key::GamepadRStickRight, stick(rx)),
+            (Key::GamepadRStickUp, stick(-ry)),
+            (Key::GamepadRStickDown, stick(ry)),
+        ];
+        events.map(|(key, (down, value))| (key, down, value))
+    }
 }
 
 impl From<MouseButton> for sys::ImGuiMouseButton {
@@ -288,6 +454,13 @@ impl From<MouseButton> for sys::ImGuiMouseButton {
     }
 }
 
+impl From<MouseSource> for sys::ImGuiMouseSource {
+    #[inline]
+    fn from(value: MouseSource) -> sys::ImGuiMouseSource {
+        value as sys::ImGuiMouseSource
+    }
+}
+
 impl From<Key> for sys::ImGuiKey {
     #[inline]
     fn from(value: Key) -> sys::ImGuiKey {
@@ -353,60 +526,70 @@ impl crate::Ui {
     /// Check if a key is being held down
     #[doc(alias = "IsKeyDown")]
     pub fn is_key_down(&self, key: Key) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsKeyDown_Nil(key as sys::ImGuiKey) }
     }
 
     /// Check if a key was pressed (went from !Down to Down)
     #[doc(alias = "IsKeyPressed")]
     pub fn is_key_pressed(&self, key: Key) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsKeyPressed_Bool(key as sys::ImGuiKey, true) }
     }
 
     /// Check if a key was pressed (went from !Down to Down), with repeat
     #[doc(alias = "IsKeyPressed")]
     pub fn is_key_pressed_with_repeat(&self, key: Key, repeat: bool) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsKeyPressed_Bool(key as sys::ImGuiKey, repeat) }
     }
 
     /// Check if a key was released (went from Down to !Down)
     #[doc(alias = "IsKeyReleased")]
     pub fn is_key_released(&self, key: Key) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsKeyReleased_Nil(key as sys::ImGuiKey) }
     }
 
     /// Check if a mouse button is being held down
     #[doc(alias = "IsMouseDown")]
     pub fn is_mouse_down(&self, button: MouseButton) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsMouseDown_Nil(button.into()) }
     }
 
     /// Check if a mouse button was clicked (went from !Down to Down)
     #[doc(alias = "IsMouseClicked")]
     pub fn is_mouse_clicked(&self, button: MouseButton) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsMouseClicked_Bool(button.into(), false) }
     }
 
     /// Check if a mouse button was clicked, with repeat
     #[doc(alias = "IsMouseClicked")]
     pub fn is_mouse_clicked_with_repeat(&self, button: MouseButton, repeat: bool) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsMouseClicked_Bool(button.into(), repeat) }
     }
 
     /// Check if a mouse button was released (went from Down to !Down)
     #[doc(alias = "IsMouseReleased")]
     pub fn is_mouse_released(&self, button: MouseButton) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsMouseReleased_Nil(button.into()) }
     }
 
     /// Check if a mouse button was double-clicked
     #[doc(alias = "IsMouseDoubleClicked")]
     pub fn is_mouse_double_clicked(&self, button: MouseButton) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsMouseDoubleClicked_Nil(button.into()) }
     }
 
     /// Get mouse position in screen coordinates
     #[doc(alias = "GetMousePos")]
     pub fn mouse_pos(&self) -> [f32; 2] {
+        self.debug_assert_current();
         let mut pos = sys::ImVec2 { x: 0.0, y: 0.0 };
         unsafe { sys::igGetMousePos(&mut pos) };
         [pos.x, pos.y]
@@ -415,6 +598,7 @@ impl crate::Ui {
     /// Get mouse position when a specific button was clicked
     #[doc(alias = "GetMousePosOnOpeningCurrentPopup")]
     pub fn mouse_pos_on_opening_current_popup(&self) -> [f32; 2] {
+        self.debug_assert_current();
         let mut pos = sys::ImVec2 { x: 0.0, y: 0.0 };
         unsafe { sys::igGetMousePosOnOpeningCurrentPopup(&mut pos) };
         [pos.x, pos.y]
@@ -423,6 +607,7 @@ impl crate::Ui {
     /// Check if mouse is hovering given rectangle
     #[doc(alias = "IsMouseHoveringRect")]
     pub fn is_mouse_hovering_rect(&self, r_min: [f32; 2], r_max: [f32; 2]) -> bool {
+        self.debug_assert_current();
         unsafe {
             sys::igIsMouseHoveringRect(
                 sys::ImVec2::new(r_min[0], r_min[1]),
@@ -440,6 +625,7 @@ impl crate::Ui {
         r_max: [f32; 2],
         clip: bool,
     ) -> bool {
+        self.debug_assert_current();
         unsafe {
             sys::igIsMouseHoveringRect(
                 sys::ImVec2::new(r_min[0], r_min[1]),
@@ -452,6 +638,7 @@ impl crate::Ui {
     /// Check if mouse is dragging
     #[doc(alias = "IsMouseDragging")]
     pub fn is_mouse_dragging(&self, button: MouseButton) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsMouseDragging(button as i32, -1.0) }
     }
 
@@ -462,12 +649,14 @@ impl crate::Ui {
         button: MouseButton,
         lock_threshold: f32,
     ) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsMouseDragging(button as i32, lock_threshold) }
     }
 
     /// Get mouse drag delta
     #[doc(alias = "GetMouseDragDelta")]
     pub fn mouse_drag_delta(&self, button: MouseButton) -> [f32; 2] {
+        self.debug_assert_current();
         let mut delta = sys::ImVec2 { x: 0.0, y: 0.0 };
         unsafe { sys::igGetMouseDragDelta(&mut delta, button as i32, -1.0) };
         [delta.x, delta.y]
@@ -480,6 +669,7 @@ impl crate::Ui {
         button: MouseButton,
         lock_threshold: f32,
     ) -> [f32; 2] {
+        self.debug_assert_current();
         let mut delta = sys::ImVec2 { x: 0.0, y: 0.0 };
         unsafe { sys::igGetMouseDragDelta(&mut delta, button as i32, lock_threshold) };
         [delta.x, delta.y]
@@ -488,6 +678,7 @@ impl crate::Ui {
     /// Reset mouse drag delta for a specific button
     #[doc(alias = "ResetMouseDragDelta")]
     pub fn reset_mouse_drag_delta(&self, button: MouseButton) {
+        self.debug_assert_current();
         unsafe { sys::igResetMouseDragDelta(button as i32) }
     }
 }
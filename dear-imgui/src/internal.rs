@@ -114,6 +114,49 @@ unsafe impl DataTypeKind for isize {
     );
 }
 
+/// Formats `value` exactly as Dear ImGui displays it in sliders and drags.
+///
+/// `format` is a C-style printf string such as `"%.3f"` or `"%d"`, and goes through
+/// `DataTypeFormatString`, so the result matches what the scalar widgets render. As with
+/// those widgets, the conversion must suit `T` (e.g. `%d` for integers, `%f` for floats).
+/// The format is cut at the first null byte.
+///
+/// ```
+/// use dear_imgui_rs::internal::format_value;
+///
+/// assert_eq!(format_value(1.5f32, "%.2f"), "1.50");
+/// assert_eq!(format_value(42u8, "%d items"), "42 items");
+/// ```
+#[doc(alias = "DataTypeFormatString")]
+pub fn format_value<T: DataTypeKind>(value: T, format: &str) -> String {
+    let format = format.split('\0').next().unwrap_or_default();
+    let format = std::ffi::CString::new(format).expect("null bytes were stripped");
+    let format_into = |buf: &mut [u8]| unsafe {
+        let ptr = if buf.is_empty() {
+            std::ptr::null_mut()
+        } else {
+            buf.as_mut_ptr().cast()
+        };
+        sys::igDataTypeFormatString(
+            ptr,
+            buf.len() as c_int,
+            T::KIND as i32,
+            &value as *const T as *const std::ffi::c_void,
+            format.as_ptr(),
+        )
+    };
+
+    // With a null buffer ImFormatString only reports the length it needs
+    let len = format_into(&mut []);
+    if len <= 0 {
+        return String::new();
+    }
+    let mut buf = vec![0u8; len as usize + 1];
+    let written = format_into(&mut buf).max(0) as usize;
+    buf.truncate(written);
+    String::from_utf8_lossy(&buf).into_owned()
+}
+
 /// A generic version of the raw imgui-sys ImVector struct types
 ///
 /// This provides a safe Rust interface to Dear ImGui's vector type.
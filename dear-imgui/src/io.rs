@@ -27,6 +27,7 @@ use crate::sys;
 bitflags! {
     /// Configuration flags
     #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     pub struct ConfigFlags: i32 {
         /// Master keyboard navigation enable flag.
         const NAV_ENABLE_KEYBOARD = sys::ImGuiConfigFlags_NavEnableKeyboard as i32;
@@ -40,9 +41,9 @@ bitflags! {
         const IS_SRGB = sys::ImGuiConfigFlags_IsSRGB as i32;
         /// Application is using a touch screen instead of a mouse.
         const IS_TOUCH_SCREEN = sys::ImGuiConfigFlags_IsTouchScreen as i32;
-
+        /// Docking enable flag.
         const DOCKING_ENABLE = sys::ImGuiConfigFlags_DockingEnable as i32;
-
+        /// Multi-viewport enable flag; needs platform and renderer backend support.
         const VIEWPORTS_ENABLE = sys::ImGuiConfigFlags_ViewportsEnable as i32;
     }
 }
@@ -68,6 +69,9 @@ bitflags! {
         #[cfg(feature = "multi-viewport")]
         /// Set if the renderer backend supports viewports.
         const RENDERER_HAS_VIEWPORTS = sys::ImGuiBackendFlags_RendererHasViewports as i32;
+        #[cfg(feature = "multi-viewport")]
+        /// Set if the platform backend reports the hovered viewport via `add_mouse_viewport_event`.
+        const HAS_MOUSE_HOVERED_VIEWPORT = sys::ImGuiBackendFlags_HasMouseHoveredViewport as i32;
     }
 }
 
@@ -157,6 +161,11 @@ impl Io {
         self.0.MousePos.y = pos[1];
     }
 
+    /// Mouse movement since the previous frame, in pixels
+    pub fn mouse_delta(&self) -> [f32; 2] {
+        [self.0.MouseDelta.x, self.0.MouseDelta.y]
+    }
+
     /// Mouse wheel vertical scrolling
     pub fn mouse_wheel(&self) -> f32 {
         self.0.MouseWheel
@@ -208,6 +217,17 @@ impl Io {
         self.0.WantTextInput
     }
 
+    /// Keyboard/gamepad navigation is currently allowed (a window is focused and does not
+    /// use `NO_NAV_INPUTS`)
+    pub fn nav_active(&self) -> bool {
+        self.0.NavActive
+    }
+
+    /// Keyboard/gamepad navigation highlight is visible and the user is navigating with it
+    pub fn nav_visible(&self) -> bool {
+        self.0.NavVisible
+    }
+
     /// Check if imgui wants to set mouse position
     pub fn want_set_mouse_pos(&self) -> bool {
         self.0.WantSetMousePos
@@ -221,11 +241,41 @@ impl Io {
         self.0.MouseDrawCursor = draw;
     }
 
+    /// Whether macOS conventions are used (Cmd for shortcuts, Option for word moves)
+    ///
+    /// Defaults to `true` on Apple targets.
+    pub fn config_mac_os_behaviors(&self) -> bool {
+        self.0.ConfigMacOSXBehaviors
+    }
+
+    /// Set whether macOS conventions are used
+    pub fn set_config_mac_os_behaviors(&mut self, enabled: bool) {
+        self.0.ConfigMacOSXBehaviors = enabled;
+    }
+
     /// Check if imgui wants to save ini settings
+    ///
+    /// Only raised when no INI filename is set; saving with
+    /// [`Context::save_ini_settings`](crate::Context::save_ini_settings) clears it.
     pub fn want_save_ini_settings(&self) -> bool {
         self.0.WantSaveIniSettings
     }
 
+    /// Set or clear the ini settings save request
+    pub fn set_want_save_ini_settings(&mut self, want: bool) {
+        self.0.WantSaveIniSettings = want;
+    }
+
+    /// Minimum time in seconds between a settings change and saving them
+    pub fn ini_saving_rate(&self) -> f32 {
+        self.0.IniSavingRate
+    }
+
+    /// Set the minimum time in seconds between a settings change and saving them
+    pub fn set_ini_saving_rate(&mut self, rate: f32) {
+        self.0.IniSavingRate = rate;
+    }
+
     /// Framerate estimation, in frames per second
     pub fn framerate(&self) -> f32 {
         self.0.Framerate
@@ -278,6 +328,25 @@ impl Io {
         }
     }
 
+    /// Add an analog key event (e.g. a gamepad trigger) to the input queue
+    ///
+    /// `value` ranges from 0.0 to 1.0.
+    pub fn add_key_analog_event(&mut self, key: crate::Key, down: bool, value: f32) {
+        unsafe {
+            sys::ImGuiIO_AddKeyAnalogEvent(&mut self.0 as *mut _, key.into(), down, value);
+        }
+    }
+
+    /// Add analog key events for every gamepad key from a snapshot of the gamepad
+    ///
+    /// Call once per frame while a gamepad is connected; stick dead zones and trigger
+    /// thresholds are applied as described on [`GamepadState`](crate::GamepadState).
+    pub fn add_gamepad_events(&mut self, state: &crate::input::GamepadState) {
+        for (key, down, value) in state.key_events() {
+            self.add_key_analog_event(key, down, value);
+        }
+    }
+
     /// Add a character input event to the input queue
     pub fn add_input_character(&mut self, character: char) {
         unsafe {
@@ -285,6 +354,22 @@ impl Io {
         }
     }
 
+    /// Add a UTF-16 code unit to the input queue
+    ///
+    /// Surrogate pairs may be sent as two separate calls, as Windows IME messages do.
+    pub fn add_input_character_utf16(&mut self, unit: u16) {
+        unsafe {
+            sys::ImGuiIO_AddInputCharacterUTF16(&mut self.0 as *mut _, unit);
+        }
+    }
+
+    /// Add a character input event to the input queue for each character of `text`
+    pub fn add_input_characters(&mut self, text: &str) {
+        for character in text.chars() {
+            self.add_input_character(character);
+        }
+    }
+
     /// Add a mouse position event to the input queue
     pub fn add_mouse_pos_event(&mut self, pos: [f32; 2]) {
         unsafe {
@@ -299,6 +384,37 @@ impl Io {
         }
     }
 
+    /// Set the source of the mouse events queued after this one
+    ///
+    /// Backends receiving touch or pen input call this before the matching position and
+    /// button events, and switch back to [`MouseSource::Mouse`](crate::MouseSource) for
+    /// regular mouse events.
+    pub fn add_mouse_source_event(&mut self, source: crate::input::MouseSource) {
+        unsafe {
+            sys::ImGuiIO_AddMouseSourceEvent(&mut self.0 as *mut _, source.into());
+        }
+    }
+
+    /// Source of the most recently processed mouse event
+    pub fn mouse_source(&self) -> crate::input::MouseSource {
+        use crate::input::MouseSource;
+        match self.0.MouseSource {
+            sys::ImGuiMouseSource_TouchScreen => MouseSource::TouchScreen,
+            sys::ImGuiMouseSource_Pen => MouseSource::Pen,
+            _ => MouseSource::Mouse,
+        }
+    }
+
+    /// Add an event telling which viewport the mouse is hovering
+    ///
+    /// Only needed by platform backends setting `BackendFlags::HAS_MOUSE_HOVERED_VIEWPORT`;
+    /// pass the default id when no viewport is hovered.
+    pub fn add_mouse_viewport_event(&mut self, id: crate::Id) {
+        unsafe {
+            sys::ImGuiIO_AddMouseViewportEvent(&mut self.0 as *mut _, id.into());
+        }
+    }
+
     /// Add a mouse wheel event to the input queue
     pub fn add_mouse_wheel_event(&mut self, wheel: [f32; 2]) {
         unsafe {
@@ -314,6 +430,37 @@ impl Io {
         }
     }
 
+    /// Set whether queued input events are accepted
+    ///
+    /// While disabled, new events are dropped instead of queued, e.g. while the
+    /// application shows a modal OS dialog.
+    pub fn set_app_accepting_events(&mut self, accepting: bool) {
+        unsafe {
+            sys::ImGuiIO_SetAppAcceptingEvents(&mut self.0 as *mut _, accepting);
+        }
+    }
+
+    /// Drop all events queued and not yet processed by a frame
+    pub fn clear_events_queue(&mut self) {
+        unsafe {
+            sys::ImGuiIO_ClearEventsQueue(&mut self.0 as *mut _);
+        }
+    }
+
+    /// Release all keys, e.g. when the application loses focus without key-up events
+    pub fn clear_input_keys(&mut self) {
+        unsafe {
+            sys::ImGuiIO_ClearInputKeys(&mut self.0 as *mut _);
+        }
+    }
+
+    /// Release all mouse buttons and reset the mouse position
+    pub fn clear_input_mouse(&mut self) {
+        unsafe {
+            sys::ImGuiIO_ClearInputMouse(&mut self.0 as *mut _);
+        }
+    }
+
     /// Get the global font scale (not available in current Dear ImGui version)
     /// Compatibility shim: maps to style.FontScaleMain (Dear ImGui 1.92+)
     pub fn font_global_scale(&self) -> f32 {
@@ -46,24 +46,28 @@ impl Ui {
     /// This becomes a vertical separator inside a menu bar or in horizontal layout mode.
     #[doc(alias = "Separator")]
     pub fn separator(&self) {
+        self.debug_assert_current();
         unsafe { sys::igSeparator() }
     }
 
     /// Renders a separator with text.
     #[doc(alias = "SeparatorText")]
     pub fn separator_with_text(&self, text: impl AsRef<str>) {
+        self.debug_assert_current();
         unsafe { sys::igSeparatorText(self.scratch_txt(text)) }
     }
 
     /// Creates a vertical separator
     #[doc(alias = "SeparatorEx")]
     pub fn separator_vertical(&self) {
+        self.debug_assert_current();
         unsafe { sys::igSeparatorEx(sys::ImGuiSeparatorFlags_Vertical as i32, 1.0) }
     }
 
     /// Creates a horizontal separator
     #[doc(alias = "SeparatorEx")]
     pub fn separator_horizontal(&self) {
+        self.debug_assert_current();
         unsafe { sys::igSeparatorEx(sys::ImGuiSeparatorFlags_Horizontal as i32, 1.0) }
     }
 
@@ -75,6 +79,7 @@ impl Ui {
     /// with the `pos` set to 0.0, which uses `Style::item_spacing`.
     #[doc(alias = "SameLine")]
     pub fn same_line(&self) {
+        self.debug_assert_current();
         self.same_line_with_pos(0.0);
     }
 
@@ -86,6 +91,7 @@ impl Ui {
     /// with the `spacing` set to -1.0, which means no extra spacing.
     #[doc(alias = "SameLine")]
     pub fn same_line_with_pos(&self, pos_x: f32) {
+        self.debug_assert_current();
         self.same_line_with_spacing(pos_x, -1.0)
     }
 
@@ -94,18 +100,21 @@ impl Ui {
     /// X position is given in window coordinates.
     #[doc(alias = "SameLine")]
     pub fn same_line_with_spacing(&self, pos_x: f32, spacing_w: f32) {
+        self.debug_assert_current();
         unsafe { sys::igSameLine(pos_x, spacing_w) }
     }
 
     /// Undo a `same_line` call or force a new line when in horizontal layout mode
     #[doc(alias = "NewLine")]
     pub fn new_line(&self) {
+        self.debug_assert_current();
         unsafe { sys::igNewLine() }
     }
 
     /// Adds vertical spacing
     #[doc(alias = "Spacing")]
     pub fn spacing(&self) {
+        self.debug_assert_current();
         unsafe { sys::igSpacing() }
     }
 
@@ -114,6 +123,7 @@ impl Ui {
     /// Can be used to move the cursor on the window.
     #[doc(alias = "Dummy")]
     pub fn dummy(&self, size: impl Into<[f32; 2]>) {
+        self.debug_assert_current();
         let size_vec: sys::ImVec2 = size.into().into();
         unsafe { sys::igDummy(size_vec) }
     }
@@ -124,12 +134,14 @@ impl Ui {
     /// `Style::indent_spacing`.
     #[doc(alias = "Indent")]
     pub fn indent(&self) {
+        self.debug_assert_current();
         self.indent_by(0.0)
     }
 
     /// Moves content position to the right by `width`
     #[doc(alias = "Indent")]
     pub fn indent_by(&self, width: f32) {
+        self.debug_assert_current();
         unsafe { sys::igIndent(width) };
     }
 
@@ -139,12 +151,14 @@ impl Ui {
     /// `Style::indent_spacing`.
     #[doc(alias = "Unindent")]
     pub fn unindent(&self) {
+        self.debug_assert_current();
         self.unindent_by(0.0)
     }
 
     /// Moves content position to the left by `width`
     #[doc(alias = "Unindent")]
     pub fn unindent_by(&self, width: f32) {
+        self.debug_assert_current();
         unsafe { sys::igUnindent(width) };
     }
 
@@ -153,6 +167,7 @@ impl Ui {
     /// Returns a `GroupToken` that must be ended by calling `.end()`.
     #[doc(alias = "BeginGroup")]
     pub fn begin_group(&self) -> GroupToken<'_> {
+        self.debug_assert_current();
         unsafe { sys::igBeginGroup() };
         GroupToken::new(self)
     }
@@ -162,15 +177,111 @@ impl Ui {
     /// May be useful to handle the same mouse event on a group of items, for example.
     #[doc(alias = "BeginGroup")]
     pub fn group<R, F: FnOnce() -> R>(&self, f: F) -> R {
+        self.debug_assert_current();
         let group = self.begin_group();
         let result = f();
         group.end();
         result
     }
 
+    /// Creates a layout group framed by a border and runs a closure to construct the
+    /// contents.
+    ///
+    /// The contents are inset by the style's frame padding and the border is drawn in the
+    /// `Border` color once the group is closed, so the whole frame is the last item.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a [`DrawListMut`](crate::DrawListMut) for the window draw list is alive
+    /// when the group is closed.
+    #[doc(alias = "BeginGroup")]
+    pub fn group_bordered<R, F: FnOnce() -> R>(&self, f: F) -> R {
+        self.debug_assert_current();
+        self.bordered_group("group_bordered", None, f)
+    }
+
+    /// Like [`group_bordered`](Self::group_bordered), with `title` set into the top
+    /// border (fieldset style).
+    ///
+    /// Anything after `##` in the title is hidden.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a [`DrawListMut`](crate::DrawListMut) for the window draw list is alive
+    /// when the group is closed.
+    #[doc(alias = "BeginGroup")]
+    pub fn group_bordered_with_title<R, F: FnOnce() -> R>(
+        &self,
+        title: impl AsRef<str>,
+        f: F,
+    ) -> R {
+        self.debug_assert_current();
+        self.bordered_group(
+            "group_bordered_with_title",
+            Some(crate::widget::visible_label(title.as_ref())),
+            f,
+        )
+    }
+
+    fn bordered_group<R, F: FnOnce() -> R>(&self, widget: &str, title: Option<&str>, f: F) -> R {
+        self.debug_assert_current();
+        let padding = self.clone_style().frame_padding();
+        let title_size =
+            title.map(|t| [crate::widget::text_width(t, false), self.text_line_height()]);
+        // The border runs through the middle of the title line
+        let border_offset = title_size.map_or(0.0, |[_, h]| h * 0.5);
+        let title_inset = padding[0] * 2.0;
+
+        let outer = self.begin_group();
+        let start = self.cursor_screen_pos();
+        let content_start = [
+            start[0] + padding[0],
+            start[1] + border_offset * 2.0 + padding[1],
+        ];
+        // Reserve the padding (and title line) before moving the cursor past it
+        self.dummy([padding[0], content_start[1] - start[1]]);
+        self.set_cursor_screen_pos(content_start);
+        let inner = self.begin_group();
+        let result = f();
+        inner.end();
+        let (_, inner_max) = self.item_rect();
+        // Extend the outer group to the padded frame, wide enough for the title
+        let right = (inner_max[0] + padding[0])
+            .max(start[0] + title_size.map_or(0.0, |[w, _]| title_inset * 2.0 + w));
+        self.set_cursor_screen_pos([start[0], inner_max[1] + padding[1]]);
+        self.dummy([right - start[0], 0.0]);
+        outer.end();
+
+        let (min, max) = self.item_rect();
+        let top = min[1] + border_offset;
+        let border = self.get_color_u32(crate::StyleColor::Border);
+        let draw_list = crate::DrawListMut::window_for_widget(self, widget);
+        match (title, title_size) {
+            (Some(title), Some([width, _])) => {
+                let gap = padding[0] * 0.5;
+                let title_x = min[0] + title_inset;
+                draw_list.path_line_to([title_x - gap, top]);
+                draw_list.path_line_to([min[0], top]);
+                draw_list.path_line_to([min[0], max[1]]);
+                draw_list.path_line_to([max[0], max[1]]);
+                draw_list.path_line_to([max[0], top]);
+                draw_list.path_line_to([title_x + width + gap, top]);
+                draw_list.path_stroke(border, crate::DrawFlags::NONE, 1.0);
+                draw_list.add_text(
+                    [title_x, min[1]],
+                    self.get_color_u32(crate::StyleColor::Text),
+                    title,
+                );
+            }
+            _ => draw_list.add_rect([min[0], top], max, border).build(),
+        }
+        result
+    }
+
     /// Returns the cursor position (in window coordinates)
     #[doc(alias = "GetCursorPos")]
     pub fn cursor_pos(&self) -> [f32; 2] {
+        self.debug_assert_current();
         unsafe {
             let mut pos = sys::ImVec2 { x: 0.0, y: 0.0 };
             sys::igGetCursorPos(&mut pos);
@@ -181,6 +292,7 @@ impl Ui {
     /// Returns the cursor position (in absolute screen coordinates)
     #[doc(alias = "GetCursorScreenPos")]
     pub fn cursor_screen_pos(&self) -> [f32; 2] {
+        self.debug_assert_current();
         unsafe {
             let mut pos = sys::ImVec2 { x: 0.0, y: 0.0 };
             sys::igGetCursorScreenPos(&mut pos);
@@ -191,6 +303,7 @@ impl Ui {
     /// Sets the cursor position (in window coordinates)
     #[doc(alias = "SetCursorPos")]
     pub fn set_cursor_pos(&self, pos: impl Into<[f32; 2]>) {
+        self.debug_assert_current();
         let pos_array = pos.into();
         let pos_vec = sys::ImVec2 {
             x: pos_array[0],
@@ -202,6 +315,7 @@ impl Ui {
     /// Sets the cursor position (in absolute screen coordinates)
     #[doc(alias = "SetCursorScreenPos")]
     pub fn set_cursor_screen_pos(&self, pos: impl Into<[f32; 2]>) {
+        self.debug_assert_current();
         let pos_array = pos.into();
         let pos_vec = sys::ImVec2 {
             x: pos_array[0],
@@ -213,30 +327,35 @@ impl Ui {
     /// Returns the X cursor position (in window coordinates)
     #[doc(alias = "GetCursorPosX")]
     pub fn cursor_pos_x(&self) -> f32 {
+        self.debug_assert_current();
         unsafe { sys::igGetCursorPosX() }
     }
 
     /// Returns the Y cursor position (in window coordinates)
     #[doc(alias = "GetCursorPosY")]
     pub fn cursor_pos_y(&self) -> f32 {
+        self.debug_assert_current();
         unsafe { sys::igGetCursorPosY() }
     }
 
     /// Sets the X cursor position (in window coordinates)
     #[doc(alias = "SetCursorPosX")]
     pub fn set_cursor_pos_x(&self, x: f32) {
+        self.debug_assert_current();
         unsafe { sys::igSetCursorPosX(x) };
     }
 
     /// Sets the Y cursor position (in window coordinates)
     #[doc(alias = "SetCursorPosY")]
     pub fn set_cursor_pos_y(&self, y: f32) {
+        self.debug_assert_current();
         unsafe { sys::igSetCursorPosY(y) };
     }
 
     /// Returns the initial cursor position (in window coordinates)
     #[doc(alias = "GetCursorStartPos")]
     pub fn cursor_start_pos(&self) -> [f32; 2] {
+        self.debug_assert_current();
         unsafe {
             let mut pos = sys::ImVec2 { x: 0.0, y: 0.0 };
             sys::igGetCursorStartPos(&mut pos);
@@ -253,24 +372,28 @@ impl Ui {
     /// Return ~ FontSize.
     #[doc(alias = "GetTextLineHeight")]
     pub fn text_line_height(&self) -> f32 {
+        self.debug_assert_current();
         unsafe { sys::igGetTextLineHeight() }
     }
 
     /// Return ~ FontSize + style.ItemSpacing.y.
     #[doc(alias = "GetTextLineHeightWithSpacing")]
     pub fn text_line_height_with_spacing(&self) -> f32 {
+        self.debug_assert_current();
         unsafe { sys::igGetTextLineHeightWithSpacing() }
     }
 
     /// Return ~ FontSize + style.FramePadding.y * 2.
     #[doc(alias = "GetFrameHeight")]
     pub fn frame_height(&self) -> f32 {
+        self.debug_assert_current();
         unsafe { sys::igGetFrameHeight() }
     }
 
     /// Return ~ FontSize + style.FramePadding.y * 2 + style.ItemSpacing.y.
     #[doc(alias = "GetFrameHeightWithSpacing")]
     pub fn frame_height_with_spacing(&self) -> f32 {
+        self.debug_assert_current();
         unsafe { sys::igGetFrameHeightWithSpacing() }
     }
 
@@ -282,6 +405,7 @@ impl Ui {
         max: impl Into<[f32; 2]>,
         intersect_with_current: bool,
     ) {
+        self.debug_assert_current();
         let min = min.into();
         let max = max.into();
         let min_v = sys::ImVec2 {
@@ -298,6 +422,7 @@ impl Ui {
     /// Pop a clipping rectangle from the stack.
     #[doc(alias = "PopClipRect")]
     pub fn pop_clip_rect(&self) {
+        self.debug_assert_current();
         unsafe { sys::igPopClipRect() }
     }
 
@@ -309,6 +434,7 @@ impl Ui {
         intersect_with_current: bool,
         f: impl FnOnce() -> R,
     ) -> R {
+        self.debug_assert_current();
         self.push_clip_rect(min, max, intersect_with_current);
         let _t = ClipRectToken::new(self);
         f()
@@ -321,6 +447,7 @@ impl Ui {
         rect_min: impl Into<[f32; 2]>,
         rect_max: impl Into<[f32; 2]>,
     ) -> bool {
+        self.debug_assert_current();
         let mn = rect_min.into();
         let mx = rect_max.into();
         let mn_v = sys::ImVec2 { x: mn[0], y: mn[1] };
@@ -331,6 +458,7 @@ impl Ui {
     /// Returns true if a rectangle of given size at the current cursor pos is visible.
     #[doc(alias = "IsRectVisible")]
     pub fn is_rect_visible_with_size(&self, size: impl Into<[f32; 2]>) -> bool {
+        self.debug_assert_current();
         let s = size.into();
         let v = sys::ImVec2 { x: s[0], y: s[1] };
         unsafe { sys::igIsRectVisible_Nil(v) }
@@ -339,6 +467,7 @@ impl Ui {
     /// Vertically align upcoming text baseline to FramePadding.y (align text to framed items).
     #[doc(alias = "AlignTextToFramePadding")]
     pub fn align_text_to_frame_padding(&self) {
+        self.debug_assert_current();
         unsafe { sys::igAlignTextToFramePadding() }
     }
 }
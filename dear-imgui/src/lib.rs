@@ -176,6 +176,21 @@
 //!
 //! Draw list wrappers expose both high-level primitives and some low-level building blocks:
 //!
+//! - Path builder (gauges, arcs, custom outlines):
+//!   - `path_clear`, `path_line_to`, `path_arc_to`, `path_arc_to_fast`, `path_elliptical_arc_to`,
+//!     `path_bezier_quadratic_curve_to`, `path_bezier_cubic_curve_to` and `path_rect` accumulate
+//!     points; `path_stroke(color, flags, thickness)` or `path_fill_convex(color)` draw and clear
+//!     the path. Pass `DrawFlags::CLOSED` to `path_stroke` to close the outline.
+//!     ```no_run
+//!     # use dear_imgui_rs::*;
+//!     # fn demo(ui: &Ui) {
+//!     let dl = ui.get_window_draw_list();
+//!     dl.path_clear();
+//!     dl.path_arc_to([100.0, 100.0], 40.0, std::f32::consts::PI, 2.0 * std::f32::consts::PI, 0);
+//!     dl.path_stroke([0.2, 0.8, 0.4, 1.0], DrawFlags::NONE, 4.0);
+//!     # }
+//!     ```
+//!
 //! - Concave polygons (ImGui 1.92+):
 //!   - `DrawListMut::add_concave_poly_filled(&[P], color)` fills an arbitrary concave polygon.
 //!   - `DrawListMut::path_fill_concave(color)` fills the current path using the concave tessellator.
@@ -298,9 +313,22 @@ pub use self::drag_drop::*;
 // Text filtering system
 pub use self::text_filter::*;
 
+// Toast notifications
+pub use self::toasts::*;
+
+// Pan/zoom canvas
+pub use self::canvas::*;
+
+// Flame graph
+pub use self::flame_graph::*;
+
 // Column layout system (included in layout module)
 pub use self::columns::*;
 
+// Headless UI driver for tests
+#[cfg(feature = "test-harness")]
+pub use self::test_harness::{ItemRecorder, ItemRect, TestHarness};
+
 // Internal modules
 mod clipboard;
 mod colors;
@@ -318,6 +346,8 @@ pub mod platform_io;
 pub mod render;
 mod string;
 mod style;
+#[cfg(feature = "serde")]
+mod style_serde;
 pub mod texture;
 mod ui;
 mod utils;
@@ -343,9 +373,22 @@ mod drag_drop;
 // Text filtering system
 mod text_filter;
 
+// Toast notifications
+mod toasts;
+
+// Pan/zoom canvas
+mod canvas;
+
+// Flame graph
+mod flame_graph;
+
 // Column layout system
 mod columns;
 
+// Headless UI driver for tests
+#[cfg(feature = "test-harness")]
+pub mod test_harness;
+
 // Logging utilities
 pub mod logging;
 
@@ -353,8 +396,8 @@ pub mod logging;
 pub use colors::*;
 pub use dock_builder::*;
 pub use dock_space::*;
-// Export DrawListMut for extensions
-pub use draw::DrawListMut;
+// Export DrawListMut for extensions, and DrawFlags for path/rect drawing
+pub use draw::{ChannelsToken, DrawFlags, DrawListMut, ItemDrawList};
 pub use error::*;
 // Note: draw types are now in render module, no need to export draw::*
 pub use render::*;
@@ -4,6 +4,7 @@
 //! lists by only processing visible items.
 //!
 use std::marker::PhantomData;
+use std::ops::Range;
 
 use crate::Ui;
 use crate::sys;
@@ -47,6 +48,22 @@ impl ListClipper {
             ListClipperToken::new(ui, ptr)
         }
     }
+
+    /// Calls `f` with each range of visible item indices, stepping the clipper until done
+    ///
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # fn f(ui: &Ui, lines: &[String]) {
+    /// ListClipper::new(lines.len() as i32).for_each(ui, |range| {
+    ///     for line in &lines[range] {
+    ///         ui.text(line);
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    pub fn for_each(self, ui: &Ui, f: impl FnMut(Range<usize>)) {
+        self.begin(ui).ranges().for_each(f);
+    }
 }
 
 /// List clipper is a mechanism to efficiently implement scrolling of
@@ -126,28 +143,57 @@ impl<'ui> ListClipperToken<'ui> {
     pub fn iter(self) -> ListClipperIterator<'ui> {
         ListClipperIterator::new(self)
     }
+
+    /// Get an iterator which outputs the range of visible indexes for each step
+    pub fn ranges(self) -> ListClipperRanges<'ui> {
+        ListClipperRanges {
+            list_clipper: self,
+            exhausted: false,
+        }
+    }
 }
 
 impl Drop for ListClipperToken<'_> {
     fn drop(&mut self) {
+        // Ending is a no-op once `step` has returned false, but is required before
+        // destroying a clipper that was abandoned early, e.g. by a `break` or a panic
         unsafe {
+            sys::ImGuiListClipper_End(self.list_clipper);
             sys::ImGuiListClipper_destroy(self.list_clipper);
         };
     }
 }
 
-pub struct ListClipperIterator<'ui> {
+/// Iterator over the ranges of visible item indexes, one per clipper step
+pub struct ListClipperRanges<'ui> {
     list_clipper: ListClipperToken<'ui>,
     exhausted: bool,
-    last_value: Option<i32>,
+}
+
+impl Iterator for ListClipperRanges<'_> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted || !self.list_clipper.step() {
+            self.exhausted = true;
+            return None;
+        }
+        let start = self.list_clipper.display_start().max(0) as usize;
+        let end = self.list_clipper.display_end().max(0) as usize;
+        Some(start..end.max(start))
+    }
+}
+
+pub struct ListClipperIterator<'ui> {
+    ranges: ListClipperRanges<'ui>,
+    current: Range<usize>,
 }
 
 impl<'ui> ListClipperIterator<'ui> {
     fn new(list_clipper: ListClipperToken<'ui>) -> Self {
         Self {
-            list_clipper,
-            exhausted: false,
-            last_value: None,
+            ranges: list_clipper.ranges(),
+            current: 0..0,
         }
     }
 }
@@ -156,52 +202,12 @@ impl Iterator for ListClipperIterator<'_> {
     type Item = i32;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if let Some(lv) = self.last_value {
-            // Currently iterating a chunk (returning all values
-            // between display_start and display_end)
-            let next_value = lv + 1;
-
-            if lv >= self.list_clipper.display_end() - 1 {
-                // If we reach the end of the current chunk, clear
-                // last_value so we call step below
-                self.last_value = None;
-            } else {
-                // Otherwise just increment it
-                self.last_value = Some(next_value);
-            }
-        }
-
-        if let Some(lv) = self.last_value {
-            // Next item within current step's chunk
-            Some(lv)
-        } else {
-            // Start iterating a new chunk
-
-            if self.exhausted {
-                // If the clipper is exhausted, don't call step again!
-                None
-            } else {
-                // Advance the clipper
-                let ret = self.list_clipper.step();
-                if !ret {
-                    self.exhausted = true;
-                    None
-                } else {
-                    // Setup iteration for this step's chunk
-                    let start = self.list_clipper.display_start();
-                    let end = self.list_clipper.display_end();
-
-                    if start == end {
-                        // Somewhat special case: if a single item, we
-                        // don't store the last_value so we call
-                        // step() again next iteration
-                        self.last_value = None;
-                    } else {
-                        self.last_value = Some(start);
-                    }
-                    Some(start)
-                }
+        loop {
+            if let Some(index) = self.current.next() {
+                return Some(index as i32);
             }
+            // Steps may yield empty ranges, so keep stepping until one has items
+            self.current = self.ranges.next()?;
         }
     }
 }
@@ -700,6 +700,33 @@ impl PlatformIo {
         }
     }
 
+    /// Replaces the monitors list with a copy of `monitors`
+    ///
+    /// Platform backends must provide at least one monitor before the first frame with
+    /// viewports enabled, and refresh the list when monitors change.
+    #[cfg(feature = "multi-viewport")]
+    pub fn set_monitors(&mut self, monitors: &[sys::ImGuiPlatformMonitor]) {
+        let vec = &mut self.raw.Monitors;
+        unsafe {
+            // The storage is owned by ImGui's allocator, which frees it on shutdown
+            if !vec.Data.is_null() {
+                sys::igMemFree(vec.Data as *mut c_void);
+            }
+            vec.Data = std::ptr::null_mut();
+            vec.Size = 0;
+            vec.Capacity = 0;
+            if monitors.is_empty() {
+                return;
+            }
+            let data =
+                sys::igMemAlloc(std::mem::size_of_val(monitors)) as *mut sys::ImGuiPlatformMonitor;
+            std::ptr::copy_nonoverlapping(monitors.as_ptr(), data, monitors.len());
+            vec.Data = data;
+            vec.Size = monitors.len() as i32;
+            vec.Capacity = monitors.len() as i32;
+        }
+    }
+
     /// Get access to the viewports vector
     #[cfg(feature = "multi-viewport")]
     pub fn viewports(&self) -> &crate::internal::ImVector<*mut sys::ImGuiViewport> {
@@ -269,6 +269,9 @@ impl DrawList {
     pub fn vtx_buffer(&self) -> &[DrawVert] {
         unsafe {
             let vtx_buffer = &self.0.VtxBuffer;
+            if vtx_buffer.Size <= 0 || vtx_buffer.Data.is_null() {
+                return &[];
+            }
             slice::from_raw_parts(vtx_buffer.Data as *const DrawVert, vtx_buffer.Size as usize)
         }
     }
@@ -277,11 +280,30 @@ impl DrawList {
     pub fn idx_buffer(&self) -> &[DrawIdx] {
         unsafe {
             let idx_buffer = &self.0.IdxBuffer;
+            if idx_buffer.Size <= 0 || idx_buffer.Data.is_null() {
+                return &[];
+            }
             slice::from_raw_parts(idx_buffer.Data, idx_buffer.Size as usize)
         }
     }
 }
 
+/// Value of `ImDrawCallback_ResetRenderState`
+pub(crate) const RESET_RENDER_STATE: isize = -8;
+
+/// Texture id of a command, following its `ImTextureData` when it has one
+///
+/// Unlike `ImDrawCmd_GetTexID` this does not assert on textures the renderer has not
+/// created yet; those resolve to the null id.
+pub(crate) fn effective_texture_id(cmd: &sys::ImDrawCmd) -> TextureId {
+    let tex_data = cmd.TexRef._TexData;
+    if tex_data.is_null() {
+        TextureId::from(cmd.TexRef._TexID)
+    } else {
+        TextureId::from(unsafe { (*tex_data).TexID })
+    }
+}
+
 /// Iterator over draw commands
 pub struct DrawCmdIterator<'a> {
     iter: slice::Iter<'a, sys::ImDrawCmd>,
@@ -299,15 +321,14 @@ impl<'a> Iterator for DrawCmdIterator<'a> {
                     cmd.ClipRect.z,
                     cmd.ClipRect.w,
                 ],
-                // Use raw field; backends may resolve effective TexID later
-                texture_id: TextureId::from(cmd.TexRef._TexID),
+                texture_id: effective_texture_id(cmd),
                 vtx_offset: cmd.VtxOffset as usize,
                 idx_offset: cmd.IdxOffset as usize,
             };
 
             // Check for special callback values
             match cmd.UserCallback {
-                Some(raw_callback) if raw_callback as usize == (-1isize) as usize => {
+                Some(raw_callback) if raw_callback as usize == RESET_RENDER_STATE as usize => {
                     DrawCmd::ResetRenderState
                 }
                 Some(raw_callback) => DrawCmd::RawCallback {
@@ -331,11 +352,10 @@ pub struct DrawCmdParams {
     pub clip_rect: [f32; 4],
     /// Texture ID to use for rendering
     ///
-    /// Notes:
-    /// - For legacy paths (plain `TextureId`), this is the effective id.
-    /// - With the modern texture system (ImTextureRef/ImTextureData), this may be 0.
-    ///   Renderer backends should resolve the effective id at bind time using
-    ///   `ImDrawCmd_GetTexID` with the `raw_cmd` pointer and the backend render state.
+    /// For commands drawing an `ImTextureData` (e.g. the font atlas) this is the id the
+    /// renderer assigned to it, read when the command is yielded. Handle
+    /// [`DrawData::textures`] before iterating commands so newly created textures
+    /// resolve to their id instead of the null id.
     pub texture_id: TextureId,
     /// Vertex buffer offset
     pub vtx_offset: usize,
@@ -350,6 +370,7 @@ pub enum DrawCmd {
     Elements {
         /// The number of indices used for this draw command
         count: usize,
+        /// Clip rect, texture and buffer offsets
         cmd_params: DrawCmdParams,
         /// Raw command pointer for backends
         ///
@@ -359,11 +380,14 @@ pub enum DrawCmd {
         /// call that produced it; do not store it.
         raw_cmd: *const sys::ImDrawCmd,
     },
-    /// Reset render state
+    /// Request to restore the renderer's default render state
     ResetRenderState,
-    /// Raw callback
+    /// User callback, run by calling `callback(draw_list.raw(), raw_cmd)` at this point
+    /// of the draw sequence
     RawCallback {
+        /// Callback function
         callback: unsafe extern "C" fn(*const sys::ImDrawList, cmd: *const sys::ImDrawCmd),
+        /// Raw command, only valid while the draw data is
         raw_cmd: *const sys::ImDrawCmd,
     },
 }
@@ -6,6 +6,61 @@
 // Re-export texture types for backward compatibility
 pub use crate::texture::{RawTextureId, TextureData, TextureFormat, TextureRect, TextureStatus};
 
+use crate::error::ImGuiRenderer;
+use crate::io::BackendFlags;
+use crate::render::DrawData;
+use crate::texture::TextureId;
+use std::convert::Infallible;
+
+/// Renderer that consumes draw data without drawing anything
+///
+/// Useful for running frames in CI and layout tests without a GPU or window: it
+/// acknowledges every texture request so the font atlas and user textures reach the
+/// `OK` state, then discards the frame. Draw data can still be inspected before or
+/// after, e.g. to assert on vertex counts. Implements [`ImGuiRenderer`]; see also
+/// [`Context::render_headless`].
+///
+/// [`Context::render_headless`]: crate::Context::render_headless
+#[derive(Copy, Clone, Debug, Default)]
+pub struct NullRenderer;
+
+impl NullRenderer {
+    /// Backend flags a context must report for the null renderer, set by
+    /// [`ContextBuilder::headless`](crate::ContextBuilder::headless)
+    pub const BACKEND_FLAGS: BackendFlags =
+        BackendFlags::RENDERER_HAS_TEXTURES.union(BackendFlags::RENDERER_HAS_VTX_OFFSET);
+}
+
+impl ImGuiRenderer for NullRenderer {
+    type Error = Infallible;
+
+    /// Does nothing: Dear ImGui reads the backend flags when the first frame starts, so
+    /// they are set on the context by [`ContextBuilder::headless`](crate::ContextBuilder::headless)
+    fn init(&mut self) -> Result<(), Infallible> {
+        Ok(())
+    }
+
+    /// Handles the texture requests of `draw_data` and discards the rest
+    fn render(&mut self, draw_data: &DrawData) -> Result<(), Infallible> {
+        for texture in draw_data.textures() {
+            match texture.status() {
+                TextureStatus::WantCreate => {
+                    // Any non-null id will do; derive one from the texture's unique id
+                    texture.set_tex_id(TextureId::new(texture.unique_id() as u64 + 1));
+                    texture.set_status(TextureStatus::OK);
+                }
+                TextureStatus::WantUpdates => texture.set_status(TextureStatus::OK),
+                TextureStatus::WantDestroy => {
+                    texture.set_tex_id(TextureId::null());
+                    texture.set_status(TextureStatus::Destroyed);
+                }
+                TextureStatus::OK | TextureStatus::Destroyed => {}
+            }
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
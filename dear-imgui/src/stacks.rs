@@ -39,6 +39,7 @@ impl Ui {
     /// ```
     #[doc(alias = "PushFont")]
     pub fn push_font(&self, id: FontId) -> FontStackToken<'_> {
+        self.debug_assert_current();
         // For now, we'll use a simplified approach without full validation
         // TODO: Add proper FontAtlas integration for validation
         let font_ptr = id.0 as *mut sys::ImFont;
@@ -67,6 +68,7 @@ impl Ui {
         style_color: StyleColor,
         color: impl Into<[f32; 4]>,
     ) -> ColorStackToken<'_> {
+        self.debug_assert_current();
         let color_array = color.into();
         unsafe {
             sys::igPushStyleColor_Vec4(
@@ -99,9 +101,137 @@ impl Ui {
     /// ```
     #[doc(alias = "PushStyleVar")]
     pub fn push_style_var(&self, style_var: StyleVar) -> StyleStackToken<'_> {
+        self.debug_assert_current();
         unsafe { push_style_var(style_var) };
         StyleStackToken::new(self)
     }
+
+    /// Changes several style colors at once.
+    ///
+    /// Returns a `MultiColorStackToken` that pops exactly as many colors as were pushed
+    /// when it is dropped or `.pop()` is called.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// let colors = ui.push_style_colors([
+    ///     (StyleColor::Button, [0.6, 0.1, 0.1, 1.0]),
+    ///     (StyleColor::ButtonHovered, [0.8, 0.2, 0.2, 1.0]),
+    /// ]);
+    /// ui.button("Delete");
+    /// colors.pop();
+    /// ```
+    #[doc(alias = "PushStyleColor")]
+    pub fn push_style_colors<C: Into<[f32; 4]>>(
+        &self,
+        style_colors: impl IntoIterator<Item = (StyleColor, C)>,
+    ) -> MultiColorStackToken<'_> {
+        self.debug_assert_current();
+        let mut count = 0;
+        for (style_color, color) in style_colors {
+            self.push_style_color(style_color, color).forget();
+            count += 1;
+        }
+        MultiColorStackToken {
+            count,
+            _ui: std::marker::PhantomData,
+        }
+    }
+
+    /// Changes several style variables at once.
+    ///
+    /// Returns a `MultiStyleStackToken` that pops exactly as many variables as were pushed
+    /// when it is dropped or `.pop()` is called.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// let style = ui.push_style_vars([StyleVar::Alpha(0.5), StyleVar::FrameRounding(4.0)]);
+    /// ui.button("Faded");
+    /// style.pop();
+    /// ```
+    #[doc(alias = "PushStyleVar")]
+    pub fn push_style_vars(
+        &self,
+        style_vars: impl IntoIterator<Item = StyleVar>,
+    ) -> MultiStyleStackToken<'_> {
+        self.debug_assert_current();
+        let mut count = 0;
+        for style_var in style_vars {
+            unsafe { push_style_var(style_var) };
+            count += 1;
+        }
+        MultiStyleStackToken {
+            count,
+            _ui: std::marker::PhantomData,
+        }
+    }
+
+    /// Runs a closure with the [`CompactStyle::default`] dense spacing preset applied.
+    ///
+    /// See [`compact_scope_with`](Self::compact_scope_with).
+    pub fn compact_scope<R, F: FnOnce() -> R>(&self, f: F) -> R {
+        self.debug_assert_current();
+        self.compact_scope_with(CompactStyle::default(), f)
+    }
+
+    /// Runs a closure with reduced item spacing, frame padding and window padding,
+    /// restoring the previous values afterwards.
+    ///
+    /// Window padding only affects windows and child windows begun inside the closure.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// let compact = true;
+    /// let draw = || {
+    ///     ui.text("Position");
+    ///     ui.button("Reset");
+    /// };
+    /// if compact {
+    ///     ui.compact_scope(draw);
+    /// } else {
+    ///     draw();
+    /// }
+    /// ```
+    pub fn compact_scope_with<R, F: FnOnce() -> R>(&self, style: CompactStyle, f: F) -> R {
+        self.debug_assert_current();
+        let _item_spacing = self.push_style_var(StyleVar::ItemSpacing(style.item_spacing));
+        let _frame_padding = self.push_style_var(StyleVar::FramePadding(style.frame_padding));
+        let _window_padding = self.push_style_var(StyleVar::WindowPadding(style.window_padding));
+        f()
+    }
+}
+
+/// Spacing used by [`Ui::compact_scope_with`] for dense layouts
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CompactStyle {
+    /// Spacing between widgets/lines (Dear ImGui default: `[8.0, 4.0]`)
+    pub item_spacing: [f32; 2],
+    /// Padding within framed widgets (Dear ImGui default: `[4.0, 3.0]`)
+    pub frame_padding: [f32; 2],
+    /// Padding within windows (Dear ImGui default: `[8.0, 8.0]`)
+    pub window_padding: [f32; 2],
+}
+
+impl Default for CompactStyle {
+    /// Roughly half of Dear ImGui's default spacing
+    fn default() -> Self {
+        Self {
+            item_spacing: [4.0, 2.0],
+            frame_padding: [2.0, 1.0],
+            window_padding: [4.0, 4.0],
+        }
+    }
 }
 
 create_token!(
@@ -152,6 +282,49 @@ impl StyleStackToken<'_> {
     }
 }
 
+impl ColorStackToken<'_> {
+    /// Leaves the color pushed, for tokens that track the pop themselves.
+    fn forget(self) {
+        std::mem::forget(self)
+    }
+}
+
+/// Tracks colors pushed with [`Ui::push_style_colors`], popping all of them on drop.
+#[must_use]
+pub struct MultiColorStackToken<'ui> {
+    count: usize,
+    _ui: std::marker::PhantomData<&'ui Ui>,
+}
+
+impl MultiColorStackToken<'_> {
+    /// Pops the pushed colors.
+    pub fn pop(self) {}
+}
+
+impl Drop for MultiColorStackToken<'_> {
+    fn drop(&mut self) {
+        unsafe { sys::igPopStyleColor(self.count as i32) }
+    }
+}
+
+/// Tracks style variables pushed with [`Ui::push_style_vars`], popping all of them on drop.
+#[must_use]
+pub struct MultiStyleStackToken<'ui> {
+    count: usize,
+    _ui: std::marker::PhantomData<&'ui Ui>,
+}
+
+impl MultiStyleStackToken<'_> {
+    /// Pops the pushed style variables.
+    pub fn pop(self) {}
+}
+
+impl Drop for MultiStyleStackToken<'_> {
+    fn drop(&mut self) {
+        unsafe { sys::igPopStyleVar(self.count as i32) }
+    }
+}
+
 /// Helper function to push style variables
 unsafe fn push_style_var(style_var: StyleVar) {
     use StyleVar::*;
@@ -263,6 +436,7 @@ impl Ui {
     ///   the right side)
     #[doc(alias = "PushItemWidth")]
     pub fn push_item_width(&self, item_width: f32) -> ItemWidthStackToken<'_> {
+        self.debug_assert_current();
         unsafe { sys::igPushItemWidth(item_width) };
         ItemWidthStackToken::new(self)
     }
@@ -273,6 +447,7 @@ impl Ui {
     /// `ItemWidthStackToken` goes out of scope, or `.end()` is called.
     #[doc(alias = "PushItemWidth")]
     pub fn push_item_width_text(&self, text: impl AsRef<str>) -> ItemWidthStackToken<'_> {
+        self.debug_assert_current();
         let text_width = {
             let text_ptr = self.scratch_txt(text);
             let mut out = sys::ImVec2 { x: 0.0, y: 0.0 };
@@ -282,6 +457,14 @@ impl Ui {
         self.push_item_width(text_width)
     }
 
+    /// Returns the width the next widget will get, resolving the pushed or next item width
+    /// against the available content region.
+    #[doc(alias = "CalcItemWidth")]
+    pub fn calc_item_width(&self) -> f32 {
+        self.debug_assert_current();
+        unsafe { sys::igCalcItemWidth() }
+    }
+
     /// Sets the position where text will wrap around.
     ///
     /// Returns a `TextWrapPosStackToken`. The pushed wrap position is popped when either
@@ -292,6 +475,7 @@ impl Ui {
     /// - `wrap_pos_x > 0.0`: wrap at `wrap_pos_x` position in window local space
     #[doc(alias = "PushTextWrapPos")]
     pub fn push_text_wrap_pos(&self, wrap_pos_x: f32) -> TextWrapPosStackToken<'_> {
+        self.debug_assert_current();
         unsafe { sys::igPushTextWrapPos(wrap_pos_x) };
         TextWrapPosStackToken::new(self)
     }
@@ -354,6 +538,7 @@ impl Ui {
     /// ```
     #[doc(alias = "PushID")]
     pub fn push_id<'a, T: Into<Id<'a>>>(&self, id: T) -> IdStackToken<'_> {
+        self.debug_assert_current();
         let id = id.into();
         unsafe {
             match id {
@@ -401,6 +586,7 @@ impl Ui {
     /// Returns a `FocusScopeToken` which will pop the focus scope when dropped.
     #[doc(alias = "PushFocusScope")]
     pub fn push_focus_scope(&self, id: sys::ImGuiID) -> FocusScopeToken<'_> {
+        self.debug_assert_current();
         unsafe { sys::igPushFocusScope(id) };
         FocusScopeToken::new(self)
     }
@@ -21,6 +21,7 @@
 //! ```
 //!
 use std::borrow::Cow;
+use std::ffi::{CStr, CString};
 use std::fmt;
 use std::ops::{Deref, Index, RangeFull};
 use std::os::raw::c_char;
@@ -84,6 +85,15 @@ impl UiBuffer {
         }
     }
 
+    /// Returns a C string pointer for `txt`, staging it in the scratch buffer only when it
+    /// is not already NUL-terminated.
+    pub fn scratch_ui_str(&mut self, txt: UiStr<'_>) -> *const std::os::raw::c_char {
+        match txt {
+            UiStr::Str(s) => self.scratch_txt(s),
+            UiStr::CStr(s) => s.as_ptr(),
+        }
+    }
+
     /// Attempts to clear the buffer if it's over the maximum length allowed.
     /// This is to prevent us from making a giant vec over time.
     pub fn refresh_buffer(&mut self) {
@@ -307,6 +317,54 @@ impl Index<RangeFull> for ImString {
     }
 }
 
+/// A borrowed label passed to Dear ImGui
+///
+/// Rust string slices are copied into the [`Ui`](crate::Ui) scratch buffer to add the NUL
+/// terminator C expects, while already NUL-terminated strings (`CStr`, `CString` and
+/// [`ImString`]) are handed over as-is. Keeping labels in one of the latter avoids a copy
+/// per call when drawing thousands of labeled items per frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UiStr<'a> {
+    /// A Rust string slice, copied before use
+    Str(&'a str),
+    /// A NUL-terminated string, borrowed without copying
+    CStr(&'a CStr),
+}
+
+impl<'a> From<&'a str> for UiStr<'a> {
+    fn from(s: &'a str) -> Self {
+        UiStr::Str(s)
+    }
+}
+
+impl<'a> From<&'a String> for UiStr<'a> {
+    fn from(s: &'a String) -> Self {
+        UiStr::Str(s)
+    }
+}
+
+impl<'a> From<&'a CStr> for UiStr<'a> {
+    fn from(s: &'a CStr) -> Self {
+        UiStr::CStr(s)
+    }
+}
+
+impl<'a> From<&'a CString> for UiStr<'a> {
+    fn from(s: &'a CString) -> Self {
+        UiStr::CStr(s)
+    }
+}
+
+impl<'a> From<&'a ImString> for UiStr<'a> {
+    fn from(s: &'a ImString) -> Self {
+        // The buffer always ends in NUL, so this only fails to find one if it is corrupt
+        match CStr::from_bytes_until_nul(&s.0) {
+            Ok(c) => UiStr::CStr(c),
+            Err(_) => UiStr::Str(s.to_str()),
+        }
+    }
+}
+
 /// Represents a borrowed string that can be either a Rust string slice or an ImString
 pub type ImStr<'a> = Cow<'a, str>;
 
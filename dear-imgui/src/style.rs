@@ -38,6 +38,7 @@ use crate::internal::RawWrapper;
 use crate::sys;
 use crate::utils::HoveredFlags;
 use crate::widget::TreeNodeFlags;
+use std::ops::{Index, IndexMut};
 
 /// User interface style/colors
 ///
@@ -64,6 +65,16 @@ impl Style {
         };
     }
 
+    /// Scales every size and spacing by `scale_factor`, e.g. for DPI scaling
+    ///
+    /// Call this once on a freshly initialized style: scaling repeatedly compounds, and
+    /// the values are rounded so scaling back down does not restore them exactly. Fonts are
+    /// not affected; see [`set_font_scale_dpi`](Self::set_font_scale_dpi).
+    #[doc(alias = "ScaleAllSizes")]
+    pub fn scale_all_sizes(&mut self, scale_factor: f32) {
+        unsafe { sys::ImGuiStyle_ScaleAllSizes(&mut self.0, scale_factor) }
+    }
+
     /// Get main font scale (formerly io.FontGlobalScale)
     pub fn font_scale_main(&self) -> f32 {
         self.0.FontScaleMain
@@ -651,6 +662,55 @@ pub enum StyleColor {
 
 impl StyleColor {
     pub const COUNT: usize = sys::ImGuiCol_COUNT as usize;
+
+    /// Dear ImGui's name for this color, e.g. `"WindowBg"`
+    #[doc(alias = "GetStyleColorName")]
+    pub fn name(self) -> &'static str {
+        style_color_name(self as usize)
+    }
+}
+
+/// Name of the color at `index` in the style's color table
+pub(crate) fn style_color_name(index: usize) -> &'static str {
+    unsafe {
+        let name_ptr = sys::igGetStyleColorName(index as sys::ImGuiCol);
+        std::ffi::CStr::from_ptr(name_ptr)
+            .to_str()
+            .unwrap_or("Unknown")
+    }
+}
+
+impl Default for Style {
+    /// Dear ImGui's default style with the dark color scheme
+    fn default() -> Self {
+        unsafe {
+            let raw = sys::ImGuiStyle_ImGuiStyle();
+            let style = Style(*raw);
+            sys::ImGuiStyle_destroy(raw);
+            style
+        }
+    }
+}
+
+// `ImVec4` colors are read as `[f32; 4]` by the `Index` impls below
+const _: () = assert!(std::mem::size_of::<sys::ImVec4>() == std::mem::size_of::<[f32; 4]>());
+
+impl Index<StyleColor> for Style {
+    type Output = [f32; 4];
+
+    /// Borrows a color of the style's color table, like [`Style::color`]
+    fn index(&self, color: StyleColor) -> &[f32; 4] {
+        let c = &self.0.Colors[color as usize];
+        unsafe { &*(c as *const sys::ImVec4 as *const [f32; 4]) }
+    }
+}
+
+impl IndexMut<StyleColor> for Style {
+    /// Mutably borrows a color of the style's color table, like [`Style::set_color`]
+    fn index_mut(&mut self, color: StyleColor) -> &mut [f32; 4] {
+        let c = &mut self.0.Colors[color as usize];
+        unsafe { &mut *(c as *mut sys::ImVec4 as *mut [f32; 4]) }
+    }
 }
 
 impl RawWrapper for Style {
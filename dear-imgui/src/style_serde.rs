@@ -0,0 +1,161 @@
+//! `serde` support for [`Style`] (behind the `serde` feature)
+//!
+//! A style serializes as a map of its sizes under snake_case keys (`"window_padding"`)
+//! plus a `"colors"` map keyed by Dear ImGui's color names (`"WindowBg": [r, g, b, a]`),
+//! so saved themes do not depend on the numeric order of [`StyleColor`].
+//!
+//! Deserializing starts from [`Style::default`] and only overrides the keys present:
+//! unknown keys are ignored and missing ones keep their defaults, so themes keep loading
+//! when Dear ImGui adds or removes style fields and colors. Direction and flag fields
+//! (`window_menu_button_position`, `color_button_position`, `tree_lines_flags` and the
+//! tooltip hover flags) are not part of the format.
+
+use crate::style::{Style, style_color_name};
+use serde::de::{IgnoredAny, MapAccess, Visitor};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::fmt;
+
+macro_rules! style_fields {
+    ($($field:ident: $ty:ty => $set:ident,)*) => {
+        fn serialize_fields<M: SerializeMap>(style: &Style, map: &mut M) -> Result<(), M::Error> {
+            $(map.serialize_entry(stringify!($field), &style.$field())?;)*
+            Ok(())
+        }
+
+        /// Reads the value of `key` into `style`, returning `false` for unknown keys
+        fn deserialize_field<'de, A: MapAccess<'de>>(
+            style: &mut Style,
+            key: &str,
+            map: &mut A,
+        ) -> Result<bool, A::Error> {
+            match key {
+                $(stringify!($field) => style.$set(map.next_value::<$ty>()?),)*
+                _ => return Ok(false),
+            }
+            Ok(true)
+        }
+    };
+}
+
+style_fields! {
+    font_scale_main: f32 => set_font_scale_main,
+    font_scale_dpi: f32 => set_font_scale_dpi,
+    font_size_base: f32 => set_font_size_base,
+    alpha: f32 => set_alpha,
+    disabled_alpha: f32 => set_disabled_alpha,
+    window_padding: [f32; 2] => set_window_padding,
+    window_rounding: f32 => set_window_rounding,
+    window_border_size: f32 => set_window_border_size,
+    window_min_size: [f32; 2] => set_window_min_size,
+    window_title_align: [f32; 2] => set_window_title_align,
+    child_rounding: f32 => set_child_rounding,
+    child_border_size: f32 => set_child_border_size,
+    popup_rounding: f32 => set_popup_rounding,
+    popup_border_size: f32 => set_popup_border_size,
+    frame_padding: [f32; 2] => set_frame_padding,
+    frame_rounding: f32 => set_frame_rounding,
+    frame_border_size: f32 => set_frame_border_size,
+    item_spacing: [f32; 2] => set_item_spacing,
+    item_inner_spacing: [f32; 2] => set_item_inner_spacing,
+    cell_padding: [f32; 2] => set_cell_padding,
+    touch_extra_padding: [f32; 2] => set_touch_extra_padding,
+    indent_spacing: f32 => set_indent_spacing,
+    columns_min_spacing: f32 => set_columns_min_spacing,
+    scrollbar_size: f32 => set_scrollbar_size,
+    scrollbar_rounding: f32 => set_scrollbar_rounding,
+    scrollbar_padding: f32 => set_scrollbar_padding,
+    grab_min_size: f32 => set_grab_min_size,
+    grab_rounding: f32 => set_grab_rounding,
+    log_slider_deadzone: f32 => set_log_slider_deadzone,
+    image_border_size: f32 => set_image_border_size,
+    tab_rounding: f32 => set_tab_rounding,
+    tab_border_size: f32 => set_tab_border_size,
+    tab_min_width_base: f32 => set_tab_min_width_base,
+    tab_min_width_shrink: f32 => set_tab_min_width_shrink,
+    tab_close_button_min_width_selected: f32 => set_tab_close_button_min_width_selected,
+    tab_close_button_min_width_unselected: f32 => set_tab_close_button_min_width_unselected,
+    tab_bar_border_size: f32 => set_tab_bar_border_size,
+    tab_bar_overline_size: f32 => set_tab_bar_overline_size,
+    table_angled_headers_angle: f32 => set_table_angled_headers_angle,
+    table_angled_headers_text_align: [f32; 2] => set_table_angled_headers_text_align,
+    tree_lines_size: f32 => set_tree_lines_size,
+    tree_lines_rounding: f32 => set_tree_lines_rounding,
+    button_text_align: [f32; 2] => set_button_text_align,
+    selectable_text_align: [f32; 2] => set_selectable_text_align,
+    separator_text_border_size: f32 => set_separator_text_border_size,
+    separator_text_align: [f32; 2] => set_separator_text_align,
+    separator_text_padding: [f32; 2] => set_separator_text_padding,
+    display_window_padding: [f32; 2] => set_display_window_padding,
+    display_safe_area_padding: [f32; 2] => set_display_safe_area_padding,
+    docking_node_has_close_button: bool => set_docking_node_has_close_button,
+    docking_separator_size: f32 => set_docking_separator_size,
+    mouse_cursor_scale: f32 => set_mouse_cursor_scale,
+    anti_aliased_lines: bool => set_anti_aliased_lines,
+    anti_aliased_lines_use_tex: bool => set_anti_aliased_lines_use_tex,
+    anti_aliased_fill: bool => set_anti_aliased_fill,
+    curve_tessellation_tol: f32 => set_curve_tessellation_tol,
+    circle_tessellation_max_error: f32 => set_circle_tessellation_max_error,
+    window_border_hover_padding: f32 => set_window_border_hover_padding,
+    hover_stationary_delay: f32 => set_hover_stationary_delay,
+    hover_delay_short: f32 => set_hover_delay_short,
+    hover_delay_normal: f32 => set_hover_delay_normal,
+}
+
+/// The color table as a map from color name to RGBA
+struct Colors<'a>(&'a Style);
+
+impl Serialize for Colors<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let colors = &self.0.0.Colors;
+        let mut map = serializer.serialize_map(Some(colors.len()))?;
+        for (index, c) in colors.iter().enumerate() {
+            map.serialize_entry(style_color_name(index), &[c.x, c.y, c.z, c.w])?;
+        }
+        map.end()
+    }
+}
+
+impl Serialize for Style {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        serialize_fields(self, &mut map)?;
+        map.serialize_entry("colors", &Colors(self))?;
+        map.end()
+    }
+}
+
+struct StyleVisitor;
+
+impl<'de> Visitor<'de> for StyleVisitor {
+    type Value = Style;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a map of style fields")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Style, A::Error> {
+        let mut style = Style::default();
+        while let Some(key) = map.next_key::<String>()? {
+            if key == "colors" {
+                let colors = map.next_value::<BTreeMap<String, [f32; 4]>>()?;
+                let table = &mut style.0.Colors;
+                for (index, slot) in table.iter_mut().enumerate() {
+                    if let Some(&[x, y, z, w]) = colors.get(style_color_name(index)) {
+                        *slot = crate::sys::ImVec4 { x, y, z, w };
+                    }
+                }
+            } else if !deserialize_field(&mut style, &key, &mut map)? {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(style)
+    }
+}
+
+impl<'de> Deserialize<'de> for Style {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Style, D::Error> {
+        deserializer.deserialize_map(StyleVisitor)
+    }
+}
@@ -0,0 +1,230 @@
+//! Headless UI driver for integration tests
+//!
+//! [`TestHarness`] owns a context configured for running without a platform or renderer
+//! backend and drives it through the input event queue, so tests can click buttons and
+//! type into fields the way a user would. Items are located by the rectangles the UI
+//! closure records with [`ItemRecorder::record`] right after submitting them.
+//!
+//! [`test_guard`] and [`headless_context`] are the shared fixtures for tests that drive
+//! frames themselves.
+//!
+//! Only built with the `test-harness` feature, which the crates in this workspace enable
+//! from their dev-dependencies; its API may change without a semver bump.
+//!
+//! Example:
+//! ```no_run
+//! # use dear_imgui_rs::*;
+//! let mut harness = TestHarness::new();
+//! let mut clicks = 0;
+//! let mut draw = |ui: &Ui, items: &mut ItemRecorder<'_>| {
+//!     ui.window("app").build(|| {
+//!         if ui.button("Add") {
+//!             clicks += 1;
+//!         }
+//!         items.record(ui, "Add");
+//!     });
+//! };
+//! harness.click("Add", &mut draw).unwrap();
+//! assert_eq!(clicks, 1);
+//! ```
+//!
+use crate::error::{ImGuiError, ImGuiResult};
+use crate::input::{Key, MouseButton};
+use crate::{Context, Ui};
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+
+/// Serializes the tests of one binary: only one ImGui context may be current per thread,
+/// and the tests share process-wide state such as the font atlas lock
+pub fn test_guard() -> MutexGuard<'static, ()> {
+    static TEST_MUTEX: Mutex<()> = Mutex::new(());
+    TEST_MUTEX.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// A context that runs frames without a platform or renderer backend: the one a
+/// [`TestHarness::new`] owns, for tests that drive frames themselves
+pub fn headless_context() -> Context {
+    TestHarness::new().into_context()
+}
+
+/// Screen-space rectangle of a recorded item
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct ItemRect {
+    /// Upper-left corner
+    pub min: [f32; 2],
+    /// Lower-right corner
+    pub max: [f32; 2],
+}
+
+impl ItemRect {
+    /// Center of the rectangle
+    pub fn center(&self) -> [f32; 2] {
+        [
+            (self.min[0] + self.max[0]) * 0.5,
+            (self.min[1] + self.max[1]) * 0.5,
+        ]
+    }
+}
+
+/// Collects item rectangles while a [`TestHarness`] frame runs
+pub struct ItemRecorder<'h> {
+    items: &'h mut HashMap<String, ItemRect>,
+}
+
+impl ItemRecorder<'_> {
+    /// Records the rectangle of the last submitted item under `name`
+    pub fn record(&mut self, ui: &Ui, name: impl Into<String>) {
+        let rect = ItemRect {
+            min: ui.item_rect_min(),
+            max: ui.item_rect_max(),
+        };
+        self.items.insert(name.into(), rect);
+    }
+}
+
+/// Drives a headless context with synthetic input, see the [module docs](self)
+pub struct TestHarness {
+    ctx: Context,
+    items: HashMap<String, ItemRect>,
+}
+
+impl Default for TestHarness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TestHarness {
+    /// Creates a harness with an 800x600 display stepping at 60 frames per second
+    ///
+    /// # Panics
+    ///
+    /// Panics if another context is active on this thread.
+    pub fn new() -> Self {
+        let mut ctx = Context::builder()
+            .no_ini()
+            .display_size([800.0, 600.0])
+            .headless()
+            .build()
+            .expect("Failed to create Dear ImGui context");
+        let io = ctx.io_mut();
+        io.set_delta_time(1.0 / 60.0);
+        // Keep shortcuts identical on every platform
+        io.set_config_mac_os_behaviors(false);
+        Self {
+            ctx,
+            items: HashMap::new(),
+        }
+    }
+
+    /// Consumes the harness, returning its configured context for tests that drive
+    /// frames themselves
+    pub fn into_context(self) -> Context {
+        self.ctx
+    }
+
+    /// The driven context, e.g. to tweak IO or queue additional events
+    pub fn context(&mut self) -> &mut Context {
+        &mut self.ctx
+    }
+
+    /// Rectangle recorded under `name` during the last frame
+    pub fn item_rect(&self, name: &str) -> Option<ItemRect> {
+        self.items.get(name).copied()
+    }
+
+    /// Runs and renders one frame, processing any queued input events
+    pub fn step(&mut self, mut draw: impl FnMut(&Ui, &mut ItemRecorder<'_>)) {
+        self.items.clear();
+        let ui = self.ctx.frame();
+        draw(
+            ui,
+            &mut ItemRecorder {
+                items: &mut self.items,
+            },
+        );
+        self.ctx.render();
+    }
+
+    /// Runs `count` frames
+    pub fn step_n(&mut self, count: usize, mut draw: impl FnMut(&Ui, &mut ItemRecorder<'_>)) {
+        for _ in 0..count {
+            self.step(&mut draw);
+        }
+    }
+
+    /// Moves the mouse to the center of the item recorded as `name`, then presses and
+    /// releases the left button, one frame each
+    ///
+    /// Runs a frame first when the item was not recorded yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the item is not recorded by the UI closure.
+    pub fn click(
+        &mut self,
+        name: &str,
+        mut draw: impl FnMut(&Ui, &mut ItemRecorder<'_>),
+    ) -> ImGuiResult<()> {
+        let target = self.locate(name, &mut draw)?.center();
+        self.ctx.io_mut().add_mouse_pos_event(target);
+        self.step(&mut draw);
+        for down in [true, false] {
+            self.ctx
+                .io_mut()
+                .add_mouse_button_event(MouseButton::Left, down);
+            self.step(&mut draw);
+        }
+        Ok(())
+    }
+
+    /// Replaces the contents of the text field recorded as `name` and presses Enter
+    ///
+    /// Clicks the field, selects everything with Ctrl+A, types `text` and submits it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the item is not recorded by the UI closure.
+    pub fn set_value(
+        &mut self,
+        name: &str,
+        text: &str,
+        mut draw: impl FnMut(&Ui, &mut ItemRecorder<'_>),
+    ) -> ImGuiResult<()> {
+        self.click(name, &mut draw)?;
+        self.press_key(Some(Key::ModCtrl), Key::A, &mut draw);
+        self.ctx.io_mut().add_input_characters(text);
+        self.step(&mut draw);
+        self.press_key(None, Key::Enter, &mut draw);
+        Ok(())
+    }
+
+    /// Presses and releases `key`, holding `modifier` if given
+    pub fn press_key(
+        &mut self,
+        modifier: Option<Key>,
+        key: Key,
+        mut draw: impl FnMut(&Ui, &mut ItemRecorder<'_>),
+    ) {
+        for down in [true, false] {
+            let io = self.ctx.io_mut();
+            if let Some(modifier) = modifier {
+                io.add_key_event(modifier, down);
+            }
+            io.add_key_event(key, down);
+            self.step(&mut draw);
+        }
+    }
+
+    fn locate(
+        &mut self,
+        name: &str,
+        draw: impl FnMut(&Ui, &mut ItemRecorder<'_>),
+    ) -> ImGuiResult<ItemRect> {
+        if !self.items.contains_key(name) {
+            self.step(draw);
+        }
+        self.item_rect(name)
+            .ok_or_else(|| ImGuiError::invalid_operation(format!("item `{name}` was not recorded")))
+    }
+}
@@ -305,6 +305,7 @@ impl Ui {
     /// let filter = ui.text_filter("Search".to_string());
     /// ```
     pub fn text_filter(&self, label: String) -> TextFilter {
+        self.debug_assert_current();
         TextFilter::new(label)
     }
 
@@ -328,6 +329,7 @@ impl Ui {
     /// );
     /// ```
     pub fn text_filter_with_filter(&self, label: String, filter: String) -> TextFilter {
+        self.debug_assert_current();
         TextFilter::new_with_filter(label, filter)
     }
 }
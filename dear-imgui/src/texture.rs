@@ -455,6 +455,44 @@ impl TextureData {
         }
     }
 
+    /// Rectangles a `WantUpdates` request asks to upload
+    ///
+    /// Returns the queued [`updates`](Self::updates), falling back to the single
+    /// [`update_rect`](Self::update_rect) when none are queued and it is not empty.
+    pub fn update_rects(&self) -> Vec<TextureRect> {
+        let mut rects: Vec<TextureRect> = self.updates().collect();
+        if rects.is_empty() {
+            let rect = self.update_rect();
+            if rect.w > 0 && rect.h > 0 {
+                rects.push(rect);
+            }
+        }
+        rects
+    }
+
+    /// Rows of pixels covered by `rect`, top to bottom
+    ///
+    /// Each row holds `rect.w * bytes_per_pixel()` bytes, ready for a partial upload.
+    /// Returns `None` if there is no pixel data or `rect` does not fit in the texture.
+    pub fn rect_rows(&self, rect: TextureRect) -> Option<impl Iterator<Item = &[u8]> + '_> {
+        let pixels = self.pixels()?;
+        let (x, y, w, h) = (
+            rect.x as usize,
+            rect.y as usize,
+            rect.w as usize,
+            rect.h as usize,
+        );
+        if x + w > self.width() as usize || y + h > self.height() as usize {
+            return None;
+        }
+        let bpp = self.bytes_per_pixel() as usize;
+        let pitch = self.pitch() as usize;
+        Some((y..y + h).map(move |row| {
+            let start = row * pitch + x * bpp;
+            &pixels[start..start + w * bpp]
+        }))
+    }
+
     /// Get the pixel data at a specific position
     ///
     /// Returns None if no pixel data is available or coordinates are out of bounds.
@@ -0,0 +1,333 @@
+//! Toast notifications
+//!
+//! A small, non-modal notification overlay: push messages such as "Saved!" or
+//! "Connection lost" into a [`Toasts`] queue and call [`Toasts::render`] once per
+//! frame. Each card is a small borderless window kept in front of the rest of the UI
+//! in a corner of the main viewport; cards slide and fade in, stack on top of each
+//! other, and dismiss themselves after their duration (or when clicked). Because they
+//! are windows, a click on a card never reaches the widgets underneath it.
+//!
+//! ```no_run
+//! # use dear_imgui_rs::*;
+//! # use std::time::Duration;
+//! # let mut ctx = Context::create();
+//! let mut toasts = Toasts::new();
+//! toasts.push(ToastKind::Success, "Saved", "project.json written", Duration::from_secs(3));
+//!
+//! let ui = ctx.frame();
+//! toasts.render(ui);
+//! ```
+//!
+use crate::sys;
+use crate::{Condition, StyleColor, StyleVar, Ui, WindowFlags};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Fade/slide-in and fade-out duration, in seconds
+const FADE_SECS: f64 = 0.25;
+/// Width of the colored accent bar on the left of a card
+const ACCENT_WIDTH: f32 = 4.0;
+
+/// Severity of a toast, which selects its accent color
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ToastKind {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastKind {
+    fn accent_color(self) -> [f32; 4] {
+        match self {
+            ToastKind::Info => [0.26, 0.59, 0.98, 1.0],
+            ToastKind::Success => [0.30, 0.80, 0.40, 1.0],
+            ToastKind::Warning => [0.95, 0.70, 0.20, 1.0],
+            ToastKind::Error => [0.90, 0.30, 0.30, 1.0],
+        }
+    }
+}
+
+/// Screen corner the toast stack is anchored to
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ToastCorner {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    #[default]
+    BottomRight,
+}
+
+#[derive(Clone, Debug)]
+struct Toast {
+    /// Unique within its queue, names the card's window
+    id: u64,
+    kind: ToastKind,
+    title: String,
+    body: String,
+    duration: f64,
+    /// `Ui::time()` of the first frame the toast was rendered in
+    shown_at: Option<f64>,
+}
+
+/// Queue of toast notifications rendered as an overlay.
+///
+/// Toasts can be pushed at any time, even without a frame in progress; their
+/// lifetime starts on the first [`render`](Self::render) that shows them.
+#[derive(Debug)]
+pub struct Toasts {
+    toasts: Vec<Toast>,
+    corner: ToastCorner,
+    width: f32,
+    max_visible: usize,
+    next_id: u64,
+    // Keeps the window names of different queues apart
+    instance: u64,
+}
+
+fn next_instance() -> u64 {
+    static NEXT_INSTANCE: AtomicU64 = AtomicU64::new(0);
+    NEXT_INSTANCE.fetch_add(1, Ordering::Relaxed)
+}
+
+impl Clone for Toasts {
+    fn clone(&self) -> Self {
+        Self {
+            toasts: self.toasts.clone(),
+            corner: self.corner,
+            width: self.width,
+            max_visible: self.max_visible,
+            next_id: self.next_id,
+            instance: next_instance(),
+        }
+    }
+}
+
+impl Default for Toasts {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Toasts {
+    /// Creates an empty queue anchored to the bottom-right corner
+    pub fn new() -> Self {
+        Self {
+            toasts: Vec::new(),
+            corner: ToastCorner::default(),
+            width: 300.0,
+            max_visible: 5,
+            next_id: 0,
+            instance: next_instance(),
+        }
+    }
+
+    /// Sets the corner of the main viewport the toasts stack from
+    pub fn corner(mut self, corner: ToastCorner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Sets the card width in pixels; the body text wraps to fit
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets how many toasts are shown at once; the rest wait in the queue
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible.max(1);
+        self
+    }
+
+    /// Queues a toast that dismisses itself `duration` after it first appears
+    pub fn push(
+        &mut self,
+        kind: ToastKind,
+        title: impl Into<String>,
+        body: impl Into<String>,
+        duration: Duration,
+    ) {
+        self.next_id += 1;
+        self.toasts.push(Toast {
+            id: self.next_id,
+            kind,
+            title: title.into(),
+            body: body.into(),
+            duration: duration.as_secs_f64(),
+            shown_at: None,
+        });
+    }
+
+    /// Number of queued toasts, including those not yet visible
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    /// Returns true if there are no toasts left
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    /// Dismisses all toasts immediately
+    pub fn clear(&mut self) {
+        self.toasts.clear();
+    }
+
+    /// Draws the visible toasts and drops expired or clicked ones.
+    ///
+    /// Call once per frame, after the rest of the UI so the cards stay on top.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a [`DrawListMut`](crate::DrawListMut) for the window draw list is alive.
+    pub fn render(&mut self, ui: &Ui) {
+        let now = ui.time();
+        self.toasts
+            .retain(|t| t.shown_at.is_none_or(|shown| now - shown < t.duration));
+        if self.toasts.is_empty() {
+            return;
+        }
+
+        let viewport = ui.main_viewport();
+        let [work_x, work_y] = viewport.work_pos();
+        let [work_w, work_h] = viewport.work_size();
+        let (padding, spacing, rounding) = unsafe {
+            let style = &*sys::igGetStyle();
+            (
+                style.WindowPadding,
+                style.ItemSpacing.y,
+                style.WindowRounding,
+            )
+        };
+        let margin = padding.x;
+        let text_width = self.width - ACCENT_WIDTH - padding.x * 2.0;
+        let right = matches!(
+            self.corner,
+            ToastCorner::TopRight | ToastCorner::BottomRight
+        );
+        let bottom = matches!(
+            self.corner,
+            ToastCorner::BottomLeft | ToastCorner::BottomRight
+        );
+
+        let window_bg = ui.style_color(StyleColor::PopupBg);
+        let border = ui.style_color(StyleColor::Border);
+        let text = ui.style_color(StyleColor::Text);
+        let text_dim = ui.style_color(StyleColor::TextDisabled);
+        let flags = WindowFlags::NO_DECORATION
+            | WindowFlags::NO_MOVE
+            | WindowFlags::NO_BACKGROUND
+            | WindowFlags::NO_SAVED_SETTINGS
+            | WindowFlags::NO_FOCUS_ON_APPEARING
+            | WindowFlags::NO_NAV
+            | WindowFlags::NO_DOCKING;
+        // The card is drawn by hand, so the window must match its rectangle exactly
+        let _style = ui.push_style_vars([
+            StyleVar::WindowPadding([0.0, 0.0]),
+            StyleVar::WindowBorderSize(0.0),
+            StyleVar::WindowMinSize([1.0, 1.0]),
+        ]);
+
+        let mut offset = margin;
+        let mut dismissed = None;
+        let visible = self.toasts.len().min(self.max_visible);
+        for (index, toast) in self.toasts.iter_mut().take(visible).enumerate() {
+            let shown_at = *toast.shown_at.get_or_insert(now);
+            let age = now - shown_at;
+            let appear = (age / FADE_SECS).min(1.0) as f32;
+            let vanish = ((toast.duration - age) / FADE_SECS).clamp(0.0, 1.0) as f32;
+            let alpha = appear.min(vanish);
+            // Ease-out slide from the nearest screen edge
+            let slide = (1.0 - appear).powi(3) * (self.width + margin);
+
+            let title_size = ui.calc_text_size_with_opts(&toast.title, false, text_width);
+            let body_size = if toast.body.is_empty() {
+                [0.0, 0.0]
+            } else {
+                ui.calc_text_size_with_opts(&toast.body, false, text_width)
+            };
+            let body_gap = if toast.body.is_empty() { 0.0 } else { spacing };
+            let height = padding.y * 2.0 + title_size[1] + body_gap + body_size[1];
+
+            let x = if right {
+                work_x + work_w - margin - self.width + slide
+            } else {
+                work_x + margin - slide
+            };
+            let y = if bottom {
+                work_y + work_h - offset - height
+            } else {
+                work_y + offset
+            };
+            let (min, max) = ([x, y], [x + self.width, y + height]);
+            offset += height + spacing;
+
+            ui.window(format!("##toast{}_{}", self.instance, toast.id))
+                .position(min, Condition::Always)
+                .size([self.width, height], Condition::Always)
+                .flags(flags)
+                .build(|| {
+                    // Stay above windows focused since the toast appeared
+                    unsafe { sys::igBringWindowToDisplayFront(sys::igGetCurrentWindow()) };
+                    if ui.invisible_button("##dismiss", [self.width, height]) && dismissed.is_none()
+                    {
+                        dismissed = Some(index);
+                    }
+
+                    let draw_list = crate::DrawListMut::window_for_widget(ui, "toasts");
+                    draw_list
+                        .add_rect(min, max, with_alpha(window_bg, alpha))
+                        .filled(true)
+                        .rounding(rounding)
+                        .build();
+                    draw_list
+                        .add_rect(min, max, with_alpha(border, alpha))
+                        .rounding(rounding)
+                        .build();
+                    draw_list
+                        .add_rect(
+                            min,
+                            [x + ACCENT_WIDTH, max[1]],
+                            with_alpha(toast.kind.accent_color(), alpha),
+                        )
+                        .filled(true)
+                        .rounding(rounding)
+                        .build();
+
+                    let font = ui.current_font();
+                    let font_size = ui.current_font_size();
+                    let text_x = x + ACCENT_WIDTH + padding.x;
+                    draw_list.add_text_with_font(
+                        font,
+                        font_size,
+                        [text_x, y + padding.y],
+                        with_alpha(text, alpha),
+                        &toast.title,
+                        text_width,
+                        None,
+                    );
+                    if !toast.body.is_empty() {
+                        draw_list.add_text_with_font(
+                            font,
+                            font_size,
+                            [text_x, y + padding.y + title_size[1] + body_gap],
+                            with_alpha(text_dim, alpha),
+                            &toast.body,
+                            text_width,
+                            None,
+                        );
+                    }
+                });
+        }
+
+        if let Some(index) = dismissed {
+            self.toasts.remove(index);
+        }
+    }
+}
+
+fn with_alpha(mut color: [f32; 4], alpha: f32) -> [f32; 4] {
+    color[3] *= alpha;
+    color
+}
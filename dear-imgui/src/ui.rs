@@ -27,6 +27,10 @@ use std::ffi::CString;
 pub struct Ui {
     /// Internal buffer for string operations
     buffer: UnsafeCell<UiBuffer>,
+    /// Context this `Ui` was created for
+    ctx: *mut sys::ImGuiContext,
+    /// Callback of the last `set_next_window_size_constraints` call, kept alive for ImGui
+    pub(crate) size_callback: UnsafeCell<Option<Box<crate::window::SizeCallback>>>,
 }
 
 impl Ui {
@@ -35,25 +39,44 @@ impl Ui {
     /// Same viewport used by `dockspace_over_main_viewport()`.
     #[doc(alias = "GetMainViewport")]
     pub fn main_viewport(&self) -> &'static crate::platform_io::Viewport {
+        self.debug_assert_current();
         crate::platform_io::Viewport::main()
     }
     /// Creates a new Ui instance
     ///
     /// This should only be called by Context::create()
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(ctx: *mut sys::ImGuiContext) -> Self {
         Ui {
             buffer: UnsafeCell::new(UiBuffer::new(1024)),
+            ctx,
+            size_callback: UnsafeCell::new(None),
         }
     }
 
+    /// Debug-asserts that the context this `Ui` belongs to is the current one.
+    ///
+    /// Calls into Dear ImGui always target the current context, so using a `Ui` after
+    /// switching to another context would silently draw into the wrong one. Every `Ui`
+    /// method calls this first.
+    #[inline]
+    pub(crate) fn debug_assert_current(&self) {
+        debug_assert_eq!(
+            unsafe { sys::igGetCurrentContext() },
+            self.ctx,
+            "Ui used while its Context is not current; call Context::set_current() first"
+        );
+    }
+
     /// Returns an immutable reference to the inputs/outputs object
     #[doc(alias = "GetIO")]
     pub fn io(&self) -> &crate::io::Io {
+        self.debug_assert_current();
         unsafe { &*(sys::igGetIO_Nil() as *const crate::io::Io) }
     }
 
     /// Internal method to push a single text to our scratch buffer.
     pub(crate) fn scratch_txt(&self, txt: impl AsRef<str>) -> *const std::os::raw::c_char {
+        self.debug_assert_current();
         unsafe {
             let handle = &mut *self.buffer.get();
             handle.scratch_txt(txt)
@@ -65,6 +88,7 @@ impl Ui {
         &self,
         txt: Option<impl AsRef<str>>,
     ) -> *const std::os::raw::c_char {
+        self.debug_assert_current();
         unsafe {
             let handle = &mut *self.buffer.get();
             handle.scratch_txt_opt(txt)
@@ -77,6 +101,7 @@ impl Ui {
         txt_0: impl AsRef<str>,
         txt_1: impl AsRef<str>,
     ) -> (*const std::os::raw::c_char, *const std::os::raw::c_char) {
+        self.debug_assert_current();
         unsafe {
             let handle = &mut *self.buffer.get();
             handle.scratch_txt_two(txt_0, txt_1)
@@ -89,20 +114,32 @@ impl Ui {
         txt_0: impl AsRef<str>,
         txt_1: Option<impl AsRef<str>>,
     ) -> (*const std::os::raw::c_char, *const std::os::raw::c_char) {
+        self.debug_assert_current();
         unsafe {
             let handle = &mut *self.buffer.get();
             handle.scratch_txt_with_opt(txt_0, txt_1)
         }
     }
 
+    /// Internal method to get a C string for a [`UiStr`](crate::UiStr), copying only `&str`.
+    pub(crate) fn scratch_ui_str(&self, txt: crate::UiStr<'_>) -> *const std::os::raw::c_char {
+        self.debug_assert_current();
+        unsafe {
+            let handle = &mut *self.buffer.get();
+            handle.scratch_ui_str(txt)
+        }
+    }
+
     /// Get access to the scratch buffer for complex string operations
     pub(crate) fn scratch_buffer(&self) -> &UnsafeCell<UiBuffer> {
+        self.debug_assert_current();
         &self.buffer
     }
 
     /// Display text
     #[doc(alias = "TextUnformatted")]
     pub fn text<T: AsRef<str>>(&self, text: T) {
+        self.debug_assert_current();
         let s = text.as_ref();
         unsafe {
             let start = s.as_ptr();
@@ -114,12 +151,28 @@ impl Ui {
         }
     }
 
+    /// Display text from a [`UiStr`](crate::UiStr)
+    ///
+    /// Neither form is copied: `&str` is passed with its end pointer, NUL-terminated
+    /// strings as they are.
+    #[doc(alias = "TextUnformatted")]
+    pub fn text_str<'a>(&self, text: impl Into<crate::UiStr<'a>>) {
+        self.debug_assert_current();
+        match text.into() {
+            crate::UiStr::Str(s) => self.text(s),
+            crate::UiStr::CStr(s) => unsafe {
+                crate::sys::igTextUnformatted(s.as_ptr(), std::ptr::null())
+            },
+        }
+    }
+
     /// Set the viewport for the next window.
     ///
     /// This is a convenience wrapper over `ImGui::SetNextWindowViewport`.
     /// Useful when hosting a fullscreen DockSpace window inside the main viewport.
     #[doc(alias = "SetNextWindowViewport")]
     pub fn set_next_window_viewport(&self, viewport_id: Id) {
+        self.debug_assert_current();
         unsafe { sys::igSetNextWindowViewport(viewport_id.into()) }
     }
 
@@ -129,6 +182,7 @@ impl Ui {
     /// for widgets or dockspaces inside the current window/scope.
     #[doc(alias = "GetID")]
     pub fn get_id(&self, label: &str) -> Id {
+        self.debug_assert_current();
         let c = CString::new(label).expect("label contained null byte");
         unsafe { Id::from(sys::igGetID_Str(c.as_ptr())) }
     }
@@ -136,23 +190,80 @@ impl Ui {
     /// Access to the current window's draw list
     #[doc(alias = "GetWindowDrawList")]
     pub fn get_window_draw_list(&self) -> DrawListMut<'_> {
+        self.debug_assert_current();
         DrawListMut::window(self)
     }
 
+    /// Access to the current window's draw list together with the last item's rectangle.
+    ///
+    /// Call this right after emitting a widget to draw over it:
+    ///
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// ui.button("Inbox");
+    /// let dl = ui.draw_list_for_item();
+    /// let [_, y] = dl.min();
+    /// let [x, _] = dl.max();
+    /// dl.add_circle([x - 3.0, y + 3.0], 4.0, [1.0, 0.2, 0.2, 1.0])
+    ///     .filled(true)
+    ///     .build();
+    /// ```
+    #[doc(
+        alias = "GetWindowDrawList",
+        alias = "GetItemRectMin",
+        alias = "GetItemRectMax"
+    )]
+    pub fn draw_list_for_item(&self) -> crate::draw::ItemDrawList<'_> {
+        self.debug_assert_current();
+        let (min, max) = self.item_rect();
+        crate::draw::ItemDrawList::new(self.get_window_draw_list(), min, max)
+    }
+
     /// Access to the background draw list
     #[doc(alias = "GetBackgroundDrawList")]
     pub fn get_background_draw_list(&self) -> DrawListMut<'_> {
-        DrawListMut::background(self)
+        self.debug_assert_current();
+        DrawListMut::background(self, std::ptr::null_mut())
     }
 
     /// Access to the foreground draw list
     #[doc(alias = "GetForegroundDrawList")]
     pub fn get_foreground_draw_list(&self) -> DrawListMut<'_> {
-        DrawListMut::foreground(self)
+        self.debug_assert_current();
+        DrawListMut::foreground(self, std::ptr::null_mut())
+    }
+
+    /// Access to the background draw list of a specific viewport, drawn behind its windows
+    ///
+    /// Like [`get_background_draw_list`](Self::get_background_draw_list), only one background
+    /// draw list can be in use at a time.
+    #[doc(alias = "GetBackgroundDrawList")]
+    pub fn get_background_draw_list_for_viewport(
+        &self,
+        viewport: &crate::platform_io::Viewport,
+    ) -> DrawListMut<'_> {
+        self.debug_assert_current();
+        DrawListMut::background(self, viewport.as_raw() as *mut sys::ImGuiViewport)
+    }
+
+    /// Access to the foreground draw list of a specific viewport, drawn over its windows
+    ///
+    /// Like [`get_foreground_draw_list`](Self::get_foreground_draw_list), only one foreground
+    /// draw list can be in use at a time.
+    #[doc(alias = "GetForegroundDrawList")]
+    pub fn get_foreground_draw_list_for_viewport(
+        &self,
+        viewport: &crate::platform_io::Viewport,
+    ) -> DrawListMut<'_> {
+        self.debug_assert_current();
+        DrawListMut::foreground(self, viewport.as_raw() as *mut sys::ImGuiViewport)
     }
 
     /// Creates a window builder
     pub fn window(&self, name: impl Into<String>) -> crate::window::Window<'_> {
+        self.debug_assert_current();
         crate::window::Window::new(self, name)
     }
 
@@ -160,6 +271,7 @@ impl Ui {
     /// Dear ImGui features.
     #[doc(alias = "ShowDemoWindow")]
     pub fn show_demo_window(&self, opened: &mut bool) {
+        self.debug_assert_current();
         unsafe {
             crate::sys::igShowDemoWindow(opened);
         }
@@ -176,6 +288,7 @@ impl Ui {
         bg_color: [f32; 4],
         tint_color: [f32; 4],
     ) {
+        self.debug_assert_current();
         crate::widget::image::Image::new(self, texture, size).build_with_bg(bg_color, tint_color)
     }
 
@@ -184,6 +297,7 @@ impl Ui {
     /// Displays the Dear ImGui version/credits, and build/system information.
     #[doc(alias = "ShowAboutWindow")]
     pub fn show_about_window(&self, opened: &mut bool) {
+        self.debug_assert_current();
         unsafe {
             crate::sys::igShowAboutWindow(opened);
         }
@@ -195,6 +309,7 @@ impl Ui {
     /// window list, basic internal state, etc.
     #[doc(alias = "ShowMetricsWindow")]
     pub fn show_metrics_window(&self, opened: &mut bool) {
+        self.debug_assert_current();
         unsafe {
             crate::sys::igShowMetricsWindow(opened);
         }
@@ -203,6 +318,7 @@ impl Ui {
     /// Renders a style editor block (not a window) for the given `Style` structure
     #[doc(alias = "ShowStyleEditor")]
     pub fn show_style_editor(&self, style: &mut crate::style::Style) {
+        self.debug_assert_current();
         unsafe {
             crate::sys::igShowStyleEditor(style.raw_mut());
         }
@@ -211,6 +327,7 @@ impl Ui {
     /// Renders a style editor block (not a window) for the currently active style
     #[doc(alias = "ShowStyleEditor")]
     pub fn show_default_style_editor(&self) {
+        self.debug_assert_current();
         unsafe {
             crate::sys::igShowStyleEditor(std::ptr::null_mut());
         }
@@ -219,6 +336,7 @@ impl Ui {
     /// Renders a basic help/info block (not a window)
     #[doc(alias = "ShowUserGuide")]
     pub fn show_user_guide(&self) {
+        self.debug_assert_current();
         unsafe {
             crate::sys::igShowUserGuide();
         }
@@ -229,30 +347,35 @@ impl Ui {
     /// Creates a drag float slider
     #[doc(alias = "DragFloat")]
     pub fn drag_float(&self, label: impl AsRef<str>, value: &mut f32) -> bool {
+        self.debug_assert_current();
         crate::widget::drag::Drag::new(label).build(self, value)
     }
 
     /// Creates a drag float slider with configuration
     #[doc(alias = "DragFloat")]
     pub fn drag_float_config<L: AsRef<str>>(&self, label: L) -> crate::widget::drag::Drag<f32, L> {
+        self.debug_assert_current();
         crate::widget::drag::Drag::new(label)
     }
 
     /// Creates a drag int slider
     #[doc(alias = "DragInt")]
     pub fn drag_int(&self, label: impl AsRef<str>, value: &mut i32) -> bool {
+        self.debug_assert_current();
         crate::widget::drag::Drag::new(label).build(self, value)
     }
 
     /// Creates a drag int slider with configuration
     #[doc(alias = "DragInt")]
     pub fn drag_int_config<L: AsRef<str>>(&self, label: L) -> crate::widget::drag::Drag<i32, L> {
+        self.debug_assert_current();
         crate::widget::drag::Drag::new(label)
     }
 
     /// Creates a drag float range slider
     #[doc(alias = "DragFloatRange2")]
     pub fn drag_float_range2(&self, label: impl AsRef<str>, min: &mut f32, max: &mut f32) -> bool {
+        self.debug_assert_current();
         crate::widget::drag::DragRange::<f32, _>::new(label).build(self, min, max)
     }
 
@@ -262,12 +385,14 @@ impl Ui {
         &self,
         label: L,
     ) -> crate::widget::drag::DragRange<f32, L> {
+        self.debug_assert_current();
         crate::widget::drag::DragRange::new(label)
     }
 
     /// Creates a drag int range slider
     #[doc(alias = "DragIntRange2")]
     pub fn drag_int_range2(&self, label: impl AsRef<str>, min: &mut i32, max: &mut i32) -> bool {
+        self.debug_assert_current();
         crate::widget::drag::DragRange::<i32, _>::new(label).build(self, min, max)
     }
 
@@ -277,6 +402,7 @@ impl Ui {
         &self,
         label: L,
     ) -> crate::widget::drag::DragRange<i32, L> {
+        self.debug_assert_current();
         crate::widget::drag::DragRange::new(label)
     }
 
@@ -285,6 +411,7 @@ impl Ui {
     /// Returns `None` if no cursor should be displayed
     #[doc(alias = "GetMouseCursor")]
     pub fn mouse_cursor(&self) -> Option<MouseCursor> {
+        self.debug_assert_current();
         unsafe {
             match sys::igGetMouseCursor() {
                 sys::ImGuiMouseCursor_Arrow => Some(MouseCursor::Arrow),
@@ -306,6 +433,7 @@ impl Ui {
     /// Passing `None` hides the mouse cursor
     #[doc(alias = "SetMouseCursor")]
     pub fn set_mouse_cursor(&self, cursor_type: Option<MouseCursor>) {
+        self.debug_assert_current();
         unsafe {
             let val: sys::ImGuiMouseCursor = cursor_type
                 .map(|x| x as sys::ImGuiMouseCursor)
@@ -324,6 +452,7 @@ impl Ui {
     /// with `offset` set to 0.
     #[doc(alias = "SetKeyboardFocusHere")]
     pub fn set_keyboard_focus_here(&self) {
+        self.debug_assert_current();
         self.set_keyboard_focus_here_with_offset(0);
     }
 
@@ -332,6 +461,7 @@ impl Ui {
     /// Use positive offset to focus on next widgets, negative offset to focus on previous widgets.
     #[doc(alias = "SetKeyboardFocusHere")]
     pub fn set_keyboard_focus_here_with_offset(&self, offset: i32) {
+        self.debug_assert_current();
         unsafe {
             sys::igSetKeyboardFocusHere(offset);
         }
@@ -342,6 +472,7 @@ impl Ui {
     /// This is useful for tree nodes, collapsing headers, etc.
     #[doc(alias = "SetNextItemOpen")]
     pub fn set_next_item_open(&self, is_open: bool) {
+        self.debug_assert_current();
         unsafe {
             sys::igSetNextItemOpen(is_open, 0); // 0 = ImGuiCond_Always
         }
@@ -350,14 +481,21 @@ impl Ui {
     /// Set next item to be open by default with condition.
     #[doc(alias = "SetNextItemOpen")]
     pub fn set_next_item_open_with_cond(&self, is_open: bool, cond: crate::Condition) {
+        self.debug_assert_current();
         unsafe { sys::igSetNextItemOpen(is_open, cond as sys::ImGuiCond) }
     }
 
     /// Set next item width.
     ///
-    /// Set to 0.0 for default width, >0.0 for explicit width, <0.0 for relative width.
+    /// Only the next widget is affected; use [`Ui::push_item_width`] for several.
+    ///
+    /// - `> 0.0`: width is `item_width` pixels
+    /// - `= 0.0`: default to ~2/3 of window width
+    /// - `< 0.0`: `item_width` pixels relative to the right of the content region, i.e.
+    ///   the available width minus `|item_width|` (-1.0 always aligns width to the right side)
     #[doc(alias = "SetNextItemWidth")]
     pub fn set_next_item_width(&self, item_width: f32) {
+        self.debug_assert_current();
         unsafe {
             sys::igSetNextItemWidth(item_width);
         }
@@ -380,6 +518,7 @@ impl Ui {
     /// pop. The [`clone_style`](Ui::clone_style) version may instead be used to avoid `unsafe`.
     #[doc(alias = "GetStyle")]
     pub unsafe fn style(&self) -> &crate::Style {
+        self.debug_assert_current();
         unsafe {
             // safe because Style is a transparent wrapper around sys::ImGuiStyle
             &*(sys::igGetStyle() as *const crate::Style)
@@ -391,72 +530,84 @@ impl Ui {
     /// This is a safe alternative to [`style`](Self::style) that avoids the lifetime issues.
     #[doc(alias = "GetStyle")]
     pub fn clone_style(&self) -> crate::Style {
+        self.debug_assert_current();
         unsafe { self.style().clone() }
     }
 
     /// Apply the built-in Dark style to the current style.
     #[doc(alias = "StyleColorsDark")]
     pub fn style_colors_dark(&self) {
+        self.debug_assert_current();
         unsafe { sys::igStyleColorsDark(std::ptr::null_mut()) }
     }
 
     /// Apply the built-in Light style to the current style.
     #[doc(alias = "StyleColorsLight")]
     pub fn style_colors_light(&self) {
+        self.debug_assert_current();
         unsafe { sys::igStyleColorsLight(std::ptr::null_mut()) }
     }
 
     /// Apply the built-in Classic style to the current style.
     #[doc(alias = "StyleColorsClassic")]
     pub fn style_colors_classic(&self) {
+        self.debug_assert_current();
         unsafe { sys::igStyleColorsClassic(std::ptr::null_mut()) }
     }
 
     /// Write the Dark style values into the provided [`Style`] object.
     #[doc(alias = "StyleColorsDark")]
     pub fn style_colors_dark_into(&self, dst: &mut crate::Style) {
+        self.debug_assert_current();
         unsafe { sys::igStyleColorsDark(dst as *mut _ as *mut sys::ImGuiStyle) }
     }
 
     /// Write the Light style values into the provided [`Style`] object.
     #[doc(alias = "StyleColorsLight")]
     pub fn style_colors_light_into(&self, dst: &mut crate::Style) {
+        self.debug_assert_current();
         unsafe { sys::igStyleColorsLight(dst as *mut _ as *mut sys::ImGuiStyle) }
     }
 
     /// Write the Classic style values into the provided [`Style`] object.
     #[doc(alias = "StyleColorsClassic")]
     pub fn style_colors_classic_into(&self, dst: &mut crate::Style) {
+        self.debug_assert_current();
         unsafe { sys::igStyleColorsClassic(dst as *mut _ as *mut sys::ImGuiStyle) }
     }
 
     /// Returns DPI scale currently associated to the current window's viewport.
     #[doc(alias = "GetWindowDpiScale")]
     pub fn window_dpi_scale(&self) -> f32 {
+        self.debug_assert_current();
         unsafe { sys::igGetWindowDpiScale() }
     }
 
     /// Display a text label with a boolean value (for quick debug UIs).
     #[doc(alias = "Value")]
     pub fn value_bool(&self, prefix: impl AsRef<str>, v: bool) {
+        self.debug_assert_current();
         unsafe { sys::igValue_Bool(self.scratch_txt(prefix), v) }
     }
 
     /// Get current window width (shortcut for `GetWindowSize().x`).
     #[doc(alias = "GetWindowWidth")]
     pub fn window_width(&self) -> f32 {
+        self.debug_assert_current();
         unsafe { sys::igGetWindowWidth() }
     }
 
     /// Get current window height (shortcut for `GetWindowSize().y`).
     #[doc(alias = "GetWindowHeight")]
     pub fn window_height(&self) -> f32 {
+        self.debug_assert_current();
         unsafe { sys::igGetWindowHeight() }
     }
 
     /// Get current window position in screen space.
     #[doc(alias = "GetWindowPos")]
     pub fn window_pos(&self) -> [f32; 2] {
+        self.debug_assert_current();
         unsafe {
             let mut v = sys::ImVec2 { x: 0.0, y: 0.0 };
             sys::igGetWindowPos(&mut v);
@@ -467,6 +618,7 @@ impl Ui {
     /// Get current window size.
     #[doc(alias = "GetWindowSize")]
     pub fn window_size(&self) -> [f32; 2] {
+        self.debug_assert_current();
         unsafe {
             let mut v = sys::ImVec2 { x: 0.0, y: 0.0 };
             sys::igGetWindowSize(&mut v);
@@ -483,6 +635,7 @@ impl Ui {
     /// Displays a simplified log of important dear imgui events.
     #[doc(alias = "ShowDebugLogWindow")]
     pub fn show_debug_log_window(&self, opened: &mut bool) {
+        self.debug_assert_current();
         unsafe {
             sys::igShowDebugLogWindow(opened);
         }
@@ -493,6 +646,7 @@ impl Ui {
     /// Hover items with mouse to query information about the source of their unique ID.
     #[doc(alias = "ShowIDStackToolWindow")]
     pub fn show_id_stack_tool_window(&self, opened: &mut bool) {
+        self.debug_assert_current();
         unsafe {
             sys::igShowIDStackToolWindow(opened);
         }
@@ -503,12 +657,14 @@ impl Ui {
     /// Returns true when a different style was selected.
     #[doc(alias = "ShowStyleSelector")]
     pub fn show_style_selector(&self, label: impl AsRef<str>) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igShowStyleSelector(self.scratch_txt(label)) }
     }
 
     /// Renders a font selector combo box.
     #[doc(alias = "ShowFontSelector")]
     pub fn show_font_selector(&self, label: impl AsRef<str>) {
+        self.debug_assert_current();
         unsafe {
             sys::igShowFontSelector(self.scratch_txt(label));
         }
@@ -517,6 +673,7 @@ impl Ui {
     /// Returns the Dear ImGui version string
     #[doc(alias = "GetVersion")]
     pub fn get_version(&self) -> &str {
+        self.debug_assert_current();
         unsafe {
             let version_ptr = sys::igGetVersion();
             let c_str = std::ffi::CStr::from_ptr(version_ptr);
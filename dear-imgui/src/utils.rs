@@ -65,12 +65,14 @@ impl crate::ui::Ui {
     /// Returns `true` if the last item open state was toggled
     #[doc(alias = "IsItemToggledOpen")]
     pub fn is_item_toggled_open(&self) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsItemToggledOpen() }
     }
 
     /// Returns the upper-left bounding rectangle of the last item (screen space)
     #[doc(alias = "GetItemRectMin")]
     pub fn item_rect_min(&self) -> [f32; 2] {
+        self.debug_assert_current();
         unsafe {
             let mut rect = sys::ImVec2 { x: 0.0, y: 0.0 };
             sys::igGetItemRectMin(&mut rect);
@@ -81,6 +83,7 @@ impl crate::ui::Ui {
     /// Returns the lower-right bounding rectangle of the last item (screen space)
     #[doc(alias = "GetItemRectMax")]
     pub fn item_rect_max(&self) -> [f32; 2] {
+        self.debug_assert_current();
         unsafe {
             let mut rect = sys::ImVec2 { x: 0.0, y: 0.0 };
             sys::igGetItemRectMax(&mut rect);
@@ -95,30 +98,35 @@ impl crate::ui::Ui {
     /// Returns `true` if the current window is hovered (and typically: not blocked by a popup/modal)
     #[doc(alias = "IsWindowHovered")]
     pub fn is_window_hovered(&self) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsWindowHovered(HoveredFlags::NONE.bits()) }
     }
 
     /// Returns `true` if the current window is hovered based on the given flags
     #[doc(alias = "IsWindowHovered")]
     pub fn is_window_hovered_with_flags(&self, flags: HoveredFlags) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsWindowHovered(flags.bits()) }
     }
 
     /// Returns `true` if the current window is focused (and typically: not blocked by a popup/modal)
     #[doc(alias = "IsWindowFocused")]
     pub fn is_window_focused(&self) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsWindowFocused(0) }
     }
 
     /// Returns `true` if the current window is appearing this frame.
     #[doc(alias = "IsWindowAppearing")]
     pub fn is_window_appearing(&self) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsWindowAppearing() }
     }
 
     /// Returns `true` if the current window is collapsed.
     #[doc(alias = "IsWindowCollapsed")]
     pub fn is_window_collapsed(&self) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsWindowCollapsed() }
     }
 
@@ -129,12 +137,14 @@ impl crate::ui::Ui {
     /// Returns the number of times the key was pressed in the current frame
     #[doc(alias = "GetKeyPressedAmount")]
     pub fn get_key_pressed_amount(&self, key: Key, repeat_delay: f32, rate: f32) -> i32 {
+        self.debug_assert_current();
         unsafe { sys::igGetKeyPressedAmount(key.into(), repeat_delay, rate) }
     }
 
     /// Returns the name of a key
     #[doc(alias = "GetKeyName")]
     pub fn get_key_name(&self, key: Key) -> &str {
+        self.debug_assert_current();
         unsafe {
             let name_ptr = sys::igGetKeyName(key.into());
             let c_str = std::ffi::CStr::from_ptr(name_ptr);
@@ -145,12 +155,14 @@ impl crate::ui::Ui {
     /// Returns the number of times the mouse button was clicked in the current frame
     #[doc(alias = "GetMouseClickedCount")]
     pub fn get_mouse_clicked_count(&self, button: MouseButton) -> i32 {
+        self.debug_assert_current();
         unsafe { sys::igGetMouseClickedCount(button.into()) }
     }
 
     /// Returns the mouse position in screen coordinates
     #[doc(alias = "GetMousePos")]
     pub fn get_mouse_pos(&self) -> [f32; 2] {
+        self.debug_assert_current();
         unsafe {
             let mut pos = sys::ImVec2 { x: 0.0, y: 0.0 };
             sys::igGetMousePos(&mut pos);
@@ -161,6 +173,7 @@ impl crate::ui::Ui {
     /// Returns the mouse position when the button was clicked
     #[doc(alias = "GetMousePosOnOpeningCurrentPopup")]
     pub fn get_mouse_pos_on_opening_current_popup(&self) -> [f32; 2] {
+        self.debug_assert_current();
         unsafe {
             let mut pos = sys::ImVec2 { x: 0.0, y: 0.0 };
             sys::igGetMousePosOnOpeningCurrentPopup(&mut pos);
@@ -171,6 +184,7 @@ impl crate::ui::Ui {
     /// Returns the mouse drag delta
     #[doc(alias = "GetMouseDragDelta")]
     pub fn get_mouse_drag_delta(&self, button: MouseButton, lock_threshold: f32) -> [f32; 2] {
+        self.debug_assert_current();
         unsafe {
             let mut delta = sys::ImVec2 { x: 0.0, y: 0.0 };
             sys::igGetMouseDragDelta(&mut delta, button.into(), lock_threshold);
@@ -181,18 +195,21 @@ impl crate::ui::Ui {
     /// Returns the mouse wheel delta
     #[doc(alias = "GetIO")]
     pub fn get_mouse_wheel(&self) -> f32 {
+        self.debug_assert_current();
         unsafe { (*sys::igGetIO_Nil()).MouseWheel }
     }
 
     /// Returns the horizontal mouse wheel delta
     #[doc(alias = "GetIO")]
     pub fn get_mouse_wheel_h(&self) -> f32 {
+        self.debug_assert_current();
         unsafe { (*sys::igGetIO_Nil()).MouseWheelH }
     }
 
     /// Returns `true` if any mouse button is down
     #[doc(alias = "IsAnyMouseDown")]
     pub fn is_any_mouse_down(&self) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsAnyMouseDown() }
     }
 
@@ -203,12 +220,14 @@ impl crate::ui::Ui {
     /// Get global imgui time. Incremented by io.DeltaTime every frame.
     #[doc(alias = "GetTime")]
     pub fn time(&self) -> f64 {
+        self.debug_assert_current();
         unsafe { sys::igGetTime() }
     }
 
     /// Get global imgui frame count. Incremented by 1 every frame.
     #[doc(alias = "GetFrameCount")]
     pub fn frame_count(&self) -> i32 {
+        self.debug_assert_current();
         unsafe { sys::igGetFrameCount() }
     }
 
@@ -218,6 +237,7 @@ impl crate::ui::Ui {
     /// style object.
     #[doc(alias = "GetStyle")]
     pub fn style_color(&self, style_color: StyleColor) -> [f32; 4] {
+        self.debug_assert_current();
         unsafe {
             let style_ptr = sys::igGetStyle();
             let colors = (*style_ptr).Colors.as_ptr();
@@ -226,6 +246,32 @@ impl crate::ui::Ui {
         }
     }
 
+    /// Returns a style color packed as `u32`, with the global style alpha applied.
+    ///
+    /// Unlike [`style_color`](Self::style_color), the result is multiplied by `style.Alpha`
+    /// (and so fades with disabled scopes), which is what custom draw-list code should use to
+    /// match surrounding widgets.
+    #[doc(alias = "GetColorU32")]
+    pub fn get_color_u32(&self, style_color: StyleColor) -> u32 {
+        self.debug_assert_current();
+        self.get_color_u32_with_alpha(style_color, 1.0)
+    }
+
+    /// Like [`get_color_u32`](Self::get_color_u32), with an extra alpha multiplier
+    #[doc(alias = "GetColorU32")]
+    pub fn get_color_u32_with_alpha(&self, style_color: StyleColor, alpha_mul: f32) -> u32 {
+        self.debug_assert_current();
+        unsafe { sys::igGetColorU32_Col(style_color as sys::ImGuiCol, alpha_mul) }
+    }
+
+    /// Packs an RGBA color as `u32`, with the global style alpha applied
+    #[doc(alias = "GetColorU32")]
+    pub fn get_color_u32_rgba(&self, color: [f32; 4]) -> u32 {
+        self.debug_assert_current();
+        let [x, y, z, w] = color;
+        unsafe { sys::igGetColorU32_Vec4(sys::ImVec4 { x, y, z, w }) }
+    }
+
     /// Returns the name of a style color.
     ///
     /// This is just a wrapper around calling [`name`] on [StyleColor].
@@ -233,16 +279,14 @@ impl crate::ui::Ui {
     /// [`name`]: StyleColor::name
     #[doc(alias = "GetStyleColorName")]
     pub fn style_color_name(&self, style_color: StyleColor) -> &'static str {
-        unsafe {
-            let name_ptr = sys::igGetStyleColorName(style_color as sys::ImGuiCol);
-            let c_str = std::ffi::CStr::from_ptr(name_ptr);
-            c_str.to_str().unwrap_or("Unknown")
-        }
+        self.debug_assert_current();
+        style_color.name()
     }
 
     /// Test if rectangle (of given size, starting from cursor position) is visible / not clipped.
     #[doc(alias = "IsRectVisible")]
     pub fn is_rect_visible(&self, size: [f32; 2]) -> bool {
+        self.debug_assert_current();
         unsafe {
             let size = sys::ImVec2 {
                 x: size[0],
@@ -255,6 +299,7 @@ impl crate::ui::Ui {
     /// Test if rectangle (in screen space) is visible / not clipped.
     #[doc(alias = "IsRectVisible")]
     pub fn is_rect_visible_ex(&self, rect_min: [f32; 2], rect_max: [f32; 2]) -> bool {
+        self.debug_assert_current();
         unsafe {
             let rect_min = sys::ImVec2 {
                 x: rect_min[0],
@@ -273,6 +318,7 @@ impl crate::ui::Ui {
     /// Get cursor position in screen coordinates.
     #[doc(alias = "GetCursorScreenPos")]
     pub fn get_cursor_screen_pos(&self) -> [f32; 2] {
+        self.debug_assert_current();
         unsafe {
             let mut pos = sys::ImVec2 { x: 0.0, y: 0.0 };
             sys::igGetCursorScreenPos(&mut pos);
@@ -283,6 +329,7 @@ impl crate::ui::Ui {
     /// Get available content region size.
     #[doc(alias = "GetContentRegionAvail")]
     pub fn get_content_region_avail(&self) -> [f32; 2] {
+        self.debug_assert_current();
         unsafe {
             let mut size = sys::ImVec2 { x: 0.0, y: 0.0 };
             sys::igGetContentRegionAvail(&mut size);
@@ -297,6 +344,7 @@ impl crate::ui::Ui {
         rect_min: [f32; 2],
         rect_max: [f32; 2],
     ) -> bool {
+        self.debug_assert_current();
         point[0] >= rect_min[0]
             && point[0] <= rect_max[0]
             && point[1] >= rect_min[1]
@@ -305,6 +353,7 @@ impl crate::ui::Ui {
 
     /// Calculate distance between two points.
     pub fn distance(&self, p1: [f32; 2], p2: [f32; 2]) -> f32 {
+        self.debug_assert_current();
         let dx = p2[0] - p1[0];
         let dy = p2[1] - p1[1];
         (dx * dx + dy * dy).sqrt()
@@ -312,6 +361,7 @@ impl crate::ui::Ui {
 
     /// Calculate squared distance between two points (faster than distance).
     pub fn distance_squared(&self, p1: [f32; 2], p2: [f32; 2]) -> f32 {
+        self.debug_assert_current();
         let dx = p2[0] - p1[0];
         let dy = p2[1] - p1[1];
         dx * dx + dy * dy
@@ -325,6 +375,7 @@ impl crate::ui::Ui {
         p3: [f32; 2],
         p4: [f32; 2],
     ) -> bool {
+        self.debug_assert_current();
         let d1 = self.cross_product(
             [p4[0] - p3[0], p4[1] - p3[1]],
             [p1[0] - p3[0], p1[1] - p3[1]],
@@ -347,11 +398,13 @@ impl crate::ui::Ui {
 
     /// Calculate cross product of two 2D vectors.
     fn cross_product(&self, v1: [f32; 2], v2: [f32; 2]) -> f32 {
+        self.debug_assert_current();
         v1[0] * v2[1] - v1[1] * v2[0]
     }
 
     /// Normalize a 2D vector.
     pub fn normalize(&self, v: [f32; 2]) -> [f32; 2] {
+        self.debug_assert_current();
         let len = (v[0] * v[0] + v[1] * v[1]).sqrt();
         if len > f32::EPSILON {
             [v[0] / len, v[1] / len]
@@ -362,11 +415,13 @@ impl crate::ui::Ui {
 
     /// Calculate dot product of two 2D vectors.
     pub fn dot_product(&self, v1: [f32; 2], v2: [f32; 2]) -> f32 {
+        self.debug_assert_current();
         v1[0] * v2[0] + v1[1] * v2[1]
     }
 
     /// Calculate the angle between two vectors in radians.
     pub fn angle_between_vectors(&self, v1: [f32; 2], v2: [f32; 2]) -> f32 {
+        self.debug_assert_current();
         let dot = self.dot_product(v1, v2);
         let len1 = (v1[0] * v1[0] + v1[1] * v1[1]).sqrt();
         let len2 = (v2[0] * v2[0] + v2[1] * v2[1]).sqrt();
@@ -380,11 +435,13 @@ impl crate::ui::Ui {
 
     /// Check if a point is inside a circle.
     pub fn is_point_in_circle(&self, point: [f32; 2], center: [f32; 2], radius: f32) -> bool {
+        self.debug_assert_current();
         self.distance_squared(point, center) <= radius * radius
     }
 
     /// Calculate the area of a triangle given three points.
     pub fn triangle_area(&self, p1: [f32; 2], p2: [f32; 2], p3: [f32; 2]) -> f32 {
+        self.debug_assert_current();
         let cross = self.cross_product(
             [p2[0] - p1[0], p2[1] - p1[1]],
             [p3[0] - p1[0], p3[1] - p1[1]],
@@ -397,6 +454,7 @@ impl crate::ui::Ui {
     /// Allows the next item to be overlapped by a subsequent item.
     #[doc(alias = "SetNextItemAllowOverlap")]
     pub fn set_next_item_allow_overlap(&self) {
+        self.debug_assert_current();
         unsafe { sys::igSetNextItemAllowOverlap() };
     }
 }
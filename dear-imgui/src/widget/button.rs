@@ -9,17 +9,31 @@ impl Ui {
     /// Creates a button with the given label
     #[doc(alias = "Button")]
     pub fn button(&self, label: impl AsRef<str>) -> bool {
+        self.debug_assert_current();
         self.button_config(label).build()
     }
 
     /// Creates a button with the given label and size
     #[doc(alias = "Button")]
     pub fn button_with_size(&self, label: impl AsRef<str>, size: impl Into<[f32; 2]>) -> bool {
+        self.debug_assert_current();
         self.button_config(label).size(size).build()
     }
 
+    /// Creates a button with a [`UiStr`](crate::UiStr) label
+    ///
+    /// Labels held as `CStr`, `CString` or [`ImString`](crate::ImString) are passed to
+    /// Dear ImGui without being copied.
+    #[doc(alias = "Button")]
+    pub fn button_str<'a>(&self, label: impl Into<crate::UiStr<'a>>) -> bool {
+        self.debug_assert_current();
+        let label_ptr = self.scratch_ui_str(label.into());
+        unsafe { sys::igButton(label_ptr, sys::ImVec2 { x: 0.0, y: 0.0 }) }
+    }
+
     /// Creates a button builder
     pub fn button_config(&self, label: impl AsRef<str>) -> Button<'_> {
+        self.debug_assert_current();
         Button::new(self, label)
     }
 }
@@ -28,6 +42,7 @@ impl Ui {
     /// Creates a checkbox
     #[doc(alias = "Checkbox")]
     pub fn checkbox(&self, label: impl AsRef<str>, value: &mut bool) -> bool {
+        self.debug_assert_current();
         let label_ptr = self.scratch_txt(label);
         unsafe { sys::igCheckbox(label_ptr, value) }
     }
@@ -35,6 +50,7 @@ impl Ui {
     /// Creates a radio button
     #[doc(alias = "RadioButton")]
     pub fn radio_button(&self, label: impl AsRef<str>, active: bool) -> bool {
+        self.debug_assert_current();
         let label_ptr = self.scratch_txt(label);
         unsafe { sys::igRadioButton_Bool(label_ptr, active) }
     }
@@ -42,6 +58,7 @@ impl Ui {
     /// Creates a radio button with integer value
     #[doc(alias = "RadioButton")]
     pub fn radio_button_int(&self, label: impl AsRef<str>, v: &mut i32, v_button: i32) -> bool {
+        self.debug_assert_current();
         let label_ptr = self.scratch_txt(label);
         unsafe { sys::igRadioButton_IntPtr(label_ptr, v, v_button) }
     }
@@ -51,6 +68,7 @@ impl Ui {
     /// Returns true if this radio button was clicked.
     #[doc(alias = "RadioButtonBool")]
     pub fn radio_button_bool(&self, label: impl AsRef<str>, active: bool) -> bool {
+        self.debug_assert_current();
         let label_ptr = self.scratch_txt(label);
         unsafe { sys::igRadioButton_Bool(label_ptr, active) }
     }
@@ -67,6 +85,7 @@ impl Ui {
             + std::ops::BitAnd<Output = T>
             + std::ops::Not<Output = T>,
     {
+        self.debug_assert_current();
         let mut value = *flags & mask == mask;
         let pressed = self.checkbox(label, &mut value);
         if pressed {
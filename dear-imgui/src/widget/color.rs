@@ -62,6 +62,15 @@ impl ColorEditFlags {
     /// ColorEdit, ColorPicker: input and output data in HSV format.
     pub const INPUT_HSV: Self = Self(sys::ImGuiColorEditFlags_InputHSV as u32);
 
+    /// Display modes, of which a color edit shows at most one
+    const DISPLAY_MASK: Self = Self(sys::ImGuiColorEditFlags_DisplayMask_ as u32);
+    /// Value formats, of which at most one may be set
+    const DATA_TYPE_MASK: Self = Self(sys::ImGuiColorEditFlags_DataTypeMask_ as u32);
+    /// Picker styles, of which at most one may be set
+    const PICKER_MASK: Self = Self(sys::ImGuiColorEditFlags_PickerMask_ as u32);
+    /// Input/output formats, of which at most one may be set
+    const INPUT_MASK: Self = Self(sys::ImGuiColorEditFlags_InputMask_ as u32);
+
     /// Returns the underlying bits
     pub const fn bits(self) -> u32 {
         self.0
@@ -71,6 +80,21 @@ impl ColorEditFlags {
     pub const fn contains(self, other: Self) -> bool {
         (self.0 & other.0) == other.0
     }
+
+    /// Inserts or removes the given flags
+    pub fn set(&mut self, other: Self, value: bool) {
+        if value {
+            *self |= other;
+        } else {
+            *self &= !other;
+        }
+    }
+
+    /// Replaces whichever flag of `mask` is set with `choice`, as ImGui asserts that
+    /// options within one group are exclusive
+    fn select(self, mask: Self, choice: Self) -> Self {
+        (self & !mask) | choice
+    }
 }
 
 impl std::ops::BitOr for ColorEditFlags {
@@ -121,33 +145,51 @@ impl std::ops::Not for ColorEditFlags {
 
 /// # Color Edit Widgets
 impl Ui {
+    /// Sets the default options of every color edit and picker.
+    ///
+    /// Widgets fall back to these for each group of options (display mode, value format,
+    /// picker style and input format) their own flags leave unset. Users can also change
+    /// them through the right-click options menu unless [`ColorEditFlags::NO_OPTIONS`] is set.
+    ///
+    /// Each group accepts at most one flag; ImGui asserts otherwise.
+    #[doc(alias = "SetColorEditOptions")]
+    pub fn set_color_edit_options(&self, flags: ColorEditFlags) {
+        self.debug_assert_current();
+        unsafe { sys::igSetColorEditOptions(flags.bits() as i32) }
+    }
+
     /// Creates a color edit widget for 3 components (RGB)
     #[doc(alias = "ColorEdit3")]
     pub fn color_edit3(&self, label: impl AsRef<str>, color: &mut [f32; 3]) -> bool {
+        self.debug_assert_current();
         self.color_edit3_config(label, color).build()
     }
 
     /// Creates a color edit widget for 4 components (RGBA)
     #[doc(alias = "ColorEdit4")]
     pub fn color_edit4(&self, label: impl AsRef<str>, color: &mut [f32; 4]) -> bool {
+        self.debug_assert_current();
         self.color_edit4_config(label, color).build()
     }
 
     /// Creates a color picker widget for 3 components (RGB)
     #[doc(alias = "ColorPicker3")]
     pub fn color_picker3(&self, label: impl AsRef<str>, color: &mut [f32; 3]) -> bool {
+        self.debug_assert_current();
         self.color_picker3_config(label, color).build()
     }
 
     /// Creates a color picker widget for 4 components (RGBA)
     #[doc(alias = "ColorPicker4")]
     pub fn color_picker4(&self, label: impl AsRef<str>, color: &mut [f32; 4]) -> bool {
+        self.debug_assert_current();
         self.color_picker4_config(label, color).build()
     }
 
     /// Creates a color button widget
     #[doc(alias = "ColorButton")]
     pub fn color_button(&self, desc_id: impl AsRef<str>, color: [f32; 4]) -> bool {
+        self.debug_assert_current();
         self.color_button_config(desc_id, color).build()
     }
 
@@ -157,6 +199,7 @@ impl Ui {
         label: impl AsRef<str>,
         color: &'p mut [f32; 3],
     ) -> ColorEdit3<'_, 'p> {
+        self.debug_assert_current();
         ColorEdit3::new(self, label, color)
     }
 
@@ -166,6 +209,7 @@ impl Ui {
         label: impl AsRef<str>,
         color: &'p mut [f32; 4],
     ) -> ColorEdit4<'_, 'p> {
+        self.debug_assert_current();
         ColorEdit4::new(self, label, color)
     }
 
@@ -175,6 +219,7 @@ impl Ui {
         label: impl AsRef<str>,
         color: &'p mut [f32; 3],
     ) -> ColorPicker3<'_, 'p> {
+        self.debug_assert_current();
         ColorPicker3::new(self, label, color)
     }
 
@@ -184,6 +229,7 @@ impl Ui {
         label: impl AsRef<str>,
         color: &'p mut [f32; 4],
     ) -> ColorPicker4<'_, 'p> {
+        self.debug_assert_current();
         ColorPicker4::new(self, label, color)
     }
 
@@ -193,6 +239,7 @@ impl Ui {
         desc_id: impl AsRef<str>,
         color: [f32; 4],
     ) -> ColorButton<'_> {
+        self.debug_assert_current();
         ColorButton::new(self, desc_id, color)
     }
 }
@@ -353,6 +400,106 @@ impl<'ui, 'p> ColorPicker4<'ui, 'p> {
     }
 }
 
+/// Adds setters for the flags shared by color edits and pickers to a builder
+macro_rules! impl_color_edit_options {
+    ($builder:ident) => {
+        impl<'ui, 'p> $builder<'ui, 'p> {
+            /// Shows a vertical alpha bar/gradient in the picker
+            pub fn alpha_bar(mut self, enabled: bool) -> Self {
+                self.flags.set(ColorEditFlags::ALPHA_BAR, enabled);
+                self
+            }
+
+            /// Displays the preview half opaque and half over a checkerboard
+            pub fn alpha_preview_half(mut self, enabled: bool) -> Self {
+                self.flags.set(ColorEditFlags::ALPHA_PREVIEW_HALF, enabled);
+                self
+            }
+
+            /// Lifts the 0.0..1.0 limits so HDR values can be edited.
+            ///
+            /// Also displays the values as floats, as the 0..255 integers could not show them.
+            pub fn hdr(mut self, enabled: bool) -> Self {
+                self.flags.set(ColorEditFlags::HDR, enabled);
+                if enabled {
+                    self.flags = self
+                        .flags
+                        .select(ColorEditFlags::DATA_TYPE_MASK, ColorEditFlags::FLOAT);
+                }
+                self
+            }
+
+            /// Displays the values as RGB
+            pub fn display_rgb(mut self) -> Self {
+                self.flags = self
+                    .flags
+                    .select(ColorEditFlags::DISPLAY_MASK, ColorEditFlags::DISPLAY_RGB);
+                self
+            }
+
+            /// Displays the values as HSV
+            pub fn display_hsv(mut self) -> Self {
+                self.flags = self
+                    .flags
+                    .select(ColorEditFlags::DISPLAY_MASK, ColorEditFlags::DISPLAY_HSV);
+                self
+            }
+
+            /// Displays the value as a hex string input field
+            pub fn display_hex(mut self) -> Self {
+                self.flags = self
+                    .flags
+                    .select(ColorEditFlags::DISPLAY_MASK, ColorEditFlags::DISPLAY_HEX);
+                self
+            }
+
+            /// Reads and writes the color as RGB
+            pub fn input_rgb(mut self) -> Self {
+                self.flags = self
+                    .flags
+                    .select(ColorEditFlags::INPUT_MASK, ColorEditFlags::INPUT_RGB);
+                self
+            }
+
+            /// Reads and writes the color as HSV
+            pub fn input_hsv(mut self) -> Self {
+                self.flags = self
+                    .flags
+                    .select(ColorEditFlags::INPUT_MASK, ColorEditFlags::INPUT_HSV);
+                self
+            }
+
+            /// Hides the input fields, leaving only the preview
+            pub fn no_inputs(mut self, enabled: bool) -> Self {
+                self.flags.set(ColorEditFlags::NO_INPUTS, enabled);
+                self
+            }
+
+            /// Uses a hue bar and a saturation/value rectangle in the picker
+            pub fn picker_hue_bar(mut self) -> Self {
+                self.flags = self
+                    .flags
+                    .select(ColorEditFlags::PICKER_MASK, ColorEditFlags::PICKER_HUE_BAR);
+                self
+            }
+
+            /// Uses a hue wheel and a saturation/value triangle in the picker
+            pub fn picker_hue_wheel(mut self) -> Self {
+                self.flags = self.flags.select(
+                    ColorEditFlags::PICKER_MASK,
+                    ColorEditFlags::PICKER_HUE_WHEEL,
+                );
+                self
+            }
+        }
+    };
+}
+
+impl_color_edit_options!(ColorEdit3);
+impl_color_edit_options!(ColorEdit4);
+impl_color_edit_options!(ColorPicker3);
+impl_color_edit_options!(ColorPicker4);
+
 /// Builder for a color button widget
 #[derive(Debug)]
 #[must_use]
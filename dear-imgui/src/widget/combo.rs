@@ -24,6 +24,7 @@ impl Ui {
         label: impl AsRef<str>,
         preview_value: impl AsRef<str>,
     ) -> Option<ComboBoxToken<'_>> {
+        self.debug_assert_current();
         self.begin_combo_with_flags(label, preview_value, ComboBoxFlags::NONE)
     }
 
@@ -41,6 +42,7 @@ impl Ui {
         preview_value: impl AsRef<str>,
         flags: ComboBoxFlags,
     ) -> Option<ComboBoxToken<'_>> {
+        self.debug_assert_current();
         let label_ptr = self.scratch_txt(label);
         let preview_ptr = self.scratch_txt(preview_value);
 
@@ -62,6 +64,7 @@ impl Ui {
     #[must_use]
     #[doc(alias = "BeginCombo")]
     pub fn begin_combo_no_preview(&self, label: impl AsRef<str>) -> Option<ComboBoxToken<'_>> {
+        self.debug_assert_current();
         self.begin_combo_no_preview_with_flags(label, ComboBoxFlags::NONE)
     }
 
@@ -78,6 +81,7 @@ impl Ui {
         label: impl AsRef<str>,
         flags: ComboBoxFlags,
     ) -> Option<ComboBoxToken<'_>> {
+        self.debug_assert_current();
         let label_ptr = self.scratch_txt(label);
 
         let should_render = unsafe { sys::igBeginCombo(label_ptr, std::ptr::null(), flags.bits()) };
@@ -89,6 +93,41 @@ impl Ui {
         }
     }
 
+    /// Creates a combo box and runs a closure to construct the popup contents.
+    /// Returns the result of the closure, if it is called.
+    ///
+    /// The popup may hold arbitrary widgets such as selectables, separators and headers.
+    /// The closure is not called if the combo box is not open.
+    ///
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # fn f(ui: &Ui, current: &mut usize) {
+    /// ui.combo_box("Font", "Sans", ComboBoxFlags::NONE, || {
+    ///     ui.text_disabled("Proportional");
+    ///     if ui.selectable("Sans") {
+    ///         *current = 0;
+    ///     }
+    ///     ui.separator();
+    ///     ui.text_disabled("Monospace");
+    ///     if ui.selectable("Mono") {
+    ///         *current = 1;
+    ///     }
+    /// });
+    /// # }
+    /// ```
+    #[doc(alias = "BeginCombo")]
+    pub fn combo_box<R>(
+        &self,
+        label: impl AsRef<str>,
+        preview_value: impl AsRef<str>,
+        flags: ComboBoxFlags,
+        f: impl FnOnce() -> R,
+    ) -> Option<R> {
+        self.debug_assert_current();
+        self.begin_combo_with_flags(label, preview_value, flags)
+            .map(|_combo| f())
+    }
+
     /// Builds a simple combo box for choosing from a slice of values.
     #[doc(alias = "Combo")]
     pub fn combo<V, L>(
@@ -101,29 +140,31 @@ impl Ui {
     where
         for<'b> L: Fn(&'b V) -> Cow<'b, str>,
     {
+        self.debug_assert_current();
         let label_fn = &label_fn;
         let mut result = false;
         let preview_value = items.get(*current_item).map(label_fn);
 
-        if let Some(combo_token) = self.begin_combo(
+        self.combo_box(
             label,
             preview_value.as_ref().map(|s| s.as_ref()).unwrap_or(""),
-        ) {
-            for (idx, item) in items.iter().enumerate() {
-                let is_selected = idx == *current_item;
-                if is_selected {
-                    self.set_item_default_focus();
-                }
+            ComboBoxFlags::NONE,
+            || {
+                for (idx, item) in items.iter().enumerate() {
+                    let is_selected = idx == *current_item;
+                    if is_selected {
+                        self.set_item_default_focus();
+                    }
 
-                let clicked = self.selectable(label_fn(item).as_ref());
+                    let clicked = self.selectable(label_fn(item).as_ref());
 
-                if clicked {
-                    *current_item = idx;
-                    result = true;
+                    if clicked {
+                        *current_item = idx;
+                        result = true;
+                    }
                 }
-            }
-            combo_token.end();
-        }
+            },
+        );
 
         result
     }
@@ -136,11 +177,13 @@ impl Ui {
         current_item: &mut usize,
         items: &[impl AsRef<str>],
     ) -> bool {
+        self.debug_assert_current();
         self.combo(label, current_item, items, |s| Cow::Borrowed(s.as_ref()))
     }
 
     /// Sets the default focus for the next item
     pub fn set_item_default_focus(&self) {
+        self.debug_assert_current();
         unsafe {
             sys::igSetItemDefaultFocus();
         }
@@ -158,6 +201,18 @@ pub struct ComboBox<'ui, Label, Preview = &'static str> {
 }
 
 impl<'ui, Label: AsRef<str>> ComboBox<'ui, Label> {
+    /// Creates a combo box builder without a preview value
+    pub fn new(ui: &'ui Ui, label: Label) -> Self {
+        ComboBox {
+            label,
+            preview_value: None,
+            flags: ComboBoxFlags::NONE,
+            ui,
+        }
+    }
+}
+
+impl<'ui, Label: AsRef<str>, Preview: AsRef<str>> ComboBox<'ui, Label, Preview> {
     /// Sets the preview value
     pub fn preview_value<P: AsRef<str>>(self, preview: P) -> ComboBox<'ui, Label, P> {
         ComboBox {
@@ -197,6 +252,14 @@ impl<'ui, Label: AsRef<str>> ComboBox<'ui, Label> {
             None
         }
     }
+
+    /// Creates a combo box and runs a closure to construct the popup contents.
+    /// Returns the result of the closure, if it is called.
+    ///
+    /// Note: the closure is not called if the combo box is not open.
+    pub fn build<R, F: FnOnce() -> R>(self, f: F) -> Option<R> {
+        self.begin().map(|_combo| f())
+    }
 }
 
 /// Tracks a combo box that can be ended by calling `.end()` or by dropping
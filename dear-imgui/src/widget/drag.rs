@@ -14,17 +14,34 @@ use crate::widget::slider::SliderFlags;
 impl Ui {
     /// Creates a new drag slider widget. Returns true if the value has been edited.
     pub fn drag<T: AsRef<str>, K: DataTypeKind>(&self, label: T, value: &mut K) -> bool {
+        self.debug_assert_current();
         Drag::new(label).build(self, value)
     }
 
     /// Creates a new unbuilt Drag.
     pub fn drag_config<T: AsRef<str>, K: DataTypeKind>(&self, label: T) -> Drag<K, T> {
+        self.debug_assert_current();
         Drag::new(label)
     }
 
+    /// Creates a horizontal row of drag sliders, one per element of `values`.
+    ///
+    /// Use [`drag_config`](Self::drag_config) and [`Drag::build_array`] to set the speed,
+    /// range or format. Returns true if any value has been edited.
+    #[doc(alias = "DragScalarN")]
+    pub fn drag_scalar_n<T: AsRef<str>, K: DataTypeKind>(
+        &self,
+        label: T,
+        values: &mut [K],
+    ) -> bool {
+        self.debug_assert_current();
+        Drag::new(label).build_array(self, values)
+    }
+
     /// Creates a drag float2 slider (2 floats)
     #[doc(alias = "DragFloat2")]
     pub fn drag_float2(&self, label: impl AsRef<str>, values: &mut [f32; 2]) -> bool {
+        self.debug_assert_current();
         unsafe {
             let label_cstr = self.scratch_txt(label);
             sys::igDragFloat2(
@@ -42,6 +59,7 @@ impl Ui {
     /// Creates a drag float3 slider (3 floats)
     #[doc(alias = "DragFloat3")]
     pub fn drag_float3(&self, label: impl AsRef<str>, values: &mut [f32; 3]) -> bool {
+        self.debug_assert_current();
         unsafe {
             let label_cstr = self.scratch_txt(label);
             sys::igDragFloat3(
@@ -59,6 +77,7 @@ impl Ui {
     /// Creates a drag float4 slider (4 floats)
     #[doc(alias = "DragFloat4")]
     pub fn drag_float4(&self, label: impl AsRef<str>, values: &mut [f32; 4]) -> bool {
+        self.debug_assert_current();
         unsafe {
             let label_cstr = self.scratch_txt(label);
             sys::igDragFloat4(
@@ -76,6 +95,7 @@ impl Ui {
     /// Creates a drag int2 slider (2 ints)
     #[doc(alias = "DragInt2")]
     pub fn drag_int2(&self, label: impl AsRef<str>, values: &mut [i32; 2]) -> bool {
+        self.debug_assert_current();
         unsafe {
             let label_cstr = self.scratch_txt(label);
             sys::igDragInt2(label_cstr, values.as_mut_ptr(), 1.0, 0, 0, ptr::null(), 0)
@@ -85,6 +105,7 @@ impl Ui {
     /// Creates a drag int3 slider (3 ints)
     #[doc(alias = "DragInt3")]
     pub fn drag_int3(&self, label: impl AsRef<str>, values: &mut [i32; 3]) -> bool {
+        self.debug_assert_current();
         unsafe {
             let label_cstr = self.scratch_txt(label);
             sys::igDragInt3(label_cstr, values.as_mut_ptr(), 1.0, 0, 0, ptr::null(), 0)
@@ -94,6 +115,7 @@ impl Ui {
     /// Creates a drag int4 slider (4 ints)
     #[doc(alias = "DragInt4")]
     pub fn drag_int4(&self, label: impl AsRef<str>, values: &mut [i32; 4]) -> bool {
+        self.debug_assert_current();
         unsafe {
             let label_cstr = self.scratch_txt(label);
             sys::igDragInt4(label_cstr, values.as_mut_ptr(), 1.0, 0, 0, ptr::null(), 0)
@@ -190,8 +212,12 @@ impl<L: AsRef<str>, T: DataTypeKind, F: AsRef<str>> Drag<T, L, F> {
 
     /// Builds a horizontal array of multiple drag sliders attached to the given slice
     ///
-    /// Returns true if any slider value was changed
+    /// Returns true if any slider value was changed. An empty slice draws nothing and
+    /// returns false.
     pub fn build_array(self, ui: &Ui, values: &mut [T]) -> bool {
+        if values.is_empty() {
+            return false;
+        }
         unsafe {
             let (one, two) = ui.scratch_txt_with_opt(self.label, self.display_format);
 
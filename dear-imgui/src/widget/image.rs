@@ -42,6 +42,7 @@ impl Ui {
     /// Creates an image widget
     #[doc(alias = "Image")]
     pub fn image(&self, texture: impl Into<TextureRef>, size: [f32; 2]) {
+        self.debug_assert_current();
         self.image_config(texture, size).build()
     }
 
@@ -53,11 +54,13 @@ impl Ui {
         texture: impl Into<TextureRef>,
         size: [f32; 2],
     ) -> bool {
+        self.debug_assert_current();
         self.image_button_config(str_id, texture, size).build()
     }
 
     /// Creates an image builder
     pub fn image_config(&self, texture: impl Into<TextureRef>, size: [f32; 2]) -> Image<'_> {
+        self.debug_assert_current();
         Image::new(self, texture, size)
     }
 
@@ -68,6 +71,7 @@ impl Ui {
         texture: impl Into<TextureRef>,
         size: [f32; 2],
     ) -> ImageButton<'_> {
+        self.debug_assert_current();
         ImageButton::new(self, str_id, texture, size)
     }
 }
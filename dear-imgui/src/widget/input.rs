@@ -59,6 +59,7 @@ impl Ui {
         label: impl AsRef<str>,
         buf: &'p mut String,
     ) -> InputText<'_, 'p, String, String, PassthroughCallback> {
+        self.debug_assert_current();
         InputText::new(self, label, buf)
     }
 
@@ -68,6 +69,7 @@ impl Ui {
         label: impl AsRef<str>,
         buf: &'p mut ImString,
     ) -> InputTextImStr<'_, 'p, String, String, PassthroughCallback> {
+        self.debug_assert_current();
         InputTextImStr::new(self, label, buf)
     }
 
@@ -91,6 +93,7 @@ impl Ui {
         buf: &'p mut String,
         size: impl Into<[f32; 2]>,
     ) -> InputTextMultiline<'_, 'p> {
+        self.debug_assert_current();
         InputTextMultiline::new(self, label, buf, size)
     }
 
@@ -101,6 +104,7 @@ impl Ui {
         buf: &'p mut ImString,
         size: impl Into<[f32; 2]>,
     ) -> InputTextMultilineImStr<'_, 'p> {
+        self.debug_assert_current();
         InputTextMultilineImStr::new(self, label, buf, size)
     }
 
@@ -109,6 +113,7 @@ impl Ui {
     /// Returns true if the value was edited.
     #[doc(alias = "InputInt")]
     pub fn input_int(&self, label: impl AsRef<str>, value: &mut i32) -> bool {
+        self.debug_assert_current();
         self.input_int_config(label).build(value)
     }
 
@@ -117,6 +122,7 @@ impl Ui {
     /// Returns true if the value was edited.
     #[doc(alias = "InputFloat")]
     pub fn input_float(&self, label: impl AsRef<str>, value: &mut f32) -> bool {
+        self.debug_assert_current();
         self.input_float_config(label).build(value)
     }
 
@@ -125,21 +131,25 @@ impl Ui {
     /// Returns true if the value was edited.
     #[doc(alias = "InputDouble")]
     pub fn input_double(&self, label: impl AsRef<str>, value: &mut f64) -> bool {
+        self.debug_assert_current();
         self.input_double_config(label).build(value)
     }
 
     /// Creates an integer input builder
     pub fn input_int_config(&self, label: impl AsRef<str>) -> InputInt<'_> {
+        self.debug_assert_current();
         InputInt::new(self, label)
     }
 
     /// Creates a float input builder
     pub fn input_float_config(&self, label: impl AsRef<str>) -> InputFloat<'_> {
+        self.debug_assert_current();
         InputFloat::new(self, label)
     }
 
     /// Creates a double input builder
     pub fn input_double_config(&self, label: impl AsRef<str>) -> InputDouble<'_> {
+        self.debug_assert_current();
         InputDouble::new(self, label)
     }
 
@@ -151,6 +161,7 @@ impl Ui {
         L: AsRef<str>,
         T: DataTypeKind,
     {
+        self.debug_assert_current();
         InputScalar::new(self, label, value)
     }
 
@@ -167,15 +178,56 @@ impl Ui {
         L: AsRef<str>,
         T: DataTypeKind,
     {
+        self.debug_assert_current();
         InputScalarN::new(self, label, values)
     }
 
+    /// Creates an unsigned 32-bit integer input widget with `+`/`-` step buttons.
+    ///
+    /// Use [`input_scalar`](Self::input_scalar) to change the steps, format or flags.
+    /// Returns true if the value was edited.
+    #[doc(alias = "InputScalar")]
+    pub fn input_u32(&self, label: impl AsRef<str>, value: &mut u32) -> bool {
+        self.debug_assert_current();
+        self.input_scalar(label, value)
+            .step(1)
+            .step_fast(100)
+            .build()
+    }
+
+    /// Creates a signed 64-bit integer input widget with `+`/`-` step buttons.
+    ///
+    /// Use [`input_scalar`](Self::input_scalar) to change the steps, format or flags.
+    /// Returns true if the value was edited.
+    #[doc(alias = "InputScalar")]
+    pub fn input_i64(&self, label: impl AsRef<str>, value: &mut i64) -> bool {
+        self.debug_assert_current();
+        self.input_scalar(label, value)
+            .step(1)
+            .step_fast(100)
+            .build()
+    }
+
+    /// Creates an unsigned 64-bit integer input widget with `+`/`-` step buttons.
+    ///
+    /// Use [`input_scalar`](Self::input_scalar) to change the steps, format or flags.
+    /// Returns true if the value was edited.
+    #[doc(alias = "InputScalar")]
+    pub fn input_u64(&self, label: impl AsRef<str>, value: &mut u64) -> bool {
+        self.debug_assert_current();
+        self.input_scalar(label, value)
+            .step(1)
+            .step_fast(100)
+            .build()
+    }
+
     /// Widget to edit two floats
     #[doc(alias = "InputFloat2")]
     pub fn input_float2<'p, L>(&self, label: L, value: &'p mut [f32; 2]) -> InputFloat2<'_, 'p, L>
     where
         L: AsRef<str>,
     {
+        self.debug_assert_current();
         InputFloat2::new(self, label, value)
     }
 
@@ -185,6 +237,7 @@ impl Ui {
     where
         L: AsRef<str>,
     {
+        self.debug_assert_current();
         InputFloat3::new(self, label, value)
     }
 
@@ -194,6 +247,7 @@ impl Ui {
     where
         L: AsRef<str>,
     {
+        self.debug_assert_current();
         InputFloat4::new(self, label, value)
     }
 
@@ -203,6 +257,7 @@ impl Ui {
     where
         L: AsRef<str>,
     {
+        self.debug_assert_current();
         InputInt2::new(self, label, value)
     }
 
@@ -212,6 +267,7 @@ impl Ui {
     where
         L: AsRef<str>,
     {
+        self.debug_assert_current();
         InputInt3::new(self, label, value)
     }
 
@@ -221,6 +277,7 @@ impl Ui {
     where
         L: AsRef<str>,
     {
+        self.debug_assert_current();
         InputInt4::new(self, label, value)
     }
 }
@@ -508,15 +565,7 @@ where
             let user = unsafe { &mut *((*data).UserData as *mut UserData<T>) };
             match event_flag {
                 InputTextFlags::CALLBACK_RESIZE => unsafe {
-                    let requested = (*data).BufSize as usize;
-                    let s = &mut *user.container;
-                    debug_assert_eq!(s.as_ptr() as *const _, (*data).Buf);
-                    if requested > s.capacity() {
-                        let additional = requested.saturating_sub(s.len());
-                        s.reserve(additional);
-                        (*data).Buf = s.as_mut_ptr() as *mut _;
-                        (*data).BufDirty = true;
-                    }
+                    resize_string_buffer(data, &mut *user.container);
                     0
                 },
                 InputTextFlags::CALLBACK_COMPLETION => {
@@ -548,9 +597,14 @@ where
                 InputTextFlags::CALLBACK_CHAR_FILTER => {
                     let ch =
                         unsafe { std::char::from_u32((*data).EventChar as u32).unwrap_or('\0') };
-                    let new_ch = user.handler.char_filter(ch).map(|c| c as u32).unwrap_or(0);
+                    // Replacements that don't fit in an `ImWchar` are discarded
+                    let new_ch = user
+                        .handler
+                        .on_char_filter(ch)
+                        .and_then(|c| sys::ImWchar::try_from(c as u32).ok())
+                        .unwrap_or(0);
                     unsafe {
-                        (*data).EventChar = new_ch as u16;
+                        (*data).EventChar = new_ch;
                     }
                     0
                 }
@@ -745,15 +799,8 @@ impl<'ui, 'p> InputTextMultiline<'ui, 'p> {
             let event_flag = unsafe { InputTextFlags::from_bits_truncate((*data).EventFlag) };
             match event_flag {
                 InputTextFlags::CALLBACK_RESIZE => unsafe {
-                    let requested = (*data).BufSize as usize;
-                    let s = &mut *(&mut *((*data).UserData as *mut UserData)).container;
-                    debug_assert_eq!(s.as_ptr() as *const _, (*data).Buf);
-                    if requested > s.capacity() {
-                        let additional = requested.saturating_sub(s.len());
-                        s.reserve(additional);
-                        (*data).Buf = s.as_mut_ptr() as *mut _;
-                        (*data).BufDirty = true;
-                    }
+                    let user = &mut *((*data).UserData as *mut UserData);
+                    resize_string_buffer(data, &mut *user.container);
                     0
                 },
                 _ => 0,
@@ -858,15 +905,7 @@ impl<'ui, 'p, T: InputTextCallbackHandler> InputTextMultilineWithCb<'ui, 'p, T>
             let user = unsafe { &mut *((*data).UserData as *mut UserData<T>) };
             match event_flag {
                 InputTextFlags::CALLBACK_RESIZE => unsafe {
-                    let requested = (*data).BufSize as usize;
-                    let s = &mut *user.container;
-                    debug_assert_eq!(s.as_ptr() as *const _, (*data).Buf);
-                    if requested > s.capacity() {
-                        let additional = requested.saturating_sub(s.len());
-                        s.reserve(additional);
-                        (*data).Buf = s.as_mut_ptr() as *mut _;
-                        (*data).BufDirty = true;
-                    }
+                    resize_string_buffer(data, &mut *user.container);
                     0
                 },
                 InputTextFlags::CALLBACK_COMPLETION => {
@@ -898,9 +937,14 @@ impl<'ui, 'p, T: InputTextCallbackHandler> InputTextMultilineWithCb<'ui, 'p, T>
                 InputTextFlags::CALLBACK_CHAR_FILTER => {
                     let ch =
                         unsafe { std::char::from_u32((*data).EventChar as u32).unwrap_or('\0') };
-                    let new_ch = user.handler.char_filter(ch).map(|c| c as u32).unwrap_or(0);
+                    // Replacements that don't fit in an `ImWchar` are discarded
+                    let new_ch = user
+                        .handler
+                        .on_char_filter(ch)
+                        .and_then(|c| sys::ImWchar::try_from(c as u32).ok())
+                        .unwrap_or(0);
                     unsafe {
-                        (*data).EventChar = new_ch as u16;
+                        (*data).EventChar = new_ch;
                     }
                     0
                 }
@@ -938,6 +982,31 @@ impl<'ui, 'p, T: InputTextCallbackHandler> InputTextMultilineWithCb<'ui, 'p, T>
     }
 }
 
+/// Handles a `CallbackResize` event for a `String`-backed text buffer.
+///
+/// ImGui sets `BufTextLen` to the new text length *before* copying the text in, so
+/// nothing past the `String`'s own length is initialized yet and its length must not be
+/// touched here. Reserving grows the allocation (carrying over whatever bytes it held),
+/// then `Buf`/`BufSize` are pointed at the new allocation for ImGui to write into. The
+/// caller sets the real length by trimming at the NUL terminator once the widget returns.
+///
+/// # Safety
+///
+/// `data` must be the callback data of a resize event whose `Buf` is `buf`'s allocation.
+unsafe fn resize_string_buffer(data: *mut sys::ImGuiInputTextCallbackData, buf: &mut String) {
+    unsafe {
+        debug_assert_eq!(buf.as_ptr() as *const _, (*data).Buf);
+        let requested = (*data).BufSize.max(0) as usize;
+        if requested > buf.capacity() {
+            let bytes = buf.as_mut_vec();
+            bytes.reserve(requested - bytes.len());
+            (*data).Buf = bytes.as_mut_ptr() as *mut _;
+            (*data).BufSize = c_int::try_from(bytes.capacity()).unwrap_or(c_int::MAX);
+            (*data).BufDirty = true;
+        }
+    }
+}
+
 /// Builder for integer input widget
 #[derive(Debug)]
 #[must_use]
@@ -1174,10 +1243,20 @@ pub enum HistoryDirection {
 /// Each method is called *if and only if* the corresponding flag for each
 /// method is passed to ImGui in the `callback` builder.
 pub trait InputTextCallbackHandler {
+    /// Filters a typed char -- returning `None` discards it, and returning
+    /// another char substitutes it out (e.g. `Some(c.to_ascii_uppercase())`).
+    ///
+    /// To make ImGui run this callback, use [InputTextCallback::CHAR_FILTER].
+    fn on_char_filter(&mut self, c: char) -> Option<char> {
+        #[allow(deprecated)]
+        self.char_filter(c)
+    }
+
     /// Filters a char -- returning a `None` means that the char is removed,
     /// and returning another char substitutes it out.
     ///
-    /// To make ImGui run this callback, use [InputTextCallback::CHAR_FILTER].
+    /// Only called through the default [`on_char_filter`](Self::on_char_filter).
+    #[deprecated(since = "0.4.1", note = "implement `on_char_filter` instead")]
     fn char_filter(&mut self, _c: char) -> Option<char> {
         None
     }
@@ -1410,6 +1489,21 @@ extern "C" fn callback(data: *mut sys::ImGuiInputTextCallbackData) -> c_int {
     0
 }
 
+/// Default format for integer scalars edited with `CHARS_HEXADECIMAL`, as ImGui would
+/// otherwise display them in decimal.
+fn hex_format<T: DataTypeKind>(flags: InputTextFlags) -> Option<&'static str> {
+    use crate::internal::DataType;
+
+    if !flags.contains(InputTextFlags::CHARS_HEXADECIMAL) {
+        return None;
+    }
+    match T::KIND {
+        DataType::I64 | DataType::U64 => Some("%llX"),
+        DataType::F32 | DataType::F64 => None,
+        _ => Some("%X"),
+    }
+}
+
 /// Builder for an input scalar widget.
 #[must_use]
 pub struct InputScalar<'ui, 'p, T, L, F = &'static str> {
@@ -1476,14 +1570,27 @@ impl<'ui, 'p, L: AsRef<str>, T: DataTypeKind, F: AsRef<str>> InputScalar<'ui, 'p
         self
     }
 
+    /// Edits the value as hexadecimal (0123456789ABCDEFabcdef).
+    ///
+    /// Unless a [`display_format`](Self::display_format) is set, the value is also shown
+    /// in uppercase hexadecimal.
+    #[inline]
+    pub fn chars_hexadecimal(mut self, hex: bool) -> Self {
+        self.flags.set(InputTextFlags::CHARS_HEXADECIMAL, hex);
+        self
+    }
+
     /// Builds an input scalar that is bound to the given value.
     ///
     /// Returns true if the value was changed.
     pub fn build(self) -> bool {
         unsafe {
-            let (one, two) = self
-                .ui
-                .scratch_txt_with_opt(self.label, self.display_format);
+            let format = self
+                .display_format
+                .as_ref()
+                .map(AsRef::as_ref)
+                .or_else(|| hex_format::<T>(self.flags));
+            let (one, two) = self.ui.scratch_txt_with_opt(self.label, format);
 
             sys::igInputScalar(
                 one,
@@ -1570,14 +1677,27 @@ impl<'ui, 'p, L: AsRef<str>, T: DataTypeKind, F: AsRef<str>> InputScalarN<'ui, '
         self
     }
 
+    /// Edits the value as hexadecimal (0123456789ABCDEFabcdef).
+    ///
+    /// Unless a [`display_format`](Self::display_format) is set, the value is also shown
+    /// in uppercase hexadecimal.
+    #[inline]
+    pub fn chars_hexadecimal(mut self, hex: bool) -> Self {
+        self.flags.set(InputTextFlags::CHARS_HEXADECIMAL, hex);
+        self
+    }
+
     /// Builds a horizontal array of multiple input scalars attached to the given slice.
     ///
     /// Returns true if any value was changed.
     pub fn build(self) -> bool {
         unsafe {
-            let (one, two) = self
-                .ui
-                .scratch_txt_with_opt(self.label, self.display_format);
+            let format = self
+                .display_format
+                .as_ref()
+                .map(AsRef::as_ref)
+                .or_else(|| hex_format::<T>(self.flags));
+            let (one, two) = self.ui.scratch_txt_with_opt(self.label, format);
 
             sys::igInputScalarN(
                 one,
@@ -0,0 +1,256 @@
+//! Knobs
+//!
+//! Rotary controls for audio and control-panel style UIs. Dear ImGui has no
+//! built-in knob; this widget is drawn with the window draw list and driven by
+//! vertical mouse drags.
+//!
+use super::{text_width, visible_label};
+use crate::StyleColor;
+use crate::sys;
+use crate::ui::Ui;
+use std::f32::consts::PI;
+
+/// Angle of the minimum value, measured clockwise from +X (bottom-left of the dial)
+const ANGLE_MIN: f32 = PI * 0.75;
+/// Angle of the maximum value (bottom-right of the dial), giving a 270° sweep
+const ANGLE_MAX: f32 = PI * 2.25;
+/// Vertical drag distance, in pixels, that sweeps the full range
+const DRAG_RANGE_PX: f32 = 200.0;
+
+/// # Knob Widgets
+impl Ui {
+    /// Creates a rotary knob bound to `value` in `[min, max]`.
+    ///
+    /// Dragging up increases the value, dragging down decreases it. Call
+    /// [`build`](Knob::build) to draw the widget.
+    pub fn knob<'v, Label: AsRef<str>>(
+        &self,
+        label: Label,
+        value: &'v mut f32,
+        min: f32,
+        max: f32,
+    ) -> Knob<'_, 'v, Label> {
+        self.debug_assert_current();
+        Knob::new(self, label, value, min, max)
+    }
+}
+
+/// Builder for a knob widget.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dear_imgui_rs::*;
+/// # let mut ctx = Context::create();
+/// # let ui = ctx.frame();
+/// let mut gain = 0.5f32;
+/// if ui.knob("Gain", &mut gain, 0.0, 1.0)
+///     .size(48.0)
+///     .format("%.2f")
+///     .build()
+/// {
+///     // gain changed
+/// }
+///
+/// let mut mode = 0.0f32;
+/// ui.knob("Mode", &mut mode, 0.0, 4.0).steps(4).format("%.0f").build();
+/// ```
+#[derive(Debug)]
+#[must_use]
+pub struct Knob<'ui, 'v, Label> {
+    ui: &'ui Ui,
+    label: Label,
+    value: &'v mut f32,
+    min: f32,
+    max: f32,
+    size: Option<f32>,
+    steps: Option<u32>,
+    format: Option<String>,
+}
+
+impl<'ui, 'v, Label: AsRef<str>> Knob<'ui, 'v, Label> {
+    /// Creates a knob builder bound to `value` in `[min, max]`
+    pub fn new(ui: &'ui Ui, label: Label, value: &'v mut f32, min: f32, max: f32) -> Self {
+        Self {
+            ui,
+            label,
+            value,
+            min,
+            max,
+            size: None,
+            steps: None,
+            format: None,
+        }
+    }
+
+    /// Sets the diameter of the dial in pixels.
+    ///
+    /// Defaults to twice the frame height.
+    pub fn size(mut self, diameter: f32) -> Self {
+        self.size = Some(diameter);
+        self
+    }
+
+    /// Snaps the value to `steps` equal intervals of the range (a detented knob).
+    ///
+    /// `0` disables snapping.
+    pub fn steps(mut self, steps: u32) -> Self {
+        self.steps = (steps > 0).then_some(steps);
+        self
+    }
+
+    /// Sets the display format of the value using *a C-style printf string*.
+    ///
+    /// Defaults to `"%.3f"`.
+    pub fn format(mut self, format: impl Into<String>) -> Self {
+        self.format = Some(format.into());
+        self
+    }
+
+    /// Draws the knob.
+    ///
+    /// Returns true if the value was changed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a [`DrawListMut`](crate::DrawListMut) for the window draw list is alive.
+    pub fn build(self) -> bool {
+        let ui = self.ui;
+        let diameter = self.size.unwrap_or_else(|| ui.frame_height() * 2.0);
+        let radius = diameter * 0.5;
+        let line_height = ui.text_line_height();
+        let spacing = unsafe { (*sys::igGetStyle()).ItemInnerSpacing.y };
+
+        let label = self.label.as_ref();
+        let value_text = self.format_value();
+        let label_width = text_width(label, true);
+        let value_width = text_width(&value_text, false);
+        let width = diameter.max(label_width).max(value_width);
+
+        let [x, y] = ui.cursor_screen_pos();
+        ui.invisible_button(
+            label,
+            [
+                width,
+                line_height + spacing + diameter + spacing + line_height,
+            ],
+        );
+        let id = unsafe { sys::igGetItemID() };
+        let hovered = ui.is_item_hovered();
+        let active = ui.is_item_active();
+
+        let mut changed = false;
+        if active && self.max != self.min {
+            let storage = unsafe { sys::igGetStateStorage() };
+            // Accumulate the unsnapped value so small drags add up across detents
+            let raw = if ui.is_item_activated() {
+                *self.value
+            } else {
+                unsafe { sys::ImGuiStorage_GetFloat(storage, id, *self.value) }
+            };
+            let delta_y = ui.io().mouse_delta()[1];
+            let raw = (raw - delta_y * (self.max - self.min) / DRAG_RANGE_PX)
+                .clamp(self.min.min(self.max), self.min.max(self.max));
+            unsafe { sys::ImGuiStorage_SetFloat(storage, id, raw) };
+
+            let new_value = self.snap(raw);
+            if new_value != *self.value {
+                *self.value = new_value;
+                changed = true;
+            }
+        }
+        if active || hovered {
+            ui.set_mouse_cursor(Some(crate::input::MouseCursor::ResizeNS));
+        }
+
+        let t = if self.max != self.min {
+            ((*self.value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+        let angle = ANGLE_MIN + (ANGLE_MAX - ANGLE_MIN) * t;
+        let center = [x + width * 0.5, y + line_height + spacing + radius];
+        let (sin, cos) = angle.sin_cos();
+
+        let draw_list = crate::DrawListMut::window_for_widget(ui, "knob");
+        let text_color = ui.get_color_u32(StyleColor::Text);
+        draw_list.add_text(
+            [center[0] - label_width * 0.5, y],
+            text_color,
+            visible_label(label),
+        );
+
+        let body_color = ui.get_color_u32(if active {
+            StyleColor::FrameBgActive
+        } else if hovered {
+            StyleColor::FrameBgHovered
+        } else {
+            StyleColor::FrameBg
+        });
+        draw_list
+            .add_circle(center, radius, body_color)
+            .filled(true)
+            .build();
+
+        if let Some(steps) = self.steps {
+            let tick_color = ui.get_color_u32(StyleColor::TextDisabled);
+            for i in 0..=steps {
+                let a = ANGLE_MIN + (ANGLE_MAX - ANGLE_MIN) * (i as f32 / steps as f32);
+                let (s, c) = a.sin_cos();
+                draw_list
+                    .add_line(
+                        [center[0] + c * radius * 0.8, center[1] + s * radius * 0.8],
+                        [center[0] + c * radius, center[1] + s * radius],
+                        tick_color,
+                    )
+                    .build();
+            }
+        }
+
+        let indicator_color = ui.get_color_u32(if active {
+            StyleColor::SliderGrabActive
+        } else {
+            StyleColor::SliderGrab
+        });
+        draw_list
+            .add_line(
+                [
+                    center[0] + cos * radius * 0.3,
+                    center[1] + sin * radius * 0.3,
+                ],
+                [
+                    center[0] + cos * radius * 0.75,
+                    center[1] + sin * radius * 0.75,
+                ],
+                indicator_color,
+            )
+            .thickness((radius * 0.1).max(2.0))
+            .build();
+
+        draw_list.add_text(
+            [center[0] - value_width * 0.5, center[1] + radius + spacing],
+            text_color,
+            &value_text,
+        );
+
+        if changed {
+            unsafe { sys::igMarkItemEdited(id) };
+        }
+        changed
+    }
+
+    /// Snaps a value to the nearest detent when `steps` is set
+    fn snap(&self, value: f32) -> f32 {
+        match self.steps {
+            Some(steps) => {
+                let step = (self.max - self.min) / steps as f32;
+                self.min + ((value - self.min) / step).round() * step
+            }
+            None => value,
+        }
+    }
+
+    fn format_value(&self) -> String {
+        crate::internal::format_value(*self.value, self.format.as_deref().unwrap_or("%.3f"))
+    }
+}
@@ -0,0 +1,358 @@
+//! Markdown-lite text
+//!
+//! Renders a small Markdown subset for help and about panels:
+//!
+//! - `#`, `##`, `###` headings (drawn larger using the current font)
+//! - `**bold**` spans (using an optional bold font)
+//! - `-` / `*` bullet lines
+//! - `[text](url)` links, reported through a callback when clicked
+//!
+//! Everything else is shown as plain text, wrapped at word boundaries to the
+//! available width. Consecutive lines are joined into one paragraph, and a line right
+//! after a bullet continues that item; blank lines separate paragraphs. Wrapped bullet
+//! text stays indented past the bullet.
+//!
+use crate::fonts::Font;
+use crate::input::MouseCursor;
+use crate::style::StyleColor;
+use crate::ui::Ui;
+
+/// Link-click callback
+type LinkHandler<'a> = Box<dyn FnMut(&str) + 'a>;
+
+/// Font size multipliers for `#`, `##` and `###` headings
+const HEADING_SCALES: [f32; 3] = [1.6, 1.35, 1.15];
+
+/// # Markdown
+impl Ui {
+    /// Creates a markdown-lite text renderer for `text`.
+    ///
+    /// Call [`build`](Markdown::build) to draw it; see the [module docs](self) for the
+    /// supported syntax.
+    pub fn markdown<'a>(&self, text: &'a str) -> Markdown<'_, 'a> {
+        self.debug_assert_current();
+        Markdown::new(self, text)
+    }
+}
+
+/// Builder for markdown-lite text.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dear_imgui_rs::*;
+/// # let mut ctx = Context::create();
+/// # let ui = ctx.frame();
+/// ui.markdown("# About\nBuilt with **Dear ImGui**.\n- See [the repo](https://github.com/ocornut/imgui)")
+///     .on_link(|url| println!("open {url}"))
+///     .build();
+/// ```
+#[must_use]
+pub struct Markdown<'ui, 'a> {
+    ui: &'ui Ui,
+    text: &'a str,
+    bold_font: Option<&'a Font>,
+    on_link: Option<LinkHandler<'a>>,
+}
+
+impl<'ui, 'a> Markdown<'ui, 'a> {
+    /// Creates a markdown-lite renderer for `text`
+    pub fn new(ui: &'ui Ui, text: &'a str) -> Self {
+        Self {
+            ui,
+            text,
+            bold_font: None,
+            on_link: None,
+        }
+    }
+
+    /// Font used for `**bold**` spans and headings.
+    ///
+    /// Without it, bold spans use the current font.
+    pub fn bold_font(mut self, font: &'a Font) -> Self {
+        self.bold_font = Some(font);
+        self
+    }
+
+    /// Callback invoked with the URL of a link when it is clicked
+    pub fn on_link(mut self, on_link: impl FnMut(&str) + 'a) -> Self {
+        self.on_link = Some(Box::new(on_link));
+        self
+    }
+
+    /// Draws the text.
+    ///
+    /// Returns the URL of the link clicked this frame, if any.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a [`DrawListMut`](crate::DrawListMut) for the window draw list is alive
+    /// while a link is hovered.
+    pub fn build(mut self) -> Option<&'a str> {
+        let ui = self.ui;
+        let mut clicked = None;
+        // Paragraph or bullet item that a following plain line continues
+        let mut open: Option<Flow> = None;
+        for line in self.text.lines() {
+            let block = parse_block(line);
+            match block {
+                Block::Blank => {
+                    open = None;
+                    ui.spacing();
+                }
+                Block::Heading { level, text } => {
+                    open = None;
+                    let size = ui.current_font_size() * HEADING_SCALES[level - 1];
+                    ui.with_font_and_size(self.bold_font, size, || {
+                        let mut flow = Flow::new(ui);
+                        clicked = clicked.or(self.render_spans(&mut flow, &parse_spans(text)));
+                    });
+                    if level == 1 {
+                        ui.separator();
+                    }
+                }
+                Block::Bullet(text) => {
+                    ui.bullet();
+                    ui.same_line();
+                    // Wrapped lines of the item line up with its first word
+                    let flow = open.insert(Flow::new(ui));
+                    clicked = clicked.or(self.render_spans(flow, &parse_spans(text)));
+                }
+                Block::Paragraph(text) => {
+                    let flow = open.get_or_insert_with(|| Flow::new(ui));
+                    clicked = clicked.or(self.render_spans(flow, &parse_spans(text)));
+                }
+            }
+        }
+        if let (Some(url), Some(on_link)) = (clicked, self.on_link.as_mut()) {
+            on_link(url);
+        }
+        clicked
+    }
+
+    /// Lays out spans word by word after the words already in `flow`, wrapping at its
+    /// right edge
+    fn render_spans(&self, flow: &mut Flow, spans: &[Span<'a>]) -> Option<&'a str> {
+        let ui = self.ui;
+        let mut clicked = None;
+        // Source lines of one paragraph are joined by a space
+        let mut gap_pending = !flow.first;
+        for span in spans {
+            let (text, bold, url) = match *span {
+                Span::Text(text) => (text, false, None),
+                Span::Bold(text) => (text, true, None),
+                Span::Link { text, url } => (text, false, Some(url)),
+            };
+            let font = if bold { self.bold_font } else { None };
+            ui.with_font_and_size(font, 0.0, || {
+                for word in text.split_inclusive(' ') {
+                    let width = super::text_width(word, false);
+                    let gap = if gap_pending {
+                        super::text_width(" ", false)
+                    } else {
+                        0.0
+                    };
+                    gap_pending = false;
+                    if !flow.first {
+                        if flow.used > 0.0 && flow.used + gap + width > flow.width {
+                            flow.used = 0.0;
+                            ui.set_cursor_screen_pos([flow.left, ui.cursor_screen_pos()[1]]);
+                        } else {
+                            ui.same_line_with_spacing(0.0, gap);
+                            flow.used += gap;
+                        }
+                    }
+                    flow.first = false;
+                    flow.used += width;
+
+                    match url {
+                        Some(url) => {
+                            ui.text_colored(ui.style_color(StyleColor::TextLink), word);
+                            if ui.is_item_hovered() {
+                                ui.set_mouse_cursor(Some(MouseCursor::Hand));
+                                let (min, max) = ui.item_rect();
+                                crate::DrawListMut::window_for_widget(ui, "markdown")
+                                    .add_line(
+                                        [min[0], max[1]],
+                                        [max[0], max[1]],
+                                        ui.get_color_u32(StyleColor::TextLink),
+                                    )
+                                    .build();
+                            }
+                            if ui.is_item_clicked() {
+                                clicked = Some(url);
+                            }
+                        }
+                        None => ui.text(word),
+                    }
+                }
+            });
+        }
+        clicked
+    }
+}
+
+/// Word wrapping state of a paragraph, carried across its source lines
+struct Flow {
+    /// Screen x at which wrapped lines start
+    left: f32,
+    /// Width available to each line
+    width: f32,
+    /// Width taken on the current line
+    used: f32,
+    /// No word has been laid out yet
+    first: bool,
+}
+
+impl Flow {
+    /// Starts a paragraph at the cursor
+    fn new(ui: &Ui) -> Self {
+        Self {
+            left: ui.cursor_screen_pos()[0],
+            width: ui.content_region_avail()[0],
+            used: 0.0,
+            first: true,
+        }
+    }
+}
+
+/// A line of markdown-lite input
+#[derive(Debug, PartialEq)]
+enum Block<'a> {
+    Blank,
+    Heading { level: usize, text: &'a str },
+    Bullet(&'a str),
+    Paragraph(&'a str),
+}
+
+/// An inline run of text within a block
+#[derive(Debug, PartialEq)]
+enum Span<'a> {
+    Text(&'a str),
+    Bold(&'a str),
+    Link { text: &'a str, url: &'a str },
+}
+
+fn parse_block(line: &str) -> Block<'_> {
+    let line = line.trim();
+    if line.is_empty() {
+        return Block::Blank;
+    }
+    let hashes = line.bytes().take_while(|&b| b == b'#').count();
+    if (1..=HEADING_SCALES.len()).contains(&hashes)
+        && let Some(text) = line[hashes..].strip_prefix(' ')
+    {
+        return Block::Heading {
+            level: hashes,
+            text: text.trim_start(),
+        };
+    }
+    if let Some(text) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+        return Block::Bullet(text.trim_start());
+    }
+    Block::Paragraph(line)
+}
+
+fn parse_spans(text: &str) -> Vec<Span<'_>> {
+    let mut spans = Vec::new();
+    let mut rest = text;
+    let mut pos = 0;
+    while pos < rest.len() {
+        let tail = &rest[pos..];
+        let parsed = if let Some(inner) = tail.strip_prefix("**") {
+            inner
+                .find("**")
+                .filter(|&end| end > 0)
+                .map(|end| (Span::Bold(&inner[..end]), 2 + end + 2))
+        } else if let Some(inner) = tail.strip_prefix('[') {
+            inner.find("](").and_then(|label_end| {
+                let after = &inner[label_end + 2..];
+                after.find(')').map(|url_end| {
+                    let span = Span::Link {
+                        text: &inner[..label_end],
+                        url: &after[..url_end],
+                    };
+                    (span, 1 + label_end + 2 + url_end + 1)
+                })
+            })
+        } else {
+            None
+        };
+
+        match parsed {
+            Some((span, len)) => {
+                if pos > 0 {
+                    spans.push(Span::Text(&rest[..pos]));
+                }
+                spans.push(span);
+                rest = &rest[pos + len..];
+                pos = 0;
+            }
+            None => pos += tail.chars().next().map_or(1, char::len_utf8),
+        }
+    }
+    if !rest.is_empty() {
+        spans.push(Span::Text(rest));
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blocks() {
+        assert_eq!(parse_block("   "), Block::Blank);
+        assert_eq!(
+            parse_block("## Title"),
+            Block::Heading {
+                level: 2,
+                text: "Title"
+            }
+        );
+        assert_eq!(
+            parse_block("#### Too deep"),
+            Block::Paragraph("#### Too deep")
+        );
+        assert_eq!(parse_block("#hashtag"), Block::Paragraph("#hashtag"));
+        assert_eq!(parse_block("- item"), Block::Bullet("item"));
+        assert_eq!(parse_block("  * item"), Block::Bullet("item"));
+        assert_eq!(
+            parse_block("-not a bullet"),
+            Block::Paragraph("-not a bullet")
+        );
+    }
+
+    #[test]
+    fn inline_spans() {
+        assert_eq!(
+            parse_spans("a **b** c"),
+            vec![Span::Text("a "), Span::Bold("b"), Span::Text(" c")]
+        );
+        assert_eq!(
+            parse_spans("see [docs](https://x.y/z) now"),
+            vec![
+                Span::Text("see "),
+                Span::Link {
+                    text: "docs",
+                    url: "https://x.y/z"
+                },
+                Span::Text(" now")
+            ]
+        );
+        assert_eq!(parse_spans("**[a](b)**"), vec![Span::Bold("[a](b)")]);
+    }
+
+    #[test]
+    fn unterminated_markup_is_plain_text() {
+        assert_eq!(parse_spans("**open"), vec![Span::Text("**open")]);
+        assert_eq!(parse_spans("[x](y"), vec![Span::Text("[x](y")]);
+        assert_eq!(parse_spans("****"), vec![Span::Text("****")]);
+        assert_eq!(
+            parse_spans("é **ü**"),
+            vec![Span::Text("é "), Span::Bold("ü")]
+        );
+        assert_eq!(parse_spans(""), vec![]);
+    }
+}
@@ -17,6 +17,7 @@ impl Ui {
     #[must_use]
     #[doc(alias = "BeginMainMenuBar")]
     pub fn begin_main_menu_bar(&self) -> Option<MainMenuBarToken<'_>> {
+        self.debug_assert_current();
         if unsafe { sys::igBeginMainMenuBar() } {
             Some(MainMenuBarToken::new(self))
         } else {
@@ -33,6 +34,7 @@ impl Ui {
     #[must_use]
     #[doc(alias = "BeginMenuBar")]
     pub fn begin_menu_bar(&self) -> Option<MenuBarToken<'_>> {
+        self.debug_assert_current();
         if unsafe { sys::igBeginMenuBar() } {
             Some(MenuBarToken::new(self))
         } else {
@@ -49,6 +51,7 @@ impl Ui {
     #[must_use]
     #[doc(alias = "BeginMenu")]
     pub fn begin_menu(&self, label: impl AsRef<str>) -> Option<MenuToken<'_>> {
+        self.debug_assert_current();
         self.begin_menu_with_enabled(label, true)
     }
 
@@ -65,6 +68,7 @@ impl Ui {
         label: impl AsRef<str>,
         enabled: bool,
     ) -> Option<MenuToken<'_>> {
+        self.debug_assert_current();
         let label_ptr = self.scratch_txt(label);
         if unsafe { sys::igBeginMenu(label_ptr, enabled) } {
             Some(MenuToken::new(self))
@@ -81,6 +85,7 @@ impl Ui {
     /// with `enabled` set to `true`.
     #[doc(alias = "BeginMenu")]
     pub fn menu<F: FnOnce()>(&self, label: impl AsRef<str>, f: F) {
+        self.debug_assert_current();
         self.menu_with_enabled(label, true, f);
     }
 
@@ -89,6 +94,7 @@ impl Ui {
     /// Note: the closure is not called if the menu is not visible.
     #[doc(alias = "BeginMenu")]
     pub fn menu_with_enabled<F: FnOnce()>(&self, label: impl AsRef<str>, enabled: bool, f: F) {
+        self.debug_assert_current();
         if let Some(_menu) = self.begin_menu_with_enabled(label, enabled) {
             f();
         }
@@ -99,6 +105,7 @@ impl Ui {
     /// Returns true if the menu item is activated.
     #[doc(alias = "MenuItem")]
     pub fn menu_item(&self, label: impl AsRef<str>) -> bool {
+        self.debug_assert_current();
         let label_ptr = self.scratch_txt(label);
         unsafe { sys::igMenuItemEx(label_ptr, std::ptr::null(), std::ptr::null(), false, true) }
     }
@@ -112,6 +119,7 @@ impl Ui {
         label: impl AsRef<str>,
         shortcut: impl AsRef<str>,
     ) -> bool {
+        self.debug_assert_current();
         let label_ptr = self.scratch_txt(label);
         let shortcut_ptr = self.scratch_txt(shortcut);
         unsafe { sys::igMenuItemEx(label_ptr, std::ptr::null(), shortcut_ptr, false, true) }
@@ -127,6 +135,7 @@ impl Ui {
         selected: bool,
         enabled: bool,
     ) -> bool {
+        self.debug_assert_current();
         let label_ptr = self.scratch_txt(label);
         let shortcut_ptr = shortcut
             .as_ref()
@@ -145,6 +154,7 @@ impl Ui {
         selected: &mut bool,
         enabled: bool,
     ) -> bool {
+        self.debug_assert_current();
         let label_ptr = self.scratch_txt(label);
         let shortcut_ptr = shortcut
             .as_ref()
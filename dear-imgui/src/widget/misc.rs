@@ -1,7 +1,7 @@
 //! Miscellaneous widgets
 //!
 //! Small convenience widgets that don’t fit elsewhere (e.g. bullets, help
-//! markers). See functions on `Ui` for details.
+//! markers, toggle switches). See functions on `Ui` for details.
 //!
 #![allow(
     clippy::cast_possible_truncation,
@@ -34,6 +34,7 @@ impl Ui {
     /// Creates a bullet point
     #[doc(alias = "Bullet")]
     pub fn bullet(&self) {
+        self.debug_assert_current();
         unsafe {
             sys::igBullet();
         }
@@ -42,6 +43,7 @@ impl Ui {
     /// Creates a bullet point with text
     #[doc(alias = "BulletText")]
     pub fn bullet_text(&self, text: impl AsRef<str>) {
+        self.debug_assert_current();
         let text_ptr = self.scratch_txt(text);
         unsafe {
             sys::igBulletText(text_ptr);
@@ -53,6 +55,7 @@ impl Ui {
     /// Creates a small button
     #[doc(alias = "SmallButton")]
     pub fn small_button(&self, label: impl AsRef<str>) -> bool {
+        self.debug_assert_current();
         let label_ptr = self.scratch_txt(label);
         unsafe { sys::igSmallButton(label_ptr) }
     }
@@ -60,6 +63,7 @@ impl Ui {
     /// Creates an invisible button
     #[doc(alias = "InvisibleButton")]
     pub fn invisible_button(&self, str_id: impl AsRef<str>, size: impl Into<[f32; 2]>) -> bool {
+        self.debug_assert_current();
         self.invisible_button_flags(str_id, size, crate::widget::ButtonFlags::NONE)
     }
 
@@ -71,6 +75,7 @@ impl Ui {
         size: impl Into<[f32; 2]>,
         flags: crate::widget::ButtonFlags,
     ) -> bool {
+        self.debug_assert_current();
         let id_ptr = self.scratch_txt(str_id);
         let size_vec: sys::ImVec2 = size.into().into();
         unsafe { sys::igInvisibleButton(id_ptr, size_vec, flags.bits()) }
@@ -79,11 +84,114 @@ impl Ui {
     /// Creates an arrow button
     #[doc(alias = "ArrowButton")]
     pub fn arrow_button(&self, str_id: impl AsRef<str>, dir: crate::Direction) -> bool {
+        self.debug_assert_current();
         let id_ptr = self.scratch_txt(str_id);
         unsafe { sys::igArrowButton(id_ptr, dir as i32) }
     }
 }
 
+// ============================================================================
+// Toggle switch
+// ============================================================================
+
+/// Duration of the toggle knob animation, in seconds
+const TOGGLE_ANIM_SECS: f32 = 0.12;
+
+impl Ui {
+    /// Creates an animated on/off switch, an alternative to [`checkbox`](Self::checkbox).
+    ///
+    /// Clicking the switch flips `value` and slides the knob over a short animation.
+    /// Returns true if the value was changed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a [`DrawListMut`](crate::DrawListMut) for the window draw list is alive.
+    ///
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// let mut enabled = true;
+    /// if ui.toggle("Enabled", &mut enabled) {
+    ///     // enabled changed
+    /// }
+    /// ```
+    pub fn toggle(&self, label: impl AsRef<str>, value: &mut bool) -> bool {
+        self.debug_assert_current();
+        let label = label.as_ref();
+        let height = self.frame_height();
+        let track_width = height * 1.75;
+        let radius = height * 0.5;
+        let visible = super::visible_label(label);
+        let label_width = super::text_width(visible, false);
+        let (inner_spacing, frame_padding_y) = unsafe {
+            let style = &*sys::igGetStyle();
+            (style.ItemInnerSpacing.x, style.FramePadding.y)
+        };
+        let width = if label_width > 0.0 {
+            track_width + inner_spacing + label_width
+        } else {
+            track_width
+        };
+
+        let [x, y] = self.cursor_screen_pos();
+        let clicked = self.invisible_button(label, [width, height]);
+        let id = unsafe { sys::igGetItemID() };
+        let hovered = self.is_item_hovered();
+        let storage = unsafe { sys::igGetStateStorage() };
+        let now = self.time() as f32;
+        if clicked {
+            *value = !*value;
+            unsafe {
+                sys::ImGuiStorage_SetFloat(storage, id, now);
+                sys::igMarkItemEdited(id);
+            }
+        }
+
+        // Knob position in 0..=1, eased towards the current state since the last toggle
+        let toggled_at = unsafe { sys::ImGuiStorage_GetFloat(storage, id, f32::MIN) };
+        let progress = ((now - toggled_at) / TOGGLE_ANIM_SECS).clamp(0.0, 1.0);
+        let t = if *value { progress } else { 1.0 - progress };
+
+        let off = self.style_color(if hovered {
+            crate::StyleColor::FrameBgHovered
+        } else {
+            crate::StyleColor::FrameBg
+        });
+        let on = self.style_color(if hovered {
+            crate::StyleColor::ButtonHovered
+        } else {
+            crate::StyleColor::Button
+        });
+        let track_color: [f32; 4] = std::array::from_fn(|i| off[i] + (on[i] - off[i]) * t);
+        let track_color = self.get_color_u32_rgba(track_color);
+
+        let draw_list = crate::DrawListMut::window_for_widget(self, "toggle");
+        draw_list
+            .add_rect([x, y], [x + track_width, y + height], track_color)
+            .filled(true)
+            .rounding(radius)
+            .build();
+        let knob_x = x + radius + (track_width - height) * t;
+        draw_list
+            .add_circle(
+                [knob_x, y + radius],
+                radius - 2.0,
+                self.get_color_u32(crate::StyleColor::Text),
+            )
+            .filled(true)
+            .build();
+        if label_width > 0.0 {
+            draw_list.add_text(
+                [x + track_width + inner_spacing, y + frame_padding_y],
+                self.get_color_u32(crate::StyleColor::Text),
+                visible,
+            );
+        }
+        clicked
+    }
+}
+
 // ============================================================================
 // Disabled scope (RAII)
 // ============================================================================
@@ -118,6 +226,7 @@ impl Ui {
     /// until the returned token is dropped.
     #[doc(alias = "BeginDisabled")]
     pub fn begin_disabled(&self) -> DisabledToken<'_> {
+        self.debug_assert_current();
         unsafe { sys::igBeginDisabled(true) }
         DisabledToken::new(self)
     }
@@ -128,6 +237,7 @@ impl Ui {
     /// token being dropped to correctly balance the internal stack.
     #[doc(alias = "BeginDisabled")]
     pub fn begin_disabled_with_cond(&self, disabled: bool) -> DisabledToken<'_> {
+        self.debug_assert_current();
         unsafe { sys::igBeginDisabled(disabled) }
         DisabledToken::new(self)
     }
@@ -143,12 +253,14 @@ impl Ui {
     /// Internally uses `PushItemFlag(ImGuiItemFlags_ButtonRepeat, repeat)`.
     #[doc(alias = "PushButtonRepeat")]
     pub fn push_button_repeat(&self, repeat: bool) {
+        self.debug_assert_current();
         unsafe { sys::igPushItemFlag(sys::ImGuiItemFlags_ButtonRepeat as i32, repeat) }
     }
 
     /// Pop the button repeat item flag.
     #[doc(alias = "PopButtonRepeat")]
     pub fn pop_button_repeat(&self) {
+        self.debug_assert_current();
         unsafe { sys::igPopItemFlag() }
     }
 }
@@ -161,6 +273,7 @@ impl Ui {
     /// Set the key owner for the last item, without flags.
     #[doc(alias = "SetItemKeyOwner")]
     pub fn set_item_key_owner(&self, key: crate::input::Key) {
+        self.debug_assert_current();
         let k: sys::ImGuiKey = key as sys::ImGuiKey;
         unsafe { sys::igSetItemKeyOwner_Nil(k) }
     }
@@ -173,6 +286,7 @@ impl Ui {
         key: crate::input::Key,
         flags: sys::ImGuiInputFlags,
     ) {
+        self.debug_assert_current();
         let k: sys::ImGuiKey = key as sys::ImGuiKey;
         unsafe { sys::igSetItemKeyOwner_InputFlags(k, flags) }
     }
@@ -22,8 +22,8 @@
 //! ```
 //!
 //! Submodules group related widgets: `button`, `color`, `combo`, `drag`,
-//! `image`, `input`, `list_box`, `menu`, `misc`, `plot`, `popup`, `progress`,
-//! `selectable`, `slider`, `tab`, `table`, `text`, `tooltip`, `tree`.
+//! `image`, `input`, `knob`, `list_box`, `markdown`, `menu`, `misc`, `multi_select`, `plot`, `popup`, `progress`,
+//! `selectable`, `slider`, `spinner`, `tab`, `table`, `text`, `tooltip`, `tree`.
 //!
 use crate::sys;
 
@@ -33,14 +33,18 @@ pub mod combo;
 pub mod drag;
 pub mod image;
 pub mod input;
+pub mod knob;
 pub mod list_box;
+pub mod markdown;
 pub mod menu;
 pub mod misc;
+pub mod multi_select;
 pub mod plot;
 pub mod popup;
 pub mod progress;
 pub mod selectable;
 pub mod slider;
+pub mod spinner;
 pub mod tab;
 pub mod table;
 pub mod text;
@@ -58,9 +62,12 @@ pub use self::combo::*;
 pub use self::drag::*;
 pub use self::image::*;
 pub use self::input::*;
+pub use self::knob::*;
 pub use self::list_box::*;
+pub use self::markdown::*;
 pub use self::menu::*;
 pub use self::misc::*;
+pub use self::multi_select::*;
 pub use self::plot::*;
 pub use self::popup::*;
 pub use self::progress::*;
@@ -226,6 +233,12 @@ bitflags::bitflags! {
     pub struct TableColumnFlags: i32 {
         /// No flags
         const NONE = 0;
+        /// Overriding/master disable flag: hide column, won't show in context menu
+        const DISABLED = sys::ImGuiTableColumnFlags_Disabled as i32;
+        /// Default as a hidden/disabled column
+        const DEFAULT_HIDE = sys::ImGuiTableColumnFlags_DefaultHide as i32;
+        /// Default as a sorting column
+        const DEFAULT_SORT = sys::ImGuiTableColumnFlags_DefaultSort as i32;
         /// Overriding width becomes fixed width
         const WIDTH_FIXED = sys::ImGuiTableColumnFlags_WidthFixed as i32;
         /// Overriding width becomes weight
@@ -268,3 +281,22 @@ bitflags::bitflags! {
         const IS_HOVERED = sys::ImGuiTableColumnFlags_IsHovered as i32;
     }
 }
+
+/// Returns the part of a label before any `##` ID suffix
+pub(crate) fn visible_label(label: &str) -> &str {
+    label.split("##").next().unwrap_or(label)
+}
+
+/// Width of `text` in the current font, optionally ignoring a `##` ID suffix
+pub(crate) fn text_width(text: &str, hide_after_double_hash: bool) -> f32 {
+    let text = if hide_after_double_hash {
+        visible_label(text)
+    } else {
+        text
+    };
+    let start = text.as_ptr() as *const std::os::raw::c_char;
+    let end = unsafe { start.add(text.len()) };
+    let mut out = sys::ImVec2 { x: 0.0, y: 0.0 };
+    unsafe { sys::igCalcTextSize(&mut out, start, end, false, -1.0) };
+    out.x
+}
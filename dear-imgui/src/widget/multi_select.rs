@@ -0,0 +1,350 @@
+//! Multi-selection
+//!
+//! Range, ctrl-click and shift-click selection across the [`Selectable`](crate::Selectable)
+//! (or tree node) items submitted between [`Ui::begin_multi_select`] and
+//! [`MultiSelectToken::end`]. Dear ImGui does not store the selection: it emits
+//! [`SelectionRequest`]s which the application applies to its own storage, or to a
+//! [`SelectionStorage`].
+//!
+//! ```no_run
+//! # use dear_imgui_rs::*;
+//! # let mut ctx = Context::create();
+//! # let ui = ctx.frame();
+//! let items = ["alpha", "beta", "gamma"];
+//! let mut selection = SelectionStorage::new();
+//!
+//! let ms = ui.begin_multi_select(MultiSelectFlags::NONE, selection.len(), items.len());
+//! selection.apply_requests(&ms.io());
+//! for (index, item) in items.iter().enumerate() {
+//!     ui.set_next_item_selection_user_data(index as i64);
+//!     ui.selectable_config(item)
+//!         .selected(selection.contains(index as u32))
+//!         .build();
+//! }
+//! selection.apply_requests(&ms.end());
+//! ```
+//!
+#![allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_sign_loss,
+    clippy::as_conversions
+)]
+use std::marker::PhantomData;
+
+use crate::Ui;
+use crate::sys;
+
+bitflags::bitflags! {
+    /// Flags for [`Ui::begin_multi_select`]
+    #[repr(transparent)]
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct MultiSelectFlags: i32 {
+        /// No flags
+        const NONE = sys::ImGuiMultiSelectFlags_None as i32;
+        /// Disable multi-selection, keeping the keyboard/mouse handling of this API
+        const SINGLE_SELECT = sys::ImGuiMultiSelectFlags_SingleSelect as i32;
+        /// Disable CTRL+A shortcut to select all
+        const NO_SELECT_ALL = sys::ImGuiMultiSelectFlags_NoSelectAll as i32;
+        /// Disable Shift+selection mouse/keyboard support
+        const NO_RANGE_SELECT = sys::ImGuiMultiSelectFlags_NoRangeSelect as i32;
+        /// Disable selecting items when navigating
+        const NO_AUTO_SELECT = sys::ImGuiMultiSelectFlags_NoAutoSelect as i32;
+        /// Disable clearing the selection when navigating or selecting another item
+        const NO_AUTO_CLEAR = sys::ImGuiMultiSelectFlags_NoAutoClear as i32;
+        /// Disable clearing the selection when clicking/selecting an already selected item
+        const NO_AUTO_CLEAR_ON_RESELECT = sys::ImGuiMultiSelectFlags_NoAutoClearOnReselect as i32;
+        /// Enable box-selection with same-width, contiguous items (e.g. a list)
+        const BOX_SELECT_1D = sys::ImGuiMultiSelectFlags_BoxSelect1d as i32;
+        /// Enable box-selection with varying-width or non-contiguous items (e.g. a grid)
+        const BOX_SELECT_2D = sys::ImGuiMultiSelectFlags_BoxSelect2d as i32;
+        /// Disable scrolling when box-selecting near the edges of the scope
+        const BOX_SELECT_NO_SCROLL = sys::ImGuiMultiSelectFlags_BoxSelectNoScroll as i32;
+        /// Clear the selection when pressing Escape while the scope is focused
+        const CLEAR_ON_ESCAPE = sys::ImGuiMultiSelectFlags_ClearOnEscape as i32;
+        /// Clear the selection when clicking on empty space in the scope
+        const CLEAR_ON_CLICK_VOID = sys::ImGuiMultiSelectFlags_ClearOnClickVoid as i32;
+        /// Scope for box-selection and clear-on-click-void is the whole window (default)
+        const SCOPE_WINDOW = sys::ImGuiMultiSelectFlags_ScopeWindow as i32;
+        /// Scope for box-selection and clear-on-click-void is the rectangle of the items
+        const SCOPE_RECT = sys::ImGuiMultiSelectFlags_ScopeRect as i32;
+        /// Apply selection on mouse down when clicking an unselected item (default)
+        const SELECT_ON_CLICK = sys::ImGuiMultiSelectFlags_SelectOnClick as i32;
+        /// Apply selection on mouse release when clicking an unselected item
+        const SELECT_ON_CLICK_RELEASE = sys::ImGuiMultiSelectFlags_SelectOnClickRelease as i32;
+        /// Allow keyboard navigation to wrap around the X axis
+        const NAV_WRAP_X = sys::ImGuiMultiSelectFlags_NavWrapX as i32;
+    }
+}
+
+/// A change to apply to the selection, read from a [`MultiSelectIo`]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SelectionRequest {
+    /// Select (`selected == true`) or clear every item
+    SetAll {
+        /// New selection state of all items
+        selected: bool,
+    },
+    /// Select or unselect the items from `first` to `last`, inclusive
+    ///
+    /// The bounds are the values passed to [`Ui::set_next_item_selection_user_data`].
+    SetRange {
+        /// User data of the first item of the range
+        first: i64,
+        /// User data of the last item of the range
+        last: i64,
+        /// New selection state of the items in the range
+        selected: bool,
+    },
+}
+
+impl SelectionRequest {
+    fn from_raw(raw: &sys::ImGuiSelectionRequest) -> Option<Self> {
+        match raw.Type {
+            sys::ImGuiSelectionRequestType_SetAll => Some(Self::SetAll {
+                selected: raw.Selected,
+            }),
+            sys::ImGuiSelectionRequestType_SetRange => Some(Self::SetRange {
+                first: raw.RangeFirstItem,
+                last: raw.RangeLastItem,
+                selected: raw.Selected,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Selection requests and state returned by `BeginMultiSelect`/`EndMultiSelect`
+///
+/// Dear ImGui reuses this storage on the next call to either function. The requests
+/// from [`MultiSelectToken::io`] borrow the token, so they cannot outlive the scope;
+/// read the ones from [`MultiSelectToken::end`] before beginning another scope.
+pub struct MultiSelectIo<'a> {
+    raw: *mut sys::ImGuiMultiSelectIO,
+    _phantom: PhantomData<&'a Ui>,
+}
+
+impl MultiSelectIo<'_> {
+    fn new(raw: *mut sys::ImGuiMultiSelectIO) -> Self {
+        Self {
+            raw,
+            _phantom: PhantomData,
+        }
+    }
+
+    fn raw(&self) -> &sys::ImGuiMultiSelectIO {
+        unsafe { &*self.raw }
+    }
+
+    /// Selection requests to apply, in order
+    pub fn requests(&self) -> impl Iterator<Item = SelectionRequest> + '_ {
+        let requests = &self.raw().Requests;
+        let slice = if requests.Data.is_null() || requests.Size <= 0 {
+            &[][..]
+        } else {
+            unsafe { std::slice::from_raw_parts(requests.Data, requests.Size as usize) }
+        };
+        slice.iter().filter_map(SelectionRequest::from_raw)
+    }
+
+    /// User data of the item anchoring shift-click ranges
+    pub fn range_src_item(&self) -> i64 {
+        self.raw().RangeSrcItem
+    }
+
+    /// User data of the item that has keyboard navigation focus
+    pub fn nav_id_item(&self) -> i64 {
+        self.raw().NavIdItem
+    }
+
+    /// Whether the item with navigation focus is selected
+    pub fn nav_id_selected(&self) -> bool {
+        self.raw().NavIdSelected
+    }
+
+    /// Whether the range anchor should be reset, e.g. because the item was deleted
+    pub fn range_src_reset(&self) -> bool {
+        self.raw().RangeSrcReset
+    }
+
+    /// Item count passed to [`Ui::begin_multi_select`]
+    pub fn items_count(&self) -> usize {
+        self.raw().ItemsCount.max(0) as usize
+    }
+}
+
+impl std::fmt::Debug for MultiSelectIo<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MultiSelectIo")
+            .field("requests", &self.requests().collect::<Vec<_>>())
+            .field("range_src_item", &self.range_src_item())
+            .field("nav_id_item", &self.nav_id_item())
+            .field("items_count", &self.items_count())
+            .finish()
+    }
+}
+
+/// Tracks a multi-select scope that must be ended by calling `.end()`
+///
+/// Dropping the token without calling [`end`](Self::end) still ends the scope, but
+/// discards the final requests.
+#[must_use]
+pub struct MultiSelectToken<'ui> {
+    begin: *mut sys::ImGuiMultiSelectIO,
+    ended: bool,
+    _phantom: PhantomData<&'ui Ui>,
+}
+
+impl<'ui> MultiSelectToken<'ui> {
+    /// Requests returned by `BeginMultiSelect`, to apply before submitting items
+    pub fn io(&self) -> MultiSelectIo<'_> {
+        MultiSelectIo::new(self.begin)
+    }
+
+    /// Ends the multi-select scope and returns the requests to apply after submitting items
+    #[doc(alias = "EndMultiSelect")]
+    pub fn end(mut self) -> MultiSelectIo<'ui> {
+        self.ended = true;
+        MultiSelectIo::new(unsafe { sys::igEndMultiSelect() })
+    }
+}
+
+impl Drop for MultiSelectToken<'_> {
+    fn drop(&mut self) {
+        if !self.ended {
+            unsafe {
+                sys::igEndMultiSelect();
+            }
+        }
+    }
+}
+
+/// # Multi-selection
+impl Ui {
+    /// Begins a multi-select scope over `items_count` items, `selection_size` of which
+    /// are currently selected.
+    ///
+    /// Each item submitted before [`MultiSelectToken::end`] must be preceded by
+    /// [`set_next_item_selection_user_data`](Self::set_next_item_selection_user_data).
+    #[doc(alias = "BeginMultiSelect")]
+    pub fn begin_multi_select(
+        &self,
+        flags: MultiSelectFlags,
+        selection_size: usize,
+        items_count: usize,
+    ) -> MultiSelectToken<'_> {
+        self.debug_assert_current();
+        let io = unsafe {
+            sys::igBeginMultiSelect(
+                flags.bits(),
+                i32::try_from(selection_size).unwrap_or(i32::MAX),
+                i32::try_from(items_count).unwrap_or(i32::MAX),
+            )
+        };
+        MultiSelectToken {
+            begin: io,
+            ended: false,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Sets the value identifying the next item in selection requests, typically its index
+    #[doc(alias = "SetNextItemSelectionUserData")]
+    pub fn set_next_item_selection_user_data(&self, user_data: i64) {
+        self.debug_assert_current();
+        unsafe { sys::igSetNextItemSelectionUserData(user_data) }
+    }
+
+    /// Returns `true` if the last item's selection state was toggled this frame
+    #[doc(alias = "IsItemToggledSelection")]
+    pub fn is_item_toggled_selection(&self) -> bool {
+        self.debug_assert_current();
+        unsafe { sys::igIsItemToggledSelection() }
+    }
+}
+
+/// Set of selected items that applies [`SelectionRequest`]s itself
+///
+/// Items are identified by the user data passed to
+/// [`Ui::set_next_item_selection_user_data`], which is expected to be the item index.
+pub struct SelectionStorage {
+    raw: *mut sys::ImGuiSelectionBasicStorage,
+}
+
+impl SelectionStorage {
+    /// Creates an empty selection
+    #[doc(alias = "ImGuiSelectionBasicStorage")]
+    pub fn new() -> Self {
+        let raw = unsafe { sys::ImGuiSelectionBasicStorage_ImGuiSelectionBasicStorage() };
+        assert!(
+            !raw.is_null(),
+            "failed to allocate ImGuiSelectionBasicStorage"
+        );
+        Self { raw }
+    }
+
+    /// Applies all requests of `io` to the selection
+    #[doc(alias = "ApplyRequests")]
+    pub fn apply_requests(&mut self, io: &MultiSelectIo<'_>) {
+        unsafe { sys::ImGuiSelectionBasicStorage_ApplyRequests(self.raw, io.raw) }
+    }
+
+    /// Returns `true` if the item is selected
+    pub fn contains(&self, id: u32) -> bool {
+        unsafe { sys::ImGuiSelectionBasicStorage_Contains(self.raw, id) }
+    }
+
+    /// Selects or unselects a single item
+    pub fn set_item_selected(&mut self, id: u32, selected: bool) {
+        unsafe { sys::ImGuiSelectionBasicStorage_SetItemSelected(self.raw, id, selected) }
+    }
+
+    /// Unselects every item
+    pub fn clear(&mut self) {
+        unsafe { sys::ImGuiSelectionBasicStorage_Clear(self.raw) }
+    }
+
+    /// Number of selected items
+    pub fn len(&self) -> usize {
+        unsafe { (*self.raw).Size.max(0) as usize }
+    }
+
+    /// Returns `true` if nothing is selected
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Keeps selected items in selection order rather than sorted by id
+    pub fn set_preserve_order(&mut self, preserve_order: bool) {
+        unsafe { (*self.raw).PreserveOrder = preserve_order }
+    }
+
+    /// Selected item ids
+    pub fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        let mut it: *mut std::os::raw::c_void = std::ptr::null_mut();
+        std::iter::from_fn(move || {
+            let mut id = 0;
+            unsafe {
+                sys::ImGuiSelectionBasicStorage_GetNextSelectedItem(self.raw, &mut it, &mut id)
+            }
+            .then_some(id)
+        })
+    }
+}
+
+impl Default for SelectionStorage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for SelectionStorage {
+    fn drop(&mut self) {
+        unsafe { sys::ImGuiSelectionBasicStorage_destroy(self.raw) }
+    }
+}
+
+impl std::fmt::Debug for SelectionStorage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
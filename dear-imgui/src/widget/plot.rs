@@ -16,12 +16,14 @@ impl Ui {
     /// Creates a plot lines widget
     #[doc(alias = "PlotLines")]
     pub fn plot_lines(&self, label: impl AsRef<str>, values: &[f32]) {
+        self.debug_assert_current();
         self.plot_lines_config(label, values).build()
     }
 
     /// Creates a plot histogram widget
     #[doc(alias = "PlotHistogram")]
     pub fn plot_histogram(&self, label: impl AsRef<str>, values: &[f32]) {
+        self.debug_assert_current();
         self.plot_histogram_config(label, values).build()
     }
 
@@ -31,6 +33,7 @@ impl Ui {
         label: impl AsRef<str>,
         values: &'p [f32],
     ) -> PlotLines<'_, 'p> {
+        self.debug_assert_current();
         PlotLines::new(self, label, values)
     }
 
@@ -40,10 +43,23 @@ impl Ui {
         label: impl AsRef<str>,
         values: &'p [f32],
     ) -> PlotHistogram<'_, 'p> {
+        self.debug_assert_current();
         PlotHistogram::new(self, label, values)
     }
 }
 
+/// Converts a value slice, wrapping offset and element stride into the sample count,
+/// offset and byte stride expected by ImGui, keeping every access inside `values`
+fn sampling(values: &[f32], offset: i32, stride: usize) -> (i32, i32, i32) {
+    let count = values.len().div_ceil(stride) as i32;
+    let offset = if count > 0 {
+        offset.rem_euclid(count)
+    } else {
+        0
+    };
+    (count, offset, (stride * std::mem::size_of::<f32>()) as i32)
+}
+
 /// Builder for a plot lines widget
 #[derive(Debug)]
 #[must_use]
@@ -56,6 +72,7 @@ pub struct PlotLines<'ui, 'p> {
     scale_min: f32,
     scale_max: f32,
     graph_size: [f32; 2],
+    stride: usize,
 }
 
 impl<'ui, 'p> PlotLines<'ui, 'p> {
@@ -70,10 +87,12 @@ impl<'ui, 'p> PlotLines<'ui, 'p> {
             scale_min: f32::MAX,
             scale_max: f32::MAX,
             graph_size: [0.0, 0.0],
+            stride: 1,
         }
     }
 
-    /// Sets the offset for the values array
+    /// Sets the index of the first value drawn, wrapping around to the start of the
+    /// values; useful for ring buffers
     pub fn values_offset(mut self, offset: i32) -> Self {
         self.values_offset = offset;
         self
@@ -85,13 +104,13 @@ impl<'ui, 'p> PlotLines<'ui, 'p> {
         self
     }
 
-    /// Sets the scale minimum value
+    /// Sets the scale minimum value (auto-scaled from the data when unset)
     pub fn scale_min(mut self, scale_min: f32) -> Self {
         self.scale_min = scale_min;
         self
     }
 
-    /// Sets the scale maximum value
+    /// Sets the scale maximum value (auto-scaled from the data when unset)
     pub fn scale_max(mut self, scale_max: f32) -> Self {
         self.scale_max = scale_max;
         self
@@ -103,23 +122,35 @@ impl<'ui, 'p> PlotLines<'ui, 'p> {
         self
     }
 
+    /// Draws only every `stride`-th value, e.g. 2 for one channel of interleaved data
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is zero.
+    pub fn stride(mut self, stride: usize) -> Self {
+        assert!(stride > 0, "stride must be at least 1");
+        self.stride = stride;
+        self
+    }
+
     /// Builds the plot lines widget
     pub fn build(self) {
         let label_ptr = self.ui.scratch_txt(&self.label);
         let overlay_ptr = self.ui.scratch_txt_opt(self.overlay_text.as_ref());
         let graph_size_vec: sys::ImVec2 = self.graph_size.into();
+        let (count, offset, stride) = sampling(self.values, self.values_offset, self.stride);
 
         unsafe {
             sys::igPlotLines_FloatPtr(
                 label_ptr,
                 self.values.as_ptr(),
-                self.values.len() as i32,
-                self.values_offset,
+                count,
+                offset,
                 overlay_ptr,
                 self.scale_min,
                 self.scale_max,
                 graph_size_vec,
-                std::mem::size_of::<f32>() as i32,
+                stride,
             );
         }
     }
@@ -137,6 +168,7 @@ pub struct PlotHistogram<'ui, 'p> {
     scale_min: f32,
     scale_max: f32,
     graph_size: [f32; 2],
+    stride: usize,
 }
 
 impl<'ui, 'p> PlotHistogram<'ui, 'p> {
@@ -151,10 +183,12 @@ impl<'ui, 'p> PlotHistogram<'ui, 'p> {
             scale_min: f32::MAX,
             scale_max: f32::MAX,
             graph_size: [0.0, 0.0],
+            stride: 1,
         }
     }
 
-    /// Sets the offset for the values array
+    /// Sets the index of the first value drawn, wrapping around to the start of the
+    /// values; useful for ring buffers
     pub fn values_offset(mut self, offset: i32) -> Self {
         self.values_offset = offset;
         self
@@ -166,13 +200,13 @@ impl<'ui, 'p> PlotHistogram<'ui, 'p> {
         self
     }
 
-    /// Sets the scale minimum value
+    /// Sets the scale minimum value (auto-scaled from the data when unset)
     pub fn scale_min(mut self, scale_min: f32) -> Self {
         self.scale_min = scale_min;
         self
     }
 
-    /// Sets the scale maximum value
+    /// Sets the scale maximum value (auto-scaled from the data when unset)
     pub fn scale_max(mut self, scale_max: f32) -> Self {
         self.scale_max = scale_max;
         self
@@ -184,23 +218,35 @@ impl<'ui, 'p> PlotHistogram<'ui, 'p> {
         self
     }
 
+    /// Draws only every `stride`-th value, e.g. 2 for one channel of interleaved data
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is zero.
+    pub fn stride(mut self, stride: usize) -> Self {
+        assert!(stride > 0, "stride must be at least 1");
+        self.stride = stride;
+        self
+    }
+
     /// Builds the plot histogram widget
     pub fn build(self) {
         let label_ptr = self.ui.scratch_txt(&self.label);
         let overlay_ptr = self.ui.scratch_txt_opt(self.overlay_text.as_ref());
         let graph_size_vec: sys::ImVec2 = self.graph_size.into();
+        let (count, offset, stride) = sampling(self.values, self.values_offset, self.stride);
 
         unsafe {
             sys::igPlotHistogram_FloatPtr(
                 label_ptr,
                 self.values.as_ptr(),
-                self.values.len() as i32,
-                self.values_offset,
+                count,
+                offset,
                 overlay_ptr,
                 self.scale_min,
                 self.scale_max,
                 graph_size_vec,
-                std::mem::size_of::<f32>() as i32,
+                stride,
             );
         }
     }
@@ -26,6 +26,7 @@ impl Ui {
     /// The confusing aspect to popups is that ImGui holds control over the popup itself.
     #[doc(alias = "OpenPopup")]
     pub fn open_popup(&self, str_id: impl AsRef<str>) {
+        self.debug_assert_current();
         let str_id_ptr = self.scratch_txt(str_id);
         unsafe { sys::igOpenPopup_Str(str_id_ptr, PopupFlags::NONE.bits()) }
     }
@@ -33,6 +34,7 @@ impl Ui {
     /// Instructs ImGui that a popup is open with flags.
     #[doc(alias = "OpenPopup")]
     pub fn open_popup_with_flags(&self, str_id: impl AsRef<str>, flags: PopupFlags) {
+        self.debug_assert_current();
         let str_id_ptr = self.scratch_txt(str_id);
         unsafe {
             sys::igOpenPopup_Str(str_id_ptr, flags.bits());
@@ -45,6 +47,7 @@ impl Ui {
     /// to signal that this popup is active.
     #[doc(alias = "BeginPopup")]
     pub fn begin_popup(&self, str_id: impl AsRef<str>) -> Option<PopupToken<'_>> {
+        self.debug_assert_current();
         self.begin_popup_with_flags(str_id, WindowFlags::empty())
     }
 
@@ -55,6 +58,7 @@ impl Ui {
         str_id: impl AsRef<str>,
         flags: WindowFlags,
     ) -> Option<PopupToken<'_>> {
+        self.debug_assert_current();
         let str_id_ptr = self.scratch_txt(str_id);
         let render = unsafe { sys::igBeginPopup(str_id_ptr, flags.bits()) };
 
@@ -74,6 +78,7 @@ impl Ui {
     where
         F: FnOnce(),
     {
+        self.debug_assert_current();
         if let Some(_token) = self.begin_popup(str_id) {
             f();
         }
@@ -84,6 +89,7 @@ impl Ui {
     /// Modal popups block interaction with the rest of the application until closed.
     #[doc(alias = "BeginPopupModal")]
     pub fn begin_modal_popup(&self, name: impl AsRef<str>) -> Option<ModalPopupToken<'_>> {
+        self.debug_assert_current();
         let name_ptr = self.scratch_txt(name);
         let render = unsafe {
             sys::igBeginPopupModal(name_ptr, std::ptr::null_mut(), WindowFlags::empty().bits())
@@ -98,6 +104,7 @@ impl Ui {
 
     /// Creates a modal popup builder.
     pub fn begin_modal_popup_config<'a>(&'a self, name: &'a str) -> ModalPopup<'a> {
+        self.debug_assert_current();
         ModalPopup {
             name,
             opened: None,
@@ -113,12 +120,14 @@ impl Ui {
     where
         F: FnOnce() -> R,
     {
+        self.debug_assert_current();
         self.begin_modal_popup(name).map(|_token| f())
     }
 
     /// Closes the current popup.
     #[doc(alias = "CloseCurrentPopup")]
     pub fn close_current_popup(&self) {
+        self.debug_assert_current();
         unsafe {
             sys::igCloseCurrentPopup();
         }
@@ -127,6 +136,7 @@ impl Ui {
     /// Returns true if the popup is open.
     #[doc(alias = "IsPopupOpen")]
     pub fn is_popup_open(&self, str_id: impl AsRef<str>) -> bool {
+        self.debug_assert_current();
         let str_id_ptr = self.scratch_txt(str_id);
         unsafe { sys::igIsPopupOpen_Str(str_id_ptr, PopupFlags::NONE.bits()) }
     }
@@ -134,6 +144,7 @@ impl Ui {
     /// Returns true if the popup is open with flags.
     #[doc(alias = "IsPopupOpen")]
     pub fn is_popup_open_with_flags(&self, str_id: impl AsRef<str>, flags: PopupFlags) -> bool {
+        self.debug_assert_current();
         let str_id_ptr = self.scratch_txt(str_id);
         unsafe { sys::igIsPopupOpen_Str(str_id_ptr, flags.bits()) }
     }
@@ -142,6 +153,7 @@ impl Ui {
     /// This is typically used with right-click context menus.
     #[doc(alias = "BeginPopupContextItem")]
     pub fn begin_popup_context_item(&self) -> Option<PopupToken<'_>> {
+        self.debug_assert_current();
         self.begin_popup_context_item_with_label(None)
     }
 
@@ -151,6 +163,7 @@ impl Ui {
         &self,
         str_id: Option<&str>,
     ) -> Option<PopupToken<'_>> {
+        self.debug_assert_current();
         let str_id_ptr = str_id
             .map(|s| self.scratch_txt(s))
             .unwrap_or(std::ptr::null());
@@ -169,6 +182,7 @@ impl Ui {
     /// Begin a popup context menu for the current window.
     #[doc(alias = "BeginPopupContextWindow")]
     pub fn begin_popup_context_window(&self) -> Option<PopupToken<'_>> {
+        self.debug_assert_current();
         self.begin_popup_context_window_with_label(None)
     }
 
@@ -178,6 +192,7 @@ impl Ui {
         &self,
         str_id: Option<&str>,
     ) -> Option<PopupToken<'_>> {
+        self.debug_assert_current();
         let str_id_ptr = str_id
             .map(|s| self.scratch_txt(s))
             .unwrap_or(std::ptr::null());
@@ -196,6 +211,7 @@ impl Ui {
     /// Begin a popup context menu for empty space (void).
     #[doc(alias = "BeginPopupContextVoid")]
     pub fn begin_popup_context_void(&self) -> Option<PopupToken<'_>> {
+        self.debug_assert_current();
         self.begin_popup_context_void_with_label(None)
     }
 
@@ -205,6 +221,7 @@ impl Ui {
         &self,
         str_id: Option<&str>,
     ) -> Option<PopupToken<'_>> {
+        self.debug_assert_current();
         let str_id_ptr = str_id
             .map(|s| self.scratch_txt(s))
             .unwrap_or(std::ptr::null());
@@ -12,6 +12,7 @@ impl Ui {
     /// The fraction should be between 0.0 (0%) and 1.0 (100%).
     #[doc(alias = "ProgressBar")]
     pub fn progress_bar(&self, fraction: f32) -> ProgressBar<'_> {
+        self.debug_assert_current();
         ProgressBar::new(self, fraction)
     }
 
@@ -22,6 +23,7 @@ impl Ui {
         fraction: f32,
         overlay: impl AsRef<str>,
     ) -> ProgressBar<'_> {
+        self.debug_assert_current();
         ProgressBar::new(self, fraction).overlay_text(overlay)
     }
 }
@@ -37,12 +37,26 @@ impl Ui {
     /// [selectable_config]: Self::selectable_config
     #[doc(alias = "Selectable")]
     pub fn selectable<T: AsRef<str>>(&self, label: T) -> bool {
+        self.debug_assert_current();
         self.selectable_config(label).build()
     }
 
+    /// Constructs a simple selectable from a [`UiStr`](crate::UiStr) label
+    ///
+    /// Labels held as `CStr`, `CString` or [`ImString`](crate::ImString) are passed to
+    /// Dear ImGui without being copied.
+    #[doc(alias = "Selectable")]
+    pub fn selectable_str<'a>(&self, label: impl Into<crate::UiStr<'a>>) -> bool {
+        self.debug_assert_current();
+        let label_ptr = self.scratch_ui_str(label.into());
+        let size = sys::ImVec2 { x: 0.0, y: 0.0 };
+        unsafe { sys::igSelectable_Bool(label_ptr, false, 0, size) }
+    }
+
     /// Constructs a new selectable builder.
     #[doc(alias = "Selectable")]
     pub fn selectable_config<T: AsRef<str>>(&self, label: T) -> Selectable<'_, T> {
+        self.debug_assert_current();
         Selectable {
             label,
             selected: false,
@@ -124,8 +124,12 @@ where
 
     /// Builds a horizontal array of multiple sliders attached to the given slice.
     ///
-    /// Returns true if any slider value was changed.
+    /// Returns true if any slider value was changed. An empty slice draws nothing and
+    /// returns false.
     pub fn build_array(self, values: &mut [Data]) -> bool {
+        if values.is_empty() {
+            return false;
+        }
         unsafe {
             let (label, display_format) = self
                 .ui
@@ -397,6 +401,7 @@ impl Ui {
         max: K,
         value: &mut K,
     ) -> bool {
+        self.debug_assert_current();
         self.slider_config(label, min, max).build(value)
     }
 
@@ -407,6 +412,7 @@ impl Ui {
         min: K,
         max: K,
     ) -> Slider<'_, T, K> {
+        self.debug_assert_current();
         Slider {
             ui: self,
             label,
@@ -417,15 +423,33 @@ impl Ui {
         }
     }
 
+    /// Creates a horizontal row of sliders sharing one range, one per element of `values`.
+    ///
+    /// Use [`slider_config`](Self::slider_config) and [`Slider::build_array`] to set the
+    /// format or flags. Returns true if any value has been edited.
+    #[doc(alias = "SliderScalarN")]
+    pub fn slider_scalar_n<T: AsRef<str>, K: DataTypeKind>(
+        &self,
+        label: T,
+        min: K,
+        max: K,
+        values: &mut [K],
+    ) -> bool {
+        self.debug_assert_current();
+        self.slider_config(label, min, max).build_array(values)
+    }
+
     /// Creates a float slider
     #[doc(alias = "SliderFloat")]
     pub fn slider_f32(&self, label: impl AsRef<str>, value: &mut f32, min: f32, max: f32) -> bool {
+        self.debug_assert_current();
         self.slider_config(label, min, max).build(value)
     }
 
     /// Creates an integer slider
     #[doc(alias = "SliderInt")]
     pub fn slider_i32(&self, label: impl AsRef<str>, value: &mut i32, min: i32, max: i32) -> bool {
+        self.debug_assert_current();
         self.slider_config(label, min, max).build(value)
     }
 
@@ -439,6 +463,7 @@ impl Ui {
         min: f32,
         max: f32,
     ) -> bool {
+        self.debug_assert_current();
         VerticalSlider::new(label, size, min, max).build(self, value)
     }
 
@@ -452,12 +477,14 @@ impl Ui {
         min: i32,
         max: i32,
     ) -> bool {
+        self.debug_assert_current();
         VerticalSlider::new(label, size, min, max).build(self, value)
     }
 
     /// Creates an angle slider (value in radians)
     #[doc(alias = "SliderAngle")]
     pub fn slider_angle(&self, label: impl AsRef<str>, value_rad: &mut f32) -> bool {
+        self.debug_assert_current();
         AngleSlider::new(label).build(self, value_rad)
     }
 }
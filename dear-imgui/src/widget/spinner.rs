@@ -0,0 +1,138 @@
+//! Spinners
+//!
+//! Animated loading indicators drawn with the window draw list. Dear ImGui has
+//! no built-in spinner; these are small custom widgets that occupy a square
+//! item of `2 * radius` and animate from [`Ui::time`].
+//!
+use crate::draw::{DrawFlags, DrawListMut, ImColor32};
+use crate::sys;
+use crate::ui::Ui;
+use std::f32::consts::{FRAC_PI_2, PI, TAU};
+
+/// Rotation speed of the indeterminate spinner, in radians per second
+const SPIN_SPEED: f32 = 6.0;
+/// Angular length of the indeterminate spinner's arc
+const SPIN_ARC: f32 = 1.5 * PI;
+/// Alpha applied to the track drawn behind a progress spinner
+const TRACK_ALPHA: f32 = 0.25;
+
+/// # Spinner Widgets
+impl Ui {
+    /// Draws an indeterminate spinner: an arc rotating around a circle of `radius`.
+    ///
+    /// The widget occupies a `2 * radius` square and advances with [`Ui::time`], so it
+    /// animates as long as frames keep being submitted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a [`DrawListMut`](crate::DrawListMut) for the window draw list is alive.
+    ///
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// ui.spinner("##loading", 10.0, 3.0, [0.3, 0.6, 1.0, 1.0]);
+    /// ui.same_line();
+    /// ui.text("Loading...");
+    /// ```
+    pub fn spinner(
+        &self,
+        id: impl AsRef<str>,
+        radius: f32,
+        thickness: f32,
+        color: impl Into<ImColor32>,
+    ) {
+        self.debug_assert_current();
+        let Some(center) = self.spinner_item(id, radius) else {
+            return;
+        };
+        let ring = Ring::new(center, radius, thickness);
+        let start = (self.time() as f32 * SPIN_SPEED) % TAU;
+        let draw_list = crate::DrawListMut::window_for_widget(self, "spinner");
+        ring.stroke(&draw_list, start, start + SPIN_ARC, color.into());
+    }
+
+    /// Draws a determinate spinner whose arc fills clockwise from the top as `fraction`
+    /// goes from 0.0 to 1.0, over a faint full-circle track.
+    ///
+    /// `fraction` is clamped to `[0.0, 1.0]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a [`DrawListMut`](crate::DrawListMut) for the window draw list is alive.
+    pub fn progress_spinner(
+        &self,
+        id: impl AsRef<str>,
+        fraction: f32,
+        radius: f32,
+        thickness: f32,
+        color: impl Into<ImColor32>,
+    ) {
+        self.debug_assert_current();
+        let Some(center) = self.spinner_item(id, radius) else {
+            return;
+        };
+        let ring = Ring::new(center, radius, thickness);
+        let color = color.into();
+        let bits = color.to_bits();
+        let track_alpha = ((bits >> 24) as f32 * TRACK_ALPHA) as u32;
+        let track = ImColor32::from((bits & 0x00ff_ffff) | (track_alpha << 24));
+        let draw_list = crate::DrawListMut::window_for_widget(self, "progress_spinner");
+        ring.stroke(&draw_list, 0.0, TAU, track);
+
+        let fraction = fraction.clamp(0.0, 1.0);
+        if fraction > 0.0 {
+            let start = -FRAC_PI_2;
+            ring.stroke(&draw_list, start, start + TAU * fraction, color);
+        }
+    }
+
+    /// Reserves the spinner's item rectangle and returns its center, or `None` if clipped.
+    fn spinner_item(&self, id: impl AsRef<str>, radius: f32) -> Option<[f32; 2]> {
+        self.debug_assert_current();
+        let [x, y] = self.cursor_screen_pos();
+        let size = sys::ImVec2 {
+            x: radius * 2.0,
+            y: radius * 2.0,
+        };
+        let bb = sys::ImRect {
+            Min: sys::ImVec2 { x, y },
+            Max: sys::ImVec2 {
+                x: x + size.x,
+                y: y + size.y,
+            },
+        };
+        let visible = unsafe {
+            let id = sys::igGetID_Str(self.scratch_txt(id));
+            sys::igItemSize_Vec2(size, -1.0);
+            sys::igItemAdd(bb, id, std::ptr::null(), 0)
+        };
+        visible.then_some([x + radius, y + radius])
+    }
+}
+
+/// Circle a spinner's arcs are stroked along
+#[derive(Copy, Clone)]
+struct Ring {
+    center: [f32; 2],
+    /// Radius of the stroke's center line, so the stroke stays inside the item
+    radius: f32,
+    thickness: f32,
+}
+
+impl Ring {
+    fn new(center: [f32; 2], radius: f32, thickness: f32) -> Self {
+        Self {
+            center,
+            radius: radius - thickness * 0.5,
+            thickness,
+        }
+    }
+
+    /// Strokes the arc from `a_min` to `a_max` (radians)
+    fn stroke(&self, draw_list: &DrawListMut<'_>, a_min: f32, a_max: f32, color: ImColor32) {
+        draw_list.path_clear();
+        draw_list.path_arc_to(self.center, self.radius, a_min, a_max, 0);
+        draw_list.path_stroke(color, DrawFlags::NONE, self.thickness);
+    }
+}
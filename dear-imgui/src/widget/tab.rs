@@ -235,6 +235,7 @@ impl Ui {
     /// use [tab_bar_with_flags](Self::tab_bar_with_flags).
     #[doc(alias = "BeginTabBar")]
     pub fn tab_bar(&self, id: impl AsRef<str>) -> Option<TabBarToken<'_>> {
+        self.debug_assert_current();
         self.tab_bar_with_flags(id, TabBarFlags::NONE)
     }
 
@@ -246,6 +247,7 @@ impl Ui {
         id: impl AsRef<str>,
         flags: TabBarFlags,
     ) -> Option<TabBarToken<'_>> {
+        self.debug_assert_current();
         let id_ptr = self.scratch_txt(id);
         let should_render = unsafe { sys::igBeginTabBar(id_ptr, flags.bits()) };
 
@@ -265,6 +267,7 @@ impl Ui {
     /// [tab_item_with_flags]: Self::tab_item_with_flags
     #[doc(alias = "BeginTabItem")]
     pub fn tab_item(&self, label: impl AsRef<str>) -> Option<TabItemToken<'_>> {
+        self.debug_assert_current();
         self.tab_item_with_flags(label, None, TabItemFlags::NONE)
     }
 
@@ -277,6 +280,7 @@ impl Ui {
         label: impl AsRef<str>,
         opened: &mut bool,
     ) -> Option<TabItemToken<'_>> {
+        self.debug_assert_current();
         self.tab_item_with_flags(label, Some(opened), TabItemFlags::NONE)
     }
 
@@ -288,6 +292,7 @@ impl Ui {
         opened: Option<&mut bool>,
         flags: TabItemFlags,
     ) -> Option<TabItemToken<'_>> {
+        self.debug_assert_current();
         let label_ptr = self.scratch_txt(label);
         let opened_ptr = opened.map(|x| x as *mut bool).unwrap_or(ptr::null_mut());
 
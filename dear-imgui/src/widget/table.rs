@@ -37,6 +37,7 @@
     clippy::cast_sign_loss,
     clippy::as_conversions
 )]
+use crate::ListClipper;
 use crate::draw::ImColor32;
 use crate::sys;
 use crate::ui::Ui;
@@ -105,6 +106,7 @@ impl Ui {
     /// # }
     /// ```
     pub fn table(&self, str_id: impl AsRef<str>) -> TableBuilder<'_> {
+        self.debug_assert_current();
         TableBuilder::new(self, str_id)
     }
     /// Begins a table with no flags and with standard sizing constraints.
@@ -118,6 +120,7 @@ impl Ui {
         str_id: impl AsRef<str>,
         column_count: usize,
     ) -> Option<TableToken<'_>> {
+        self.debug_assert_current();
         self.begin_table_with_flags(str_id, column_count, TableFlags::NONE)
     }
 
@@ -129,6 +132,7 @@ impl Ui {
         column_count: usize,
         flags: TableFlags,
     ) -> Option<TableToken<'_>> {
+        self.debug_assert_current();
         self.begin_table_with_sizing(str_id, column_count, flags, [0.0, 0.0], 0.0)
     }
 
@@ -143,6 +147,7 @@ impl Ui {
         outer_size: impl Into<[f32; 2]>,
         inner_width: f32,
     ) -> Option<TableToken<'_>> {
+        self.debug_assert_current();
         let str_id_ptr = self.scratch_txt(str_id);
         let outer_size_vec: sys::ImVec2 = outer_size.into().into();
 
@@ -173,6 +178,7 @@ impl Ui {
         str_id: impl AsRef<str>,
         column_data: [TableColumnSetup<Name>; N],
     ) -> Option<TableToken<'_>> {
+        self.debug_assert_current();
         self.begin_table_header_with_flags(str_id, column_data, TableFlags::NONE)
     }
 
@@ -187,6 +193,7 @@ impl Ui {
         column_data: [TableColumnSetup<Name>; N],
         flags: TableFlags,
     ) -> Option<TableToken<'_>> {
+        self.debug_assert_current();
         if let Some(token) = self.begin_table_with_flags(str_id, N, flags) {
             // Setup columns
             for column in &column_data {
@@ -212,6 +219,7 @@ impl Ui {
         init_width_or_weight: f32,
         user_id: u32,
     ) {
+        self.debug_assert_current();
         let label_ptr = self.scratch_txt(label);
         unsafe {
             sys::igTableSetupColumn(label_ptr, flags.bits(), init_width_or_weight, user_id);
@@ -220,6 +228,7 @@ impl Ui {
 
     /// Submit all headers cells based on data provided to TableSetupColumn() + submit context menu
     pub fn table_headers_row(&self) {
+        self.debug_assert_current();
         unsafe {
             sys::igTableHeadersRow();
         }
@@ -227,21 +236,25 @@ impl Ui {
 
     /// Append into the next column (or first column of next row if currently in last column)
     pub fn table_next_column(&self) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igTableNextColumn() }
     }
 
     /// Append into the specified column
     pub fn table_set_column_index(&self, column_n: i32) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igTableSetColumnIndex(column_n) }
     }
 
     /// Append into the next row
     pub fn table_next_row(&self) {
+        self.debug_assert_current();
         self.table_next_row_with_flags(TableRowFlags::NONE, 0.0);
     }
 
     /// Append into the next row with flags and minimum height
     pub fn table_next_row_with_flags(&self, flags: TableRowFlags, min_row_height: f32) {
+        self.debug_assert_current();
         unsafe {
             sys::igTableNextRow(flags.bits(), min_row_height);
         }
@@ -250,12 +263,14 @@ impl Ui {
     /// Freeze columns/rows so they stay visible when scrolling.
     #[doc(alias = "TableSetupScrollFreeze")]
     pub fn table_setup_scroll_freeze(&self, frozen_cols: i32, frozen_rows: i32) {
+        self.debug_assert_current();
         unsafe { sys::igTableSetupScrollFreeze(frozen_cols, frozen_rows) }
     }
 
     /// Submit one header cell at current column position.
     #[doc(alias = "TableHeader")]
     pub fn table_header(&self, label: impl AsRef<str>) {
+        self.debug_assert_current();
         let label_ptr = self.scratch_txt(label);
         unsafe { sys::igTableHeader(label_ptr) }
     }
@@ -263,24 +278,28 @@ impl Ui {
     /// Return columns count.
     #[doc(alias = "TableGetColumnCount")]
     pub fn table_get_column_count(&self) -> i32 {
+        self.debug_assert_current();
         unsafe { sys::igTableGetColumnCount() }
     }
 
     /// Return current column index.
     #[doc(alias = "TableGetColumnIndex")]
     pub fn table_get_column_index(&self) -> i32 {
+        self.debug_assert_current();
         unsafe { sys::igTableGetColumnIndex() }
     }
 
     /// Return current row index.
     #[doc(alias = "TableGetRowIndex")]
     pub fn table_get_row_index(&self) -> i32 {
+        self.debug_assert_current();
         unsafe { sys::igTableGetRowIndex() }
     }
 
     /// Return the name of a column by index.
     #[doc(alias = "TableGetColumnName")]
     pub fn table_get_column_name(&self, column_n: i32) -> &str {
+        self.debug_assert_current();
         unsafe {
             let ptr = sys::igTableGetColumnName_Int(column_n);
             if ptr.is_null() {
@@ -294,24 +313,28 @@ impl Ui {
     /// Return the flags of a column by index.
     #[doc(alias = "TableGetColumnFlags")]
     pub fn table_get_column_flags(&self, column_n: i32) -> TableColumnFlags {
+        self.debug_assert_current();
         unsafe { TableColumnFlags::from_bits_truncate(sys::igTableGetColumnFlags(column_n)) }
     }
 
     /// Enable/disable a column by index.
     #[doc(alias = "TableSetColumnEnabled")]
     pub fn table_set_column_enabled(&self, column_n: i32, enabled: bool) {
+        self.debug_assert_current();
         unsafe { sys::igTableSetColumnEnabled(column_n, enabled) }
     }
 
     /// Return hovered column index, or -1 when none.
     #[doc(alias = "TableGetHoveredColumn")]
     pub fn table_get_hovered_column(&self) -> i32 {
+        self.debug_assert_current();
         unsafe { sys::igTableGetHoveredColumn() }
     }
 
     /// Set column width (for fixed-width columns).
     #[doc(alias = "TableSetColumnWidth")]
     pub fn table_set_column_width(&self, column_n: i32, width: f32) {
+        self.debug_assert_current();
         unsafe { sys::igTableSetColumnWidth(column_n, width) }
     }
 
@@ -321,11 +344,13 @@ impl Ui {
     /// Use `crate::colors::Color::to_imgui_u32()` to convert RGBA floats.
     #[doc(alias = "TableSetBgColor")]
     pub fn table_set_bg_color_u32(&self, target: TableBgTarget, color: u32, column_n: i32) {
+        self.debug_assert_current();
         unsafe { sys::igTableSetBgColor(target as i32, color, column_n) }
     }
 
     /// Set a table background color target using RGBA color (0..=1 floats).
     pub fn table_set_bg_color(&self, target: TableBgTarget, rgba: [f32; 4], column_n: i32) {
+        self.debug_assert_current();
         // Pack to ImGui's ABGR layout.
         let col = crate::colors::Color::from_array(rgba).to_imgui_u32();
         unsafe { sys::igTableSetBgColor(target as i32, col, column_n) }
@@ -334,12 +359,14 @@ impl Ui {
     /// Return hovered row index, or -1 when none.
     #[doc(alias = "TableGetHoveredRow")]
     pub fn table_get_hovered_row(&self) -> i32 {
+        self.debug_assert_current();
         unsafe { sys::igTableGetHoveredRow() }
     }
 
     /// Header row height in pixels.
     #[doc(alias = "TableGetHeaderRowHeight")]
     pub fn table_get_header_row_height(&self) -> f32 {
+        self.debug_assert_current();
         unsafe { sys::igTableGetHeaderRowHeight() }
     }
 
@@ -351,6 +378,7 @@ impl Ui {
         dir: SortDirection,
         append_to_sort_specs: bool,
     ) {
+        self.debug_assert_current();
         unsafe { sys::igTableSetColumnSortDirection(column_n, dir.into(), append_to_sort_specs) }
     }
 
@@ -359,6 +387,7 @@ impl Ui {
     /// then call `clear_dirty()`.
     #[doc(alias = "TableGetSortSpecs")]
     pub fn table_get_sort_specs(&self) -> Option<TableSortSpecs<'_>> {
+        self.debug_assert_current();
         unsafe {
             let ptr = sys::igTableGetSortSpecs();
             if ptr.is_null() {
@@ -519,6 +548,149 @@ impl<'ui> TableToken<'ui> {
         TableToken { ui }
     }
 
+    /// Keeps the first `frozen_cols` columns and `frozen_rows` rows visible while scrolling.
+    ///
+    /// Call before setting up the columns. Only has an effect on tables with
+    /// [`TableFlags::SCROLL_X`] / [`TableFlags::SCROLL_Y`].
+    #[doc(alias = "TableSetupScrollFreeze")]
+    pub fn setup_scroll_freeze(&self, frozen_cols: i32, frozen_rows: i32) {
+        self.ui.table_setup_scroll_freeze(frozen_cols, frozen_rows)
+    }
+
+    /// Sets up the next column of this table.
+    ///
+    /// `init_width_or_weight` is a width in pixels for [`TableColumnFlags::WIDTH_FIXED`]
+    /// columns and a weight for [`TableColumnFlags::WIDTH_STRETCH`] ones (`0.0` for the
+    /// default). `user_id` is reported back as
+    /// [`TableColumnSortSpec::column_user_id`], so sorting can be keyed off it rather than
+    /// the column index.
+    #[doc(alias = "TableSetupColumn")]
+    pub fn setup_column(
+        &self,
+        label: impl AsRef<str>,
+        flags: TableColumnFlags,
+        init_width_or_weight: f32,
+        user_id: u32,
+    ) {
+        self.ui
+            .table_setup_column(label, flags, init_width_or_weight, user_id)
+    }
+
+    /// Sets up the next column of this table with default flags and width.
+    #[doc(alias = "TableSetupColumn")]
+    pub fn setup_column_simple(&self, label: impl AsRef<str>) {
+        self.setup_column(label, TableColumnFlags::NONE, 0.0, 0)
+    }
+
+    /// Returns the sort specs of this table, if it is [`TableFlags::SORTABLE`].
+    ///
+    /// The specs borrow the token since they are only valid until the table ends. When
+    /// [`is_dirty`](TableSortSpecs::is_dirty) is true, sort your data by the specs in order
+    /// and then call [`clear_dirty`](TableSortSpecs::clear_dirty).
+    #[doc(alias = "TableGetSortSpecs")]
+    pub fn sort_specs(&self) -> Option<TableSortSpecs<'_>> {
+        self.ui.table_get_sort_specs()
+    }
+
+    /// Draws `count` rows through a list clipper, calling `row` only for visible rows.
+    ///
+    /// A new row is started with [`Ui::table_next_row`] before each call, so the closure
+    /// only fills the cells (e.g. with [`Ui::table_next_column`]). Rows must share a fixed
+    /// height, which the clipper measures from the first row. Use a table with
+    /// [`TableFlags::SCROLL_Y`] (or inside a scrolling child) so off-screen rows exist to
+    /// be clipped.
+    ///
+    /// When the table is sortable, sort the model when the [`sort_specs`](Self::sort_specs)
+    /// are dirty and map the clipped row index through that order: the clipper only
+    /// decides which view rows are visible.
+    ///
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # fn f(ui: &Ui, names: &[String], order: &[usize]) {
+    /// if let Some(table) = ui.begin_table_with_flags("grid", 1, TableFlags::SCROLL_Y) {
+    ///     table.clip_rows(order.len(), |row| {
+    ///         ui.table_next_column();
+    ///         ui.text(&names[order[row]]);
+    ///     });
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` does not fit in an `i32`.
+    pub fn clip_rows(&self, count: usize, mut row: impl FnMut(usize)) {
+        let count = i32::try_from(count).expect("row count does not fit in an i32");
+        ListClipper::new(count).for_each(self.ui, |range| {
+            for index in range {
+                self.ui.table_next_row();
+                row(index);
+            }
+        });
+    }
+
+    /// Overrides the background color of the current row or of one of its cells.
+    ///
+    /// Call after [`Ui::table_next_row`]. `column` selects the cell for
+    /// [`TableBgTarget::CellBg`] (`-1` for the current column) and is ignored for the row
+    /// targets, which color the whole row on top of the alternating row background.
+    /// [`TableBgTarget::None`] does nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `target` is [`TableBgTarget::CellBg`] and `column` is neither `-1` nor
+    /// a column of this table.
+    #[doc(alias = "TableSetBgColor")]
+    pub fn set_bg_color(&self, target: TableBgTarget, color: [f32; 4], column: i32) {
+        match target {
+            TableBgTarget::None => {}
+            TableBgTarget::RowBg0 | TableBgTarget::RowBg1 => {
+                self.ui.table_set_bg_color(target, color, -1)
+            }
+            TableBgTarget::CellBg => {
+                let count = self.ui.table_get_column_count();
+                assert!(
+                    column == -1 || (0..count).contains(&column),
+                    "column {column} is out of range for a table with {count} columns"
+                );
+                self.ui.table_set_bg_color(target, color, column)
+            }
+        }
+    }
+
+    /// Returns the flags of a column (`-1` for the current column).
+    ///
+    /// Besides the setup flags this reports the column's live state:
+    /// [`IS_ENABLED`](TableColumnFlags::IS_ENABLED),
+    /// [`IS_VISIBLE`](TableColumnFlags::IS_VISIBLE),
+    /// [`IS_SORTED`](TableColumnFlags::IS_SORTED) and
+    /// [`IS_HOVERED`](TableColumnFlags::IS_HOVERED). Out-of-range columns have no flags.
+    #[doc(alias = "TableGetColumnFlags")]
+    pub fn column_flags(&self, column: i32) -> TableColumnFlags {
+        self.ui.table_get_column_flags(column)
+    }
+
+    /// Shows or hides a column, as the user can from the header context menu.
+    ///
+    /// The table must have [`TableFlags::HIDEABLE`]; columns set up with
+    /// [`TableColumnFlags::NO_HIDE`] stay enabled. The enabled state is stored in the
+    /// table's ini settings like widths and order, so it persists across sessions unless
+    /// the table has [`TableFlags::NO_SAVED_SETTINGS`]. The change applies from the next
+    /// frame.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `column` is not a column of this table.
+    #[doc(alias = "TableSetColumnEnabled")]
+    pub fn set_column_enabled(&self, column: i32, enabled: bool) {
+        let count = self.ui.table_get_column_count();
+        assert!(
+            (0..count).contains(&column),
+            "column {column} is out of range for a table with {count} columns"
+        );
+        self.ui.table_set_column_enabled(column, enabled)
+    }
+
     /// Ends the table
     pub fn end(self) {
         // The drop implementation will handle the actual ending
@@ -565,12 +737,14 @@ impl Ui {
     /// Maximum label width used for angled headers (when enabled in style/options).
     #[doc(alias = "TableGetHeaderAngledMaxLabelWidth")]
     pub fn table_get_header_angled_max_label_width(&self) -> f32 {
+        self.debug_assert_current();
         unsafe { sys::igTableGetHeaderAngledMaxLabelWidth() }
     }
 
     /// Submit angled headers row (requires style/flags enabling angled headers).
     #[doc(alias = "TableAngledHeadersRow")]
     pub fn table_angled_headers_row(&self) {
+        self.debug_assert_current();
         unsafe { sys::igTableAngledHeadersRow() }
     }
 
@@ -589,6 +763,7 @@ impl Ui {
         max_label_width: f32,
         headers: &[TableHeaderData],
     ) {
+        self.debug_assert_current();
         if headers.is_empty() {
             unsafe { sys::igTableAngledHeadersRow() }
             return;
@@ -616,29 +791,34 @@ impl Ui {
     /// Push background draw channel for the current table and return a token to pop it.
     #[doc(alias = "TablePushBackgroundChannel")]
     pub fn table_push_background_channel(&self) {
+        self.debug_assert_current();
         unsafe { sys::igTablePushBackgroundChannel() }
     }
 
     /// Pop background draw channel for the current table.
     #[doc(alias = "TablePopBackgroundChannel")]
     pub fn table_pop_background_channel(&self) {
+        self.debug_assert_current();
         unsafe { sys::igTablePopBackgroundChannel() }
     }
 
     /// Push column draw channel for the given column index and return a token to pop it.
     #[doc(alias = "TablePushColumnChannel")]
     pub fn table_push_column_channel(&self, column_n: i32) {
+        self.debug_assert_current();
         unsafe { sys::igTablePushColumnChannel(column_n) }
     }
 
     /// Pop column draw channel.
     #[doc(alias = "TablePopColumnChannel")]
     pub fn table_pop_column_channel(&self) {
+        self.debug_assert_current();
         unsafe { sys::igTablePopColumnChannel() }
     }
 
     /// Run a closure after pushing table background channel (auto-pop on return).
     pub fn with_table_background_channel<R>(&self, f: impl FnOnce() -> R) -> R {
+        self.debug_assert_current();
         self.table_push_background_channel();
         let result = f();
         self.table_pop_background_channel();
@@ -647,6 +827,7 @@ impl Ui {
 
     /// Run a closure after pushing a table column channel (auto-pop on return).
     pub fn with_table_column_channel<R>(&self, column_n: i32, f: impl FnOnce() -> R) -> R {
+        self.debug_assert_current();
         self.table_push_column_channel(column_n);
         let result = f();
         self.table_pop_column_channel();
@@ -656,6 +837,7 @@ impl Ui {
     /// Open the table context menu for a given column (use -1 for current/default).
     #[doc(alias = "TableOpenContextMenu")]
     pub fn table_open_context_menu(&self, column_n: Option<i32>) {
+        self.debug_assert_current();
         unsafe { sys::igTableOpenContextMenu(column_n.unwrap_or(-1)) }
     }
 }
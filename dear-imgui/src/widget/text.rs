@@ -34,6 +34,7 @@ impl Ui {
     /// ```
     #[doc(alias = "TextColored")]
     pub fn text_colored(&self, color: [f32; 4], text: impl AsRef<str>) {
+        self.debug_assert_current();
         let s = text.as_ref();
 
         // Temporarily set the text color
@@ -61,6 +62,7 @@ impl Ui {
     /// ```
     #[doc(alias = "TextDisabled")]
     pub fn text_disabled(&self, text: impl AsRef<str>) {
+        self.debug_assert_current();
         let s = text.as_ref();
 
         // Get the disabled color from the current style
@@ -85,6 +87,7 @@ impl Ui {
     /// Optimization for this function requires additional investigation.
     #[doc(alias = "TextWrapped")]
     pub fn text_wrapped(&self, text: impl AsRef<str>) {
+        self.debug_assert_current();
         let text_ptr = self.scratch_txt(text);
         unsafe {
             sys::igTextWrapped(text_ptr);
@@ -94,6 +97,7 @@ impl Ui {
     /// Display a label and text on the same line
     #[doc(alias = "LabelText")]
     pub fn label_text(&self, label: impl AsRef<str>, text: impl AsRef<str>) {
+        self.debug_assert_current();
         let (label_ptr, text_ptr) = self.scratch_txt_two(label, text);
         unsafe {
             sys::igLabelText(label_ptr, text_ptr);
@@ -103,13 +107,21 @@ impl Ui {
     /// Render a hyperlink-style text button. Returns true when clicked.
     #[doc(alias = "TextLink")]
     pub fn text_link(&self, label: impl AsRef<str>) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igTextLink(self.scratch_txt(label)) }
     }
 
     /// Render a hyperlink-style text button, and open the given URL when clicked.
     /// Returns true when clicked.
+    ///
+    /// The URL is opened through the platform backend's `Platform_OpenInShellFn`.
+    /// An empty `url` renders the same link but opens nothing, like [`text_link`](Self::text_link).
     #[doc(alias = "TextLinkOpenURL")]
     pub fn text_link_open_url(&self, label: impl AsRef<str>, url: impl AsRef<str>) -> bool {
+        self.debug_assert_current();
+        if url.as_ref().is_empty() {
+            return self.text_link(label);
+        }
         let (label_ptr, url_ptr) = self.scratch_txt_two(label, url);
         unsafe { sys::igTextLinkOpenURL(label_ptr, url_ptr) }
     }
@@ -33,6 +33,7 @@ impl Ui {
     /// ```
     #[doc(alias = "BeginTooltip", alias = "EndTooltip")]
     pub fn tooltip<F: FnOnce()>(&self, f: F) {
+        self.debug_assert_current();
         if let Some(_token) = self.begin_tooltip() {
             f();
         }
@@ -43,6 +44,7 @@ impl Ui {
     /// Returns a `TooltipToken` that must be ended by calling `.end()` or by dropping.
     #[doc(alias = "BeginTooltip")]
     pub fn begin_tooltip(&self) -> Option<TooltipToken<'_>> {
+        self.debug_assert_current();
         if unsafe { sys::igBeginTooltip() } {
             Some(TooltipToken::new(self))
         } else {
@@ -65,6 +67,7 @@ impl Ui {
     /// ```
     #[doc(alias = "BeginTooltip", alias = "EndTooltip", alias = "SetTooltip")]
     pub fn tooltip_text(&self, text: impl AsRef<str>) {
+        self.debug_assert_current();
         self.tooltip(|| self.text(text));
     }
 
@@ -72,6 +75,7 @@ impl Ui {
     /// This is more efficient than begin_tooltip/end_tooltip for simple text.
     #[doc(alias = "SetTooltip")]
     pub fn set_tooltip(&self, text: impl AsRef<str>) {
+        self.debug_assert_current();
         let text_ptr = self.scratch_txt(text);
         unsafe {
             sys::igSetTooltip(text_ptr);
@@ -81,6 +85,7 @@ impl Ui {
     /// Sets a tooltip with formatted text content.
     #[doc(alias = "SetTooltip")]
     pub fn set_tooltip_formatted(&self, text: impl AsRef<str>) {
+        self.debug_assert_current();
         self.set_tooltip(text);
     }
 
@@ -88,6 +93,7 @@ impl Ui {
     /// More efficient than building a tooltip window for simple cases.
     #[doc(alias = "SetItemTooltip")]
     pub fn set_item_tooltip(&self, text: impl AsRef<str>) {
+        self.debug_assert_current();
         let text_ptr = self.scratch_txt(text);
         unsafe { sys::igSetItemTooltip(text_ptr) }
     }
@@ -99,84 +105,132 @@ impl Ui {
     /// This is typically used to show tooltips.
     #[doc(alias = "IsItemHovered")]
     pub fn is_item_hovered(&self) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsItemHovered(crate::HoveredFlags::NONE.bits()) }
     }
 
     /// Returns true if the last item is being hovered by mouse with specific flags.
     #[doc(alias = "IsItemHovered")]
     pub fn is_item_hovered_with_flags(&self, flags: crate::HoveredFlags) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsItemHovered(flags.bits()) }
     }
 
     /// Returns true if the last item is active (e.g. button being held, text field being edited).
     #[doc(alias = "IsItemActive")]
     pub fn is_item_active(&self) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsItemActive() }
     }
 
     /// Returns true if the last item is focused (e.g. text input field).
     #[doc(alias = "IsItemFocused")]
     pub fn is_item_focused(&self) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsItemFocused() }
     }
 
     /// Returns true if the last item was just clicked.
     #[doc(alias = "IsItemClicked")]
     pub fn is_item_clicked(&self) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsItemClicked(crate::input::MouseButton::Left as i32) }
     }
 
     /// Returns true if the last item was clicked with specific mouse button.
     #[doc(alias = "IsItemClicked")]
     pub fn is_item_clicked_with_button(&self, mouse_button: MouseButton) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsItemClicked(mouse_button as i32) }
     }
 
     /// Returns true if the last item is visible (not clipped).
     #[doc(alias = "IsItemVisible")]
     pub fn is_item_visible(&self) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsItemVisible() }
     }
 
     /// Returns true if the last item was just made active (e.g. button was pressed).
     #[doc(alias = "IsItemActivated")]
     pub fn is_item_activated(&self) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsItemActivated() }
     }
 
     /// Returns true if the last item was just made inactive (e.g. button was released).
     #[doc(alias = "IsItemDeactivated")]
     pub fn is_item_deactivated(&self) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsItemDeactivated() }
     }
 
     /// Returns true if the last item was just made inactive and was edited.
     #[doc(alias = "IsItemDeactivatedAfterEdit")]
     pub fn is_item_deactivated_after_edit(&self) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsItemDeactivatedAfterEdit() }
     }
 
-    /// Returns true if any item is active.
+    /// Returns true if any item is active (a text field being edited, a slider being dragged, ...).
+    ///
+    /// Use it to keep application shortcuts from firing while the user interacts with a
+    /// widget. For text specifically, [`Io::want_text_input`](crate::Io::want_text_input)
+    /// is the more precise check, and [`Io::want_capture_keyboard`](crate::Io::want_capture_keyboard)
+    /// also covers keyboard navigation:
+    ///
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # let mut ctx = Context::create();
+    /// # let ui = ctx.frame();
+    /// let typing = ui.io().want_text_input() || ui.is_any_item_active();
+    /// if !typing && ui.is_key_pressed(Key::S) {
+    ///     // save
+    /// }
+    /// ```
     #[doc(alias = "IsAnyItemActive")]
     pub fn is_any_item_active(&self) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsAnyItemActive() }
     }
 
-    /// Returns true if any item is focused.
+    /// Returns true if any item is focused (including by keyboard/gamepad navigation).
     #[doc(alias = "IsAnyItemFocused")]
     pub fn is_any_item_focused(&self) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsAnyItemFocused() }
     }
 
     /// Returns true if any item is hovered.
     #[doc(alias = "IsAnyItemHovered")]
     pub fn is_any_item_hovered(&self) -> bool {
+        self.debug_assert_current();
         unsafe { sys::igIsAnyItemHovered() }
     }
 
+    /// Returns true if keyboard/gamepad navigation is currently allowed.
+    ///
+    /// Shortcut for [`Io::nav_active`](crate::Io::nav_active).
+    #[doc(alias = "NavActive")]
+    pub fn nav_active(&self) -> bool {
+        self.debug_assert_current();
+        self.io().nav_active()
+    }
+
+    /// Returns true if the navigation highlight is visible, i.e. the user is navigating
+    /// with the keyboard or gamepad.
+    ///
+    /// Shortcut for [`Io::nav_visible`](crate::Io::nav_visible).
+    #[doc(alias = "NavVisible")]
+    pub fn nav_visible(&self) -> bool {
+        self.debug_assert_current();
+        self.io().nav_visible()
+    }
+
     /// Gets the bounding rectangle of the last item in screen space.
     #[doc(alias = "GetItemRectMin", alias = "GetItemRectMax")]
     pub fn item_rect(&self) -> ([f32; 2], [f32; 2]) {
+        self.debug_assert_current();
         unsafe {
             let mut min = sys::ImVec2 { x: 0.0, y: 0.0 };
             let mut max = sys::ImVec2 { x: 0.0, y: 0.0 };
@@ -186,9 +240,19 @@ impl Ui {
         }
     }
 
+    /// Gets the bounding rectangle of the last item as `(min, max)` in screen space.
+    ///
+    /// Same as [`item_rect`](Self::item_rect).
+    #[doc(alias = "GetItemRectMin", alias = "GetItemRectMax")]
+    pub fn get_item_rect(&self) -> ([f32; 2], [f32; 2]) {
+        self.debug_assert_current();
+        self.item_rect()
+    }
+
     /// Gets the size of the last item.
     #[doc(alias = "GetItemRectSize")]
     pub fn item_rect_size(&self) -> [f32; 2] {
+        self.debug_assert_current();
         unsafe {
             let mut size = sys::ImVec2 { x: 0.0, y: 0.0 };
             sys::igGetItemRectSize(&mut size);
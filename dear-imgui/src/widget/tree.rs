@@ -55,6 +55,7 @@ impl Ui {
         I: Into<TreeNodeId<T>>,
         T: AsRef<str>,
     {
+        self.debug_assert_current();
         self.tree_node_config(id).push()
     }
 
@@ -68,6 +69,7 @@ impl Ui {
         I: Into<TreeNodeId<T>>,
         T: AsRef<str>,
     {
+        self.debug_assert_current();
         TreeNode {
             id: id.into(),
             label: None,
@@ -81,6 +83,7 @@ impl Ui {
     /// Creates a collapsing header widget
     #[doc(alias = "CollapsingHeader")]
     pub fn collapsing_header(&self, label: impl AsRef<str>, flags: TreeNodeFlags) -> bool {
+        self.debug_assert_current();
         let label_ptr = self.scratch_txt(label);
         unsafe { sys::igCollapsingHeader_TreeNodeFlags(label_ptr, flags.bits()) }
     }
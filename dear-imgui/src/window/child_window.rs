@@ -30,6 +30,7 @@ use std::ffi::CString;
 bitflags::bitflags! {
     /// Configuration flags for child windows
     #[repr(transparent)]
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
     pub struct ChildFlags: u32 {
         /// No flags
         const NONE = 0;
@@ -54,7 +55,39 @@ bitflags::bitflags! {
     }
 }
 
+/// Window flags that [`ChildWindow::scroll_flags`] controls
+const SCROLL_FLAGS: WindowFlags = WindowFlags::NO_SCROLLBAR
+    .union(WindowFlags::NO_SCROLL_WITH_MOUSE)
+    .union(WindowFlags::HORIZONTAL_SCROLLBAR)
+    .union(WindowFlags::ALWAYS_VERTICAL_SCROLLBAR)
+    .union(WindowFlags::ALWAYS_HORIZONTAL_SCROLLBAR);
+
 /// Represents a child window that can be built
+///
+/// A child window takes two sets of flags:
+///
+/// - [`ChildFlags`] describe the child region itself: border, padding, frame styling,
+///   resizing and auto-sizing. Use [`border`](Self::border), [`frame_style`](Self::frame_style)
+///   or [`child_flags`](Self::child_flags).
+/// - [`WindowFlags`] describe the window inside it, mainly scrolling and menu bars. Use
+///   [`scroll_flags`](Self::scroll_flags) or [`flags`](Self::flags).
+///
+/// Sizing belongs to the child flags: Dear ImGui asserts if `WindowFlags::ALWAYS_AUTO_RESIZE`
+/// is passed to a child, use `ChildFlags::AUTO_RESIZE_X | ChildFlags::AUTO_RESIZE_Y` instead.
+///
+/// ```no_run
+/// # use dear_imgui_rs::*;
+/// # let mut ctx = Context::create();
+/// # let ui = ctx.frame();
+/// // A log panel that looks like an input box and scrolls horizontally for long lines
+/// ui.child_window("log")
+///     .size([0.0, 200.0])
+///     .frame_style(true)
+///     .scroll_flags(WindowFlags::HORIZONTAL_SCROLLBAR | WindowFlags::ALWAYS_VERTICAL_SCROLLBAR)
+///     .build(ui, || {
+///         ui.text("[info] a very long log line that needs a horizontal scrollbar ...");
+///     });
+/// ```
 pub struct ChildWindow<'ui> {
     name: String,
     size: [f32; 2],
@@ -87,13 +120,35 @@ impl<'ui> ChildWindow<'ui> {
         self
     }
 
+    /// Draws the child like a framed item (an input box) instead of a child window.
+    ///
+    /// Uses `FrameBg`, `FrameRounding`, `FrameBorderSize` and `FramePadding` in place of
+    /// `ChildBg`, `ChildRounding`, `ChildBorderSize` and `WindowPadding`.
+    #[doc(alias = "ImGuiChildFlags_FrameStyle")]
+    pub fn frame_style(mut self, frame_style: bool) -> Self {
+        self.child_flags.set(ChildFlags::FRAME_STYLE, frame_style);
+        self
+    }
+
+    /// Sets the scrolling behavior of the child window.
+    ///
+    /// Only the scroll-related bits of `scroll_flags` are used (`NO_SCROLLBAR`,
+    /// `NO_SCROLL_WITH_MOUSE`, `HORIZONTAL_SCROLLBAR`, `ALWAYS_VERTICAL_SCROLLBAR` and
+    /// `ALWAYS_HORIZONTAL_SCROLLBAR`); they replace any previously set scroll flags while
+    /// other window flags are kept.
+    pub fn scroll_flags(mut self, scroll_flags: WindowFlags) -> Self {
+        self.flags = (self.flags - SCROLL_FLAGS) | (scroll_flags & SCROLL_FLAGS);
+        self
+    }
+
     /// Sets child flags for the child window
     pub fn child_flags(mut self, child_flags: ChildFlags) -> Self {
         self.child_flags = child_flags;
         self
     }
 
-    /// Sets window flags for the child window
+    /// Sets window flags for the child window, replacing any set by
+    /// [`scroll_flags`](Self::scroll_flags)
     pub fn flags(mut self, flags: WindowFlags) -> Self {
         self.flags = flags;
         self
@@ -161,6 +216,7 @@ impl<'ui> Drop for ChildWindowToken<'ui> {
 impl Ui {
     /// Creates a child window builder
     pub fn child_window(&self, name: impl Into<String>) -> ChildWindow<'_> {
+        self.debug_assert_current();
         ChildWindow::new(name)
     }
 }
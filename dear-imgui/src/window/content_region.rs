@@ -12,6 +12,7 @@ impl Ui {
     /// This is the size of the window minus decorations (title bar, scrollbars, etc.)
     #[doc(alias = "GetContentRegionAvail")]
     pub fn content_region_avail(&self) -> [f32; 2] {
+        self.debug_assert_current();
         unsafe {
             let mut size = sys::ImVec2 { x: 0.0, y: 0.0 };
             sys::igGetContentRegionAvail(&mut size);
@@ -23,6 +24,7 @@ impl Ui {
     ///
     /// This is equivalent to `content_region_avail()[0]`
     pub fn content_region_avail_width(&self) -> f32 {
+        self.debug_assert_current();
         self.content_region_avail()[0]
     }
 
@@ -30,6 +32,7 @@ impl Ui {
     ///
     /// This is equivalent to `content_region_avail()[1]`
     pub fn content_region_avail_height(&self) -> f32 {
+        self.debug_assert_current();
         self.content_region_avail()[1]
     }
 }
@@ -0,0 +1,156 @@
+//! Window layout persistence
+//!
+//! [`LayoutStore`] keeps the position and size of windows keyed by name in a plain
+//! `HashMap`, as an explicit alternative to the `.ini` settings blob for apps that
+//! keep their own (e.g. JSON) config. Restore a window before `Begin` and capture it
+//! from inside the window:
+//!
+//! ```no_run
+//! # use dear_imgui_rs::*;
+//! # let mut ctx = Context::create();
+//! # let ui = ctx.frame();
+//! let mut layouts = LayoutStore::new();
+//! layouts
+//!     .window(ui, "Inspector")
+//!     .build(|| {
+//!         ui.text("Position and size are remembered by the store");
+//!         layouts.capture(ui, "Inspector");
+//!     });
+//!
+//! if layouts.is_dirty() {
+//!     for (name, layout) in layouts.as_map() {
+//!         println!("{name}: pos={:?} size={:?}", layout.pos, layout.size);
+//!     }
+//!     layouts.mark_saved();
+//! }
+//! ```
+//!
+use crate::sys;
+use crate::{Condition, Ui, Window};
+use std::collections::HashMap;
+
+/// Saved position and size of a single window
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct WindowLayout {
+    /// Window position in screen space
+    pub pos: [f32; 2],
+    /// Window size
+    pub size: [f32; 2],
+}
+
+/// Per-window position/size storage keyed by window name.
+///
+/// The store never touches the filesystem: build it from a map loaded with your own
+/// config format ([`from_map`](Self::from_map)) and write [`as_map`](Self::as_map) back
+/// out whenever [`is_dirty`](Self::is_dirty) reports a change.
+#[derive(Clone, Debug, Default)]
+pub struct LayoutStore {
+    windows: HashMap<String, WindowLayout>,
+    dirty: bool,
+}
+
+impl LayoutStore {
+    /// Creates an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a store from previously saved layouts
+    pub fn from_map(windows: HashMap<String, WindowLayout>) -> Self {
+        Self {
+            windows,
+            dirty: false,
+        }
+    }
+
+    /// Returns the saved layouts
+    pub fn as_map(&self) -> &HashMap<String, WindowLayout> {
+        &self.windows
+    }
+
+    /// Consumes the store and returns the saved layouts
+    pub fn into_map(self) -> HashMap<String, WindowLayout> {
+        self.windows
+    }
+
+    /// Returns the saved layout of the window `name`, if any
+    pub fn get(&self, name: &str) -> Option<WindowLayout> {
+        self.windows.get(name).copied()
+    }
+
+    /// Stores `layout` for the window `name`
+    pub fn set(&mut self, name: impl Into<String>, layout: WindowLayout) {
+        let name = name.into();
+        if self.windows.get(&name) != Some(&layout) {
+            self.windows.insert(name, layout);
+            self.dirty = true;
+        }
+    }
+
+    /// Forgets the layout of the window `name`, returning it if present
+    pub fn remove(&mut self, name: &str) -> Option<WindowLayout> {
+        let removed = self.windows.remove(name);
+        self.dirty |= removed.is_some();
+        removed
+    }
+
+    /// Returns true if a layout changed since creation or the last [`mark_saved`](Self::mark_saved)
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    /// Clears the dirty flag after the layouts have been persisted
+    pub fn mark_saved(&mut self) {
+        self.dirty = false;
+    }
+
+    /// Applies the saved layout of `name` to the next window, if one exists.
+    ///
+    /// Call right before beginning the window. The layout is applied with
+    /// [`Condition::FirstUseEver`], like `.ini` settings, so the user can still move and
+    /// resize the window afterwards.
+    #[doc(alias = "SetNextWindowPos", alias = "SetNextWindowSize")]
+    pub fn restore(&self, _ui: &Ui, name: &str) -> bool {
+        let Some(layout) = self.get(name) else {
+            return false;
+        };
+        let cond = Condition::FirstUseEver as i32;
+        unsafe {
+            sys::igSetNextWindowPos(
+                sys::ImVec2::new(layout.pos[0], layout.pos[1]),
+                cond,
+                sys::ImVec2::new(0.0, 0.0),
+            );
+            sys::igSetNextWindowSize(sys::ImVec2::new(layout.size[0], layout.size[1]), cond);
+        }
+        true
+    }
+
+    /// Records the position and size of the current window under `name`.
+    ///
+    /// Call from inside the window, after `Begin`.
+    #[doc(alias = "GetWindowPos", alias = "GetWindowSize")]
+    pub fn capture(&mut self, ui: &Ui, name: impl Into<String>) {
+        let layout = WindowLayout {
+            pos: ui.window_pos(),
+            size: ui.window_size(),
+        };
+        self.set(name, layout);
+    }
+
+    /// Creates a window builder with the saved layout of `name` applied, if one exists.
+    ///
+    /// The builder does not borrow the store, so [`capture`](Self::capture) can be called
+    /// from the window's closure.
+    pub fn window<'ui>(&self, ui: &'ui Ui, name: impl Into<String>) -> Window<'ui> {
+        let name = name.into();
+        let layout = self.get(&name);
+        let window = Window::new(ui, name);
+        match layout {
+            Some(layout) => window
+                .position(layout.pos, Condition::FirstUseEver)
+                .size(layout.size, Condition::FirstUseEver),
+            None => window,
+        }
+    }
+}
@@ -21,6 +21,7 @@
 //! - `child_window` for scoped child areas
 //! - `content_region` for available size queries
 //! - `scroll` for reading and setting scroll positions
+//! - [`LayoutStore`] for persisting window positions and sizes in your own config
 //!
 //! Quick example (flags + size/pos conditions):
 //! ```no_run
@@ -50,8 +51,12 @@ use crate::{Condition, Ui};
 
 pub(crate) mod child_window;
 pub(crate) mod content_region;
+mod layout_store;
 pub(crate) mod scroll;
 
+pub use child_window::{ChildFlags, ChildWindow, ChildWindowToken};
+pub use layout_store::{LayoutStore, WindowLayout};
+
 // Window-focused/hovered helpers are available via utils.rs variants.
 // Window hovered/focused flag helpers are provided by crate::utils::HoveredFlags.
 
@@ -283,6 +288,101 @@ impl<'ui> Window<'ui> {
     }
 }
 
+/// Callback constraining the size of the next window, see
+/// [`Ui::set_next_window_size_constraints`]
+pub type SizeCallback = Box<dyn FnMut(&mut SizeCallbackData)>;
+
+/// Data passed to a window size constraint callback
+#[repr(transparent)]
+pub struct SizeCallbackData {
+    raw: sys::ImGuiSizeCallbackData,
+}
+
+impl SizeCallbackData {
+    /// Window position
+    pub fn pos(&self) -> [f32; 2] {
+        [self.raw.Pos.x, self.raw.Pos.y]
+    }
+
+    /// Window size before this resize
+    pub fn current_size(&self) -> [f32; 2] {
+        [self.raw.CurrentSize.x, self.raw.CurrentSize.y]
+    }
+
+    /// Size the window is about to take, already clamped to the min/max constraints
+    pub fn desired_size(&self) -> [f32; 2] {
+        [self.raw.DesiredSize.x, self.raw.DesiredSize.y]
+    }
+
+    /// Overrides the size the window will take
+    pub fn set_desired_size(&mut self, size: [f32; 2]) {
+        self.raw.DesiredSize = sys::ImVec2 {
+            x: size[0],
+            y: size[1],
+        };
+    }
+}
+
+unsafe extern "C" fn size_callback_trampoline(data: *mut sys::ImGuiSizeCallbackData) {
+    unsafe {
+        let callback = &mut *((*data).UserData as *mut SizeCallback);
+        callback(&mut *(data as *mut SizeCallbackData));
+    }
+}
+
+impl Ui {
+    /// Constrains the size of the next window to `size_min..=size_max`
+    ///
+    /// Use -1.0 on an axis to keep its current size. The optional callback runs when the next
+    /// window is sized and can adjust the final size further, e.g. to keep an aspect ratio:
+    ///
+    /// ```no_run
+    /// # use dear_imgui_rs::*;
+    /// # fn f(ui: &Ui) {
+    /// ui.set_next_window_size_constraints(
+    ///     [100.0, 100.0],
+    ///     [f32::MAX, f32::MAX],
+    ///     Some(Box::new(|data: &mut SizeCallbackData| {
+    ///         let [w, _] = data.desired_size();
+    ///         data.set_desired_size([w, w * 9.0 / 16.0]);
+    ///     })),
+    /// );
+    /// ui.window("Video").build(|| {});
+    /// # }
+    /// ```
+    ///
+    /// The callback is kept alive until the next call to this method.
+    #[doc(alias = "SetNextWindowSizeConstraints")]
+    pub fn set_next_window_size_constraints(
+        &self,
+        size_min: [f32; 2],
+        size_max: [f32; 2],
+        callback: Option<SizeCallback>,
+    ) {
+        self.debug_assert_current();
+        // Replacing the stored callback also replaces the pointer ImGui holds, so the one
+        // being dropped can no longer be called
+        let slot = unsafe { &mut *self.size_callback.get() };
+        *slot = callback.map(Box::new);
+        let (custom_callback, user_data): (sys::ImGuiSizeCallback, *mut std::os::raw::c_void) =
+            match slot {
+                Some(callback) => (
+                    Some(size_callback_trampoline),
+                    &mut **callback as *mut SizeCallback as *mut _,
+                ),
+                None => (None, std::ptr::null_mut()),
+            };
+        unsafe {
+            sys::igSetNextWindowSizeConstraints(
+                size_min.into(),
+                size_max.into(),
+                custom_callback,
+                user_data,
+            )
+        }
+    }
+}
+
 /// Token representing an active window
 pub struct WindowToken<'ui> {
     _phantom: std::marker::PhantomData<&'ui ()>,
@@ -10,30 +10,35 @@ impl Ui {
     /// Returns the current scroll position of the window
     #[doc(alias = "GetScrollX")]
     pub fn scroll_x(&self) -> f32 {
+        self.debug_assert_current();
         unsafe { sys::igGetScrollX() }
     }
 
     /// Returns the current vertical scroll position of the window
     #[doc(alias = "GetScrollY")]
     pub fn scroll_y(&self) -> f32 {
+        self.debug_assert_current();
         unsafe { sys::igGetScrollY() }
     }
 
     /// Returns the maximum horizontal scroll position
     #[doc(alias = "GetScrollMaxX")]
     pub fn scroll_max_x(&self) -> f32 {
+        self.debug_assert_current();
         unsafe { sys::igGetScrollMaxX() }
     }
 
     /// Returns the maximum vertical scroll position
     #[doc(alias = "GetScrollMaxY")]
     pub fn scroll_max_y(&self) -> f32 {
+        self.debug_assert_current();
         unsafe { sys::igGetScrollMaxY() }
     }
 
     /// Sets the horizontal scroll position
     #[doc(alias = "SetScrollX")]
     pub fn set_scroll_x(&self, scroll_x: f32) {
+        self.debug_assert_current();
         unsafe {
             sys::igSetScrollX_Float(scroll_x);
         }
@@ -42,6 +47,7 @@ impl Ui {
     /// Sets the vertical scroll position
     #[doc(alias = "SetScrollY")]
     pub fn set_scroll_y(&self, scroll_y: f32) {
+        self.debug_assert_current();
         unsafe {
             sys::igSetScrollY_Float(scroll_y);
         }
@@ -52,6 +58,7 @@ impl Ui {
     /// The center_x_ratio parameter should be between 0.0 (left) and 1.0 (right)
     #[doc(alias = "SetScrollFromPosX")]
     pub fn set_scroll_from_pos_x(&self, local_x: f32, center_x_ratio: f32) {
+        self.debug_assert_current();
         unsafe {
             sys::igSetScrollFromPosX_Float(local_x, center_x_ratio);
         }
@@ -62,6 +69,7 @@ impl Ui {
     /// The center_y_ratio parameter should be between 0.0 (top) and 1.0 (bottom)
     #[doc(alias = "SetScrollFromPosY")]
     pub fn set_scroll_from_pos_y(&self, local_y: f32, center_y_ratio: f32) {
+        self.debug_assert_current();
         unsafe {
             sys::igSetScrollFromPosY_Float(local_y, center_y_ratio);
         }
@@ -72,6 +80,7 @@ impl Ui {
     /// This is useful when you want to ensure a specific item is visible in a scrollable region
     #[doc(alias = "SetScrollHereX")]
     pub fn set_scroll_here_x(&self, center_x_ratio: f32) {
+        self.debug_assert_current();
         unsafe {
             sys::igSetScrollHereX(center_x_ratio);
         }
@@ -82,6 +91,7 @@ impl Ui {
     /// This is useful when you want to ensure a specific item is visible in a scrollable region
     #[doc(alias = "SetScrollHereY")]
     pub fn set_scroll_here_y(&self, center_y_ratio: f32) {
+        self.debug_assert_current();
         unsafe {
             sys::igSetScrollHereY(center_y_ratio);
         }
@@ -0,0 +1,206 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{
+    CanvasState, CanvasTransform, Condition, Context, FlameGraphState, FlameSpan, MouseButton,
+    ToastKind, Toasts, WindowFlags,
+};
+use std::time::Duration;
+
+const EPS: f32 = 1e-3;
+
+/// Runs one frame with a 400x300 canvas in an undecorated window at the origin
+fn canvas_frame(ctx: &mut Context, state: &mut CanvasState) -> CanvasTransform {
+    let mut transform = None;
+    {
+        let ui = ctx.frame();
+        ui.window("host")
+            .position([0.0, 0.0], Condition::Always)
+            .size([500.0, 400.0], Condition::Always)
+            .flags(WindowFlags::NO_DECORATION | WindowFlags::NO_MOVE)
+            .build(|| {
+                transform = Some(
+                    ui.canvas("##canvas", state)
+                        .size([400.0, 300.0])
+                        .build(|_| {}),
+                );
+            });
+    }
+    ctx.render();
+    transform.expect("canvas was not built")
+}
+
+fn assert_close(a: [f32; 2], b: [f32; 2]) {
+    assert!(
+        (a[0] - b[0]).abs() < EPS && (a[1] - b[1]).abs() < EPS,
+        "{a:?} != {b:?}"
+    );
+}
+
+#[test]
+fn transform_round_trips() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let mut state = CanvasState {
+        offset: [25.0, -10.0],
+        zoom: 2.5,
+    };
+    let t = canvas_frame(&mut ctx, &mut state);
+
+    let p = [13.0, -7.5];
+    assert_close(t.screen_to_world(t.world_to_screen(p)), p);
+    let (min, _) = t.rect();
+    assert_close(t.origin(), [min[0] + 25.0, min[1] - 10.0]);
+    assert_eq!(t.world_to_screen_len(4.0), 10.0);
+}
+
+#[test]
+fn wheel_zooms_around_cursor() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let mut state = CanvasState::new();
+    let mouse = [150.0, 120.0];
+
+    ctx.io_mut().add_mouse_pos_event(mouse);
+    let before = canvas_frame(&mut ctx, &mut state);
+    let world_under_mouse = before.screen_to_world(mouse);
+
+    ctx.io_mut().add_mouse_wheel_event([0.0, 1.0]);
+    let _ = canvas_frame(&mut ctx, &mut state);
+    assert!(state.zoom > 1.0, "wheel up should zoom in");
+
+    let after = canvas_frame(&mut ctx, &mut state);
+    assert_close(after.screen_to_world(mouse), world_under_mouse);
+}
+
+#[test]
+fn pan_button_drag_moves_offset() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let mut state = CanvasState::new();
+
+    ctx.io_mut().add_mouse_pos_event([100.0, 100.0]);
+    canvas_frame(&mut ctx, &mut state);
+    ctx.io_mut()
+        .add_mouse_button_event(MouseButton::Right, true);
+    canvas_frame(&mut ctx, &mut state);
+    ctx.io_mut().add_mouse_pos_event([130.0, 90.0]);
+    canvas_frame(&mut ctx, &mut state);
+
+    assert_close(state.offset, [30.0, -10.0]);
+    assert_eq!(state.zoom, 1.0);
+}
+
+#[test]
+#[should_panic(expected = "`spinner` draws with the window draw list")]
+fn self_drawing_widgets_inside_a_canvas_panic_clearly() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let mut state = CanvasState::default();
+    let ui = ctx.frame();
+    ui.window("host").build(|| {
+        ui.canvas("##canvas", &mut state)
+            .size([200.0, 200.0])
+            .build(|_| ui.spinner("##busy", 10.0, 2.0, [1.0, 1.0, 1.0, 1.0]));
+    });
+}
+
+#[test]
+#[should_panic(expected = "`knob` draws with the window draw list")]
+fn knob_inside_a_canvas_panics_clearly() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let mut state = CanvasState::default();
+    let mut gain = 0.5;
+    let ui = ctx.frame();
+    ui.window("host").build(|| {
+        ui.canvas("##canvas", &mut state)
+            .size([200.0, 200.0])
+            .build(|_| {
+                let _ = ui.knob("Gain", &mut gain, 0.0, 1.0).build();
+            });
+    });
+}
+
+#[test]
+#[should_panic(expected = "`toggle` draws with the window draw list")]
+fn toggle_inside_a_canvas_panics_clearly() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let mut state = CanvasState::default();
+    let mut enabled = false;
+    let ui = ctx.frame();
+    ui.window("host").build(|| {
+        ui.canvas("##canvas", &mut state)
+            .size([200.0, 200.0])
+            .build(|_| {
+                ui.toggle("Enabled", &mut enabled);
+            });
+    });
+}
+
+#[test]
+#[should_panic(expected = "`toasts` draws with the window draw list")]
+fn toasts_inside_a_canvas_panic_clearly() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let mut state = CanvasState::default();
+    let mut toasts = Toasts::new();
+    toasts.push(ToastKind::Info, "Saved", "", Duration::from_secs(60));
+    let ui = ctx.frame();
+    ui.window("host").build(|| {
+        ui.canvas("##canvas", &mut state)
+            .size([200.0, 200.0])
+            .build(|_| toasts.render(ui));
+    });
+}
+
+#[test]
+#[should_panic(expected = "`canvas` draws with the window draw list")]
+fn nested_canvas_panics_clearly() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let (mut outer, mut inner) = (CanvasState::default(), CanvasState::default());
+    let ui = ctx.frame();
+    ui.window("host").build(|| {
+        ui.canvas("##outer", &mut outer)
+            .size([200.0, 200.0])
+            .build(|_| {
+                ui.canvas("##inner", &mut inner)
+                    .size([50.0, 50.0])
+                    .build(|_| {});
+            });
+    });
+}
+
+#[test]
+#[should_panic(expected = "`flame_graph` draws with the window draw list")]
+fn flame_graph_inside_a_canvas_panics_clearly() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let mut state = CanvasState::default();
+    let mut graph = FlameGraphState::new();
+    let spans = [FlameSpan::new("frame", 0.0, 16.0, 0)];
+    let ui = ctx.frame();
+    ui.window("host").build(|| {
+        ui.canvas("##canvas", &mut state)
+            .size([200.0, 200.0])
+            .build(|_| {
+                ui.flame_graph("##profile", &spans, &mut graph).build();
+            });
+    });
+}
+
+#[test]
+#[should_panic(expected = "`group_bordered` draws with the window draw list")]
+fn bordered_group_inside_a_canvas_panics_clearly() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let mut state = CanvasState::default();
+    let ui = ctx.frame();
+    ui.window("host").build(|| {
+        ui.canvas("##canvas", &mut state)
+            .size([200.0, 200.0])
+            .build(|_| {
+                ui.group_bordered(|| ui.text("inside"));
+            });
+    });
+}
@@ -0,0 +1,46 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{ChildFlags, WindowFlags, sys};
+
+/// Builds a child inside a window with `configure` applied and returns the
+/// `(window flags, child flags)` Dear ImGui recorded for it
+fn child_flags_for(
+    configure: impl FnOnce(dear_imgui_rs::ChildWindow<'_>) -> dear_imgui_rs::ChildWindow<'_>,
+) -> (i32, i32) {
+    let _guard = test_guard();
+
+    let mut ctx = headless_context();
+
+    let mut recorded = None;
+    {
+        let ui = ctx.frame();
+        ui.window("parent").build(|| {
+            configure(ui.child_window("child").size([200.0, 100.0])).build(ui, || {
+                let window = unsafe { &*sys::igGetCurrentWindow() };
+                recorded = Some((window.Flags, window.ChildFlags));
+            });
+        });
+    }
+    ctx.render();
+    recorded.expect("child window was not shown")
+}
+
+#[test]
+fn frame_style_sets_child_flag() {
+    let (_, child) = child_flags_for(|c| c.frame_style(true));
+    assert_ne!(child & ChildFlags::FRAME_STYLE.bits() as i32, 0);
+
+    let (_, child) = child_flags_for(|c| c.frame_style(true).frame_style(false));
+    assert_eq!(child & ChildFlags::FRAME_STYLE.bits() as i32, 0);
+}
+
+#[test]
+fn scroll_flags_only_touch_scroll_bits() {
+    let (window, _) = child_flags_for(|c| {
+        c.flags(WindowFlags::MENU_BAR | WindowFlags::NO_SCROLLBAR)
+            .scroll_flags(WindowFlags::HORIZONTAL_SCROLLBAR | WindowFlags::NO_TITLE_BAR)
+    });
+    let window = WindowFlags::from_bits_truncate(window);
+    assert!(window.contains(WindowFlags::MENU_BAR));
+    assert!(window.contains(WindowFlags::HORIZONTAL_SCROLLBAR));
+    assert!(!window.contains(WindowFlags::NO_SCROLLBAR));
+}
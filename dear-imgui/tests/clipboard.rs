@@ -0,0 +1,31 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn handlers_route_clipboard_through_closures() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+
+    let buffer = Rc::new(RefCell::new(Some("pasted".to_string())));
+    let (get_buf, set_buf) = (buffer.clone(), buffer.clone());
+    ctx.set_clipboard_handlers(
+        move || get_buf.borrow().clone(),
+        move |text| *set_buf.borrow_mut() = Some(text.to_string()),
+    );
+
+    {
+        let ui = ctx.frame();
+        assert_eq!(ui.clipboard_text().as_deref(), Some("pasted"));
+        ui.set_clipboard_text("copied");
+        assert_eq!(buffer.borrow().as_deref(), Some("copied"));
+
+        // Text past an embedded nul is dropped instead of aborting
+        *buffer.borrow_mut() = Some("before\0after".to_string());
+        assert_eq!(ui.clipboard_text().as_deref(), Some("before"));
+
+        *buffer.borrow_mut() = None;
+        assert_eq!(ui.clipboard_text(), None);
+    }
+    ctx.render();
+}
@@ -0,0 +1,67 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{ColorEditFlags, sys};
+
+#[test]
+fn flags_set_inserts_and_removes() {
+    let mut flags = ColorEditFlags::ALPHA_BAR;
+    flags.set(ColorEditFlags::HDR, true);
+    assert!(flags.contains(ColorEditFlags::ALPHA_BAR | ColorEditFlags::HDR));
+    flags.set(ColorEditFlags::ALPHA_BAR, false);
+    assert_eq!(flags, ColorEditFlags::HDR);
+}
+
+#[test]
+fn set_color_edit_options_updates_the_defaults() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+
+    let options = ColorEditFlags::DISPLAY_HEX
+        | ColorEditFlags::FLOAT
+        | ColorEditFlags::PICKER_HUE_WHEEL
+        | ColorEditFlags::INPUT_HSV;
+    {
+        let ui = ctx.frame();
+        ui.set_color_edit_options(options);
+    }
+    ctx.render();
+
+    let stored = unsafe { (*sys::igGetCurrentContext()).ColorEditOptions };
+    assert_eq!(stored as u32, options.bits());
+}
+
+#[test]
+fn exclusive_options_replace_each_other() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+
+    let mut hdr = [2.5f32, 0.5, 0.25, 1.0];
+    let mut rgb = [0.1f32, 0.2, 0.3];
+    for _ in 0..2 {
+        let ui = ctx.frame();
+        ui.window("theme").build(|| {
+            // Only the last choice of each group reaches ImGui, which asserts on mixes
+            let edited = ui
+                .color_edit4_config("accent", &mut hdr)
+                .display_rgb()
+                .display_hex()
+                .input_hsv()
+                .input_rgb()
+                .picker_hue_bar()
+                .picker_hue_wheel()
+                .alpha_bar(true)
+                .alpha_preview_half(true)
+                .hdr(true)
+                .build();
+            assert!(!edited);
+            assert!(
+                !ui.color_edit3_config("base", &mut rgb)
+                    .display_hsv()
+                    .no_inputs(true)
+                    .build()
+            );
+        });
+        ctx.render();
+    }
+    // HDR values above 1.0 survive untouched
+    assert_eq!(hdr, [2.5, 0.5, 0.25, 1.0]);
+}
@@ -0,0 +1,48 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{ComboBox, ComboBoxFlags, Condition, Context, MouseButton, WindowFlags};
+
+/// Runs one frame with two combos at the top of an undecorated window, returning which
+/// closures ran
+fn combo_frame(ctx: &mut Context) -> (Option<&'static str>, Option<usize>) {
+    let mut results = (None, None);
+    {
+        let ui = ctx.frame();
+        ui.window("host")
+            .position([0.0, 0.0], Condition::Always)
+            .size([300.0, 300.0], Condition::Always)
+            .flags(WindowFlags::NO_DECORATION | WindowFlags::NO_MOVE)
+            .build(|| {
+                ui.set_next_item_width(200.0);
+                results.0 = ui.combo_box("font", "Sans", ComboBoxFlags::NONE, || {
+                    ui.text_disabled("Proportional");
+                    ui.selectable("Sans");
+                    ui.separator();
+                    ui.selectable("Mono");
+                    "open"
+                });
+                let preview = String::from("none");
+                results.1 = ComboBox::new(ui, "other")
+                    .preview_value(preview)
+                    .flags(ComboBoxFlags::HEIGHT_SMALL)
+                    .build(|| 1);
+            });
+    }
+    ctx.render();
+    results
+}
+
+#[test]
+fn combo_box_closure_runs_only_while_open() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+
+    assert_eq!(combo_frame(&mut ctx), (None, None));
+
+    ctx.io_mut().add_mouse_pos_event([50.0, 15.0]);
+    combo_frame(&mut ctx);
+    ctx.io_mut().add_mouse_button_event(MouseButton::Left, true);
+    combo_frame(&mut ctx);
+    ctx.io_mut()
+        .add_mouse_button_event(MouseButton::Left, false);
+    assert_eq!(combo_frame(&mut ctx), (Some("open"), None));
+}
@@ -0,0 +1,73 @@
+use dear_imgui_rs::test_harness::headless_context;
+use dear_imgui_rs::{CompactStyle, StyleColor, StyleVar};
+
+#[test]
+fn compact_scope_pushes_preset_and_restores_style() {
+    let mut ctx = headless_context();
+
+    let ui = ctx.frame();
+    let before = ui.clone_style();
+
+    let preset = CompactStyle::default();
+    let value = ui.compact_scope(|| {
+        let style = ui.clone_style();
+        assert_eq!(style.item_spacing(), preset.item_spacing);
+        assert_eq!(style.frame_padding(), preset.frame_padding);
+        assert_eq!(style.window_padding(), preset.window_padding);
+        42
+    });
+    assert_eq!(value, 42);
+
+    let custom = CompactStyle {
+        item_spacing: [1.0, 1.0],
+        ..CompactStyle::default()
+    };
+    ui.compact_scope_with(custom, || {
+        assert_eq!(ui.clone_style().item_spacing(), [1.0, 1.0]);
+    });
+
+    let after = ui.clone_style();
+    assert_eq!(after.item_spacing(), before.item_spacing());
+    assert_eq!(after.frame_padding(), before.frame_padding());
+    assert_eq!(after.window_padding(), before.window_padding());
+}
+
+#[test]
+fn batched_style_pushes_pop_together() {
+    let mut ctx = headless_context();
+
+    let ui = ctx.frame();
+    let before = ui.clone_style();
+
+    let red = [1.0, 0.0, 0.0, 1.0];
+    let colors = ui.push_style_colors([(StyleColor::Text, red), (StyleColor::Button, red)]);
+    let vars = ui.push_style_vars([StyleVar::Alpha(0.5), StyleVar::ItemSpacing([1.0, 2.0])]);
+    let style = ui.clone_style();
+    assert_eq!(style.color(StyleColor::Text), red);
+    assert_eq!(style.color(StyleColor::Button), red);
+    assert_eq!(style.alpha(), 0.5);
+    assert_eq!(style.item_spacing(), [1.0, 2.0]);
+
+    // An early return inside the scope still pops every push
+    let nested = || -> Option<()> {
+        let _extra = ui.push_style_vars([StyleVar::Alpha(0.1)]);
+        None?;
+        Some(())
+    };
+    assert!(nested().is_none());
+    assert_eq!(ui.clone_style().alpha(), 0.5);
+
+    vars.pop();
+    colors.pop();
+    let after = ui.clone_style();
+    assert_eq!(
+        after.color(StyleColor::Text),
+        before.color(StyleColor::Text)
+    );
+    assert_eq!(
+        after.color(StyleColor::Button),
+        before.color(StyleColor::Button)
+    );
+    assert_eq!(after.alpha(), before.alpha());
+    assert_eq!(after.item_spacing(), before.item_spacing());
+}
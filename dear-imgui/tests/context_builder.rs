@@ -0,0 +1,58 @@
+use dear_imgui_rs::test_harness::test_guard;
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe extern "C" fn counting_alloc(size: usize, _user_data: *mut c_void) -> *mut c_void {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    unsafe { libc_malloc(size) }
+}
+
+unsafe extern "C" fn counting_free(ptr: *mut c_void, _user_data: *mut c_void) {
+    unsafe { libc_free(ptr) }
+}
+
+unsafe extern "C" {
+    #[link_name = "malloc"]
+    fn libc_malloc(size: usize) -> *mut c_void;
+    #[link_name = "free"]
+    fn libc_free(ptr: *mut c_void);
+}
+
+#[test]
+fn builder_applies_settings_and_allocator() {
+    let _guard = test_guard();
+
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    let ctx = unsafe {
+        dear_imgui_rs::Context::builder()
+            .allocator(counting_alloc, counting_free, std::ptr::null_mut())
+            .no_ini()
+            .log_filename(Some("builder_test.log"))
+            .config_flags(dear_imgui_rs::ConfigFlags::NAV_ENABLE_KEYBOARD)
+    }
+    .build()
+    .expect("builder should create a context");
+
+    assert!(ALLOCATIONS.load(Ordering::Relaxed) > before);
+    let io = unsafe { &*dear_imgui_rs::sys::igGetIO_Nil() };
+    assert!(io.IniFilename.is_null());
+    assert!(!io.LogFilename.is_null());
+    assert!(
+        ctx.io()
+            .config_flags()
+            .contains(dear_imgui_rs::ConfigFlags::NAV_ENABLE_KEYBOARD)
+    );
+}
+
+#[test]
+fn builder_rejects_active_context() {
+    let _guard = test_guard();
+
+    let _ctx = dear_imgui_rs::Context::create();
+    assert!(matches!(
+        dear_imgui_rs::Context::builder().build(),
+        Err(dear_imgui_rs::ImGuiError::ContextAlreadyActive)
+    ));
+}
@@ -0,0 +1,66 @@
+use dear_imgui_rs::Condition;
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+
+const FOREGROUND: [f32; 4] = [1.0, 0.0, 0.0, 1.0];
+const BACKGROUND: [f32; 4] = [0.0, 0.0, 1.0, 1.0];
+// Packed as 0xAABBGGRR in vertices
+const FOREGROUND_BITS: u32 = 0xFF00_00FF;
+const BACKGROUND_BITS: u32 = 0xFFFF_0000;
+
+#[test]
+fn lower_channel_is_drawn_first_after_merge() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+
+    {
+        let ui = ctx.frame();
+        ui.window("nodes")
+            .position([0.0, 0.0], Condition::Always)
+            .size([300.0, 300.0], Condition::Always)
+            .build(|| {
+                let draw_list = ui.get_window_draw_list();
+                let channels = draw_list.begin_channels_split(2);
+                // Node content first, on top...
+                channels.set_current(1);
+                draw_list
+                    .add_rect([20.0, 20.0], [60.0, 60.0], FOREGROUND)
+                    .filled(true)
+                    .build();
+                // ...then its background, behind it
+                channels.set_current(0);
+                draw_list
+                    .add_rect([10.0, 10.0], [70.0, 70.0], BACKGROUND)
+                    .filled(true)
+                    .build();
+                channels.merge();
+
+                // Splitting again only works if the first split was merged exactly once
+                let channels = draw_list.begin_channels_split(2);
+                channels.set_current(1);
+            });
+    }
+
+    let draw_data = ctx.render();
+    let first_vertex_of = |bits: u32| {
+        draw_data
+            .draw_lists()
+            .find_map(|list| list.vtx_buffer().iter().position(|v| v.col == bits))
+            .expect("color was drawn")
+    };
+    assert!(first_vertex_of(BACKGROUND_BITS) < first_vertex_of(FOREGROUND_BITS));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "out of range")]
+fn set_current_rejects_out_of_range_channel() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+
+    let ui = ctx.frame();
+    ui.window("nodes").build(|| {
+        let draw_list = ui.get_window_draw_list();
+        let channels = draw_list.begin_channels_split(2);
+        channels.set_current(2);
+    });
+}
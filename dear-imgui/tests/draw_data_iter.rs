@@ -0,0 +1,59 @@
+use dear_imgui_rs::Condition;
+use dear_imgui_rs::internal::RawWrapper;
+use dear_imgui_rs::render::DrawCmd;
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+#[test]
+fn commands_split_elements_callbacks_and_resets() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+
+    let ran = Arc::new(AtomicBool::new(false));
+    {
+        let ui = ctx.frame();
+        ui.window("canvas")
+            .position([0.0, 0.0], Condition::Always)
+            .size([300.0, 200.0], Condition::Always)
+            .build(|| {
+                ui.text("before");
+                let draw_list = ui.get_window_draw_list();
+                let flag = ran.clone();
+                draw_list
+                    .add_callback_safe(move || flag.store(true, Ordering::SeqCst))
+                    .build();
+                draw_list.add_reset_render_state();
+                ui.text("after");
+            });
+    }
+    let draw_data = ctx.render_headless();
+
+    let (mut elements, mut callbacks, mut resets) = (0, 0, 0);
+    for draw_list in draw_data.draw_lists() {
+        let (vtx, idx) = (draw_list.vtx_buffer(), draw_list.idx_buffer());
+        for cmd in draw_list.commands() {
+            match cmd {
+                DrawCmd::Elements {
+                    count, cmd_params, ..
+                } => {
+                    elements += 1;
+                    assert!(cmd_params.idx_offset + count <= idx.len());
+                    let range = cmd_params.idx_offset..cmd_params.idx_offset + count;
+                    for &i in &idx[range] {
+                        assert!(cmd_params.vtx_offset + (i as usize) < vtx.len());
+                    }
+                    assert!(!cmd_params.texture_id.is_null());
+                }
+                DrawCmd::ResetRenderState => resets += 1,
+                DrawCmd::RawCallback { callback, raw_cmd } => {
+                    callbacks += 1;
+                    unsafe { callback(draw_list.raw(), raw_cmd) };
+                }
+            }
+        }
+    }
+    assert!(elements >= 2, "{elements}");
+    assert_eq!((callbacks, resets), (1, 1));
+    assert!(ran.load(Ordering::SeqCst));
+}
@@ -0,0 +1,68 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{Context, DrawFlags, DrawListMut, TextureId};
+
+const TEXTURE: TextureId = TextureId::new(7);
+const WHITE: [f32; 4] = [1.0, 1.0, 1.0, 1.0];
+
+/// Renders one frame where `draw` adds to the background draw list, returning the number
+/// of vertices it added
+fn added_vertices(ctx: &mut Context, draw: impl FnOnce(&DrawListMut<'_>)) -> i32 {
+    let _ = ctx.frame();
+    let empty = ctx.render().total_vtx_count;
+    {
+        let ui = ctx.frame();
+        draw(&ui.get_background_draw_list());
+    }
+    ctx.render().total_vtx_count - empty
+}
+
+#[test]
+fn image_quad_adds_one_quad() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+
+    let added = added_vertices(&mut ctx, |draw_list| {
+        draw_list.add_image_quad(
+            TEXTURE,
+            [10.0, 10.0],
+            [110.0, 20.0],
+            [120.0, 120.0],
+            [5.0, 100.0],
+            [0.0, 0.0],
+            [1.0, 0.0],
+            [1.0, 1.0],
+            [0.0, 1.0],
+            WHITE,
+        );
+    });
+    assert_eq!(added, 4);
+}
+
+#[test]
+fn rounded_image_adds_corner_vertices() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+
+    let rounded = |rounding: f32, flags: DrawFlags| {
+        move |draw_list: &DrawListMut<'_>| {
+            draw_list.add_image_rounded(
+                TEXTURE,
+                [10.0, 10.0],
+                [110.0, 110.0],
+                [0.0, 0.0],
+                [1.0, 1.0],
+                WHITE,
+                rounding,
+                flags,
+            );
+        }
+    };
+    // Without rounding this is a plain image
+    assert_eq!(
+        added_vertices(&mut ctx, rounded(0.0, DrawFlags::ROUND_CORNERS_ALL)),
+        4
+    );
+    let all = added_vertices(&mut ctx, rounded(16.0, DrawFlags::ROUND_CORNERS_ALL));
+    let top = added_vertices(&mut ctx, rounded(16.0, DrawFlags::ROUND_CORNERS_TOP));
+    assert!(all > top && top > 4, "{all} {top}");
+}
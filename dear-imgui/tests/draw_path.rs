@@ -0,0 +1,68 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{Condition, Context, DrawFlags, DrawListMut};
+
+const RED: [f32; 4] = [1.0, 0.0, 0.0, 1.0];
+
+/// Renders one frame where `draw` adds to the window draw list, returning the total
+/// vertex count of the frame
+fn vertex_count(ctx: &mut Context, draw: impl FnOnce(&DrawListMut<'_>)) -> i32 {
+    {
+        let ui = ctx.frame();
+        ui.window("canvas")
+            .position([0.0, 0.0], Condition::Always)
+            .size([300.0, 300.0], Condition::Always)
+            .build(|| draw(&ui.get_window_draw_list()));
+    }
+    ctx.render().total_vtx_count
+}
+
+fn triangle(draw_list: &DrawListMut<'_>) {
+    draw_list.path_clear();
+    draw_list.path_line_to([50.0, 50.0]);
+    draw_list.path_line_to([150.0, 50.0]);
+    draw_list.path_line_to([100.0, 120.0]);
+}
+
+#[test]
+fn filled_triangle_path_adds_vertices() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    // The window's first frame is not representative
+    vertex_count(&mut ctx, |_| {});
+
+    let empty = vertex_count(&mut ctx, |_| {});
+    let filled = vertex_count(&mut ctx, |draw_list| {
+        triangle(draw_list);
+        draw_list.path_fill_convex(RED);
+    });
+    assert!(filled > empty, "{filled} <= {empty}");
+
+    // A path left unfilled and unstroked draws nothing
+    assert_eq!(vertex_count(&mut ctx, triangle), empty);
+}
+
+#[test]
+fn stroke_consumes_the_path() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    vertex_count(&mut ctx, |_| {});
+
+    let stroked = vertex_count(&mut ctx, |draw_list| {
+        triangle(draw_list);
+        draw_list.path_stroke(RED, DrawFlags::CLOSED, 2.0);
+    });
+    let stroked_then_filled = vertex_count(&mut ctx, |draw_list| {
+        triangle(draw_list);
+        draw_list.path_stroke(RED, DrawFlags::CLOSED, 2.0);
+        draw_list.path_fill_convex(RED);
+    });
+    assert_eq!(stroked, stroked_then_filled);
+
+    let with_arc = vertex_count(&mut ctx, |draw_list| {
+        triangle(draw_list);
+        draw_list.path_arc_to([100.0, 120.0], 30.0, 0.0, std::f32::consts::PI, 12);
+        draw_list.path_bezier_cubic_curve_to([80.0, 160.0], [60.0, 100.0], [50.0, 50.0], 8);
+        draw_list.path_stroke(RED, DrawFlags::CLOSED, 2.0);
+    });
+    assert!(with_arc > stroked, "{with_arc} <= {stroked}");
+}
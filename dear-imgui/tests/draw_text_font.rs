@@ -0,0 +1,55 @@
+use dear_imgui_rs::Condition;
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+
+/// Text color used to pick the label's vertices; packed by ImGui as 0xAABBGGRR
+const LABEL: [f32; 4] = [1.0, 0.0, 1.0, 1.0];
+const LABEL_PACKED: u32 = 0xFF_FF_00_FF;
+
+/// Renders "Wide label" at `font_size` with the default font looked up by id and returns
+/// the horizontal extent of its glyph quads
+fn label_width(font_size: f32) -> f32 {
+    let _guard = test_guard();
+
+    let mut ctx = headless_context();
+
+    {
+        let ui = ctx.frame();
+        ui.window("canvas")
+            .position([0.0, 0.0], Condition::Always)
+            .size([600.0, 400.0], Condition::Always)
+            .build(|| {
+                let font = ui.current_font().id();
+                ui.get_window_draw_list().add_text_with_font_id(
+                    font,
+                    font_size,
+                    [20.0, 40.0],
+                    LABEL,
+                    "Wide label",
+                    0.0,
+                    None,
+                );
+            });
+    }
+
+    let draw_data = ctx.render();
+    let xs: Vec<f32> = draw_data
+        .draw_lists()
+        .flat_map(|list| list.vtx_buffer().iter())
+        .filter(|v| v.col == LABEL_PACKED)
+        .map(|v| v.pos[0])
+        .collect();
+    assert!(!xs.is_empty(), "label was not drawn");
+    let min = xs.iter().copied().fold(f32::MAX, f32::min);
+    let max = xs.iter().copied().fold(f32::MIN, f32::max);
+    max - min
+}
+
+#[test]
+fn add_text_with_font_id_honours_size_override() {
+    let small = label_width(13.0);
+    let large = label_width(39.0);
+    assert!(
+        large > small * 2.5,
+        "expected 3x size to draw ~3x wider, got {small} vs {large}"
+    );
+}
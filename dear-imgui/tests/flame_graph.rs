@@ -0,0 +1,133 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{Condition, Context, FlameGraphState, FlameSpan, MouseButton, WindowFlags};
+
+const SPANS: [FlameSpan<'static>; 3] = [
+    FlameSpan {
+        name: "frame",
+        start: 0.0,
+        duration: 100.0,
+        depth: 0,
+    },
+    FlameSpan {
+        name: "update",
+        start: 0.0,
+        duration: 25.0,
+        depth: 1,
+    },
+    FlameSpan {
+        name: "render",
+        start: 50.0,
+        duration: 50.0,
+        depth: 1,
+    },
+];
+
+/// Runs one frame with a 400x40 flame graph (20px rows) at the origin of an undecorated
+/// window, returning the hovered span
+fn graph_frame(ctx: &mut Context, state: &mut FlameGraphState) -> Option<usize> {
+    let mut hovered = None;
+    {
+        let ui = ctx.frame();
+        ui.window("host")
+            .position([0.0, 0.0], Condition::Always)
+            .size([500.0, 400.0], Condition::Always)
+            .flags(WindowFlags::NO_DECORATION | WindowFlags::NO_MOVE)
+            .build(|| {
+                ui.set_cursor_screen_pos([0.0, 0.0]);
+                hovered = ui
+                    .flame_graph("##profile", &SPANS, state)
+                    .size([400.0, 0.0])
+                    .row_height(20.0)
+                    .build();
+            });
+    }
+    ctx.render();
+    hovered
+}
+
+#[test]
+fn hover_reports_span_under_mouse() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let mut state = FlameGraphState::new();
+
+    // x = 300 is t = 75, inside "render" on the second row
+    ctx.io_mut().add_mouse_pos_event([300.0, 30.0]);
+    graph_frame(&mut ctx, &mut state);
+    assert_eq!(graph_frame(&mut ctx, &mut state), Some(2));
+
+    // Between "update" and "render" only the root row has a span
+    ctx.io_mut().add_mouse_pos_event([150.0, 30.0]);
+    graph_frame(&mut ctx, &mut state);
+    assert_eq!(graph_frame(&mut ctx, &mut state), None);
+    ctx.io_mut().add_mouse_pos_event([150.0, 10.0]);
+    graph_frame(&mut ctx, &mut state);
+    assert_eq!(graph_frame(&mut ctx, &mut state), Some(0));
+}
+
+#[test]
+fn click_zooms_to_span_and_wheel_zooms_around_cursor() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let mut state = FlameGraphState::new();
+
+    ctx.io_mut().add_mouse_pos_event([50.0, 30.0]);
+    graph_frame(&mut ctx, &mut state);
+    ctx.io_mut().add_mouse_button_event(MouseButton::Left, true);
+    graph_frame(&mut ctx, &mut state);
+    ctx.io_mut()
+        .add_mouse_button_event(MouseButton::Left, false);
+    graph_frame(&mut ctx, &mut state);
+    assert_eq!(
+        state.view,
+        Some([0.0, 25.0]),
+        "clicking \"update\" zooms to it"
+    );
+
+    state.reset();
+    ctx.io_mut().add_mouse_pos_event([100.0, 30.0]);
+    graph_frame(&mut ctx, &mut state);
+    ctx.io_mut().add_mouse_wheel_event([0.0, 1.0]);
+    graph_frame(&mut ctx, &mut state);
+    let [start, end] = state.view.expect("wheel sets the view");
+    assert!(end - start < 100.0, "wheel up should zoom in");
+    // t = 25 stays under the cursor, a quarter of the way across
+    assert!(((25.0 - start) / (end - start) - 0.25).abs() < 1e-6);
+}
+
+#[test]
+fn wheel_zoom_keeps_the_view_from_collapsing() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let mut state = FlameGraphState::new();
+
+    ctx.io_mut().add_mouse_pos_event([100.0, 30.0]);
+    graph_frame(&mut ctx, &mut state);
+    for _ in 0..10 {
+        ctx.io_mut().add_mouse_wheel_event([0.0, 1000.0]);
+        graph_frame(&mut ctx, &mut state);
+    }
+    let [start, end] = state.view.expect("wheel sets the view");
+    assert!(end > start, "view collapsed to [{start}, {end}]");
+    // Drawing the zoomed view must not produce NaN span positions
+    graph_frame(&mut ctx, &mut state);
+}
+
+#[test]
+fn right_drag_pans_view() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let mut state = FlameGraphState::new();
+    state.view = Some([0.0, 40.0]);
+
+    ctx.io_mut().add_mouse_pos_event([200.0, 30.0]);
+    graph_frame(&mut ctx, &mut state);
+    ctx.io_mut()
+        .add_mouse_button_event(MouseButton::Right, true);
+    graph_frame(&mut ctx, &mut state);
+    ctx.io_mut().add_mouse_pos_event([100.0, 30.0]);
+    graph_frame(&mut ctx, &mut state);
+
+    // Dragging left by a quarter of the width moves the view right by a quarter
+    assert_eq!(state.view, Some([10.0, 50.0]));
+}
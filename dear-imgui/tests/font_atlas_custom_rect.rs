@@ -0,0 +1,28 @@
+use dear_imgui_rs::test_harness::test_guard;
+use dear_imgui_rs::{FontAtlas, FontSource};
+
+#[test]
+fn custom_rects_pack_and_accept_pixels() {
+    let _guard = test_guard();
+    let mut atlas = FontAtlas::new();
+    atlas.add_font(&[FontSource::default_font_with_size(13.0)]);
+    atlas.build_rgba32().expect("rgba32 atlas");
+
+    let id = atlas.add_custom_rect(8, 4).expect("packed rect");
+    let rect = atlas.custom_rect(&id).unwrap();
+    assert_eq!((rect.width, rect.height), (8, 4));
+    let (uv0, uv1) = atlas.custom_rect_uv(&id).unwrap();
+    assert!(uv0[0] < uv1[0] && uv0[1] < uv1[1]);
+    assert!(uv0.iter().chain(&uv1).all(|&uv| (0.0..=1.0).contains(&uv)));
+
+    assert!(atlas.write_custom_rect(&id, &[0; 3]).is_err());
+    let red: Vec<u8> = [255, 0, 0, 255].repeat(8 * 4);
+    atlas.write_custom_rect(&id, &red).unwrap();
+
+    let (pixels, width, _) = unsafe { atlas.get_tex_data_ptr() }.unwrap();
+    let offset = ((rect.y as u32 + 3) * width + rect.x as u32 + 7) as usize * 4;
+    let last = unsafe { std::slice::from_raw_parts(pixels.add(offset), 4) };
+    assert_eq!(last, [255, 0, 0, 255]);
+
+    atlas.remove_custom_rect(id);
+}
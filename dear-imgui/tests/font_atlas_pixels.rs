@@ -0,0 +1,28 @@
+use dear_imgui_rs::test_harness::test_guard;
+use dear_imgui_rs::{FontAtlas, FontSource, TextureId};
+
+#[test]
+fn atlas_pixels_in_both_formats() {
+    let _guard = test_guard();
+    let mut atlas = FontAtlas::new();
+    atlas.add_font(&[FontSource::default_font_with_size(13.0)]);
+
+    let (width, height, len) = {
+        let (w, h, pixels) = atlas.build_rgba32().expect("rgba32 atlas");
+        (w, h, pixels.len())
+    };
+    assert!(width > 0 && height > 0);
+    assert_eq!(len, (width * height * 4) as usize);
+
+    let (w, h, pixels) = atlas.build_alpha8().expect("alpha8 atlas");
+    assert_eq!(pixels.len(), (w * h) as usize);
+    assert!(pixels.contains(&255));
+
+    atlas.set_texture_id(TextureId::new(7));
+    assert_eq!(atlas.get_tex_ref()._TexID, 7);
+
+    // Cleared pixels are rebuilt on demand
+    atlas.clear_tex_data();
+    assert!(unsafe { atlas.get_tex_data_ptr() }.is_none());
+    assert!(atlas.build_rgba32().is_some());
+}
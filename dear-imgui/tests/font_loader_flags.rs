@@ -0,0 +1,44 @@
+use dear_imgui_rs::test_harness::test_guard;
+use dear_imgui_rs::{FontAtlas, FontAtlasFlags, FontLoaderFlags, HAS_FREETYPE, ImGuiError};
+
+#[test]
+fn loader_flags_match_freetype_bits() {
+    let _guard = test_guard();
+    assert_eq!(FontLoaderFlags::NO_HINTING.0, 1 << 0);
+    assert_eq!(FontLoaderFlags::LIGHT_HINTING.0, 1 << 3);
+    assert_eq!(FontLoaderFlags::BOLD.0, 1 << 5);
+    assert_eq!(FontLoaderFlags::LOAD_COLOR.0, 1 << 8);
+    assert_eq!(FontLoaderFlags::BITMAP.0, 1 << 9);
+
+    let flags = FontLoaderFlags::LIGHT_HINTING | FontLoaderFlags::OBLIQUE;
+    assert!(flags.contains(FontLoaderFlags::OBLIQUE));
+    assert!(!flags.contains(FontLoaderFlags::BOLD));
+}
+
+#[test]
+fn freetype_flags_require_the_feature() {
+    let _guard = test_guard();
+    let mut atlas = FontAtlas::new();
+    let result = atlas.set_freetype_loader_flags(FontLoaderFlags::LIGHT_HINTING);
+    if HAS_FREETYPE {
+        assert!(result.is_ok());
+        assert_eq!(atlas.font_loader_flags(), FontLoaderFlags::LIGHT_HINTING);
+    } else {
+        assert!(matches!(result, Err(ImGuiError::Configuration { .. })));
+        assert_eq!(atlas.font_loader_flags(), FontLoaderFlags::NONE);
+    }
+}
+
+#[test]
+fn atlas_flags_round_trip() {
+    let _guard = test_guard();
+    let mut atlas = FontAtlas::new();
+    assert_eq!(atlas.flags(), FontAtlasFlags::NONE);
+    atlas.set_flags(FontAtlasFlags::NO_MOUSE_CURSORS | FontAtlasFlags::NO_BAKED_LINES);
+    assert!(atlas.flags().contains(FontAtlasFlags::NO_BAKED_LINES));
+    assert!(
+        !atlas
+            .flags()
+            .contains(FontAtlasFlags::NO_POWER_OF_TWO_HEIGHT)
+    );
+}
@@ -0,0 +1,35 @@
+#![allow(deprecated)]
+
+use dear_imgui_rs::test_harness::test_guard;
+use dear_imgui_rs::{FontAtlas, FontConfig, FontSource, GlyphRanges};
+
+#[test]
+fn later_sources_merge_into_the_first_font() {
+    let _guard = test_guard();
+    let mut atlas = FontAtlas::new();
+    let icons = FontConfig::new()
+        .glyph_ranges(GlyphRanges::CYRILLIC)
+        .glyph_offset([0.0, 1.0])
+        .glyph_min_advance_x(13.0)
+        .pixel_snap_h(true)
+        .rasterizer_multiply(1.2);
+    atlas.add_font(&[
+        FontSource::default_font_with_size(13.0),
+        FontSource::default_font_with_size(13.0).with_config(icons),
+    ]);
+
+    let raw = unsafe { &*atlas.raw() };
+    assert_eq!(raw.Fonts.Size, 1);
+    assert_eq!(raw.Sources.Size, 2);
+    let (base, merged) = unsafe { (&*raw.Sources.Data, &*raw.Sources.Data.add(1)) };
+    assert!(!base.MergeMode && merged.MergeMode);
+    assert_eq!(merged.GlyphRanges, GlyphRanges::CYRILLIC.as_ptr());
+    assert_eq!(merged.GlyphMinAdvanceX, 13.0);
+}
+
+#[test]
+#[should_panic(expected = "glyph ranges must be zero-terminated")]
+fn unterminated_glyph_ranges_panic() {
+    let _guard = test_guard();
+    let _ = FontConfig::new().glyph_ranges(&[0x20, 0xFF]);
+}
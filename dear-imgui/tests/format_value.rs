@@ -0,0 +1,28 @@
+use dear_imgui_rs::internal::format_value;
+use dear_imgui_rs::test_harness::test_guard;
+
+#[test]
+fn matches_printf_for_each_kind() {
+    let _guard = test_guard();
+    assert_eq!(format_value(-3i8, "%d"), "-3");
+    assert_eq!(format_value(65535u16, "%u"), "65535");
+    assert_eq!(format_value(u64::MAX, "%llu"), "18446744073709551615");
+    assert_eq!(format_value(0.125f64, "%.3f"), "0.125");
+    assert_eq!(format_value(2.0f32, "%g units"), "2 units");
+}
+
+#[test]
+fn long_output_is_not_truncated() {
+    let _guard = test_guard();
+    let format = format!("{}%d", "-".repeat(500));
+    let out = format_value(7i32, &format);
+    assert_eq!(out.len(), 501);
+    assert!(out.ends_with("-7"));
+}
+
+#[test]
+fn null_byte_ends_format() {
+    let _guard = test_guard();
+    assert_eq!(format_value(1i32, "%d\0ignored"), "1");
+    assert_eq!(format_value(1i32, ""), "");
+}
@@ -0,0 +1,64 @@
+use dear_imgui_rs::test_harness::test_guard;
+use dear_imgui_rs::{BackendFlags, ConfigFlags, Context, GamepadState, Key};
+
+fn gamepad_context() -> Context {
+    let mut ctx = dear_imgui_rs::test_harness::headless_context();
+    let flags = ctx.io().backend_flags() | BackendFlags::HAS_GAMEPAD;
+    ctx.io_mut().set_backend_flags(flags);
+    let config = ctx.io().config_flags() | ConfigFlags::NAV_ENABLE_GAMEPAD;
+    ctx.io_mut().set_config_flags(config);
+    ctx
+}
+
+fn value_of(state: &GamepadState, key: Key) -> (bool, f32) {
+    let (_, down, value) = state
+        .key_events()
+        .into_iter()
+        .find(|&(k, _, _)| k == key)
+        .unwrap();
+    (down, value)
+}
+
+#[test]
+fn sticks_apply_dead_zone_and_split_axes() {
+    let state = GamepadState {
+        left_stick: [-1.0, 0.1],
+        right_trigger: 0.5,
+        face_down: true,
+        ..Default::default()
+    };
+    assert_eq!(value_of(&state, Key::GamepadLStickLeft), (true, 1.0));
+    assert_eq!(value_of(&state, Key::GamepadLStickRight), (false, 0.0));
+    // Inside the dead zone
+    assert_eq!(value_of(&state, Key::GamepadLStickDown), (false, 0.0));
+    assert_eq!(value_of(&state, Key::GamepadR2), (true, 0.5));
+    assert_eq!(value_of(&state, Key::GamepadFaceDown), (true, 1.0));
+    assert_eq!(value_of(&state, Key::GamepadStart), (false, 0.0));
+}
+
+#[test]
+fn gamepad_events_reach_the_frame() {
+    let _guard = test_guard();
+    let mut ctx = gamepad_context();
+
+    let state = GamepadState {
+        left_stick: [0.0, 1.0],
+        dpad_up: true,
+        ..Default::default()
+    };
+    ctx.io_mut().add_gamepad_events(&state);
+    {
+        let ui = ctx.frame();
+        assert!(ui.is_key_down(Key::GamepadLStickDown));
+        assert!(ui.is_key_down(Key::GamepadDpadUp));
+        assert!(!ui.is_key_down(Key::GamepadLStickUp));
+    }
+    ctx.render();
+
+    ctx.io_mut().add_gamepad_events(&GamepadState::default());
+    {
+        let ui = ctx.frame();
+        assert!(!ui.is_key_down(Key::GamepadDpadUp));
+    }
+    ctx.render();
+}
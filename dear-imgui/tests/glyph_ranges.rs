@@ -0,0 +1,48 @@
+#![allow(deprecated)]
+
+use dear_imgui_rs::test_harness::test_guard;
+use dear_imgui_rs::{GlyphRanges, GlyphRangesBuilder};
+
+#[test]
+fn builder_merges_chars_text_and_ranges() {
+    let _guard = test_guard();
+    let mut builder = GlyphRangesBuilder::new();
+    builder.add_char('a');
+    builder.add_text("bcz");
+    builder.add_ranges(&[(0x3041, 0x3043), (0x10, 0x5), (0x1F600, 0x1F64F)]);
+    // Emoji are outside what 16-bit glyph ranges can hold
+    builder.add_char('😀');
+
+    let ranges = builder.build();
+    assert_eq!(ranges, vec![0x61, 0x63, 0x7A, 0x7A, 0x3041, 0x3043, 0]);
+    assert_eq!(
+        builder.build_ranges(),
+        ranges.iter().map(|&c| u32::from(c)).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn empty_builder_builds_terminator() {
+    let _guard = test_guard();
+    assert_eq!(GlyphRangesBuilder::default().build(), vec![0]);
+}
+
+#[test]
+fn predefined_ranges_are_zero_terminated_pairs() {
+    let _guard = test_guard();
+    for ranges in [
+        GlyphRanges::DEFAULT,
+        GlyphRanges::JAPANESE,
+        GlyphRanges::CHINESE_FULL,
+        GlyphRanges::CHINESE_SIMPLIFIED_COMMON,
+        GlyphRanges::KOREAN,
+    ] {
+        assert_eq!(ranges.last(), Some(&0));
+        assert_eq!(ranges.len() % 2, 1);
+        assert!(
+            ranges
+                .chunks(2)
+                .all(|pair| pair.len() == 1 || pair[0] <= pair[1])
+        );
+    }
+}
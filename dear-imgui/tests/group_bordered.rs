@@ -0,0 +1,39 @@
+use dear_imgui_rs::test_harness::headless_context;
+use dear_imgui_rs::{Condition, WindowFlags};
+
+#[test]
+fn bordered_group_pads_contents_and_fits_title() {
+    let mut ctx = headless_context();
+
+    let ui = ctx.frame();
+    let padding = ui.clone_style().frame_padding();
+    ui.window("settings")
+        .position([0.0, 0.0], Condition::Always)
+        .size([500.0, 400.0], Condition::Always)
+        .flags(WindowFlags::NO_DECORATION)
+        .build(|| {
+            let mut content = ([0.0; 2], [0.0; 2]);
+            let value = ui.group_bordered(|| {
+                ui.button_with_size("ok", [40.0, 20.0]);
+                content = ui.item_rect();
+                7
+            });
+            assert_eq!(value, 7);
+            let (min, max) = ui.item_rect();
+            assert_eq!(content.0, [min[0] + padding[0], min[1] + padding[1]]);
+            assert_eq!(max, [content.1[0] + padding[0], content.1[1] + padding[1]]);
+
+            let title = "A rather long section title##audio";
+            ui.group_bordered_with_title(title, || {
+                ui.button_with_size("x", [10.0, 10.0]);
+                content = ui.item_rect();
+            });
+            let (min, max) = ui.item_rect();
+            assert_eq!(content.0[1], min[1] + ui.text_line_height() + padding[1]);
+            // The 10px button alone would give a frame about 20px wide
+            assert!(
+                max[0] - min[0] > 100.0,
+                "frame is wide enough for the title"
+            );
+        });
+}
@@ -0,0 +1,44 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{Condition, Context};
+
+/// Runs one frame with a "Tools" window placed at `pos` unless settings say otherwise,
+/// returning where the window actually ended up
+fn tools_frame(ctx: &mut Context, pos: [f32; 2]) -> [f32; 2] {
+    let mut seen = [0.0; 2];
+    {
+        let ui = ctx.frame();
+        ui.window("Tools")
+            .position(pos, Condition::FirstUseEver)
+            .size([200.0, 100.0], Condition::FirstUseEver)
+            .build(|| seen = ui.window_pos());
+    }
+    ctx.render();
+    seen
+}
+
+#[test]
+fn settings_round_trip_through_strings() {
+    let _guard = test_guard();
+
+    let ini = {
+        let mut ctx = headless_context();
+        ctx.io_mut().set_ini_saving_rate(0.01);
+        assert!(!ctx.io().want_save_ini_settings());
+
+        // A new window dirties the settings, which are flagged once the saving rate elapses
+        for _ in 0..3 {
+            tools_frame(&mut ctx, [120.0, 80.0]);
+        }
+        assert!(ctx.io().want_save_ini_settings());
+
+        let ini = ctx.save_ini_settings_to_string();
+        assert!(!ctx.io().want_save_ini_settings());
+        assert!(ini.contains("[Window][Tools]"), "{ini}");
+        assert!(ini.contains("Pos=120,80"), "{ini}");
+        ini
+    };
+
+    let mut ctx = headless_context();
+    ctx.load_ini_settings(&ini);
+    assert_eq!(tools_frame(&mut ctx, [10.0, 10.0]), [120.0, 80.0]);
+}
@@ -89,3 +89,239 @@ fn input_scalars_build_no_panic() {
 
     // No render required in headless tests
 }
+
+#[test]
+fn focused_text_input_is_reported_as_active() {
+    let _guard = test_guard();
+    let mut ctx = imgui::Context::create();
+    {
+        let io = ctx.io_mut();
+        io.set_display_size([800.0, 600.0]);
+        io.set_delta_time(1.0 / 60.0);
+    }
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+
+    let mut text = String::new();
+    let mut active = Vec::new();
+    for frame in 0..3 {
+        let ui = ctx.frame();
+        ui.window("shortcuts").build(|| {
+            active.push(ui.is_any_item_active());
+            if frame == 0 {
+                ui.set_keyboard_focus_here();
+            }
+            let _ = ui.input_text("name", &mut text).build();
+        });
+        ctx.render();
+    }
+
+    // Focus requests take a frame or two to activate the field
+    assert!(!active[0]);
+    assert!(active[2]);
+    assert!(ctx.io().want_text_input());
+    assert!(ctx.io().want_capture_keyboard());
+}
+
+#[test]
+fn input_text_char_filter_discards_vowels() {
+    let _guard = test_guard();
+    let mut ctx = imgui::Context::create();
+    {
+        let io = ctx.io_mut();
+        io.set_display_size([800.0, 600.0]);
+        io.set_delta_time(1.0 / 60.0);
+    }
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+
+    struct NoVowels;
+    impl imgui::InputTextCallbackHandler for NoVowels {
+        fn on_char_filter(&mut self, c: char) -> Option<char> {
+            (!"aeiouAEIOU".contains(c)).then_some(c)
+        }
+    }
+
+    let mut text = String::new();
+    for frame in 0..6 {
+        if frame == 3 {
+            // The field is active by now (see `focused_text_input_is_reported_as_active`)
+            for c in "Education".chars() {
+                ctx.io_mut().add_input_character(c);
+            }
+        }
+        let ui = ctx.frame();
+        ui.window("filter").build(|| {
+            if frame == 0 {
+                ui.set_keyboard_focus_here();
+            }
+            let _ = ui
+                .input_text("consonants", &mut text)
+                .callback(NoVowels)
+                .callback_flags(imgui::InputTextCallback::CHAR_FILTER)
+                .build();
+        });
+        ctx.render();
+    }
+
+    assert_eq!(text, "dctn");
+}
+
+#[test]
+fn input_text_multiline_grows_while_typing() {
+    let _guard = test_guard();
+    let mut ctx = imgui::Context::create();
+    {
+        let io = ctx.io_mut();
+        io.set_display_size([800.0, 600.0]);
+        io.set_delta_time(1.0 / 60.0);
+    }
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+
+    // Far more text than the empty buffer can hold, so ImGui has to ask for a resize
+    let typed: String = "line of text\n".repeat(40);
+    let mut text = String::new();
+    for frame in 0..6 {
+        if frame == 3 {
+            for c in typed.chars() {
+                ctx.io_mut().add_input_character(c);
+            }
+        }
+        let ui = ctx.frame();
+        ui.window("notes").build(|| {
+            if frame == 0 {
+                ui.set_keyboard_focus_here();
+            }
+            let _ = ui
+                .input_text_multiline("##notes", &mut text, [300.0, 200.0])
+                .build();
+        });
+        ctx.render();
+    }
+
+    assert_eq!(text, typed);
+}
+
+/// Focuses a single scalar field, types `typed` into it and returns the value it holds
+fn type_into_scalar<T: imgui::internal::DataTypeKind>(
+    mut value: T,
+    typed: &str,
+    edit: impl Fn(&imgui::Ui, &mut T) -> bool,
+) -> (T, bool) {
+    let mut ctx = imgui::Context::create();
+    {
+        let io = ctx.io_mut();
+        io.set_display_size([800.0, 600.0]);
+        io.set_delta_time(1.0 / 60.0);
+    }
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+
+    let mut changed = false;
+    for frame in 0..6 {
+        if frame == 3 {
+            for c in typed.chars() {
+                ctx.io_mut().add_input_character(c);
+            }
+        }
+        let ui = ctx.frame();
+        ui.window("scalars").build(|| {
+            if frame == 0 {
+                ui.set_keyboard_focus_here();
+            }
+            changed |= edit(ui, &mut value);
+        });
+        ctx.render();
+    }
+    (value, changed)
+}
+
+#[test]
+fn hexadecimal_scalar_input_parses_hex_digits() {
+    let _guard = test_guard();
+
+    let (value, changed) = type_into_scalar(0u32, "ff", |ui, v| {
+        ui.input_scalar("addr", v).chars_hexadecimal(true).build()
+    });
+    assert!(changed);
+    assert_eq!(value, 0xFF);
+
+    let (value, changed) = type_into_scalar(0u64, "1f", |ui, v| {
+        ui.input_scalar("addr", v)
+            .chars_hexadecimal(true)
+            .display_format("%016llX")
+            .build()
+    });
+    assert!(changed);
+    assert_eq!(value, 0x1F);
+
+    // Without the flag hex digits are filtered out
+    let (value, changed) = type_into_scalar(0u32, "ff", |ui, v| ui.input_u32("count", v));
+    assert!(!changed);
+    assert_eq!(value, 0);
+}
+
+#[test]
+fn typed_integer_inputs_build_no_panic() {
+    let _guard = test_guard();
+    let mut ctx = imgui::Context::create();
+    {
+        let io = ctx.io_mut();
+        io.set_display_size([800.0, 600.0]);
+        io.set_delta_time(1.0 / 60.0);
+    }
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+    let ui = ctx.frame();
+
+    let mut u = u32::MAX;
+    let mut i = i64::MIN;
+    let mut big = u64::MAX;
+    let mut values = [1u16, 2, 3];
+    assert!(!ui.input_u32("u32", &mut u));
+    assert!(!ui.input_i64("i64", &mut i));
+    assert!(!ui.input_u64("u64", &mut big));
+    assert!(
+        !ui.input_scalar_n("u16x3", &mut values)
+            .step(1)
+            .chars_hexadecimal(true)
+            .build()
+    );
+    assert_eq!((u, i, big), (u32::MAX, i64::MIN, u64::MAX));
+}
+
+#[test]
+fn input_text_grows_past_capacity_and_keeps_contents() {
+    let _guard = test_guard();
+    let mut ctx = imgui::Context::create();
+    {
+        let io = ctx.io_mut();
+        io.set_display_size([800.0, 600.0]);
+        io.set_delta_time(1.0 / 60.0);
+    }
+    let _ = ctx.font_atlas_mut().build();
+    let _ = ctx.set_ini_filename::<std::path::PathBuf>(None);
+
+    // Multi-byte text well past the initial allocation forces at least one resize
+    let typed = "héllo wörld ".repeat(30);
+    let mut text = String::with_capacity(4);
+    for frame in 0..6 {
+        if frame == 3 {
+            for c in typed.chars() {
+                ctx.io_mut().add_input_character(c);
+            }
+        }
+        let ui = ctx.frame();
+        ui.window("single line").build(|| {
+            if frame == 0 {
+                ui.set_keyboard_focus_here();
+            }
+            let _ = ui.input_text("##line", &mut text).build();
+        });
+        ctx.render();
+    }
+
+    assert!(text.capacity() > typed.len());
+    assert_eq!(text, typed);
+}
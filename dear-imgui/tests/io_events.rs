@@ -0,0 +1,83 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{Condition, Context, Key, MouseButton, MouseSource};
+
+/// Runs one frame with a window covering the top-left corner and a focused text field,
+/// returning whether `key` was down and the field's text
+fn frame(ctx: &mut Context, key: Key, text: &mut String) -> bool {
+    let mut down = false;
+    {
+        let ui = ctx.frame();
+        ui.window("input")
+            .position([0.0, 0.0], Condition::Always)
+            .size([300.0, 200.0], Condition::Always)
+            .build(|| {
+                down = ui.is_key_down(key);
+                if !ui.is_any_item_active() {
+                    ui.set_keyboard_focus_here();
+                }
+                let _ = ui.input_text("##field", text).build();
+            });
+    }
+    ctx.render();
+    down
+}
+
+#[test]
+fn queued_events_reach_the_next_frame() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let mut text = String::new();
+    // Hovering and capture flags are computed from the previous frame's windows
+    frame(&mut ctx, Key::F1, &mut text);
+
+    ctx.io_mut().add_mouse_pos_event([50.0, 50.0]);
+    ctx.io_mut().add_key_event(Key::F1, true);
+    assert!(frame(&mut ctx, Key::F1, &mut text));
+    assert_eq!(ctx.io().mouse_pos(), [50.0, 50.0]);
+    assert!(ctx.io().want_capture_mouse());
+
+    ctx.io_mut().add_key_event(Key::F1, false);
+    ctx.io_mut().add_mouse_pos_event([600.0, 500.0]);
+    assert!(!frame(&mut ctx, Key::F1, &mut text));
+    assert!(!ctx.io().want_capture_mouse());
+
+    ctx.io_mut().add_input_characters("héllo");
+    frame(&mut ctx, Key::F1, &mut text);
+    frame(&mut ctx, Key::F1, &mut text);
+    assert_eq!(text, "héllo");
+    assert!(ctx.io().want_text_input());
+
+    ctx.io_mut().add_mouse_button_event(MouseButton::Left, true);
+    frame(&mut ctx, Key::F1, &mut text);
+    assert!(ctx.io().mouse_down(0));
+}
+
+#[test]
+fn mouse_source_and_queue_controls() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let mut text = String::new();
+    assert_eq!(ctx.io().mouse_source(), MouseSource::Mouse);
+
+    ctx.io_mut()
+        .add_mouse_source_event(MouseSource::TouchScreen);
+    ctx.io_mut().add_mouse_pos_event([20.0, 20.0]);
+    frame(&mut ctx, Key::F1, &mut text);
+    assert_eq!(ctx.io().mouse_source(), MouseSource::TouchScreen);
+
+    // Cleared events never reach the frame
+    ctx.io_mut().add_key_event(Key::F2, true);
+    ctx.io_mut().clear_events_queue();
+    assert!(!frame(&mut ctx, Key::F2, &mut text));
+
+    // Events are dropped while the app does not accept them
+    ctx.io_mut().set_app_accepting_events(false);
+    ctx.io_mut().add_key_event(Key::F2, true);
+    ctx.io_mut().set_app_accepting_events(true);
+    assert!(!frame(&mut ctx, Key::F2, &mut text));
+
+    ctx.io_mut().add_key_event(Key::F2, true);
+    assert!(frame(&mut ctx, Key::F2, &mut text));
+    ctx.io_mut().clear_input_keys();
+    assert!(!frame(&mut ctx, Key::F2, &mut text));
+}
@@ -0,0 +1,38 @@
+use dear_imgui_rs::Condition;
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+
+#[test]
+fn pushed_widths_resolve_against_the_region_and_pop_on_drop() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+
+    let mut widths = Vec::new();
+    {
+        let ui = ctx.frame();
+        ui.window("form")
+            .position([0.0, 0.0], Condition::Always)
+            .size([400.0, 300.0], Condition::Always)
+            .build(|| {
+                let avail = ui.content_region_avail_width();
+                let default = ui.calc_item_width();
+                {
+                    let _width = ui.push_item_width(120.0);
+                    widths.push(ui.calc_item_width() - 120.0);
+                    let _nested = ui.push_item_width(-50.0);
+                    widths.push(ui.calc_item_width() - (avail - 50.0));
+                }
+                widths.push(ui.calc_item_width() - default);
+
+                let mut value = 0.0f32;
+                ui.set_next_item_width(80.0);
+                ui.input_float("##a", &mut value);
+                widths.push(ui.item_rect_size()[0] - 80.0);
+                // Only the next item is affected
+                ui.input_float("##b", &mut value);
+                widths.push(ui.item_rect_size()[0] - default);
+            });
+    }
+    ctx.render();
+
+    assert!(widths.iter().all(|d| d.abs() < 0.5), "{widths:?}");
+}
@@ -0,0 +1,63 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{LayoutStore, WindowLayout};
+use std::collections::HashMap;
+
+#[test]
+fn restores_and_captures_window_layout() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+
+    let saved = WindowLayout {
+        pos: [120.0, 80.0],
+        size: [300.0, 150.0],
+    };
+    let mut layouts = LayoutStore::from_map(HashMap::from([("Tools".to_string(), saved)]));
+    assert!(!layouts.is_dirty());
+
+    let mut seen = None;
+    {
+        let ui = ctx.frame();
+        layouts.window(ui, "Tools").build(|| {
+            seen = Some((ui.window_pos(), ui.window_size()));
+            layouts.capture(ui, "Tools");
+        });
+    }
+    ctx.render();
+
+    assert_eq!(seen, Some((saved.pos, saved.size)));
+    assert_eq!(layouts.get("Tools"), Some(saved));
+    assert!(
+        !layouts.is_dirty(),
+        "unchanged layout should not mark the store dirty"
+    );
+}
+
+#[test]
+fn capture_records_new_windows() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+
+    let mut layouts = LayoutStore::new();
+    {
+        let ui = ctx.frame();
+        assert!(!layouts.restore(ui, "Fresh"));
+        ui.window("Fresh")
+            .position([10.0, 20.0], dear_imgui_rs::Condition::Always)
+            .size([200.0, 100.0], dear_imgui_rs::Condition::Always)
+            .build(|| layouts.capture(ui, "Fresh"));
+    }
+    ctx.render();
+
+    assert!(layouts.is_dirty());
+    assert_eq!(
+        layouts.get("Fresh"),
+        Some(WindowLayout {
+            pos: [10.0, 20.0],
+            size: [200.0, 100.0],
+        })
+    );
+    layouts.mark_saved();
+    assert!(layouts.remove("Fresh").is_some());
+    assert!(layouts.is_dirty());
+    assert!(layouts.into_map().is_empty());
+}
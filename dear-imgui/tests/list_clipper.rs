@@ -0,0 +1,70 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{Condition, Context, ListClipper, Ui};
+use std::ops::Range;
+
+const ITEMS: i32 = 1_000_000;
+
+/// Renders one frame with `f` inside a 400px tall window
+fn frame(ctx: &mut Context, f: impl FnOnce(&Ui)) {
+    {
+        let ui = ctx.frame();
+        ui.window("log")
+            .position([0.0, 0.0], Condition::Always)
+            .size([400.0, 400.0], Condition::Always)
+            .build(|| f(ui));
+    }
+    ctx.render();
+}
+
+#[test]
+fn only_visible_rows_are_iterated() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+
+    let mut ranges: Vec<Range<usize>> = Vec::new();
+    let mut rows = Vec::new();
+    for _ in 0..2 {
+        ranges.clear();
+        rows.clear();
+        frame(&mut ctx, |ui| {
+            ListClipper::new(ITEMS).for_each(ui, |range| {
+                for row in range.clone() {
+                    ui.text(format!("line {row}"));
+                }
+                ranges.push(range);
+            });
+            // The item iterator walks the same rows
+            rows.extend(ListClipper::new(ITEMS).begin(ui).iter());
+        });
+    }
+
+    let visible: usize = ranges.iter().map(|r| r.len()).sum();
+    assert_eq!(ranges[0].start, 0);
+    assert!((10..100).contains(&visible), "{ranges:?}");
+    assert_eq!(rows.len(), visible);
+    assert!(rows.iter().all(|&row| (row as usize) < visible), "{rows:?}");
+}
+
+#[test]
+fn abandoned_clipper_is_ended_on_drop() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+
+    for _ in 0..2 {
+        frame(&mut ctx, |ui| {
+            // Stop after the first visible row
+            let first = ListClipper::new(ITEMS).begin(ui).iter().next();
+            assert_eq!(first, Some(0));
+
+            let panicked = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                ListClipper::new(ITEMS)
+                    .items_height(20.0)
+                    .for_each(ui, |_| panic!("row failed"));
+            }));
+            assert!(panicked.is_err());
+
+            // A fresh clipper still works after both were dropped mid-iteration
+            assert!(ListClipper::new(10).begin(ui).ranges().count() > 0);
+        });
+    }
+}
@@ -0,0 +1,75 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{CanvasState, Condition, Context, WindowFlags};
+
+/// Runs one frame with `text` as markdown in an undecorated `width`-wide window at the
+/// origin, returning where the text started and the rect of its last word
+fn markdown_frame(ctx: &mut Context, text: &str, width: f32) -> ([f32; 2], [f32; 2]) {
+    let mut result = None;
+    {
+        let ui = ctx.frame();
+        ui.window("host")
+            .position([0.0, 0.0], Condition::Always)
+            .size([width, 400.0], Condition::Always)
+            .flags(WindowFlags::NO_DECORATION | WindowFlags::NO_MOVE)
+            .build(|| {
+                let start = ui.cursor_screen_pos();
+                ui.markdown(text).build();
+                result = Some((start, ui.item_rect().0));
+            });
+    }
+    ctx.render();
+    result.expect("window was drawn")
+}
+
+#[test]
+fn consecutive_lines_form_one_paragraph() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let (start, last) = markdown_frame(&mut ctx, "one\ntwo", 400.0);
+    assert_eq!(last[1], start[1], "the second line continues the first row");
+    assert!(last[0] > start[0]);
+
+    let (start, last) = markdown_frame(&mut ctx, "one\n\ntwo", 400.0);
+    assert!(last[1] > start[1], "a blank line starts a new paragraph");
+    assert_eq!(last[0], start[0]);
+}
+
+#[test]
+fn wrapped_bullet_text_keeps_its_indent() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let text = "- many words that cannot possibly fit on a single line of a narrow window end";
+    let (start, last) = markdown_frame(&mut ctx, text, 120.0);
+    assert!(last[1] > start[1], "the item wraps");
+    assert!(
+        last[0] > start[0],
+        "wrapped text starts past the bullet, not at the window edge"
+    );
+}
+
+#[test]
+#[should_panic(expected = "`markdown` draws with the window draw list")]
+fn hovered_link_inside_a_canvas_panics_clearly() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let mut state = CanvasState::default();
+    // Over the link, which sits below the canvas so the canvas does not take the hover
+    ctx.io_mut().add_mouse_pos_event([5.0, 255.0]);
+    for _ in 0..2 {
+        let ui = ctx.frame();
+        ui.window("host")
+            .position([0.0, 0.0], Condition::Always)
+            .size([400.0, 400.0], Condition::Always)
+            .flags(WindowFlags::NO_DECORATION | WindowFlags::NO_MOVE)
+            .build(|| {
+                ui.set_cursor_screen_pos([0.0, 0.0]);
+                ui.canvas("##canvas", &mut state)
+                    .size([200.0, 200.0])
+                    .build(|_| {
+                        ui.set_cursor_screen_pos([0.0, 250.0]);
+                        ui.markdown("[link](url)").build();
+                    });
+            });
+        ctx.render();
+    }
+}
@@ -0,0 +1,86 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{
+    Condition, Context, Key, MouseButton, MultiSelectFlags, SelectionRequest, SelectionStorage,
+    WindowFlags,
+};
+
+const ITEMS: [&str; 4] = ["alpha", "beta", "gamma", "delta"];
+
+/// Runs one frame listing `ITEMS` as 20px tall selectables from the top of an undecorated
+/// window, returning every request emitted by the scope
+fn list_frame(ctx: &mut Context, selection: &mut SelectionStorage) -> Vec<SelectionRequest> {
+    let mut requests = Vec::new();
+    {
+        let ui = ctx.frame();
+        ui.window("host")
+            .position([0.0, 0.0], Condition::Always)
+            .size([200.0, 200.0], Condition::Always)
+            .flags(WindowFlags::NO_DECORATION | WindowFlags::NO_MOVE)
+            .build(|| {
+                let ms =
+                    ui.begin_multi_select(MultiSelectFlags::NONE, selection.len(), ITEMS.len());
+                requests.extend(ms.io().requests());
+                selection.apply_requests(&ms.io());
+                for (index, item) in ITEMS.iter().enumerate() {
+                    ui.set_cursor_screen_pos([0.0, index as f32 * 20.0]);
+                    ui.set_next_item_selection_user_data(index as i64);
+                    ui.selectable_config(item)
+                        .selected(selection.contains(index as u32))
+                        .size([100.0, 20.0])
+                        .build();
+                }
+                let io = ms.end();
+                requests.extend(io.requests());
+                selection.apply_requests(&io);
+            });
+    }
+    ctx.render();
+    requests
+}
+
+fn click(ctx: &mut Context, selection: &mut SelectionStorage, pos: [f32; 2]) {
+    ctx.io_mut().add_mouse_pos_event(pos);
+    list_frame(ctx, selection);
+    ctx.io_mut().add_mouse_button_event(MouseButton::Left, true);
+    list_frame(ctx, selection);
+    ctx.io_mut()
+        .add_mouse_button_event(MouseButton::Left, false);
+    list_frame(ctx, selection);
+}
+
+#[test]
+fn selection_storage_tracks_items() {
+    let mut selection = SelectionStorage::new();
+    assert!(selection.is_empty());
+
+    selection.set_item_selected(3, true);
+    selection.set_item_selected(1, true);
+    assert_eq!(selection.len(), 2);
+    assert!(selection.contains(1) && selection.contains(3) && !selection.contains(2));
+    assert_eq!(selection.iter().collect::<Vec<_>>(), vec![1, 3]);
+
+    selection.set_item_selected(3, false);
+    assert_eq!(selection.iter().collect::<Vec<_>>(), vec![1]);
+    selection.clear();
+    assert!(selection.is_empty());
+}
+
+#[test]
+fn click_and_shift_click_select_a_range() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let mut selection = SelectionStorage::new();
+
+    // Nothing is requested while the mouse stays away
+    list_frame(&mut ctx, &mut selection);
+    assert!(list_frame(&mut ctx, &mut selection).is_empty());
+
+    click(&mut ctx, &mut selection, [50.0, 30.0]);
+    assert_eq!(selection.iter().collect::<Vec<_>>(), vec![1]);
+
+    ctx.io_mut().add_key_event(Key::LeftShift, true);
+    click(&mut ctx, &mut selection, [50.0, 70.0]);
+    ctx.io_mut().add_key_event(Key::LeftShift, false);
+    list_frame(&mut ctx, &mut selection);
+    assert_eq!(selection.iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+}
@@ -0,0 +1,63 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{Context, sys};
+
+fn draw_window(ctx: &mut Context, name: &str) {
+    let ui = ctx.frame();
+    ui.window(name).build(|| ui.text(name));
+    ctx.render();
+}
+
+fn has_window(name: &std::ffi::CStr) -> bool {
+    unsafe { !sys::igFindWindowByName(name.as_ptr()).is_null() }
+}
+
+#[test]
+fn contexts_switch_explicitly_and_keep_windows_isolated() {
+    let _guard = test_guard();
+
+    let mut a = headless_context();
+    Context::clear_current();
+    let mut b = headless_context();
+    assert!(b.is_current());
+    assert!(!a.is_current());
+
+    a.set_current();
+    draw_window(&mut a, "Only A");
+    b.set_current();
+    draw_window(&mut b, "Only B");
+
+    assert!(has_window(c"Only B"));
+    assert!(!has_window(c"Only A"));
+
+    a.set_current();
+    assert!(has_window(c"Only A"));
+    assert!(!has_window(c"Only B"));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "not current")]
+fn ui_of_non_current_context_panics() {
+    let _guard = test_guard();
+
+    let mut a = headless_context();
+    Context::clear_current();
+    let _b = headless_context();
+    // `a` is not current, so starting a frame on it must be caught
+    a.frame();
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "not current")]
+fn ui_calls_without_strings_check_the_current_context() {
+    let _guard = test_guard();
+
+    let mut a = headless_context();
+    Context::clear_current();
+    let b = headless_context();
+    a.set_current();
+    let ui = a.frame();
+    b.set_current();
+    ui.separator();
+}
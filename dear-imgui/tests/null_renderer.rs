@@ -0,0 +1,38 @@
+use dear_imgui_rs::test_harness::test_guard;
+use dear_imgui_rs::{Condition, Context, TextureStatus};
+
+#[test]
+fn headless_frames_acknowledge_textures_and_keep_geometry() {
+    let _guard = test_guard();
+    let mut ctx = Context::builder()
+        .no_ini()
+        .display_size([640.0, 480.0])
+        .headless()
+        .build()
+        .unwrap();
+
+    let mut window_pos = [0.0; 2];
+    for frame in 0..3 {
+        {
+            let ui = ctx.frame();
+            ui.window("report")
+                .position([40.0, 30.0], Condition::Always)
+                .size([200.0, 100.0], Condition::Always)
+                .build(|| {
+                    ui.text("hello");
+                    window_pos = ui.window_pos();
+                });
+        }
+        let draw_data = ctx.render_headless();
+        assert_eq!(draw_data.display_size(), [640.0, 480.0]);
+        assert!(draw_data.total_vtx_count > 0);
+        if frame == 0 {
+            assert!(draw_data.textures_count() > 0);
+        }
+        for texture in draw_data.textures() {
+            assert_eq!(texture.status(), TextureStatus::OK);
+            assert!(!texture.tex_id().is_null());
+        }
+    }
+    assert_eq!(window_pos, [40.0, 30.0]);
+}
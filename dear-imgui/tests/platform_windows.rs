@@ -0,0 +1,102 @@
+#![cfg(feature = "multi-viewport")]
+
+use dear_imgui_rs::test_harness::test_guard;
+use dear_imgui_rs::{BackendFlags, Condition, Context, sys};
+use std::ffi::c_void;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static CREATED: AtomicUsize = AtomicUsize::new(0);
+static DESTROYED: AtomicUsize = AtomicUsize::new(0);
+static RENDERED: AtomicUsize = AtomicUsize::new(0);
+static RENDER_ARG: AtomicUsize = AtomicUsize::new(0);
+
+unsafe extern "C" fn create_window(_: *mut sys::ImGuiViewport) {
+    CREATED.fetch_add(1, Ordering::SeqCst);
+}
+
+unsafe extern "C" fn destroy_window(viewport: *mut sys::ImGuiViewport) {
+    DESTROYED.fetch_add(1, Ordering::SeqCst);
+    unsafe { (*viewport).PlatformHandle = std::ptr::null_mut() };
+}
+
+unsafe extern "C" fn ignore_window(_: *mut sys::ImGuiViewport) {}
+
+unsafe extern "C" fn ignore_vec2(_: *mut sys::ImGuiViewport, _: sys::ImVec2) {}
+
+unsafe extern "C" fn window_pos(viewport: *mut sys::ImGuiViewport) -> sys::ImVec2 {
+    unsafe { (*viewport).Pos }
+}
+
+unsafe extern "C" fn window_size(viewport: *mut sys::ImGuiViewport) -> sys::ImVec2 {
+    unsafe { (*viewport).Size }
+}
+
+unsafe extern "C" fn render_window(_: *mut sys::ImGuiViewport, arg: *mut c_void) {
+    RENDERED.fetch_add(1, Ordering::SeqCst);
+    RENDER_ARG.store(arg as usize, Ordering::SeqCst);
+}
+
+/// Sets up a context as a headless platform and renderer backend supporting viewports
+fn viewport_context() -> Context {
+    let mut ctx = dear_imgui_rs::test_harness::headless_context();
+    ctx.enable_multi_viewport();
+    let flags = ctx.io().backend_flags()
+        | BackendFlags::PLATFORM_HAS_VIEWPORTS
+        | BackendFlags::RENDERER_HAS_VIEWPORTS;
+    ctx.io_mut().set_backend_flags(flags);
+
+    let pio = ctx.platform_io_mut();
+    pio.set_platform_create_window_raw(Some(create_window));
+    pio.set_platform_destroy_window_raw(Some(destroy_window));
+    pio.set_platform_show_window_raw(Some(ignore_window));
+    pio.set_platform_set_window_pos_raw(Some(ignore_vec2));
+    pio.set_platform_get_window_pos_raw(Some(window_pos));
+    pio.set_platform_set_window_size_raw(Some(ignore_vec2));
+    pio.set_platform_get_window_size_raw(Some(window_size));
+    pio.set_renderer_render_window_raw(Some(render_window));
+
+    let mut monitor = sys::ImGuiPlatformMonitor::default();
+    monitor.MainSize = sys::ImVec2 {
+        x: 1920.0,
+        y: 1080.0,
+    };
+    monitor.WorkSize = monitor.MainSize;
+    monitor.DpiScale = 1.0;
+    pio.set_monitors(&[monitor, monitor]);
+    pio.set_monitors(&[monitor]);
+    assert_eq!(pio.monitors().len(), 1);
+
+    // The main viewport's window belongs to the application
+    unsafe { (*sys::igGetMainViewport()).PlatformHandle = 1 as *mut c_void };
+    ctx
+}
+
+#[test]
+fn windows_outside_the_main_viewport_get_platform_windows() {
+    let _guard = test_guard();
+    let mut ctx = viewport_context();
+
+    let mut marker = 0u8;
+    let marker_ptr = &mut marker as *mut u8 as *mut c_void;
+    for _ in 0..3 {
+        {
+            let ui = ctx.frame();
+            ui.window("Detached")
+                .position([1000.0, 100.0], Condition::Always)
+                .size([200.0, 100.0], Condition::Always)
+                .build(|| ui.text("outside"));
+        }
+        ctx.render();
+        ctx.update_platform_windows();
+        unsafe {
+            ctx.render_platform_windows_default_with_args(std::ptr::null_mut(), marker_ptr);
+        }
+    }
+
+    assert_eq!(CREATED.load(Ordering::SeqCst), 1);
+    assert!(RENDERED.load(Ordering::SeqCst) >= 1);
+    assert_eq!(RENDER_ARG.load(Ordering::SeqCst), marker_ptr as usize);
+
+    ctx.destroy_platform_windows();
+    assert_eq!(DESTROYED.load(Ordering::SeqCst), 1);
+}
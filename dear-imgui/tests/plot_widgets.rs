@@ -0,0 +1,47 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{Condition, Context, Ui};
+
+/// Renders one frame with `draw` inside a window, returning the total vertex count
+fn vertex_count(ctx: &mut Context, draw: impl FnOnce(&Ui)) -> i32 {
+    {
+        let ui = ctx.frame();
+        ui.window("sparklines")
+            .position([0.0, 0.0], Condition::Always)
+            .size([300.0, 300.0], Condition::Always)
+            .build(|| draw(ui));
+    }
+    ctx.render().total_vtx_count
+}
+
+#[test]
+fn stride_and_wrapping_offset_stay_within_values() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let interleaved = [0.0, 10.0, 1.0, 20.0, 2.0, 30.0, 3.0];
+    vertex_count(&mut ctx, |_| {});
+
+    let empty = vertex_count(&mut ctx, |_| {});
+    let lines = vertex_count(&mut ctx, |ui| {
+        ui.plot_lines_config("lines", &interleaved)
+            .stride(2)
+            .values_offset(-1)
+            .overlay_text("even")
+            .build();
+        ui.plot_histogram_config("histogram", &interleaved[1..])
+            .stride(2)
+            .values_offset(7)
+            .scale_min(0.0)
+            .build();
+        ui.plot_lines("empty", &[]);
+    });
+    assert!(lines > empty, "{lines} <= {empty}");
+}
+
+#[test]
+#[should_panic(expected = "stride must be at least 1")]
+fn zero_stride_panics() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let ui = ctx.frame();
+    let _ = ui.plot_lines_config("lines", &[1.0]).stride(0);
+}
@@ -0,0 +1,59 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{Context, Key};
+
+fn run_frame(ctx: &mut Context) {
+    let ui = ctx.frame();
+    ui.window("idle").build(|| ui.text("nothing moves here"));
+    ctx.render();
+}
+
+/// Renders frames until the context goes idle, returning how many it took
+fn frames_until_idle(ctx: &mut Context) -> usize {
+    (1..=10)
+        .find(|_| {
+            run_frame(ctx);
+            !ctx.wants_redraw()
+        })
+        .expect("context never went idle")
+}
+
+#[test]
+fn goes_idle_without_input_and_wakes_on_input() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+
+    assert!(ctx.wants_redraw(), "first frames must be drawn");
+    frames_until_idle(&mut ctx);
+    run_frame(&mut ctx);
+    assert!(!ctx.wants_redraw());
+
+    ctx.io_mut().add_mouse_pos_event([5.0, 5.0]);
+    assert!(frames_until_idle(&mut ctx) > 1);
+
+    ctx.io_mut().add_key_event(Key::A, true);
+    run_frame(&mut ctx);
+    assert!(ctx.wants_redraw(), "held key counts as input");
+    ctx.io_mut().add_key_event(Key::A, false);
+    assert!(frames_until_idle(&mut ctx) > 1);
+}
+
+#[test]
+fn request_redraw_and_frames_after_input() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    ctx.set_redraw_frames_after_input(1);
+    frames_until_idle(&mut ctx);
+
+    ctx.request_redraw(2);
+    assert!(ctx.wants_redraw());
+    run_frame(&mut ctx);
+    assert!(ctx.wants_redraw());
+    run_frame(&mut ctx);
+    assert!(!ctx.wants_redraw());
+
+    ctx.io_mut().add_mouse_pos_event([700.0, 500.0]);
+    run_frame(&mut ctx);
+    assert!(ctx.wants_redraw(), "one frame after input was requested");
+    run_frame(&mut ctx);
+    assert!(!ctx.wants_redraw());
+}
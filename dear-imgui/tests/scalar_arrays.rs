@@ -0,0 +1,83 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{Condition, Context, MouseButton, Ui, WindowFlags};
+
+/// Runs one frame inside an undecorated window at the origin, returning what `f` returns
+fn frame<R>(ctx: &mut Context, f: impl FnOnce(&Ui) -> R) -> R {
+    let result = {
+        let ui = ctx.frame();
+        ui.window("arrays")
+            .position([0.0, 0.0], Condition::Always)
+            .size([400.0, 300.0], Condition::Always)
+            .flags(WindowFlags::NO_DECORATION | WindowFlags::NO_MOVE)
+            .build(|| f(ui))
+            .unwrap()
+    };
+    ctx.render();
+    result
+}
+
+#[test]
+fn empty_slices_are_a_no_op() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+
+    let changed = frame(&mut ctx, |ui| {
+        let mut none: [f32; 0] = [];
+        [
+            ui.drag_scalar_n("drag", &mut none),
+            ui.slider_scalar_n("slider", 0.0, 1.0, &mut none),
+            ui.drag_config("config")
+                .range(0.0, 1.0)
+                .build_array(ui, &mut none),
+        ]
+    });
+    assert_eq!(changed, [false; 3]);
+}
+
+#[test]
+fn arbitrary_component_counts_render() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+
+    let mut knots = [0.0f32, 0.2, f32::NAN, 0.6, 0.8, 1.0];
+    let mut counters = [1u64, 2, 3, 4, 5, 6, 7, 8];
+    for _ in 0..2 {
+        let changed = frame(&mut ctx, |ui| {
+            ui.drag_scalar_n("knots", &mut knots)
+                | ui.slider_scalar_n("knots##slider", 0.0, 1.0, &mut knots)
+                | ui.drag_config("counters")
+                    .speed(0.5)
+                    .range(0, 100)
+                    .display_format("%llu")
+                    .build_array(ui, &mut counters)
+        });
+        assert!(!changed);
+    }
+    assert!(knots[2].is_nan());
+    assert_eq!(counters, [1, 2, 3, 4, 5, 6, 7, 8]);
+}
+
+#[test]
+fn clicking_a_slider_component_edits_only_that_value() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+
+    let mut values = [50i32, 50];
+    let mut slider = |ctx: &mut Context| {
+        frame(ctx, |ui| {
+            ui.set_next_item_width(200.0);
+            ui.slider_scalar_n("pair", 0, 100, &mut values)
+        })
+    };
+    // The first component starts at the window padding; click its left edge
+    ctx.io_mut().add_mouse_pos_event([9.0, 15.0]);
+    slider(&mut ctx);
+    ctx.io_mut().add_mouse_button_event(MouseButton::Left, true);
+    let changed = slider(&mut ctx);
+    ctx.io_mut()
+        .add_mouse_button_event(MouseButton::Left, false);
+    slider(&mut ctx);
+
+    assert!(changed);
+    assert_eq!(values, [0, 50]);
+}
@@ -0,0 +1,33 @@
+use dear_imgui_rs::test_harness::test_guard;
+use dear_imgui_rs::{Context, StyleColor};
+
+#[test]
+fn style_colors_index_by_name() {
+    let _guard = test_guard();
+    let mut ctx = Context::create();
+
+    let style = ctx.style_mut();
+    style[StyleColor::WindowBg] = [0.1, 0.2, 0.3, 1.0];
+    style[StyleColor::Text][3] = 0.5;
+    assert_eq!(style.color(StyleColor::WindowBg), [0.1, 0.2, 0.3, 1.0]);
+    assert_eq!(style.color(StyleColor::Text)[3], 0.5);
+
+    style.set_color(StyleColor::Border, [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(ctx.style()[StyleColor::Border], [1.0, 0.0, 0.0, 1.0]);
+}
+
+#[test]
+fn scale_all_sizes_scales_spacing_and_rounding() {
+    let _guard = test_guard();
+    let mut ctx = Context::create();
+
+    let style = ctx.style_mut();
+    style.set_window_rounding(3.0);
+    let padding = style.window_padding();
+    let spacing = style.item_spacing();
+
+    style.scale_all_sizes(2.0);
+    assert_eq!(style.window_rounding(), 6.0);
+    assert_eq!(style.window_padding(), [padding[0] * 2.0, padding[1] * 2.0]);
+    assert_eq!(style.item_spacing(), [spacing[0] * 2.0, spacing[1] * 2.0]);
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "serde")]
+
+use dear_imgui_rs::test_harness::test_guard;
+use dear_imgui_rs::{Style, StyleColor};
+use serde_json::json;
+
+#[test]
+fn style_round_trips_with_named_colors() {
+    let _guard = test_guard();
+    let mut style = Style::default();
+    style.set_window_rounding(7.0);
+    style.set_item_spacing([3.0, 5.0]);
+    style.set_anti_aliased_fill(false);
+    style[StyleColor::WindowBg] = [0.1, 0.2, 0.3, 0.9];
+
+    let value = serde_json::to_value(style).unwrap();
+    assert_eq!(value["window_rounding"], json!(7.0));
+    let window_bg: [f32; 4] = serde_json::from_value(value["colors"]["WindowBg"].clone()).unwrap();
+    assert_eq!(window_bg, [0.1, 0.2, 0.3, 0.9]);
+
+    let text = serde_json::to_string(&style).unwrap();
+    let restored: Style = serde_json::from_str(&text).unwrap();
+    assert_eq!(serde_json::to_string(&restored).unwrap(), text);
+    assert_eq!(restored.window_rounding(), 7.0);
+    assert_eq!(restored.item_spacing(), [3.0, 5.0]);
+    assert!(!restored.anti_aliased_fill());
+    assert_eq!(restored[StyleColor::WindowBg], [0.1, 0.2, 0.3, 0.9]);
+}
+
+#[test]
+fn unknown_keys_are_ignored_and_missing_keys_keep_defaults() {
+    let _guard = test_guard();
+    let theme = r#"{
+        "alpha": 0.5,
+        "future_field": true,
+        "colors": {
+            "Text": [1.0, 0.0, 0.0, 1.0],
+            "FutureColor": [0.0, 0.0, 0.0, 0.0]
+        }
+    }"#;
+
+    let style: Style = serde_json::from_str(theme).unwrap();
+    let default = Style::default();
+    assert_eq!(style.alpha(), 0.5);
+    assert_eq!(style[StyleColor::Text], [1.0, 0.0, 0.0, 1.0]);
+    assert_eq!(style.window_padding(), default.window_padding());
+    assert_eq!(style[StyleColor::WindowBg], default[StyleColor::WindowBg]);
+}
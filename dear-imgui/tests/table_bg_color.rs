@@ -0,0 +1,44 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{TableBgTarget, TableFlags};
+
+#[test]
+fn set_bg_color_on_rows_and_cells() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+
+    let ui = ctx.frame();
+    ui.window("log").build(|| {
+        if let Some(table) = ui.begin_table_with_flags("entries", 2, TableFlags::ROW_BG) {
+            for (i, level) in ["info", "error", "info"].iter().enumerate() {
+                ui.table_next_row();
+                if *level == "error" {
+                    // Row targets ignore the column
+                    table.set_bg_color(TableBgTarget::RowBg1, [0.6, 0.1, 0.1, 0.6], 7);
+                }
+                ui.table_next_column();
+                ui.text(level);
+                table.set_bg_color(TableBgTarget::CellBg, [0.2, 0.2, 0.6, 1.0], -1);
+                ui.table_next_column();
+                ui.text(format!("entry {i}"));
+                table.set_bg_color(TableBgTarget::CellBg, [0.2, 0.6, 0.2, 1.0], 0);
+                table.set_bg_color(TableBgTarget::None, [1.0; 4], 0);
+            }
+        }
+    });
+    ctx.render();
+}
+
+#[test]
+#[should_panic(expected = "out of range")]
+fn set_bg_color_rejects_out_of_range_cell() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+
+    let ui = ctx.frame();
+    ui.window("log").build(|| {
+        if let Some(table) = ui.begin_table("entries", 2) {
+            ui.table_next_row();
+            table.set_bg_color(TableBgTarget::CellBg, [1.0, 0.0, 0.0, 1.0], 2);
+        }
+    });
+}
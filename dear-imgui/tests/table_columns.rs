@@ -0,0 +1,133 @@
+use dear_imgui_rs::test_harness::headless_context;
+use dear_imgui_rs::{SortDirection, TableColumnFlags, TableFlags};
+
+#[test]
+fn set_column_enabled_hides_column_from_next_frame() {
+    let mut ctx = headless_context();
+
+    let mut flags = Vec::new();
+    for frame in 0..3 {
+        let ui = ctx.frame();
+        ui.window("grid").build(|| {
+            if let Some(table) = ui.begin_table_with_flags("cols", 3, TableFlags::HIDEABLE) {
+                for name in ["a", "b", "c"] {
+                    ui.table_setup_column(name, TableColumnFlags::NONE, 0.0, 0);
+                }
+                ui.table_headers_row();
+                if frame == 1 {
+                    table.set_column_enabled(1, false);
+                }
+                flags.push([0, 1, 2].map(|column| table.column_flags(column)));
+            }
+        });
+        ctx.render();
+    }
+
+    assert!(flags[1][1].contains(TableColumnFlags::IS_ENABLED));
+    let last = flags.last().unwrap();
+    assert!(last[0].contains(TableColumnFlags::IS_ENABLED));
+    assert!(!last[1].contains(TableColumnFlags::IS_ENABLED));
+    assert!(!last[1].contains(TableColumnFlags::IS_VISIBLE));
+}
+
+#[test]
+fn sort_specs_report_columns_until_cleared() {
+    let mut ctx = headless_context();
+
+    let mut seen = Vec::new();
+    for frame in 0..3 {
+        let ui = ctx.frame();
+        ui.window("grid").build(|| {
+            let flags = TableFlags::SORTABLE | TableFlags::SCROLL_Y;
+            if let Some(table) = ui.begin_table_with_flags("sorted", 2, flags) {
+                table.setup_scroll_freeze(0, 1);
+                for name in ["name", "size"] {
+                    ui.table_setup_column(name, TableColumnFlags::NONE, 0.0, 0);
+                }
+                ui.table_headers_row();
+                if frame == 2 {
+                    ui.table_set_column_sort_direction(1, SortDirection::Descending, false);
+                }
+                let mut specs = table.sort_specs().expect("sortable table has specs");
+                let columns: Vec<_> = specs
+                    .iter()
+                    .map(|spec| (spec.column_index, spec.sort_direction))
+                    .collect();
+                seen.push((specs.is_dirty(), columns));
+                specs.clear_dirty();
+            }
+        });
+        ctx.render();
+    }
+
+    // The first sortable column is sorted by default
+    assert_eq!(seen[0], (true, vec![(0, SortDirection::Ascending)]));
+    assert_eq!(seen[1], (false, vec![(0, SortDirection::Ascending)]));
+    assert_eq!(seen[2], (true, vec![(1, SortDirection::Descending)]));
+}
+
+#[test]
+fn setup_column_applies_flags_and_user_id() {
+    let mut ctx = headless_context();
+
+    let mut seen = Vec::new();
+    for _ in 0..2 {
+        let ui = ctx.frame();
+        ui.window("grid").build(|| {
+            let flags = TableFlags::SORTABLE | TableFlags::HIDEABLE;
+            if let Some(table) = ui.begin_table_with_flags("keyed", 3, flags) {
+                table.setup_column_simple("name");
+                table.setup_column(
+                    "size",
+                    TableColumnFlags::WIDTH_FIXED
+                        | TableColumnFlags::DEFAULT_SORT
+                        | TableColumnFlags::PREFER_SORT_DESCENDING,
+                    80.0,
+                    42,
+                );
+                table.setup_column("notes", TableColumnFlags::DEFAULT_HIDE, 0.0, 7);
+                ui.table_headers_row();
+                let specs = table.sort_specs().expect("sortable table has specs");
+                let sorted: Vec<_> = specs
+                    .iter()
+                    .map(|spec| (spec.column_user_id, spec.sort_direction))
+                    .collect();
+                seen.push((sorted, table.column_flags(2)));
+            }
+        });
+        ctx.render();
+    }
+
+    let (sorted, notes) = seen.last().unwrap();
+    assert_eq!(sorted, &vec![(42, SortDirection::Descending)]);
+    assert!(!notes.contains(TableColumnFlags::IS_ENABLED));
+}
+
+#[test]
+fn clip_rows_visits_only_visible_rows() {
+    let mut ctx = headless_context();
+
+    let mut rows = Vec::new();
+    for _ in 0..2 {
+        rows.clear();
+        let ui = ctx.frame();
+        ui.window("grid")
+            .size([400.0, 300.0], dear_imgui_rs::Condition::Always)
+            .build(|| {
+                if let Some(table) = ui.begin_table_with_flags("big", 2, TableFlags::SCROLL_Y) {
+                    table.clip_rows(500_000, |row| {
+                        ui.table_next_column();
+                        ui.text(format!("{row}"));
+                        ui.table_next_column();
+                        ui.text("value");
+                        rows.push(row);
+                    });
+                }
+            });
+        ctx.render();
+    }
+
+    assert_eq!(rows.first(), Some(&0));
+    assert!((5..100).contains(&rows.len()), "{}", rows.len());
+    assert!(rows.windows(2).all(|w| w[0] < w[1]));
+}
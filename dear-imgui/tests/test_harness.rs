@@ -0,0 +1,33 @@
+use dear_imgui_rs::test_harness::test_guard;
+use dear_imgui_rs::{Condition, ItemRecorder, TestHarness, Ui};
+
+#[test]
+fn click_and_set_value_drive_widgets() {
+    let _guard = test_guard();
+    let mut harness = TestHarness::new();
+
+    let mut clicks = 0;
+    let mut name = String::from("draft");
+    let mut draw = |ui: &Ui, items: &mut ItemRecorder<'_>| {
+        ui.window("form")
+            .position([0.0, 0.0], Condition::Always)
+            .size([400.0, 300.0], Condition::Always)
+            .build(|| {
+                if ui.button("Save") {
+                    clicks += 1;
+                }
+                items.record(ui, "Save");
+                let _ = ui.input_text("Name", &mut name).build();
+                items.record(ui, "Name");
+            });
+    };
+
+    harness.click("Save", &mut draw).unwrap();
+    harness.click("Save", &mut draw).unwrap();
+    harness.set_value("Name", "final", &mut draw).unwrap();
+    assert!(harness.item_rect("Save").is_some());
+    assert!(harness.click("Missing", &mut draw).is_err());
+
+    assert_eq!(clicks, 2);
+    assert_eq!(name, "final");
+}
@@ -0,0 +1,60 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+
+#[test]
+fn calc_text_size_variants() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let ui = ctx.frame();
+
+    let line = ui.text_line_height();
+    let [w, h] = ui.calc_text_size("Save");
+    assert!(w > 0.0);
+    assert_eq!(h, line);
+
+    // The `##` suffix only counts when not hidden
+    assert_eq!(
+        ui.calc_text_size_with_opts("Save##button", true, -1.0),
+        [w, h]
+    );
+    assert!(ui.calc_text_size("Save##button")[0] > w);
+
+    // Wrapping multi-byte text stays on character boundaries and adds lines
+    let text = "héllo wörld ünïcödé ".repeat(8);
+    let [unwrapped_w, unwrapped_h] = ui.calc_text_size(&text);
+    let [wrapped_w, wrapped_h] = ui.calc_text_size_with_opts(&text, false, 100.0);
+    assert_eq!(unwrapped_h, line);
+    assert!(wrapped_w < unwrapped_w);
+    assert!(wrapped_h > line * 2.0, "{wrapped_h}");
+
+    // Only the given slice is measured
+    assert_eq!(ui.calc_text_size(&"SaveLoad"[..4]), [w, h]);
+}
+
+#[test]
+fn glyph_metrics_add_up_to_text_width() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let ui = ctx.frame();
+    let font = ui.current_font();
+    let size = ui.current_font_size();
+
+    let a = font.find_glyph_at_size(size, 'A').expect("glyph for 'A'");
+    assert_eq!(a.codepoint(), 'A' as u32);
+    assert!(a.visible());
+    assert!(a.advance_x() > 0.0);
+    let (min, max) = a.position_and_size();
+    assert!(max[0] > min[0] && max[1] > min[1]);
+    let (uv0, uv1) = a.tex_coords();
+    assert!(uv1[0] > uv0[0] && uv1[1] > uv0[1]);
+
+    let space = font.find_glyph_at_size(size, ' ').expect("glyph for ' '");
+    assert!(!space.visible());
+    // CalcTextSize rounds widths up to whole pixels
+    let advance = a.advance_x() * 2.0 + space.advance_x();
+    assert!((ui.calc_text_size("A A")[0] - advance).abs() < 1.0);
+
+    assert!(font.find_glyph('A').is_some());
+    // The default font has no CJK glyphs, and codepoints past U+FFFF never match
+    assert!(font.find_glyph_at_size(size, '字').is_none());
+    assert!(font.find_glyph_at_size(size, '😀').is_none());
+}
@@ -0,0 +1,41 @@
+use dear_imgui_rs::test_harness::test_guard;
+use dear_imgui_rs::{TextureData, TextureFormat, TextureRect, TextureStatus};
+
+#[test]
+fn update_rects_and_rows_cover_partial_uploads() {
+    let _guard = test_guard();
+    let mut texture = TextureData::new();
+    texture.create(TextureFormat::RGBA32, 4, 3);
+    let pixels: Vec<u8> = (0..4 * 3 * 4).map(|i| i as u8).collect();
+    texture.set_data(&pixels);
+
+    // `set_data` queues the whole texture through the single update rect
+    assert_eq!(texture.status(), TextureStatus::WantUpdates);
+    let full = TextureRect {
+        x: 0,
+        y: 0,
+        w: 4,
+        h: 3,
+    };
+    assert_eq!(texture.update_rects(), vec![full]);
+
+    let rect = TextureRect {
+        x: 1,
+        y: 1,
+        w: 2,
+        h: 2,
+    };
+    let rows: Vec<&[u8]> = texture.rect_rows(rect).unwrap().collect();
+    assert_eq!(rows, [&pixels[20..28], &pixels[36..44]]);
+
+    let outside = TextureRect {
+        x: 3,
+        y: 0,
+        w: 2,
+        h: 1,
+    };
+    assert!(texture.rect_rows(outside).is_none());
+
+    texture.destroy_pixels();
+    assert!(texture.rect_rows(rect).is_none());
+}
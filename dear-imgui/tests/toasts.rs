@@ -0,0 +1,79 @@
+use dear_imgui_rs::test_harness::test_guard;
+use dear_imgui_rs::{
+    Condition, ItemRecorder, TestHarness, ToastCorner, ToastKind, Toasts, Ui, WindowFlags,
+};
+use std::time::Duration;
+
+/// Presses and releases the left button at `pos`, one frame each
+fn click_at(
+    harness: &mut TestHarness,
+    pos: [f32; 2],
+    mut draw: impl FnMut(&Ui, &mut ItemRecorder<'_>),
+) {
+    harness.context().io_mut().add_mouse_pos_event(pos);
+    harness.step(&mut draw);
+    for down in [true, false] {
+        harness
+            .context()
+            .io_mut()
+            .add_mouse_button_event(dear_imgui_rs::MouseButton::Left, down);
+        harness.step(&mut draw);
+    }
+}
+
+#[test]
+fn clicking_a_toast_dismisses_it_without_reaching_widgets_below() {
+    let _guard = test_guard();
+    let mut harness = TestHarness::new();
+    let mut toasts = Toasts::new();
+    toasts.push(ToastKind::Info, "Saved", "", Duration::from_secs(60));
+
+    let mut clicks = 0;
+    {
+        // A button covering the whole display, underneath the bottom-right toast
+        let mut draw = |ui: &Ui, _: &mut ItemRecorder<'_>| {
+            ui.window("app")
+                .position([0.0, 0.0], Condition::Always)
+                .size([800.0, 600.0], Condition::Always)
+                .flags(WindowFlags::NO_DECORATION)
+                .build(|| {
+                    if ui.button_with_size("Behind", [780.0, 580.0]) {
+                        clicks += 1;
+                    }
+                });
+            toasts.render(ui);
+        };
+        // Let the slide-in animation finish
+        harness.step_n(30, &mut draw);
+
+        click_at(&mut harness, [100.0, 100.0], &mut draw);
+        click_at(&mut harness, [642.0, 575.0], &mut draw);
+    }
+
+    assert_eq!(clicks, 1);
+    assert!(toasts.is_empty());
+}
+
+#[test]
+fn separate_queues_get_separate_windows() {
+    let _guard = test_guard();
+    let mut harness = TestHarness::new();
+    let mut top = Toasts::new().corner(ToastCorner::TopLeft);
+    let mut bottom = Toasts::new().corner(ToastCorner::BottomRight);
+    top.push(ToastKind::Info, "Top", "", Duration::from_secs(60));
+    bottom.push(ToastKind::Info, "Bottom", "", Duration::from_secs(60));
+
+    {
+        let mut draw = |ui: &Ui, _: &mut ItemRecorder<'_>| {
+            top.render(ui);
+            bottom.render(ui);
+        };
+        harness.step_n(30, &mut draw);
+
+        // Both first toasts would share one window if names were per queue only
+        click_at(&mut harness, [20.0, 20.0], &mut draw);
+    }
+
+    assert!(top.is_empty());
+    assert_eq!(bottom.len(), 1);
+}
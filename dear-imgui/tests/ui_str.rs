@@ -0,0 +1,42 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{Condition, ImString, UiStr, sys};
+use std::ffi::CString;
+
+#[test]
+fn nul_terminated_labels_are_borrowed() {
+    let owned = CString::new("Save").unwrap();
+    let im = ImString::new("Save");
+    assert_eq!(UiStr::from("Save"), UiStr::Str("Save"));
+    assert_eq!(UiStr::from(&owned), UiStr::CStr(c"Save"));
+    assert_eq!(UiStr::from(&im), UiStr::CStr(c"Save"));
+}
+
+#[test]
+fn borrowed_and_copied_labels_share_ids() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+
+    let mut ids = Vec::new();
+    {
+        let ui = ctx.frame();
+        ui.window("labels")
+            .position([0.0, 0.0], Condition::Always)
+            .size([300.0, 300.0], Condition::Always)
+            .build(|| {
+                ui.text_str(c"header");
+                ui.text_str("footer");
+                ui.button_str(c"Save");
+                ids.push(unsafe { sys::igGetItemID() });
+                ui.button("Save");
+                ids.push(unsafe { sys::igGetItemID() });
+                ui.selectable_str(&ImString::new("Row"));
+                ids.push(unsafe { sys::igGetItemID() });
+                ui.selectable("Row");
+                ids.push(unsafe { sys::igGetItemID() });
+            });
+    }
+    ctx.render();
+
+    assert_eq!(ids[0], ids[1]);
+    assert_eq!(ids[2], ids[3]);
+}
@@ -0,0 +1,51 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{Context, Ui};
+
+const RED: [f32; 4] = [1.0, 0.0, 0.0, 1.0];
+
+/// Renders one frame running `draw`, returning the vertex count and draw list count
+fn frame_counts(ctx: &mut Context, draw: impl FnOnce(&Ui)) -> (i32, usize) {
+    {
+        let ui = ctx.frame();
+        draw(ui);
+    }
+    let draw_data = ctx.render();
+    (draw_data.total_vtx_count, draw_data.draw_lists().count())
+}
+
+#[test]
+fn main_viewport_lists_match_the_default_ones() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let (empty, _) = frame_counts(&mut ctx, |_| {});
+
+    let crosshair = |ui: &Ui| {
+        let viewport = ui.main_viewport();
+        {
+            let background = ui.get_background_draw_list_for_viewport(viewport);
+            background
+                .add_line([400.0, 0.0], [400.0, 600.0], RED)
+                .build();
+        }
+        let foreground = ui.get_foreground_draw_list_for_viewport(viewport);
+        foreground
+            .add_line([0.0, 300.0], [800.0, 300.0], RED)
+            .build();
+    };
+    let default_lists = |ui: &Ui| {
+        {
+            let background = ui.get_background_draw_list();
+            background
+                .add_line([400.0, 0.0], [400.0, 600.0], RED)
+                .build();
+        }
+        let foreground = ui.get_foreground_draw_list();
+        foreground
+            .add_line([0.0, 300.0], [800.0, 300.0], RED)
+            .build();
+    };
+
+    let for_viewport = frame_counts(&mut ctx, crosshair);
+    assert!(for_viewport.0 > empty, "{for_viewport:?} {empty}");
+    assert_eq!(frame_counts(&mut ctx, default_lists), for_viewport);
+}
@@ -0,0 +1,50 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{Condition, Context, SizeCallbackData};
+
+/// Runs one frame sizing a window to 500x500, returning its resulting size
+fn constrained_size(ctx: &mut Context, constrain: impl FnOnce(&dear_imgui_rs::Ui)) -> [f32; 2] {
+    let mut size = [0.0; 2];
+    {
+        let ui = ctx.frame();
+        constrain(ui);
+        ui.window("constrained")
+            .size([500.0, 500.0], Condition::Always)
+            .build(|| size = ui.window_size());
+    }
+    ctx.render();
+    size
+}
+
+#[test]
+fn min_max_clamps_without_callback() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+
+    let size = constrained_size(&mut ctx, |ui| {
+        ui.set_next_window_size_constraints([100.0, 100.0], [300.0, 400.0], None);
+    });
+    assert_eq!(size, [300.0, 400.0]);
+}
+
+#[test]
+fn callback_adjusts_the_clamped_size() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+
+    let size = constrained_size(&mut ctx, |ui| {
+        ui.set_next_window_size_constraints(
+            [0.0, 0.0],
+            [400.0, f32::MAX],
+            Some(Box::new(|data: &mut SizeCallbackData| {
+                // Keep a 2:1 aspect ratio from the clamped width
+                let [w, _] = data.desired_size();
+                data.set_desired_size([w, w / 2.0]);
+            })),
+        );
+    });
+    assert_eq!(size, [400.0, 200.0]);
+
+    // A later window without constraints is not affected by the stored callback
+    let size = constrained_size(&mut ctx, |_| {});
+    assert_eq!(size, [500.0, 500.0]);
+}
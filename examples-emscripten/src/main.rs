@@ -0,0 +1,164 @@
+//! Minimal Dear ImGui app for `wasm32-unknown-emscripten`, rendering with WebGL2 via glow.
+//!
+//! Unlike `examples-wasm` (import-style, two modules sharing memory), cimgui is compiled by
+//! em++ and linked into this module, so no provider module or memory patching is needed.
+//!
+//! ```text
+//! rustup target add wasm32-unknown-emscripten
+//! EMCC_CFLAGS="-sUSE_WEBGL2=1 -sMAX_WEBGL_VERSION=2 -sGL_ENABLE_GET_PROC_ADDRESS=1 -sALLOW_MEMORY_GROWTH=1" \
+//!     cargo build -p dear-imgui-emscripten --target wasm32-unknown-emscripten --release
+//! ```
+//!
+//! Serve the generated `dear-imgui-emscripten.js`/`.wasm` next to a page containing
+//! `<canvas id="canvas">` (see docs/WASM.md). Input is left to the host page; this example
+//! only shows the context, font atlas and draw data working end to end.
+
+#[cfg(target_os = "emscripten")]
+mod app {
+    use dear_imgui_glow::GlowRenderer;
+    use dear_imgui_rs::*;
+    use glow::HasContext;
+    use std::cell::RefCell;
+    use std::ffi::{CStr, c_char, c_int, c_void};
+
+    const CANVAS: &CStr = c"#canvas";
+
+    /// Mirrors `EmscriptenWebGLContextAttributes` from `<emscripten/html5.h>`
+    #[repr(C)]
+    #[derive(Default)]
+    struct WebGlContextAttributes {
+        alpha: bool,
+        depth: bool,
+        stencil: bool,
+        antialias: bool,
+        premultiplied_alpha: bool,
+        preserve_drawing_buffer: bool,
+        power_preference: c_int,
+        fail_if_major_performance_caveat: bool,
+        major_version: c_int,
+        minor_version: c_int,
+        enable_extensions_by_default: bool,
+        explicit_swap_control: bool,
+        proxy_context_to_main_thread: c_int,
+        render_via_offscreen_back_buffer: bool,
+    }
+
+    unsafe extern "C" {
+        fn emscripten_set_main_loop(
+            func: extern "C" fn(),
+            fps: c_int,
+            simulate_infinite_loop: c_int,
+        );
+        fn emscripten_get_now() -> f64;
+        fn emscripten_webgl_init_context_attributes(attrs: *mut WebGlContextAttributes);
+        fn emscripten_webgl_create_context(
+            target: *const c_char,
+            attrs: *const WebGlContextAttributes,
+        ) -> c_int;
+        fn emscripten_webgl_make_context_current(context: c_int) -> c_int;
+        fn emscripten_webgl_get_proc_address(name: *const c_char) -> *const c_void;
+        fn emscripten_get_canvas_element_size(
+            target: *const c_char,
+            width: *mut c_int,
+            height: *mut c_int,
+        ) -> c_int;
+    }
+
+    struct App {
+        context: Context,
+        renderer: GlowRenderer,
+        last_frame: f64,
+        demo_open: bool,
+    }
+
+    thread_local! {
+        static APP: RefCell<Option<App>> = const { RefCell::new(None) };
+    }
+
+    pub fn run() {
+        let gl = unsafe {
+            let mut attrs = WebGlContextAttributes::default();
+            emscripten_webgl_init_context_attributes(&mut attrs);
+            attrs.major_version = 2;
+            attrs.minor_version = 0;
+            let handle = emscripten_webgl_create_context(CANVAS.as_ptr(), &attrs);
+            assert!(handle > 0, "failed to create a WebGL2 context on #canvas");
+            emscripten_webgl_make_context_current(handle);
+            glow::Context::from_loader_function_cstr(|name| {
+                emscripten_webgl_get_proc_address(name.as_ptr())
+            })
+        };
+
+        let mut context = Context::create();
+        context.set_ini_filename(None::<String>).unwrap();
+        let renderer = GlowRenderer::new(gl, &mut context).expect("create glow renderer");
+
+        APP.with(|app| {
+            *app.borrow_mut() = Some(App {
+                context,
+                renderer,
+                last_frame: unsafe { emscripten_get_now() },
+                demo_open: true,
+            });
+        });
+        // Hand control to the browser; `frame` runs once per requestAnimationFrame
+        unsafe { emscripten_set_main_loop(frame, 0, 1) };
+    }
+
+    extern "C" fn frame() {
+        APP.with(|app| {
+            if let Some(app) = app.borrow_mut().as_mut() {
+                app.render_frame();
+            }
+        });
+    }
+
+    impl App {
+        fn render_frame(&mut self) {
+            let (mut width, mut height) = (0, 0);
+            unsafe { emscripten_get_canvas_element_size(CANVAS.as_ptr(), &mut width, &mut height) };
+            let now = unsafe { emscripten_get_now() };
+            let io = self.context.io_mut();
+            io.set_display_size([width as f32, height as f32]);
+            io.set_delta_time((((now - self.last_frame) / 1000.0) as f32).max(1.0e-4));
+            self.last_frame = now;
+
+            let ui = self.context.frame();
+            ui.window("Hello from Emscripten")
+                .size([360.0, 160.0], Condition::FirstUseEver)
+                .build(|| {
+                    ui.text("Dear ImGui running on wasm32-unknown-emscripten");
+                    ui.text(format!("{:.1} FPS", ui.io().framerate()));
+                });
+            if self.demo_open {
+                ui.show_demo_window(&mut self.demo_open);
+            }
+
+            if let Some(gl) = self.renderer.gl_context() {
+                unsafe {
+                    gl.viewport(0, 0, width, height);
+                    gl.clear_color(0.1, 0.1, 0.12, 1.0);
+                    gl.clear(glow::COLOR_BUFFER_BIT);
+                }
+            }
+            let draw_data = self.context.render();
+            if let Err(e) = self
+                .renderer
+                .new_frame()
+                .and_then(|_| self.renderer.render(draw_data))
+            {
+                eprintln!("render error: {e}");
+            }
+        }
+    }
+}
+
+#[cfg(target_os = "emscripten")]
+fn main() {
+    app::run();
+}
+
+#[cfg(not(target_os = "emscripten"))]
+fn main() {
+    eprintln!("dear-imgui-emscripten only runs on wasm32-unknown-emscripten; see the crate docs");
+}
@@ -176,7 +176,7 @@ impl AppWindow {
                 #[derive(Default)]
                 struct DemoHandler;
                 impl dear_imgui_rs::InputTextCallbackHandler for DemoHandler {
-                    fn char_filter(&mut self, c: char) -> Option<char> {
+                    fn on_char_filter(&mut self, c: char) -> Option<char> {
                         // Filter out 'x' or 'X'
                         if c == 'x' || c == 'X' { None } else { Some(c) }
                     }
@@ -1,5 +1,6 @@
 //! Tables minimal example (single file).
-//! Shows a basic 3-column table with sorting and resizing.
+//! Shows a basic 3-column table with sorting and resizing, and a log table with
+//! per-row background colors and a menu to show/hide its columns.
 
 use std::{cmp::Ordering, num::NonZeroU32, sync::Arc, time::Instant};
 
@@ -43,8 +44,12 @@ struct AppWindow {
     context: PossiblyCurrentContext,
     imgui: ImguiState,
     rows: Vec<Row>,
+    log_columns: [bool; 3],
+    log_column_toggle: Option<(i32, bool)>,
 }
 
+const LOG_COLUMNS: [&str; 3] = ["Time", "Level", "Message"];
+
 #[derive(Default)]
 struct App {
     window: Option<AppWindow>,
@@ -142,6 +147,8 @@ impl AppWindow {
             context,
             imgui,
             rows,
+            log_columns: [true; 3],
+            log_column_toggle: None,
         })
     }
 
@@ -241,6 +248,71 @@ impl AppWindow {
 
                 // Keep a sorted copy in state (optional)
                 self.rows = rows;
+
+                ui.spacing();
+                ui.text("Log with highlighted error rows");
+                ui.same_line();
+                // Columns can only be toggled while their table is current, so the menu
+                // records the click and the table applies it below
+                if ui.small_button("Columns") {
+                    ui.open_popup("log_columns");
+                }
+                if let Some(_popup) = ui.begin_popup("log_columns") {
+                    for (column, name) in LOG_COLUMNS.iter().enumerate() {
+                        let mut enabled = self.log_columns[column];
+                        if ui.checkbox(name, &mut enabled) {
+                            self.log_column_toggle = Some((column as i32, enabled));
+                        }
+                    }
+                }
+                ui.separator();
+
+                const LOG: [(&str, &str, &str); 5] = [
+                    ("12:00:01", "INFO", "Loaded 5 items"),
+                    ("12:00:02", "ERROR", "Price feed unreachable"),
+                    ("12:00:02", "INFO", "Retrying in 5s"),
+                    ("12:00:07", "WARN", "Using cached prices"),
+                    ("12:00:07", "ERROR", "Price feed unreachable"),
+                ];
+                if let Some(table) = ui.begin_table_with_flags(
+                    "log",
+                    LOG_COLUMNS.len(),
+                    TableFlags::ROW_BG | TableFlags::BORDERS_OUTER | TableFlags::HIDEABLE,
+                ) {
+                    for name in LOG_COLUMNS {
+                        ui.table_setup_column(name, TableColumnFlags::NONE, 0.0, 0);
+                    }
+                    ui.table_headers_row();
+                    if let Some((column, enabled)) = self.log_column_toggle.take() {
+                        table.set_column_enabled(column, enabled);
+                    }
+                    // Mirror the current state, which the header context menu can change too
+                    for (column, enabled) in self.log_columns.iter_mut().enumerate() {
+                        *enabled = table
+                            .column_flags(column as i32)
+                            .contains(TableColumnFlags::IS_ENABLED);
+                    }
+
+                    for (time, level, message) in LOG {
+                        ui.table_next_row();
+                        match level {
+                            "ERROR" => {
+                                table.set_bg_color(TableBgTarget::RowBg1, [0.7, 0.1, 0.1, 0.5], -1)
+                            }
+                            // Only the level cell for warnings
+                            "WARN" => {
+                                table.set_bg_color(TableBgTarget::CellBg, [0.7, 0.5, 0.1, 0.5], 1)
+                            }
+                            _ => {}
+                        }
+                        ui.table_next_column();
+                        ui.text(time);
+                        ui.table_next_column();
+                        ui.text(level);
+                        ui.table_next_column();
+                        ui.text(message);
+                    }
+                }
             });
 
         if let Some(gl) = self.imgui.renderer.gl_context() {
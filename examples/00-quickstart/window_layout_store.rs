@@ -0,0 +1,94 @@
+//! Window layout persistence without the .ini file
+//! - `LayoutStore` restores/captures per-window position and size by name
+//! - Layouts live in a plain `HashMap`, saved here as one line per window
+//! - Move or resize the windows, restart, and they come back where you left them
+
+use dear_app::{AddOnsConfig, RunnerConfig, run};
+use dear_imgui_rs::*;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+const WINDOWS: [&str; 3] = ["Scene", "Inspector", "Console"];
+
+fn config_path() -> PathBuf {
+    std::env::temp_dir().join("dear_imgui_window_layout.txt")
+}
+
+/// Parses `name<TAB>x<TAB>y<TAB>w<TAB>h` lines; stands in for the app's own JSON/TOML config
+fn load_layouts() -> HashMap<String, WindowLayout> {
+    let text = std::fs::read_to_string(config_path()).unwrap_or_default();
+    text.lines()
+        .filter_map(|line| {
+            let mut parts = line.split('\t');
+            let name = parts.next()?.to_string();
+            let v: Vec<f32> = parts.filter_map(|p| p.parse().ok()).collect();
+            let [x, y, w, h] = v[..] else { return None };
+            Some((
+                name,
+                WindowLayout {
+                    pos: [x, y],
+                    size: [w, h],
+                },
+            ))
+        })
+        .collect()
+}
+
+fn save_layouts(layouts: &HashMap<String, WindowLayout>) {
+    let text: String = layouts
+        .iter()
+        .map(|(name, l)| {
+            format!(
+                "{name}\t{}\t{}\t{}\t{}\n",
+                l.pos[0], l.pos[1], l.size[0], l.size[1]
+            )
+        })
+        .collect();
+    if let Err(e) = std::fs::write(config_path(), text) {
+        eprintln!("failed to save layout: {e}");
+    }
+}
+
+fn main() {
+    dear_imgui_rs::logging::init_tracing_with_filter("dear_imgui=info,wgpu=warn");
+
+    let runner = RunnerConfig {
+        window_title: "Window Layout Store".to_string(),
+        window_size: (1280.0, 720.0),
+        // Geometry comes from the LayoutStore instead of imgui.ini
+        ini_filename: None,
+        ..Default::default()
+    };
+
+    let mut layouts = LayoutStore::from_map(load_layouts());
+    // Default placement for windows that have no saved layout yet
+    for (i, name) in WINDOWS.into_iter().enumerate() {
+        if layouts.get(name).is_none() {
+            let offset = 40.0 + i as f32 * 60.0;
+            layouts.set(
+                name,
+                WindowLayout {
+                    pos: [offset, offset],
+                    size: [320.0, 200.0],
+                },
+            );
+        }
+    }
+
+    run(runner, AddOnsConfig::auto(), move |ui, _addons| {
+        for name in WINDOWS {
+            layouts.window(ui, name).build(|| {
+                ui.text(format!("This is the {name} window."));
+                ui.text_disabled("Move or resize me, then restart.");
+                layouts.capture(ui, name);
+            });
+        }
+
+        // Write the config once a drag/resize has finished rather than every frame
+        if layouts.is_dirty() && !ui.is_mouse_down(MouseButton::Left) {
+            save_layouts(layouts.as_map());
+            layouts.mark_saved();
+        }
+    })
+    .unwrap();
+}
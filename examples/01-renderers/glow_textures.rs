@@ -234,6 +234,36 @@ impl TextureDemo {
     }
 
     fn show_ui(&self, ui: &dear_imgui_rs::Ui) {
+        // Textured primitives drawn straight into the background draw list, behind all windows
+        if let Some(texture_id) = self.checkerboard_texture {
+            let draw_list = ui.get_background_draw_list();
+            let [w, h] = ui.io().display_size();
+            // Rounded thumbnail in the bottom-right corner
+            draw_list.add_image_rounded(
+                texture_id,
+                [w - 180.0, h - 180.0],
+                [w - 20.0, h - 20.0],
+                [0.0, 0.0],
+                [1.0, 1.0],
+                [1.0, 1.0, 1.0, 1.0],
+                24.0,
+                DrawFlags::ROUND_CORNERS_ALL,
+            );
+            // Perspective-like warp: the top edge is narrower than the bottom one
+            draw_list.add_image_quad(
+                texture_id,
+                [w - 340.0, h - 180.0],
+                [w - 240.0, h - 180.0],
+                [w - 200.0, h - 20.0],
+                [w - 380.0, h - 20.0],
+                [0.0, 0.0],
+                [1.0, 0.0],
+                [1.0, 1.0],
+                [0.0, 1.0],
+                [1.0, 1.0, 1.0, 0.8],
+            );
+        }
+
         ui.window("Modern Texture Management Demo")
             .size([500.0, 600.0], Condition::FirstUseEver)
             .build(|| {
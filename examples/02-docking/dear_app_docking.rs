@@ -34,7 +34,7 @@ fn apply_default_layout(ui: &Ui, dockspace_id: Id) {
     let size = vp.size();
 
     DockBuilder::remove_node(dockspace_id);
-    let root = DockBuilder::add_node(dockspace_id, DockNodeFlags::NONE);
+    let root = DockBuilder::add_node(dockspace_id, DockNodeFlags::DOCK_SPACE);
     DockBuilder::set_node_size(root, [size[0], size[1]]);
 
     // Layout like the C++ demo comments:
@@ -60,7 +60,7 @@ fn apply_alternative_layout(ui: &Ui, dockspace_id: Id) {
     let size = vp.size();
 
     DockBuilder::remove_node(dockspace_id);
-    let root = DockBuilder::add_node(dockspace_id, DockNodeFlags::NONE);
+    let root = DockBuilder::add_node(dockspace_id, DockNodeFlags::DOCK_SPACE);
     DockBuilder::set_node_size(root, [size[0], size[1]]);
 
     // Alternative layout: Misc on left (30%), bottom split between Command and Command2
@@ -183,7 +183,7 @@ impl AppWindow {
                 // Configure DockBuilder only once (if node doesn't exist yet)
                 if !DockBuilder::node_exists(&ui, dockspace_id) {
                     DockBuilder::remove_node(dockspace_id);
-                    DockBuilder::add_node(dockspace_id, DockNodeFlags::NONE);
+                    DockBuilder::add_node(dockspace_id, DockNodeFlags::DOCK_SPACE);
                     DockBuilder::set_node_size(dockspace_id, size);
 
                     let mut dock_main_id = dockspace_id;
@@ -430,8 +430,7 @@ impl AppWindow {
             }
         }
         if actions.save_ini {
-            let mut buf = String::new();
-            imgui.context.save_ini_settings(&mut buf);
+            let buf = imgui.context.save_ini_settings_to_string();
             if std::fs::write("examples/02-docking/game_engine_docking.ini", buf).is_ok() {
                 imgui
                     .game_state
@@ -458,7 +457,7 @@ fn setup_initial_docking_layout(dockspace_id: dear_imgui_rs::Id) {
     // Clear any existing layout and create fresh dockspace (size comes from main viewport)
     DockBuilder::remove_node_docked_windows(dockspace_id, true);
     DockBuilder::remove_node(dockspace_id);
-    DockBuilder::add_node(dockspace_id, dear_imgui_rs::DockNodeFlags::NONE);
+    DockBuilder::add_node(dockspace_id, dear_imgui_rs::DockNodeFlags::DOCK_SPACE);
     // Match node pos/size to main viewport work area (exclude menu bars) before splitting
     {
         let vp = dear_imgui_rs::Viewport::main();
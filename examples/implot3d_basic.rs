@@ -136,7 +136,7 @@ fn setup_dockspace_layout(ui: &Ui) {
         DockBuilder::remove_node(dockspace_id);
 
         // Create the root dockspace node
-        DockBuilder::add_node(dockspace_id, DockNodeFlags::NONE);
+        DockBuilder::add_node(dockspace_id, DockNodeFlags::DOCK_SPACE);
         DockBuilder::set_node_size(dockspace_id, viewport_size);
 
         // Split the dockspace: left 50% for Rust demo, right 50% for C++ demo
@@ -194,10 +194,14 @@ fn try_download_prebuilt(
     target_env: &str,
 ) -> Result<PathBuf, String> {
     let lib_name = expected_lib_name(target_env);
-    build_support::download_prebuilt(cache_root, url, lib_name, target_env)
+    let timeout =
+        build_support::prebuilt_timeout_from_env("IMGUIZMO_QUAT_SYS_PREBUILT_TIMEOUT_SECS");
+    build_support::download_prebuilt_with_timeout(cache_root, url, lib_name, target_env, timeout)
 }
 
-fn try_download_prebuilt_from_release(cfg: &BuildConfig) -> Option<PathBuf> {
+fn try_download_prebuilt_from_release(
+    cfg: &BuildConfig,
+) -> Option<(PathBuf, build_support::LibProvenance)> {
     if build_support::is_offline() {
         return None;
     }
@@ -237,7 +241,12 @@ fn try_download_prebuilt_from_release(cfg: &BuildConfig) -> Option<PathBuf> {
                     &cache_root,
                     expected_lib_name(&cfg.target_env),
                 ) {
-                    return Some(lib_dir);
+                    return Some((
+                        lib_dir,
+                        build_support::LibProvenance::PrebuiltLocal {
+                            origin: archive_path.display().to_string(),
+                        },
+                    ));
                 }
             }
         }
@@ -247,29 +256,40 @@ fn try_download_prebuilt_from_release(cfg: &BuildConfig) -> Option<PathBuf> {
     let urls = build_support::release_candidate_urls_env(&tags, &names);
     for url in urls {
         if let Ok(lib_dir) = try_download_prebuilt(&cache_root, &url, &cfg.target_env) {
-            return Some(lib_dir);
+            return Some((
+                lib_dir,
+                build_support::LibProvenance::PrebuiltDownloaded { origin: url },
+            ));
         }
     }
     None
 }
 
-fn try_link_prebuilt_all(cfg: &BuildConfig) -> bool {
+fn try_link_prebuilt_all(cfg: &BuildConfig) -> Option<build_support::LibProvenance> {
     let target_env = &cfg.target_env;
     if let Ok(dir) = env::var("IMGUIZMO_QUAT_SYS_LIB_DIR") {
-        if try_link_prebuilt(PathBuf::from(dir.clone()), target_env) {
-            return true;
+        if try_link_prebuilt(PathBuf::from(&dir), target_env) {
+            return Some(build_support::LibProvenance::PrebuiltLocal { origin: dir });
         }
         println!(
             "cargo:warning=IMGUIZMO_QUAT_SYS_LIB_DIR set but library not found in {}",
             dir
         );
     }
+    if env::var_os("IMGUIZMO_QUAT_SYS_PREBUILT_URL").is_none()
+        && let Some(dir) = build_support::shared_prebuilt_lib_dir(expected_lib_name(target_env))
+        && try_link_prebuilt(dir.clone(), target_env)
+    {
+        return Some(build_support::LibProvenance::PrebuiltLocal {
+            origin: dir.display().to_string(),
+        });
+    }
     if let Ok(url) = env::var("IMGUIZMO_QUAT_SYS_PREBUILT_URL") {
         let cache_root = prebuilt_cache_root(cfg);
         if let Ok(dir) = try_download_prebuilt(&cache_root, &url, target_env)
             && try_link_prebuilt(dir.clone(), target_env)
         {
-            return true;
+            return Some(build_support::LibProvenance::PrebuiltDownloaded { origin: url });
         }
     } else {
         // Only attempt automatic release download when explicitly enabled.
@@ -291,17 +311,22 @@ fn try_link_prebuilt_all(cfg: &BuildConfig) -> bool {
                 "cargo:warning=auto-prebuilt enabled (dear-imguizmo-quat-sys): source={}, repo={}/{}",
                 source, owner, repo
             );
-            if let Some(dir) = try_download_prebuilt_from_release(cfg)
-                && try_link_prebuilt(dir.clone(), target_env)
+            if let Some((dir, provenance)) = try_download_prebuilt_from_release(cfg)
+                && try_link_prebuilt(dir, target_env)
             {
-                return true;
+                return Some(provenance);
             }
         }
     }
-    false
+    None
 }
 
-fn build_with_cc(cfg: &BuildConfig, quat_root: &Path, imgui_src: &Path, cimgui_root: &Path) {
+fn build_with_cc(
+    cfg: &BuildConfig,
+    quat_root: &Path,
+    imgui_src: &Path,
+    cimgui_root: &Path,
+) -> usize {
     let imguizmo_quat_inc = quat_root.join("imGuIZMO.quat").join("imguizmo_quat");
 
     let mut build = cc::Build::new();
@@ -339,7 +364,9 @@ fn build_with_cc(cfg: &BuildConfig, quat_root: &Path, imgui_src: &Path, cimgui_r
         }
         build.flag("/D_ITERATOR_DEBUG_LEVEL=0");
     }
+    let files = build.get_files().count();
     build.compile("dear_imguizmo_quat");
+    files
 }
 
 fn main() {
@@ -357,39 +384,66 @@ fn main() {
     );
     println!("cargo:rerun-if-changed=../../dear-imgui-sys");
     println!("cargo:rerun-if-env-changed=IMGUIZMO_QUAT_SYS_LIB_DIR");
+    println!("cargo:rerun-if-env-changed=DEAR_IMGUI_PREBUILT_DIR");
     println!("cargo:rerun-if-env-changed=IMGUIZMO_QUAT_SYS_SKIP_CC");
     println!("cargo:rerun-if-env-changed=IMGUIZMO_QUAT_SYS_PREBUILT_URL");
+    println!("cargo:rerun-if-env-changed=IMGUIZMO_QUAT_SYS_PREBUILT_TIMEOUT_SECS");
     println!("cargo:rerun-if-env-changed=IMGUIZMO_QUAT_SYS_FORCE_BUILD");
 
     let (imgui_src, cimgui_root) = resolve_imgui_includes(&cfg);
     let quat_root = cfg.manifest_dir.join("third-party/cimguizmo_quat");
-    if !imgui_src.exists() {
-        panic!("ImGui include not found at {:?}", imgui_src);
-    }
-    if !cimgui_root.exists() {
-        panic!("cimgui root not found at {:?}", cimgui_root);
-    }
-    if !quat_root.exists() {
-        panic!(
-            "cimguizmo_quat root not found at {:?}. Did you init submodules?",
-            quat_root
-        );
-    }
+    // `pregenerated-bindings`: without the C/C++ sources (e.g. a crates.io build using a
+    // prebuilt library), fall back to the checked-in bindings instead of running bindgen.
+    let header_free =
+        cfg!(feature = "pregenerated-bindings") && !quat_root.join("cimguizmo_quat.h").exists();
+    if header_free {
+        if !use_pregenerated_bindings(&cfg.out_dir) {
+            panic!(
+                "dear-imguizmo-quat-sys: feature `pregenerated-bindings` is enabled but src/bindings_pregenerated.rs is not available"
+            );
+        }
+    } else {
+        if !imgui_src.exists() {
+            panic!("ImGui include not found at {:?}", imgui_src);
+        }
+        if !cimgui_root.exists() {
+            panic!("cimgui root not found at {:?}", cimgui_root);
+        }
+        if !quat_root.exists() {
+            panic!(
+                "cimguizmo_quat root not found at {:?}. Did you init submodules?",
+                quat_root
+            );
+        }
 
-    // Generate bindings
-    docsrs_build(&cfg, &quat_root, &imgui_src, &cimgui_root);
+        // Generate bindings
+        docsrs_build(&cfg, &quat_root, &imgui_src, &cimgui_root);
+    }
 
     // Link/build native
     let force_build =
         cfg!(feature = "build-from-source") || env::var("IMGUIZMO_QUAT_SYS_FORCE_BUILD").is_ok();
-    let linked_prebuilt = if force_build {
-        false
+    let mut provenance = if force_build {
+        None
     } else {
         try_link_prebuilt_all(&cfg)
     };
+    let linked_prebuilt = provenance.is_some();
+    if header_free && !linked_prebuilt && env::var("IMGUIZMO_QUAT_SYS_SKIP_CC").is_err() {
+        panic!(
+            "dear-imguizmo-quat-sys: C/C++ sources are missing and no prebuilt library was linked; enable the `prebuilt` feature or set IMGUIZMO_QUAT_SYS_LIB_DIR, IMGUIZMO_QUAT_SYS_PREBUILT_URL or DEAR_IMGUI_PREBUILT_DIR"
+        );
+    }
     if !cfg.docs_rs && !linked_prebuilt && env::var("IMGUIZMO_QUAT_SYS_SKIP_CC").is_err() {
-        build_with_cc(&cfg, &quat_root, &imgui_src, &cimgui_root);
+        let files = build_with_cc(&cfg, &quat_root, &imgui_src, &cimgui_root);
+        provenance = Some(build_support::LibProvenance::Source {
+            tool: "cc",
+            files: Some(files),
+        });
     } else if cfg.docs_rs {
         docsrs_build(&cfg, &quat_root, &imgui_src, &cimgui_root);
     }
+    if let Some(provenance) = &provenance {
+        build_support::report_provenance(&cfg.out_dir, "dear_imguizmo_quat", provenance);
+    }
 }
@@ -108,23 +108,31 @@ fn generate_bindings(
     sanitize_bindings_file(&out);
 }
 
-fn try_link_prebuilt_all(cfg: &BuildConfig) -> bool {
+fn try_link_prebuilt_all(cfg: &BuildConfig) -> Option<build_support::LibProvenance> {
     let target_env = &cfg.target_env;
     if let Ok(dir) = env::var("IMNODES_SYS_LIB_DIR") {
-        if try_link_prebuilt(PathBuf::from(dir.clone()), target_env) {
-            return true;
+        if try_link_prebuilt(PathBuf::from(&dir), target_env) {
+            return Some(build_support::LibProvenance::PrebuiltLocal { origin: dir });
         }
         println!(
             "cargo:warning=IMNODES_SYS_LIB_DIR set but library not found in {}",
             dir
         );
     }
+    if env::var_os("IMNODES_SYS_PREBUILT_URL").is_none()
+        && let Some(dir) = build_support::shared_prebuilt_lib_dir(expected_lib_name(target_env))
+        && try_link_prebuilt(dir.clone(), target_env)
+    {
+        return Some(build_support::LibProvenance::PrebuiltLocal {
+            origin: dir.display().to_string(),
+        });
+    }
     if let Ok(url) = env::var("IMNODES_SYS_PREBUILT_URL") {
         let cache_root = prebuilt_cache_root(cfg);
         if let Ok(dir) = try_download_prebuilt(&cache_root, &url, target_env)
             && try_link_prebuilt(dir.clone(), target_env)
         {
-            return true;
+            return Some(build_support::LibProvenance::PrebuiltDownloaded { origin: url });
         }
     } else {
         // Only attempt automatic release download when explicitly enabled.
@@ -146,17 +154,22 @@ fn try_link_prebuilt_all(cfg: &BuildConfig) -> bool {
                 "cargo:warning=auto-prebuilt enabled (dear-imnodes-sys): source={}, repo={}/{}",
                 source, owner, repo
             );
-            if let Some(dir) = try_download_prebuilt_from_release(cfg)
-                && try_link_prebuilt(dir.clone(), target_env)
+            if let Some((dir, provenance)) = try_download_prebuilt_from_release(cfg)
+                && try_link_prebuilt(dir, target_env)
             {
-                return true;
+                return Some(provenance);
             }
         }
     }
-    false
+    None
 }
 
-fn build_with_cc(cfg: &BuildConfig, cimnodes_root: &Path, imgui_src: &Path, cimgui_root: &Path) {
+fn build_with_cc(
+    cfg: &BuildConfig,
+    cimnodes_root: &Path,
+    imgui_src: &Path,
+    cimgui_root: &Path,
+) -> usize {
     let mut build = cc::Build::new();
     build.cpp(true).std("c++17");
     for (k, v) in env::vars() {
@@ -190,7 +203,9 @@ fn build_with_cc(cfg: &BuildConfig, cimnodes_root: &Path, imgui_src: &Path, cimg
         }
         build.flag("/D_ITERATOR_DEBUG_LEVEL=0");
     }
+    let files = build.get_files().count();
     build.compile("dear_imnodes");
+    files
 }
 
 fn main() {
@@ -204,39 +219,66 @@ fn main() {
     println!("cargo:rerun-if-changed=third-party/cimnodes/imnodes/imnodes.cpp");
     println!("cargo:rerun-if-changed=../../dear-imgui-sys");
     println!("cargo:rerun-if-env-changed=IMNODES_SYS_LIB_DIR");
+    println!("cargo:rerun-if-env-changed=DEAR_IMGUI_PREBUILT_DIR");
     println!("cargo:rerun-if-env-changed=IMNODES_SYS_SKIP_CC");
     println!("cargo:rerun-if-env-changed=IMNODES_SYS_PREBUILT_URL");
+    println!("cargo:rerun-if-env-changed=IMNODES_SYS_PREBUILT_TIMEOUT_SECS");
     println!("cargo:rerun-if-env-changed=IMNODES_SYS_FORCE_BUILD");
     println!("cargo:rerun-if-env-changed=IMNODES_SYS_USE_CMAKE");
 
     let (imgui_src, cimgui_root) = resolve_imgui_includes(&cfg);
     let cimnodes_root = cfg.manifest_dir.join("third-party/cimnodes");
-    if !imgui_src.exists() {
-        panic!("ImGui include not found at {:?}", imgui_src);
-    }
-    if !cimnodes_root.exists() {
-        panic!(
-            "cimnodes root not found at {:?}. Did you init submodules?",
-            cimnodes_root
-        );
-    }
+    // `pregenerated-bindings`: without the C/C++ sources (e.g. a crates.io build using a
+    // prebuilt library), fall back to the checked-in bindings instead of running bindgen.
+    let header_free =
+        cfg!(feature = "pregenerated-bindings") && !cimnodes_root.join("cimnodes.h").exists();
+    if header_free {
+        if !use_pregenerated_bindings(&cfg.out_dir) {
+            panic!(
+                "dear-imnodes-sys: feature `pregenerated-bindings` is enabled but src/bindings_pregenerated.rs is not available"
+            );
+        }
+    } else {
+        if !imgui_src.exists() {
+            panic!("ImGui include not found at {:?}", imgui_src);
+        }
+        if !cimnodes_root.exists() {
+            panic!(
+                "cimnodes root not found at {:?}. Did you init submodules?",
+                cimnodes_root
+            );
+        }
 
-    // Generate bindings
-    generate_bindings(&cfg, &cimnodes_root, &imgui_src, &cimgui_root);
+        // Generate bindings
+        generate_bindings(&cfg, &cimnodes_root, &imgui_src, &cimgui_root);
+    }
 
     // Try prebuilt then build (unless build-from-source feature)
     let force_build =
         cfg!(feature = "build-from-source") || env::var("IMNODES_SYS_FORCE_BUILD").is_ok();
-    let linked = if force_build {
-        false
+    let mut provenance = if force_build {
+        None
     } else {
         try_link_prebuilt_all(&cfg)
     };
+    let linked = provenance.is_some();
+    if header_free && !linked && env::var("IMNODES_SYS_SKIP_CC").is_err() {
+        panic!(
+            "dear-imnodes-sys: C/C++ sources are missing and no prebuilt library was linked; enable the `prebuilt` feature or set IMNODES_SYS_LIB_DIR, IMNODES_SYS_PREBUILT_URL or DEAR_IMGUI_PREBUILT_DIR"
+        );
+    }
     if !cfg.docs_rs && !linked && env::var("IMNODES_SYS_SKIP_CC").is_err() {
-        build_with_cc(&cfg, &cimnodes_root, &imgui_src, &cimgui_root);
+        let files = build_with_cc(&cfg, &cimnodes_root, &imgui_src, &cimgui_root);
+        provenance = Some(build_support::LibProvenance::Source {
+            tool: "cc",
+            files: Some(files),
+        });
     } else if cfg.docs_rs {
         docsrs_build(&cfg);
     }
+    if let Some(provenance) = &provenance {
+        build_support::report_provenance(&cfg.out_dir, "dear_imnodes", provenance);
+    }
 }
 
 fn docsrs_build(cfg: &BuildConfig) {
@@ -342,10 +384,13 @@ fn try_download_prebuilt(
     target_env: &str,
 ) -> Result<PathBuf, String> {
     let lib_name = expected_lib_name(target_env);
-    build_support::download_prebuilt(cache_root, url, lib_name, target_env)
+    let timeout = build_support::prebuilt_timeout_from_env("IMNODES_SYS_PREBUILT_TIMEOUT_SECS");
+    build_support::download_prebuilt_with_timeout(cache_root, url, lib_name, target_env, timeout)
 }
 
-fn try_download_prebuilt_from_release(cfg: &BuildConfig) -> Option<PathBuf> {
+fn try_download_prebuilt_from_release(
+    cfg: &BuildConfig,
+) -> Option<(PathBuf, build_support::LibProvenance)> {
     if build_support::is_offline() {
         return None;
     }
@@ -380,7 +425,12 @@ fn try_download_prebuilt_from_release(cfg: &BuildConfig) -> Option<PathBuf> {
                     &cache_root,
                     expected_lib_name(&cfg.target_env),
                 ) {
-                    return Some(lib_dir);
+                    return Some((
+                        lib_dir,
+                        build_support::LibProvenance::PrebuiltLocal {
+                            origin: archive_path.display().to_string(),
+                        },
+                    ));
                 }
             }
         }
@@ -390,7 +440,10 @@ fn try_download_prebuilt_from_release(cfg: &BuildConfig) -> Option<PathBuf> {
     let urls = build_support::release_candidate_urls_env(&tags, &names);
     for url in urls {
         if let Ok(lib_dir) = try_download_prebuilt(&cache_root, &url, &cfg.target_env) {
-            return Some(lib_dir);
+            return Some((
+                lib_dir,
+                build_support::LibProvenance::PrebuiltDownloaded { origin: url },
+            ));
         }
     }
     None
@@ -105,21 +105,29 @@ fn generate_bindings(cfg: &BuildConfig, cimplot_root: &Path, imgui_src: &Path, c
     sanitize_bindings_file(&out);
 }
 
-fn try_link_prebuilt_all(cfg: &BuildConfig) -> bool {
+fn try_link_prebuilt_all(cfg: &BuildConfig) -> Option<build_support::LibProvenance> {
     let target_env = &cfg.target_env;
     if let Ok(dir) = env::var("IMPLOT_SYS_LIB_DIR") {
-        if try_link_prebuilt(PathBuf::from(dir), target_env) {
-            return true;
+        if try_link_prebuilt(PathBuf::from(&dir), target_env) {
+            return Some(build_support::LibProvenance::PrebuiltLocal { origin: dir });
         }
         println!(
             "cargo:warning=IMPLOT_SYS_LIB_DIR set but no library found; falling back to build"
         );
     }
+    if env::var_os("IMPLOT_SYS_PREBUILT_URL").is_none()
+        && let Some(dir) = build_support::shared_prebuilt_lib_dir(expected_lib_name(target_env))
+        && try_link_prebuilt(dir.clone(), target_env)
+    {
+        return Some(build_support::LibProvenance::PrebuiltLocal {
+            origin: dir.display().to_string(),
+        });
+    }
     if let Ok(url) = env::var("IMPLOT_SYS_PREBUILT_URL") {
         let cache_root = prebuilt_cache_root(cfg);
         if let Ok(dir) = try_download_prebuilt(&cache_root, &url, target_env) {
             if try_link_prebuilt(dir.clone(), target_env) {
-                return true;
+                return Some(build_support::LibProvenance::PrebuiltDownloaded { origin: url });
             }
             println!(
                 "cargo:warning=Downloaded prebuilt library but failed to link from {}",
@@ -145,17 +153,22 @@ fn try_link_prebuilt_all(cfg: &BuildConfig) -> bool {
                 "cargo:warning=auto-prebuilt enabled (dear-implot-sys): source={}, repo={}/{}",
                 source, owner, repo
             );
-            if let Some(dir) = try_download_prebuilt_from_release(cfg)
-                && try_link_prebuilt(dir.clone(), target_env)
+            if let Some((dir, provenance)) = try_download_prebuilt_from_release(cfg)
+                && try_link_prebuilt(dir, target_env)
             {
-                return true;
+                return Some(provenance);
             }
         }
     }
-    false
+    None
 }
 
-fn build_with_cc(cfg: &BuildConfig, cimplot_root: &Path, imgui_src: &Path, cimgui_root: &Path) {
+fn build_with_cc(
+    cfg: &BuildConfig,
+    cimplot_root: &Path,
+    imgui_src: &Path,
+    cimgui_root: &Path,
+) -> usize {
     let mut build = cc::Build::new();
     if cfg.target_arch == "wasm32" {
         build.define("IMGUI_DISABLE_DEFAULT_SHELL_FUNCTIONS", "1");
@@ -205,7 +218,9 @@ fn build_with_cc(cfg: &BuildConfig, cimplot_root: &Path, imgui_src: &Path, cimgu
     build.file(cimplot_root.join("implot/implot_items.cpp"));
     build.file(cimplot_root.join("implot/implot_demo.cpp"));
 
+    let files = build.get_files().count();
     build.compile("dear_implot");
+    files
 }
 
 fn main() {
@@ -220,8 +235,10 @@ fn main() {
     println!("cargo:rerun-if-changed=third-party/cimplot/implot/implot_items.cpp");
     println!("cargo:rerun-if-changed=../../dear-imgui-sys");
     println!("cargo:rerun-if-env-changed=IMPLOT_SYS_LIB_DIR");
+    println!("cargo:rerun-if-env-changed=DEAR_IMGUI_PREBUILT_DIR");
     println!("cargo:rerun-if-env-changed=IMPLOT_SYS_SKIP_CC");
     println!("cargo:rerun-if-env-changed=IMPLOT_SYS_PREBUILT_URL");
+    println!("cargo:rerun-if-env-changed=IMPLOT_SYS_PREBUILT_TIMEOUT_SECS");
     println!("cargo:rerun-if-env-changed=IMPLOT_SYS_FORCE_BUILD");
     println!("cargo:rerun-if-env-changed=IMPLOT_SYS_USE_CMAKE");
 
@@ -234,39 +251,68 @@ fn main() {
         return;
     }
 
-    if !imgui_src.exists() {
-        panic!(
-            "ImGui source not found at {:?}. Did you forget to initialize git submodules?",
-            imgui_src
-        );
-    }
-    if !cimplot_root.exists() {
-        panic!(
-            "cimplot source not found at {:?}. Did you forget to initialize git submodules?",
-            cimplot_root
-        );
-    }
+    // `pregenerated-bindings`: without the C/C++ sources (e.g. a crates.io build using a
+    // prebuilt library), fall back to the checked-in bindings instead of running bindgen.
+    let header_free =
+        cfg!(feature = "pregenerated-bindings") && !cimplot_root.join("cimplot.h").exists();
+    if header_free {
+        if !use_pregenerated_bindings(&cfg.out_dir) {
+            panic!(
+                "dear-implot-sys: feature `pregenerated-bindings` is enabled but src/bindings_pregenerated.rs is not available"
+            );
+        }
+    } else {
+        if !imgui_src.exists() {
+            panic!(
+                "ImGui source not found at {:?}. Did you forget to initialize git submodules?",
+                imgui_src
+            );
+        }
+        if !cimplot_root.exists() {
+            panic!(
+                "cimplot source not found at {:?}. Did you forget to initialize git submodules?",
+                cimplot_root
+            );
+        }
 
-    // Generate bindings (native/source build path)
-    generate_bindings(&cfg, &cimplot_root, &imgui_src, &cimgui_root);
+        // Generate bindings (native/source build path)
+        generate_bindings(&cfg, &cimplot_root, &imgui_src, &cimgui_root);
+    }
 
     // Features: build-from-source forces source build; prebuilt is opt-in
     let force_build =
         cfg!(feature = "build-from-source") || env::var("IMPLOT_SYS_FORCE_BUILD").is_ok();
-    let linked_prebuilt = if force_build {
-        false
+    let mut provenance = if force_build {
+        None
     } else {
         try_link_prebuilt_all(&cfg)
     };
+    let linked_prebuilt = provenance.is_some();
+    if header_free && (force_build || (!linked_prebuilt && env::var("IMPLOT_SYS_SKIP_CC").is_err()))
+    {
+        panic!(
+            "dear-implot-sys: C/C++ sources are missing and no prebuilt library was linked; enable the `prebuilt` feature or set IMPLOT_SYS_LIB_DIR, IMPLOT_SYS_PREBUILT_URL or DEAR_IMGUI_PREBUILT_DIR"
+        );
+    }
     if !cfg.docs_rs
         && (force_build || (!linked_prebuilt && env::var("IMPLOT_SYS_SKIP_CC").is_err()))
     {
         if use_cmake_requested() && build_with_cmake(&cfg, &cimplot_root) {
-            // built via CMake
+            provenance = Some(build_support::LibProvenance::Source {
+                tool: "cmake",
+                files: None,
+            });
         } else {
-            build_with_cc(&cfg, &cimplot_root, &imgui_src, &cimgui_root);
+            let files = build_with_cc(&cfg, &cimplot_root, &imgui_src, &cimgui_root);
+            provenance = Some(build_support::LibProvenance::Source {
+                tool: "cc",
+                files: Some(files),
+            });
         }
     }
+    if let Some(provenance) = &provenance {
+        build_support::report_provenance(&cfg.out_dir, "dear_implot", provenance);
+    }
 }
 
 fn docsrs_build(cfg: &BuildConfig, cimplot_root: &Path, imgui_src: &Path, cimgui_root: &Path) {
@@ -427,10 +473,13 @@ fn try_download_prebuilt(
     target_env: &str,
 ) -> Result<PathBuf, String> {
     let lib_name = expected_lib_name(target_env);
-    build_support::download_prebuilt(cache_root, url, lib_name, target_env)
+    let timeout = build_support::prebuilt_timeout_from_env("IMPLOT_SYS_PREBUILT_TIMEOUT_SECS");
+    build_support::download_prebuilt_with_timeout(cache_root, url, lib_name, target_env, timeout)
 }
 
-fn try_download_prebuilt_from_release(cfg: &BuildConfig) -> Option<PathBuf> {
+fn try_download_prebuilt_from_release(
+    cfg: &BuildConfig,
+) -> Option<(PathBuf, build_support::LibProvenance)> {
     if build_support::is_offline() {
         return None;
     }
@@ -459,7 +508,12 @@ fn try_download_prebuilt_from_release(cfg: &BuildConfig) -> Option<PathBuf> {
                     &cache_root,
                     expected_lib_name(&cfg.target_env),
                 ) {
-                    return Some(lib_dir);
+                    return Some((
+                        lib_dir,
+                        build_support::LibProvenance::PrebuiltLocal {
+                            origin: archive_path.display().to_string(),
+                        },
+                    ));
                 }
             }
         }
@@ -469,7 +523,10 @@ fn try_download_prebuilt_from_release(cfg: &BuildConfig) -> Option<PathBuf> {
     let urls = build_support::release_candidate_urls_env(&tags, &names);
     for url in urls {
         if let Ok(lib_dir) = try_download_prebuilt(&cache_root, &url, &cfg.target_env) {
-            return Some(lib_dir);
+            return Some((
+                lib_dir,
+                build_support::LibProvenance::PrebuiltDownloaded { origin: url },
+            ));
         }
     }
     None
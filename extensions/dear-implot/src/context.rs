@@ -100,8 +100,7 @@ impl Drop for PlotContext {
 pub struct PlotUi<'ui> {
     #[allow(dead_code)]
     context: &'ui PlotContext,
-    #[allow(dead_code)]
-    ui: &'ui Ui,
+    pub(crate) ui: &'ui Ui,
 }
 
 impl<'ui> PlotUi<'ui> {
@@ -139,6 +138,36 @@ impl<'ui> PlotUi<'ui> {
         }
     }
 
+    /// Begin a plot with custom size and flags
+    ///
+    /// See [`PlotFlags`](crate::PlotFlags); e.g. `PlotFlags::EQUAL` keeps a 1:1 aspect ratio.
+    pub fn begin_plot_with_flags(
+        &self,
+        title: &str,
+        size: [f32; 2],
+        flags: crate::PlotFlags,
+    ) -> Option<PlotToken<'_>> {
+        let title_cstr = std::ffi::CString::new(title).ok()?;
+
+        let plot_size = sys::ImVec2 {
+            x: size[0],
+            y: size[1],
+        };
+        let started = unsafe {
+            sys::ImPlot_BeginPlot(
+                title_cstr.as_ptr(),
+                plot_size,
+                flags.bits() as sys::ImPlotFlags,
+            )
+        };
+
+        if started {
+            Some(PlotToken::new())
+        } else {
+            None
+        }
+    }
+
     /// Plot a line with the given label and data
     ///
     /// This is a convenience method that can be called within a plot.
@@ -217,6 +246,63 @@ impl<'ui> PlotUi<'ui> {
         out
     }
 
+    /// Draggable rectangle for selecting a region of interest
+    ///
+    /// Returns true if the rectangle was moved or resized this frame.
+    #[allow(clippy::too_many_arguments)]
+    pub fn drag_rect(
+        &self,
+        id: i32,
+        x1: &mut f64,
+        y1: &mut f64,
+        x2: &mut f64,
+        y2: &mut f64,
+        color: [f32; 4],
+        flags: crate::DragToolFlags,
+    ) -> bool {
+        crate::drag_rect(id, x1, y1, x2, y2, color, flags).changed
+    }
+
+    /// Draggable point at (x,y), e.g. a control point of a curve
+    ///
+    /// `x` and `y` are written back while the point is dragged; `id` must be unique
+    /// among the drag tools of the plot.
+    pub fn drag_point(
+        &self,
+        id: i32,
+        x: &mut f64,
+        y: &mut f64,
+        color: [f32; 4],
+        size: f32,
+        flags: crate::DragToolFlags,
+    ) -> crate::DragResult {
+        crate::drag_point(id, x, y, color, size, flags)
+    }
+
+    /// Draggable vertical line at `x`, written back while the line is dragged
+    pub fn drag_line_x(
+        &self,
+        id: i32,
+        x: &mut f64,
+        color: [f32; 4],
+        thickness: f32,
+        flags: crate::DragToolFlags,
+    ) -> crate::DragResult {
+        crate::drag_line_x(id, x, color, thickness, flags)
+    }
+
+    /// Draggable horizontal line at `y`, written back while the line is dragged
+    pub fn drag_line_y(
+        &self,
+        id: i32,
+        y: &mut f64,
+        color: [f32; 4],
+        thickness: f32,
+        flags: crate::DragToolFlags,
+    ) -> crate::DragResult {
+        crate::drag_line_y(id, y, color, thickness, flags)
+    }
+
     /// Set current axes for subsequent plot submissions
     pub fn set_axes(&self, x_axis: XAxis, y_axis: YAxis) {
         unsafe { sys::ImPlot_SetAxes(x_axis as i32, y_axis as i32) }
@@ -269,15 +355,20 @@ impl<'ui> PlotUi<'ui> {
     }
 
     /// Link an axis to external min/max values (live binding)
-    pub fn setup_axis_links(
+    ///
+    /// ImPlot reads the values now and writes the axis range back into them when the plot
+    /// ends. Prefer [`PlotToken::setup_axis_links`], which ties the borrows to the plot.
+    ///
+    /// # Safety
+    ///
+    /// Non-null pointers must stay valid for writes until the current plot's token is dropped.
+    pub unsafe fn setup_axis_links(
         &self,
-        axis: i32,
-        link_min: Option<&mut f64>,
-        link_max: Option<&mut f64>,
+        axis: impl Into<sys::ImAxis>,
+        link_min: *mut f64,
+        link_max: *mut f64,
     ) {
-        let pmin = link_min.map_or(std::ptr::null_mut(), |r| r as *mut f64);
-        let pmax = link_max.map_or(std::ptr::null_mut(), |r| r as *mut f64);
-        unsafe { sys::ImPlot_SetupAxisLinks(axis, pmin, pmax) }
+        unsafe { sys::ImPlot_SetupAxisLinks(axis.into(), link_min, link_max) }
     }
 
     /// Setup both axes labels/flags at once
@@ -333,16 +424,18 @@ impl<'ui> PlotUi<'ui> {
         }
     }
 
-    /// Link an axis to external min/max for next frame
-    pub fn set_next_axis_links(
+    /// Link an axis of the next plot to external min/max values
+    ///
+    /// # Safety
+    ///
+    /// Non-null pointers must stay valid for writes until the next plot's token is dropped.
+    pub unsafe fn set_next_axis_links(
         &self,
-        axis: i32,
-        link_min: Option<&mut f64>,
-        link_max: Option<&mut f64>,
+        axis: impl Into<sys::ImAxis>,
+        link_min: *mut f64,
+        link_max: *mut f64,
     ) {
-        let pmin = link_min.map_or(std::ptr::null_mut(), |r| r as *mut f64);
-        let pmax = link_max.map_or(std::ptr::null_mut(), |r| r as *mut f64);
-        unsafe { sys::ImPlot_SetNextAxisLinks(axis, pmin, pmax) }
+        unsafe { sys::ImPlot_SetNextAxisLinks(axis.into(), link_min, link_max) }
     }
 
     /// Set next frame limits for both axes
@@ -454,6 +547,54 @@ impl<'ui> PlotUi<'ui> {
         }
     }
 
+    /// Setup ticks at explicit positions on any axis, optionally with one label per position
+    ///
+    /// Must be called between `begin_plot` and the first plotting call. When `keep_default` is
+    /// false the given ticks replace the axis' default ticks, otherwise they are added to them.
+    /// ImPlot copies the labels into the axis ticker during this call, so they only need to
+    /// live for its duration.
+    ///
+    /// Returns an error if `labels` and `positions` differ in length or a label contains an
+    /// interior NUL byte; no ticks are set up in that case.
+    pub fn setup_axis_ticks(
+        &self,
+        axis: impl Into<sys::ImAxis>,
+        positions: &[f64],
+        labels: Option<&[&str]>,
+        keep_default: bool,
+    ) -> Result<(), crate::PlotError> {
+        let labels = labels
+            .map(|labels| {
+                if labels.len() != positions.len() {
+                    return Err(crate::PlotError::DataLengthMismatch {
+                        x_len: positions.len(),
+                        y_len: labels.len(),
+                    });
+                }
+                labels
+                    .iter()
+                    .map(|&label| {
+                        std::ffi::CString::new(label)
+                            .map_err(|e| crate::PlotError::StringConversion(e.to_string()))
+                    })
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .transpose()?;
+        let raw: Option<Vec<*const std::os::raw::c_char>> = labels
+            .as_ref()
+            .map(|labels| labels.iter().map(|c| c.as_ptr()).collect());
+        unsafe {
+            sys::ImPlot_SetupAxisTicks_doublePtr(
+                axis.into(),
+                positions.as_ptr(),
+                positions.len() as i32,
+                raw.as_ref().map_or(std::ptr::null(), |raw| raw.as_ptr()),
+                keep_default,
+            )
+        }
+        Ok(())
+    }
+
     /// Setup ticks on a range with tick count and optional labels for an X axis
     pub fn setup_x_axis_ticks_range(
         &self,
@@ -743,6 +884,84 @@ impl<'ui> PlotToken<'ui> {
         }
     }
 
+    /// The current box selection in X1/Y1 coordinates, if the user is selecting a region
+    ///
+    /// The selection mouse button and modifiers are configured with
+    /// [`crate::set_select_input`].
+    pub fn selection(&self) -> Option<sys::ImPlotRect> {
+        self.selection_axes(XAxis::X1, YAxis::Y1)
+    }
+
+    /// The current box selection in the coordinates of the given axes
+    pub fn selection_axes(&self, x_axis: XAxis, y_axis: YAxis) -> Option<sys::ImPlotRect> {
+        crate::get_plot_selection_axes(x_axis, y_axis)
+    }
+
+    /// Cancel the current box selection, e.g. after acting on it
+    pub fn cancel_selection(&self) {
+        unsafe { sys::ImPlot_CancelPlotSelection() }
+    }
+
+    /// Whether the mouse is over this plot's data area
+    pub fn is_hovered(&self) -> bool {
+        crate::is_plot_hovered()
+    }
+
+    /// The mouse position in X1/Y1 coordinates
+    ///
+    /// The position is tracked even when the plot is not hovered; check
+    /// [`is_hovered`](Self::is_hovered) before using it for hover readouts.
+    pub fn mouse_pos(&self) -> sys::ImPlotPoint {
+        self.mouse_pos_axes(XAxis::X1, YAxis::Y1)
+    }
+
+    /// The mouse position in the coordinates of the given axes
+    pub fn mouse_pos_axes(&self, x_axis: XAxis, y_axis: YAxis) -> sys::ImPlotPoint {
+        crate::get_plot_mouse_position_axes(x_axis, y_axis)
+    }
+
+    /// The visible X1/Y1 range of this plot
+    pub fn limits(&self) -> sys::ImPlotRect {
+        self.limits_axes(XAxis::X1, YAxis::Y1)
+    }
+
+    /// The visible range of the given axes
+    pub fn limits_axes(&self, x_axis: XAxis, y_axis: YAxis) -> sys::ImPlotRect {
+        crate::get_plot_limits_axes(x_axis, y_axis)
+    }
+
+    /// Bind an axis range to `min`/`max` so that plots linked to the same variables share
+    /// zoom and pan
+    ///
+    /// Call this during setup, before [`PlotUi::setup_finish`] or the first plot item. ImPlot
+    /// reads the values now and writes the axis range back into them when this token is
+    /// dropped, so the variables stay borrowed until then and can be linked again by the
+    /// next plot:
+    ///
+    /// ```no_run
+    /// # use dear_implot::*;
+    /// # fn f(plot_ui: &PlotUi, range: &mut (f64, f64)) {
+    /// for title in ["Voltage", "Current"] {
+    ///     if let Some(mut plot) = plot_ui.begin_plot(title) {
+    ///         plot.setup_axis_links(XAxis::X1, Some(&mut range.0), Some(&mut range.1));
+    ///         // ... plot items
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn setup_axis_links(
+        &mut self,
+        axis: impl Into<sys::ImAxis>,
+        min: Option<&'ui mut f64>,
+        max: Option<&'ui mut f64>,
+    ) {
+        // Taking `&mut self` keeps `'ui` invariant, so the borrows cannot be shortened below
+        // the token's lifetime and are still live when EndPlot writes through them.
+        let min = min.map_or(std::ptr::null_mut(), |r| r as *mut f64);
+        let max = max.map_or(std::ptr::null_mut(), |r| r as *mut f64);
+        unsafe { sys::ImPlot_SetupAxisLinks(axis.into(), min, max) }
+    }
+
     /// Manually end the plot
     ///
     /// This is called automatically when the token is dropped,
@@ -118,6 +118,18 @@ impl YAxis {
     }
 }
 
+impl From<XAxis> for sys::ImAxis {
+    fn from(axis: XAxis) -> Self {
+        axis as sys::ImAxis
+    }
+}
+
+impl From<YAxis> for sys::ImAxis {
+    fn from(axis: YAxis) -> Self {
+        axis as sys::ImAxis
+    }
+}
+
 /// Ui extension for obtaining a PlotUi from an ImPlot PlotContext
 pub trait ImPlotExt {
     fn implot<'ui>(&'ui self, ctx: &'ui PlotContext) -> PlotUi<'ui>;
@@ -190,6 +202,12 @@ pub enum Colormap {
     Cool = 7,
     Pink = 8,
     Jet = 9,
+    Twilight = 10,
+    RdBu = 11,
+    BrBG = 12,
+    PiYG = 13,
+    Spectral = 14,
+    Greys = 15,
 }
 
 /// Plot location for legends, labels, etc.
@@ -278,6 +296,7 @@ bitflags::bitflags! {
 
 bitflags::bitflags! {
     /// Flags for bar plots
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub struct BarsFlags: u32 {
         const NONE = 0;
         const HORIZONTAL = 1 << 10;
@@ -364,6 +383,32 @@ bitflags::bitflags! {
     }
 }
 
+bitflags::bitflags! {
+    /// Flags for colormap scales
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct ColormapScaleFlags: u32 {
+        const NONE = 0;
+        /// The colormap axis has no label
+        const NO_LABEL = 1 << 0;
+        /// Render the colormap label and tick labels on the opposite side
+        const OPPOSITE = 1 << 1;
+        /// Invert the colormap bar and axis scale
+        const INVERT = 1 << 2;
+    }
+}
+
+bitflags::bitflags! {
+    /// Keyboard modifiers for plot input mappings, matching ImGui's `ImGuiMod_*` values
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct InputModifiers: u32 {
+        const NONE = 0;
+        const CTRL = 1 << 12;
+        const SHIFT = 1 << 13;
+        const ALT = 1 << 14;
+        const SUPER = 1 << 15;
+    }
+}
+
 bitflags::bitflags! {
     /// Flags for infinite lines plots
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -383,25 +428,34 @@ bitflags::bitflags! {
 
 bitflags::bitflags! {
     /// Axis flags matching ImPlotAxisFlags_ (see cimplot.h)
+    ///
+    /// Use `INVERT` for image-like coordinate systems (Y growing downwards) and
+    /// `OPPOSITE` to place an axis on the other side (e.g. a right-hand Y axis).
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub struct AxisFlags: u32 {
-        const NONE           = 0;
-        const NO_LABEL       = 1 << 0;
-        const NO_GRID_LINES  = 1 << 1;
-        const NO_TICK_MARKS  = 1 << 2;
-        const NO_TICK_LABELS = 1 << 3;
-        const NO_INITIAL_FIT = 1 << 4;
-        const NO_MENUS       = 1 << 5;
-        const NO_SIDE_SWITCH = 1 << 6;
-        const NO_HIGHLIGHT   = 1 << 7;
-        const OPPOSITE       = 1 << 8;
-        const FOREGROUND     = 1 << 9;
-        const INVERT         = 1 << 10;
-        const AUTO_FIT       = 1 << 11;
-        const RANGE_FIT      = 1 << 12;
-        const PAN_STRETCH    = 1 << 13;
-        const LOCK_MIN       = 1 << 14;
-        const LOCK_MAX       = 1 << 15;
+        const NONE           = sys::ImPlotAxisFlags_None as u32;
+        const NO_LABEL       = sys::ImPlotAxisFlags_NoLabel as u32;
+        const NO_GRID_LINES  = sys::ImPlotAxisFlags_NoGridLines as u32;
+        const NO_TICK_MARKS  = sys::ImPlotAxisFlags_NoTickMarks as u32;
+        const NO_TICK_LABELS = sys::ImPlotAxisFlags_NoTickLabels as u32;
+        const NO_INITIAL_FIT = sys::ImPlotAxisFlags_NoInitialFit as u32;
+        const NO_MENUS       = sys::ImPlotAxisFlags_NoMenus as u32;
+        const NO_SIDE_SWITCH = sys::ImPlotAxisFlags_NoSideSwitch as u32;
+        const NO_HIGHLIGHT   = sys::ImPlotAxisFlags_NoHighlight as u32;
+        const OPPOSITE       = sys::ImPlotAxisFlags_Opposite as u32;
+        const FOREGROUND     = sys::ImPlotAxisFlags_Foreground as u32;
+        const INVERT         = sys::ImPlotAxisFlags_Invert as u32;
+        const AUTO_FIT       = sys::ImPlotAxisFlags_AutoFit as u32;
+        const RANGE_FIT      = sys::ImPlotAxisFlags_RangeFit as u32;
+        const PAN_STRETCH    = sys::ImPlotAxisFlags_PanStretch as u32;
+        const LOCK_MIN       = sys::ImPlotAxisFlags_LockMin as u32;
+        const LOCK_MAX       = sys::ImPlotAxisFlags_LockMax as u32;
+        /// Lock both ends of the axis
+        const LOCK           = sys::ImPlotAxisFlags_Lock as u32;
+        /// No label, grid lines, tick marks or tick labels
+        const NO_DECORATIONS = sys::ImPlotAxisFlags_NoDecorations as u32;
+        /// Default flags for auxiliary axes (opposite side, no grid lines)
+        const AUX_DEFAULT    = sys::ImPlotAxisFlags_AuxDefault as u32;
     }
 }
 
@@ -422,3 +476,80 @@ pub use advanced::{
     LegendFlags, LegendLocation, LegendManager, LegendToken, MultiAxisPlot, MultiAxisToken,
     SubplotFlags, SubplotGrid, SubplotToken, YAxisConfig,
 };
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_plot_flags_values() {
+        assert_eq!(PlotFlags::NO_TITLE.bits(), 1 << 0);
+        assert_eq!(PlotFlags::NO_LEGEND.bits(), 1 << 1);
+        assert_eq!(PlotFlags::NO_MOUSE_TEXT.bits(), 1 << 2);
+        assert_eq!(PlotFlags::NO_INPUTS.bits(), 1 << 3);
+        assert_eq!(PlotFlags::NO_MENUS.bits(), 1 << 4);
+        assert_eq!(PlotFlags::NO_BOX_SELECT.bits(), 1 << 5);
+        assert_eq!(PlotFlags::NO_FRAME.bits(), 1 << 6);
+        assert_eq!(PlotFlags::EQUAL.bits(), 1 << 7);
+        assert_eq!(PlotFlags::CROSSHAIRS.bits(), 1 << 8);
+        assert_eq!(
+            PlotFlags::CANVAS_ONLY,
+            PlotFlags::NO_TITLE
+                | PlotFlags::NO_LEGEND
+                | PlotFlags::NO_MENUS
+                | PlotFlags::NO_BOX_SELECT
+                | PlotFlags::NO_MOUSE_TEXT
+        );
+    }
+
+    #[test]
+    fn test_axis_flags_values() {
+        assert_eq!(AxisFlags::NO_LABEL.bits(), 1 << 0);
+        assert_eq!(AxisFlags::NO_GRID_LINES.bits(), 1 << 1);
+        assert_eq!(AxisFlags::NO_TICK_MARKS.bits(), 1 << 2);
+        assert_eq!(AxisFlags::NO_TICK_LABELS.bits(), 1 << 3);
+        assert_eq!(AxisFlags::NO_INITIAL_FIT.bits(), 1 << 4);
+        assert_eq!(AxisFlags::NO_MENUS.bits(), 1 << 5);
+        assert_eq!(AxisFlags::NO_SIDE_SWITCH.bits(), 1 << 6);
+        assert_eq!(AxisFlags::NO_HIGHLIGHT.bits(), 1 << 7);
+        assert_eq!(AxisFlags::OPPOSITE.bits(), 1 << 8);
+        assert_eq!(AxisFlags::FOREGROUND.bits(), 1 << 9);
+        assert_eq!(AxisFlags::INVERT.bits(), 1 << 10);
+        assert_eq!(AxisFlags::AUTO_FIT.bits(), 1 << 11);
+        assert_eq!(AxisFlags::RANGE_FIT.bits(), 1 << 12);
+        assert_eq!(AxisFlags::PAN_STRETCH.bits(), 1 << 13);
+        assert_eq!(AxisFlags::LOCK_MIN.bits(), 1 << 14);
+        assert_eq!(AxisFlags::LOCK_MAX.bits(), 1 << 15);
+    }
+
+    #[test]
+    fn test_input_modifiers_match_imgui() {
+        use dear_imgui_sys as imgui_sys;
+        assert_eq!(InputModifiers::CTRL.bits() as i32, imgui_sys::ImGuiMod_Ctrl);
+        assert_eq!(
+            InputModifiers::SHIFT.bits() as i32,
+            imgui_sys::ImGuiMod_Shift
+        );
+        assert_eq!(InputModifiers::ALT.bits() as i32, imgui_sys::ImGuiMod_Alt);
+        assert_eq!(
+            InputModifiers::SUPER.bits() as i32,
+            imgui_sys::ImGuiMod_Super
+        );
+    }
+
+    #[test]
+    fn test_axis_flags_composites() {
+        assert_eq!(AxisFlags::LOCK, AxisFlags::LOCK_MIN | AxisFlags::LOCK_MAX);
+        assert_eq!(
+            AxisFlags::NO_DECORATIONS,
+            AxisFlags::NO_LABEL
+                | AxisFlags::NO_GRID_LINES
+                | AxisFlags::NO_TICK_MARKS
+                | AxisFlags::NO_TICK_LABELS
+        );
+        assert_eq!(
+            AxisFlags::AUX_DEFAULT,
+            AxisFlags::NO_GRID_LINES | AxisFlags::OPPOSITE
+        );
+    }
+}
@@ -1,40 +1,33 @@
 // Plot flags and configuration
-// This module contains plot and axis flags for configuration
+// This module contains plot flags for configuration
+
+use crate::sys;
 
 // Plot flags for configuration
 bitflags::bitflags! {
-    /// Flags for plot configuration
+    /// Flags for plot configuration matching ImPlotFlags_ (see cimplot.h)
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     pub struct PlotFlags: u32 {
-        const NONE = 0;
-        const NO_TITLE = 1 << 0;
-        const NO_LEGEND = 1 << 1;
-        const NO_MOUSE_POS = 1 << 2;
-        const NO_HIGHLIGHT = 1 << 3;
-        const NO_CHILD = 1 << 4;
-        const EQUAL = 1 << 5;
-        const Y_AXIS_2 = 1 << 6;
-        const Y_AXIS_3 = 1 << 7;
-        const QUERY = 1 << 8;
-        const CROSSHAIRS = 1 << 9;
-        const ANTI_ALIASED = 1 << 10;
-        const CANVAS_ONLY = 1 << 11;
-    }
-}
-
-// Axis flags
-bitflags::bitflags! {
-    /// Flags for axis configuration
-    pub struct AxisFlags: u32 {
-        const NONE = 0;
-        const NO_LABEL = 1 << 0;
-        const NO_GRID_LINES = 1 << 1;
-        const NO_TICK_MARKS = 1 << 2;
-        const NO_TICK_LABELS = 1 << 3;
-        const LOG_SCALE = 1 << 4;
-        const TIME = 1 << 5;
-        const INVERT = 1 << 6;
-        const LOCK_MIN = 1 << 7;
-        const LOCK_MAX = 1 << 8;
-        const LOCK = Self::LOCK_MIN.bits() | Self::LOCK_MAX.bits();
+        const NONE = sys::ImPlotFlags_None as u32;
+        /// Hide the plot title
+        const NO_TITLE = sys::ImPlotFlags_NoTitle as u32;
+        /// Hide the legend
+        const NO_LEGEND = sys::ImPlotFlags_NoLegend as u32;
+        /// Hide the mouse position text in the plot area
+        const NO_MOUSE_TEXT = sys::ImPlotFlags_NoMouseText as u32;
+        /// Disable user interaction (pan, zoom, select)
+        const NO_INPUTS = sys::ImPlotFlags_NoInputs as u32;
+        /// Disable the context menus
+        const NO_MENUS = sys::ImPlotFlags_NoMenus as u32;
+        /// Disable box selection
+        const NO_BOX_SELECT = sys::ImPlotFlags_NoBoxSelect as u32;
+        /// Do not draw the frame background
+        const NO_FRAME = sys::ImPlotFlags_NoFrame as u32;
+        /// Force a 1:1 aspect ratio between X1 and Y1 (useful for spatial data)
+        const EQUAL = sys::ImPlotFlags_Equal as u32;
+        /// Replace the mouse cursor with crosshairs while hovering the plot
+        const CROSSHAIRS = sys::ImPlotFlags_Crosshairs as u32;
+        /// Only draw the plot canvas (no title, legend, menus, box select or mouse text)
+        const CANVAS_ONLY = sys::ImPlotFlags_CanvasOnly as u32;
     }
 }
@@ -1,7 +1,7 @@
 //! Bar plot implementation
 
-use super::{Plot, PlotError, safe_cstring};
-use crate::{BarsFlags, sys};
+use super::{Plot, PlotError, safe_cstring, validate_data_lengths};
+use crate::{BarsFlags, ShadedFlags, sys};
 
 /// Builder for bar plots with customization options
 pub struct BarPlot<'a> {
@@ -9,6 +9,7 @@ pub struct BarPlot<'a> {
     values: &'a [f64],
     bar_size: f64,
     shift: f64,
+    base: f64,
     flags: BarsFlags,
     offset: i32,
     stride: i32,
@@ -22,6 +23,7 @@ impl<'a> BarPlot<'a> {
             values,
             bar_size: 0.67, // Default bar width
             shift: 0.0,
+            base: 0.0,
             flags: BarsFlags::NONE,
             offset: 0,
             stride: std::mem::size_of::<f64>() as i32,
@@ -40,12 +42,27 @@ impl<'a> BarPlot<'a> {
         self
     }
 
+    /// Set the baseline the bars start from (in plot units)
+    ///
+    /// ImPlot bars always start at 0; a non-zero base draws the bars as a
+    /// [`FloatingBarPlot`] instead, keeping the flags, offset and stride.
+    pub fn with_base(mut self, base: f64) -> Self {
+        self.base = base;
+        self
+    }
+
     /// Set bar flags for customization
     pub fn with_flags(mut self, flags: BarsFlags) -> Self {
         self.flags = flags;
         self
     }
 
+    /// Draw the bars horizontally, with the values along the X axis
+    pub fn horizontal(mut self, horizontal: bool) -> Self {
+        self.flags.set(BarsFlags::HORIZONTAL, horizontal);
+        self
+    }
+
     /// Set data offset for partial plotting
     pub fn with_offset(mut self, offset: i32) -> Self {
         self.offset = offset;
@@ -74,6 +91,26 @@ impl<'a> Plot for BarPlot<'a> {
             return; // Skip plotting if data is invalid
         }
 
+        if self.base != 0.0 {
+            // Read the values the way ImPlot's indexer does: `offset` rotates the
+            // start, `stride` is in bytes
+            let count = self.values.len();
+            let step = self.stride.max(0) as usize / std::mem::size_of::<f64>();
+            let highs: Vec<f64> = (0..count)
+                .map_while(|i| {
+                    let index = (self.offset as i64 + i as i64).rem_euclid(count as i64);
+                    self.values.get(index as usize * step).copied()
+                })
+                .collect();
+            let positions: Vec<f64> = (0..highs.len()).map(|i| i as f64 + self.shift).collect();
+            let lows = vec![self.base; highs.len()];
+            FloatingBarPlot::new(self.label, &positions, &lows, &highs)
+                .with_bar_size(self.bar_size)
+                .with_flags(self.flags)
+                .plot();
+            return;
+        }
+
         let label_cstr = safe_cstring(self.label);
 
         unsafe {
@@ -128,6 +165,12 @@ impl<'a> PositionalBarPlot<'a> {
         self
     }
 
+    /// Draw the bars horizontally, with `x_data` as Y positions and `y_data` as lengths
+    pub fn horizontal(mut self, horizontal: bool) -> Self {
+        self.flags.set(BarsFlags::HORIZONTAL, horizontal);
+        self
+    }
+
     /// Validate the plot data
     pub fn validate(&self) -> Result<(), PlotError> {
         super::validate_data_lengths(self.x_data, self.y_data)
@@ -161,6 +204,107 @@ impl<'a> Plot for PositionalBarPlot<'a> {
     }
 }
 
+/// Bar plot whose bars span from a low to a high value instead of from 0
+///
+/// Useful for waterfall charts (each bar starts where the previous one ended)
+/// and Gantt-like ranges. The bars are filled as one shaded region under the
+/// label, so they share one legend entry and color.
+pub struct FloatingBarPlot<'a> {
+    label: &'a str,
+    positions: &'a [f64],
+    lows: &'a [f64],
+    highs: &'a [f64],
+    bar_size: f64,
+    flags: BarsFlags,
+}
+
+impl<'a> FloatingBarPlot<'a> {
+    /// Create a new floating bar plot with bar centers, bottoms and tops
+    pub fn new(label: &'a str, positions: &'a [f64], lows: &'a [f64], highs: &'a [f64]) -> Self {
+        Self {
+            label,
+            positions,
+            lows,
+            highs,
+            bar_size: 0.67,
+            flags: BarsFlags::NONE,
+        }
+    }
+
+    /// Set the bar width (in plot units)
+    pub fn with_bar_size(mut self, bar_size: f64) -> Self {
+        self.bar_size = bar_size;
+        self
+    }
+
+    /// Set bar flags for customization
+    pub fn with_flags(mut self, flags: BarsFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+
+    /// Draw the bars horizontally, with positions along the Y axis and values along X
+    pub fn horizontal(mut self, horizontal: bool) -> Self {
+        self.flags.set(BarsFlags::HORIZONTAL, horizontal);
+        self
+    }
+
+    /// Validate the plot data
+    pub fn validate(&self) -> Result<(), PlotError> {
+        validate_data_lengths(self.positions, self.lows)?;
+        validate_data_lengths(self.positions, self.highs)
+    }
+}
+
+impl<'a> Plot for FloatingBarPlot<'a> {
+    fn plot(&self) {
+        if self.validate().is_err() {
+            return; // Skip plotting if data is invalid
+        }
+
+        let label_cstr = safe_cstring(self.label);
+        let half = self.bar_size * 0.5;
+        let horizontal = self.flags.contains(BarsFlags::HORIZONTAL);
+
+        // All bars go through one shaded plot. Shaded regions fill between two Y values
+        // along X, so each bar is four points: its two edges, each entered and left at
+        // zero height, so nothing is filled between one bar and the next
+        let count = self.positions.len() * 4;
+        let (mut xs, mut ys1, mut ys2) = (
+            Vec::with_capacity(count),
+            Vec::with_capacity(count),
+            Vec::with_capacity(count),
+        );
+        for ((&pos, &low), &high) in self.positions.iter().zip(self.lows).zip(self.highs) {
+            // A horizontal bar spans low..high in X and is filled across its width in Y
+            let (x0, x1, bottom, top) = if horizontal {
+                (low, high, pos - half, pos + half)
+            } else {
+                (pos - half, pos + half, low, high)
+            };
+            xs.extend_from_slice(&[x0, x0, x1, x1]);
+            ys1.extend_from_slice(&[bottom; 4]);
+            ys2.extend_from_slice(&[bottom, top, top, bottom]);
+        }
+        unsafe {
+            sys::ImPlot_PlotShaded_doublePtrdoublePtrdoublePtr(
+                label_cstr.as_ptr(),
+                xs.as_ptr(),
+                ys1.as_ptr(),
+                ys2.as_ptr(),
+                count as i32,
+                ShadedFlags::NONE.bits() as sys::ImPlotShadedFlags,
+                0,
+                std::mem::size_of::<f64>() as i32,
+            );
+        }
+    }
+
+    fn label(&self) -> &str {
+        self.label
+    }
+}
+
 /// Convenience functions for quick bar plotting
 impl<'ui> crate::PlotUi<'ui> {
     /// Plot a bar chart with values (X will be indices)
@@ -196,6 +340,20 @@ impl<'ui> crate::PlotUi<'ui> {
         plot.plot();
         Ok(())
     }
+
+    /// Plot bars spanning from `lows` to `highs` at the given X positions
+    pub fn floating_bar_plot(
+        &self,
+        label: &str,
+        positions: &[f64],
+        lows: &[f64],
+        highs: &[f64],
+    ) -> Result<(), PlotError> {
+        let plot = FloatingBarPlot::new(label, positions, lows, highs);
+        plot.validate()?;
+        plot.plot();
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -217,6 +375,15 @@ mod tests {
         assert!(plot.validate().is_err());
     }
 
+    #[test]
+    fn test_bar_plot_horizontal() {
+        let values = [1.0, 2.0];
+        let plot = BarPlot::new("test", &values).horizontal(true);
+        assert!(plot.flags.contains(BarsFlags::HORIZONTAL));
+        let plot = plot.horizontal(false);
+        assert!(!plot.flags.contains(BarsFlags::HORIZONTAL));
+    }
+
     #[test]
     fn test_positional_bar_plot() {
         let x_data = [1.0, 2.0, 3.0, 4.0];
@@ -235,4 +402,25 @@ mod tests {
         let plot = PositionalBarPlot::new("test", &x_data, &y_data);
         assert!(plot.validate().is_err());
     }
+
+    #[test]
+    fn test_floating_bar_plot_validation() {
+        let positions = [0.0, 1.0, 2.0];
+        let lows = [0.0, 3.0, 5.0];
+        let highs = [3.0, 5.0, 4.0];
+
+        let plot = FloatingBarPlot::new("waterfall", &positions, &lows, &highs);
+        assert_eq!(plot.label(), "waterfall");
+        assert!(plot.validate().is_ok());
+
+        let plot = FloatingBarPlot::new("waterfall", &positions, &lows, &highs[..2]);
+        assert_eq!(
+            plot.validate(),
+            Err(PlotError::DataLengthMismatch { x_len: 3, y_len: 2 })
+        );
+        assert_eq!(
+            FloatingBarPlot::new("empty", &[], &[], &[]).validate(),
+            Err(PlotError::EmptyData)
+        );
+    }
 }
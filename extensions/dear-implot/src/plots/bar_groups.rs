@@ -98,7 +98,13 @@ impl<'a> BarGroupsPlot<'a> {
     }
 
     /// Plot the bar groups
+    ///
+    /// Nothing is drawn if the data is invalid.
     pub fn plot(self) {
+        if self.validate().is_err() {
+            return;
+        }
+
         // Convert labels to CString pointers
         let label_cstrings: Vec<CString> = self
             .label_ids
@@ -219,7 +225,13 @@ impl<'a> BarGroupsPlotF32<'a> {
     }
 
     /// Plot the bar groups
+    ///
+    /// Nothing is drawn if the data is invalid.
     pub fn plot(self) {
+        if self.validate().is_err() {
+            return;
+        }
+
         // Convert labels to CString pointers
         let label_cstrings: Vec<CString> = self
             .label_ids
@@ -308,18 +320,7 @@ impl<'a> SimpleBarGroupsPlot<'a> {
 
         let item_count = self.data.len();
         let group_count = self.data[0].len();
-
-        // Flatten data into row-major order
-        let mut flattened_data = Vec::with_capacity(item_count * group_count);
-        for group_idx in 0..group_count {
-            for item_idx in 0..item_count {
-                if group_idx < self.data[item_idx].len() {
-                    flattened_data.push(self.data[item_idx][group_idx]);
-                } else {
-                    flattened_data.push(0.0); // Fill missing data with zeros
-                }
-            }
-        }
+        let flattened_data = flatten_series(&self.data, group_count);
 
         let plot = BarGroupsPlot::new(self.labels, &flattened_data, item_count, group_count)
             .with_group_size(self.group_size)
@@ -328,3 +329,58 @@ impl<'a> SimpleBarGroupsPlot<'a> {
         plot.plot();
     }
 }
+
+/// Flattens series into the row-major (item_count rows, group_count cols) layout
+/// ImPlot expects, padding short series with zeros
+fn flatten_series(data: &[Vec<f64>], group_count: usize) -> Vec<f64> {
+    data.iter()
+        .flat_map(|series| {
+            (0..group_count).map(move |group_idx| series.get(group_idx).copied().unwrap_or(0.0))
+        })
+        .collect()
+}
+
+/// Convenience functions for quick bar groups plotting
+impl<'ui> crate::PlotUi<'ui> {
+    /// Plot one bar per item in each of `group_count` groups
+    ///
+    /// `values` holds one row of `group_count` values per label in `label_ids`.
+    pub fn bar_groups_plot(
+        &self,
+        label_ids: &[&str],
+        values: &[f64],
+        group_count: usize,
+    ) -> Result<(), PlotError> {
+        let plot = BarGroupsPlot::new(label_ids.to_vec(), values, label_ids.len(), group_count);
+        plot.validate()?;
+        plot.plot();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bar_groups_validation() {
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let plot = BarGroupsPlot::new(vec!["a", "b"], &values, 2, 3).stacked();
+        assert!(plot.validate().is_ok());
+
+        let plot = BarGroupsPlot::new(vec!["a", "b"], &values[..5], 2, 3);
+        assert!(matches!(plot.validate(), Err(PlotError::InvalidData(_))));
+
+        let plot = BarGroupsPlot::new(vec!["a"], &values, 2, 3);
+        assert!(matches!(plot.validate(), Err(PlotError::InvalidData(_))));
+
+        let plot = BarGroupsPlot::new(vec![], &[], 0, 3);
+        assert!(matches!(plot.validate(), Err(PlotError::EmptyData)));
+    }
+
+    #[test]
+    fn test_flatten_series_is_item_major() {
+        let data = vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0]];
+        assert_eq!(flatten_series(&data, 3), vec![1.0, 2.0, 3.0, 4.0, 5.0, 0.0]);
+    }
+}
@@ -58,7 +58,12 @@ impl<'a> DigitalPlot<'a> {
     }
 
     /// Plot the digital signal
+    ///
+    /// Nothing is drawn if the data is invalid.
     pub fn plot(self) {
+        if self.validate().is_err() {
+            return;
+        }
         let label_cstring = safe_cstring(self.label);
 
         unsafe {
@@ -305,3 +310,45 @@ impl<'a> PlotData for BooleanDigitalPlot<'a> {
         self.x_data.len().min(self.y_data.len())
     }
 }
+
+/// Convenience functions for quick digital plotting
+impl<'ui> crate::PlotUi<'ui> {
+    /// Plot a digital signal with X and Y data
+    ///
+    /// Each Y value is drawn as a high/low level. Digital series are stacked at the
+    /// bottom of the plot and do not take part in Y-axis fitting, so they stay in place
+    /// when the Y axis is zoomed or dragged.
+    pub fn digital_plot(
+        &self,
+        label: &str,
+        x_data: &[f64],
+        y_data: &[f64],
+    ) -> Result<(), PlotError> {
+        let plot = DigitalPlot::new(label, x_data, y_data);
+        plot.validate()?;
+        plot.plot();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digital_plot_validation() {
+        let x_data = [0.0, 1.0, 2.0, 3.0];
+        let y_data = [0.0, 1.0, 1.0, 0.0];
+
+        let plot = DigitalPlot::new("test", &x_data, &y_data).with_flags(DigitalFlags::NONE);
+        assert_eq!(plot.label(), "test");
+        assert_eq!(plot.data_len(), 4);
+        assert!(plot.validate().is_ok());
+
+        let plot = DigitalPlot::new("test", &x_data, &y_data[..2]);
+        assert!(matches!(
+            plot.validate(),
+            Err(PlotError::DataLengthMismatch { x_len: 4, y_len: 2 })
+        ));
+    }
+}
@@ -1,6 +1,10 @@
 //! Error bars plot implementation
+//!
+//! Error bars are drawn on top of another series, so plot them inside the same
+//! `begin_plot` as the scatter, line or bar series they annotate. Reusing that series'
+//! label makes them share its legend entry and color.
 
-use super::{Plot, PlotError, safe_cstring, validate_data_lengths};
+use super::{Plot, PlotError, safe_cstring, strided_count, validate_data_lengths};
 use crate::{ErrorBarsFlags, sys};
 
 /// Builder for error bars plots
@@ -46,16 +50,17 @@ impl<'a> ErrorBarsPlot<'a> {
         self
     }
 
-    /// Set data stride for non-contiguous data
+    /// Set data stride in bytes for non-contiguous data
+    ///
+    /// Must be a multiple of `size_of::<f64>()`; every `stride / 8`-th value is plotted.
     pub fn with_stride(mut self, stride: i32) -> Self {
         self.stride = stride;
         self
     }
 
-    /// Make error bars horizontal instead of vertical
-    pub fn horizontal(self) -> Self {
-        // Note: This would require a different flag or function
-        // For now, we'll keep it as a placeholder
+    /// Make error bars horizontal (errors along X) instead of vertical
+    pub fn horizontal(mut self) -> Self {
+        self.flags.insert(ErrorBarsFlags::HORIZONTAL);
         self
     }
 
@@ -63,6 +68,7 @@ impl<'a> ErrorBarsPlot<'a> {
     pub fn validate(&self) -> Result<(), PlotError> {
         validate_data_lengths(self.x_data, self.y_data)?;
         validate_data_lengths(self.x_data, self.err_data)?;
+        strided_count(self.x_data.len(), self.stride)?;
 
         // Check for negative error values
         if self.err_data.iter().any(|&err| err < 0.0) {
@@ -81,6 +87,9 @@ impl<'a> Plot for ErrorBarsPlot<'a> {
             return;
         }
 
+        let Ok(count) = strided_count(self.x_data.len(), self.stride) else {
+            return;
+        };
         let label_cstr = safe_cstring(self.label);
 
         unsafe {
@@ -89,7 +98,7 @@ impl<'a> Plot for ErrorBarsPlot<'a> {
                 self.x_data.as_ptr(),
                 self.y_data.as_ptr(),
                 self.err_data.as_ptr(),
-                self.x_data.len() as i32,
+                count,
                 self.flags.bits() as i32,
                 self.offset,
                 self.stride,
@@ -110,6 +119,8 @@ pub struct AsymmetricErrorBarsPlot<'a> {
     err_neg: &'a [f64],
     err_pos: &'a [f64],
     flags: ErrorBarsFlags,
+    offset: i32,
+    stride: i32,
 }
 
 impl<'a> AsymmetricErrorBarsPlot<'a> {
@@ -135,6 +146,8 @@ impl<'a> AsymmetricErrorBarsPlot<'a> {
             err_neg,
             err_pos,
             flags: ErrorBarsFlags::NONE,
+            offset: 0,
+            stride: std::mem::size_of::<f64>() as i32,
         }
     }
 
@@ -144,11 +157,32 @@ impl<'a> AsymmetricErrorBarsPlot<'a> {
         self
     }
 
+    /// Set data offset for partial plotting
+    pub fn with_offset(mut self, offset: i32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set data stride in bytes for non-contiguous data
+    ///
+    /// Must be a multiple of `size_of::<f64>()`; every `stride / 8`-th value is plotted.
+    pub fn with_stride(mut self, stride: i32) -> Self {
+        self.stride = stride;
+        self
+    }
+
+    /// Make error bars horizontal (errors along X) instead of vertical
+    pub fn horizontal(mut self) -> Self {
+        self.flags.insert(ErrorBarsFlags::HORIZONTAL);
+        self
+    }
+
     /// Validate the plot data
     pub fn validate(&self) -> Result<(), PlotError> {
         validate_data_lengths(self.x_data, self.y_data)?;
         validate_data_lengths(self.x_data, self.err_neg)?;
         validate_data_lengths(self.x_data, self.err_pos)?;
+        strided_count(self.x_data.len(), self.stride)?;
 
         // Check for negative error values
         if self.err_neg.iter().any(|&err| err < 0.0) || self.err_pos.iter().any(|&err| err < 0.0) {
@@ -167,6 +201,9 @@ impl<'a> Plot for AsymmetricErrorBarsPlot<'a> {
             return;
         }
 
+        let Ok(count) = strided_count(self.x_data.len(), self.stride) else {
+            return;
+        };
         let label_cstr = safe_cstring(self.label);
 
         unsafe {
@@ -176,10 +213,10 @@ impl<'a> Plot for AsymmetricErrorBarsPlot<'a> {
                 self.y_data.as_ptr(),
                 self.err_neg.as_ptr(),
                 self.err_pos.as_ptr(),
-                self.x_data.len() as i32,
+                count,
                 self.flags.bits() as i32,
-                0,
-                std::mem::size_of::<f64>() as i32,
+                self.offset,
+                self.stride,
             );
         }
     }
@@ -270,6 +307,27 @@ impl<'a> Plot for SimpleErrorBarsPlot<'a> {
 
 /// Convenience functions for quick error bars plotting
 impl<'ui> crate::PlotUi<'ui> {
+    /// Plot configured error bars with symmetric errors
+    ///
+    /// Call it inside the same plot as the series the errors belong to.
+    pub fn error_bars(&self, plot: &ErrorBarsPlot<'_>) -> Result<(), PlotError> {
+        plot.validate()?;
+        plot.plot();
+        Ok(())
+    }
+
+    /// Plot configured error bars with separate negative and positive errors
+    ///
+    /// Call it inside the same plot as the series the errors belong to.
+    pub fn asymmetric_error_bars(
+        &self,
+        plot: &AsymmetricErrorBarsPlot<'_>,
+    ) -> Result<(), PlotError> {
+        plot.validate()?;
+        plot.plot();
+        Ok(())
+    }
+
     /// Plot error bars with symmetric errors
     pub fn error_bars_plot(
         &self,
@@ -312,3 +370,50 @@ impl<'ui> crate::PlotUi<'ui> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_horizontal_sets_flag() {
+        let x = [1.0, 2.0];
+        let err = [0.1, 0.2];
+        assert_eq!(
+            ErrorBarsFlags::HORIZONTAL.bits(),
+            sys::ImPlotErrorBarsFlags_Horizontal as u32
+        );
+
+        let plot = ErrorBarsPlot::new("m", &x, &x, &err).horizontal();
+        assert!(plot.flags.contains(ErrorBarsFlags::HORIZONTAL));
+        let plot = AsymmetricErrorBarsPlot::new("m", &x, &x, &err, &err).horizontal();
+        assert!(plot.flags.contains(ErrorBarsFlags::HORIZONTAL));
+    }
+
+    #[test]
+    fn test_error_bars_validation() {
+        let x = [1.0, 2.0, 3.0];
+        let err = [0.1, 0.2, 0.3];
+        let short = [0.1, 0.2];
+
+        assert!(ErrorBarsPlot::new("m", &x, &x, &err).validate().is_ok());
+        assert_eq!(
+            ErrorBarsPlot::new("m", &x, &x, &short).validate(),
+            Err(PlotError::DataLengthMismatch { x_len: 3, y_len: 2 })
+        );
+        assert_eq!(
+            AsymmetricErrorBarsPlot::new("m", &x, &x, &err, &short).validate(),
+            Err(PlotError::DataLengthMismatch { x_len: 3, y_len: 2 })
+        );
+        assert!(matches!(
+            AsymmetricErrorBarsPlot::new("m", &x, &x, &err, &[0.1, -0.2, 0.3]).validate(),
+            Err(PlotError::InvalidData(_))
+        ));
+        assert!(matches!(
+            ErrorBarsPlot::new("m", &x, &x, &err)
+                .with_stride(4)
+                .validate(),
+            Err(PlotError::InvalidData(_))
+        ));
+    }
+}
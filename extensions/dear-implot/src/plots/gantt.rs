@@ -0,0 +1,213 @@
+//! Gantt-style timeline chart implementation
+
+use crate::{
+    PlotCond, XAxis, YAxis, get_plot_draw_list, get_plot_mouse_position, is_plot_hovered,
+    plot_to_pixels, pop_plot_clip_rect, push_plot_clip_rect, sys,
+};
+use dear_imgui_sys as imgui_sys;
+
+/// Half the height of a bar, in rows
+const BAR_HALF_HEIGHT: f64 = 0.3;
+
+/// One bar of a [`GanttRow`], spanning `start..end` on the X axis
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GanttInterval {
+    /// X where the bar starts
+    pub start: f64,
+    /// X where the bar ends
+    pub end: f64,
+    /// Fill color; `None` uses the row's color from the current colormap
+    pub color: Option<[f32; 4]>,
+}
+
+impl GanttInterval {
+    /// Create an interval from `start` to `end`
+    pub fn new(start: f64, end: f64) -> Self {
+        Self {
+            start,
+            end,
+            color: None,
+        }
+    }
+
+    /// Set the fill color of this interval
+    pub fn with_color(mut self, color: [f32; 4]) -> Self {
+        self.color = Some(color);
+        self
+    }
+}
+
+/// A labelled row of a Gantt chart
+#[derive(Debug, Clone, Copy)]
+pub struct GanttRow<'a> {
+    /// Tick label shown on the Y axis (anything after `##` is hidden)
+    pub label: &'a str,
+    /// Bars drawn on this row, later ones on top
+    pub intervals: &'a [GanttInterval],
+}
+
+impl<'a> GanttRow<'a> {
+    /// Create a row with the given label and intervals
+    pub fn new(label: &'a str, intervals: &'a [GanttInterval]) -> Self {
+        Self { label, intervals }
+    }
+}
+
+impl crate::PlotUi<'_> {
+    /// Draw a Gantt-style timeline: one row per [`GanttRow`], top to bottom, with a
+    /// horizontal bar for each of its intervals
+    ///
+    /// Call right after `begin_plot`, before plotting anything else: it sets up the Y1
+    /// axis ticks with the row labels and, once, the axis limits to fit all intervals.
+    /// Bars are drawn on the plot draw list, so they don't take part in auto-fit or the
+    /// legend. Hovering a bar shows a tooltip with its row, start, end and duration.
+    ///
+    /// Returns the `(row, interval)` indices of the hovered bar, if any.
+    pub fn gantt(&self, rows: &[GanttRow<'_>]) -> Option<(usize, usize)> {
+        if rows.is_empty() {
+            return None;
+        }
+        let count = rows.len();
+        let positions: Vec<f64> = (0..count).map(|row| row_y(row, count)).collect();
+        let labels: Vec<&str> = rows.iter().map(|row| row.label).collect();
+        self.setup_y_axis_ticks_positions(YAxis::Y1, &positions, Some(&labels), false);
+        self.setup_y_axis_limits(YAxis::Y1, -0.5, count as f64 - 0.5, PlotCond::Once);
+        if let Some((min, max)) = time_extent(rows) {
+            self.setup_x_axis_limits(XAxis::X1, min, max, PlotCond::Once);
+        }
+        unsafe { sys::ImPlot_SetupLock() };
+
+        let hovered = if is_plot_hovered() {
+            let mouse = get_plot_mouse_position(None);
+            hit_test(rows, mouse.x, mouse.y)
+        } else {
+            None
+        };
+
+        let draw_list = get_plot_draw_list();
+        if draw_list.is_null() {
+            return None;
+        }
+        push_plot_clip_rect(0.0);
+        for (row_idx, row) in rows.iter().enumerate() {
+            let y = row_y(row_idx, count);
+            for (interval_idx, interval) in row.intervals.iter().enumerate() {
+                let a = plot_to_pixels(
+                    sys::ImPlotPoint {
+                        x: interval.start,
+                        y: y + BAR_HALF_HEIGHT,
+                    },
+                    None,
+                );
+                let b = plot_to_pixels(
+                    sys::ImPlotPoint {
+                        x: interval.end,
+                        y: y - BAR_HALF_HEIGHT,
+                    },
+                    None,
+                );
+                let min = imgui_sys::ImVec2 {
+                    x: a[0].min(b[0]),
+                    y: a[1].min(b[1]),
+                };
+                let max = imgui_sys::ImVec2 {
+                    x: a[0].max(b[0]),
+                    y: a[1].max(b[1]),
+                };
+                let color = match interval.color {
+                    Some([r, g, b, a]) => unsafe {
+                        imgui_sys::igColorConvertFloat4ToU32(imgui_sys::ImVec4 {
+                            x: r,
+                            y: g,
+                            z: b,
+                            w: a,
+                        })
+                    },
+                    None => unsafe { sys::ImPlot_GetColormapColorU32(row_idx as i32, -1) },
+                };
+                unsafe {
+                    imgui_sys::ImDrawList_AddRectFilled(draw_list, min, max, color, 0.0, 0);
+                    if hovered == Some((row_idx, interval_idx)) {
+                        let outline = imgui_sys::igGetColorU32_Col(
+                            imgui_sys::ImGuiCol_Text as imgui_sys::ImGuiCol,
+                            1.0,
+                        );
+                        imgui_sys::ImDrawList_AddRect(draw_list, min, max, outline, 0.0, 0, 2.0);
+                    }
+                }
+            }
+        }
+        pop_plot_clip_rect();
+
+        if let Some((row_idx, interval_idx)) = hovered {
+            let row = &rows[row_idx];
+            let interval = &row.intervals[interval_idx];
+            self.ui.tooltip(|| {
+                self.ui
+                    .text(row.label.split("##").next().unwrap_or_default());
+                self.ui.text(format!("start: {:.3}", interval.start));
+                self.ui.text(format!("end: {:.3}", interval.end));
+                self.ui
+                    .text(format!("duration: {:.3}", interval.end - interval.start));
+            });
+        }
+        hovered
+    }
+}
+
+/// Y of a row's center: the first row is drawn at the top
+fn row_y(row: usize, count: usize) -> f64 {
+    (count - 1 - row) as f64
+}
+
+/// Smallest start and largest end over all intervals
+fn time_extent(rows: &[GanttRow<'_>]) -> Option<(f64, f64)> {
+    rows.iter()
+        .flat_map(|row| row.intervals)
+        .map(|interval| {
+            (
+                interval.start.min(interval.end),
+                interval.start.max(interval.end),
+            )
+        })
+        .reduce(|(lo, hi), (start, end)| (lo.min(start), hi.max(end)))
+}
+
+/// Bar under the plot point `(x, y)`; later intervals are drawn on top, so they win
+fn hit_test(rows: &[GanttRow<'_>], x: f64, y: f64) -> Option<(usize, usize)> {
+    let count = rows.len();
+    rows.iter().enumerate().find_map(|(row_idx, row)| {
+        if (y - row_y(row_idx, count)).abs() > BAR_HALF_HEIGHT {
+            return None;
+        }
+        row.intervals
+            .iter()
+            .rposition(|iv| iv.start.min(iv.end) <= x && x <= iv.start.max(iv.end))
+            .map(|interval_idx| (row_idx, interval_idx))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gantt_extent_and_hit_test() {
+        let build = [GanttInterval::new(0.0, 2.0), GanttInterval::new(3.0, 5.0)];
+        let test = [
+            GanttInterval::new(1.5, 4.0).with_color([1.0, 0.0, 0.0, 1.0]),
+            GanttInterval::new(3.5, 4.5),
+        ];
+        let rows = [GanttRow::new("build", &build), GanttRow::new("test", &test)];
+
+        assert_eq!(time_extent(&rows), Some((0.0, 5.0)));
+        assert_eq!(time_extent(&[]), None);
+
+        // "build" is the top row (y = 1), "test" the bottom one (y = 0)
+        assert_eq!(hit_test(&rows, 1.0, 1.1), Some((0, 0)));
+        assert_eq!(hit_test(&rows, 2.5, 1.0), None);
+        assert_eq!(hit_test(&rows, 1.0, 0.5), None);
+        assert_eq!(hit_test(&rows, 2.0, -0.2), Some((1, 0)));
+        assert_eq!(hit_test(&rows, 4.0, 0.0), Some((1, 1)));
+    }
+}
@@ -29,8 +29,8 @@ impl<'a> HeatmapPlot<'a> {
         Self {
             label,
             values,
-            rows: rows as i32,
-            cols: cols as i32,
+            rows: grid_dim(rows),
+            cols: grid_dim(cols),
             scale_min: 0.0,
             scale_max: 0.0, // Auto-scale when both are 0
             label_fmt: Some("%.1f"),
@@ -41,7 +41,7 @@ impl<'a> HeatmapPlot<'a> {
     }
 
     /// Set the color scale range (min, max)
-    /// If both are 0.0, auto-scaling will be used
+    /// If both are 0.0, or either is NaN, auto-scaling will be used
     pub fn with_scale(mut self, min: f64, max: f64) -> Self {
         self.scale_min = min;
         self.scale_max = max;
@@ -83,25 +83,7 @@ impl<'a> HeatmapPlot<'a> {
 
     /// Validate the plot data
     pub fn validate(&self) -> Result<(), PlotError> {
-        if self.values.is_empty() {
-            return Err(PlotError::EmptyData);
-        }
-
-        let expected_size = (self.rows * self.cols) as usize;
-        if self.values.len() != expected_size {
-            return Err(PlotError::DataLengthMismatch {
-                x_len: expected_size,
-                y_len: self.values.len(),
-            });
-        }
-
-        if self.rows <= 0 || self.cols <= 0 {
-            return Err(PlotError::InvalidData(
-                "Rows and columns must be positive".to_string(),
-            ));
-        }
-
-        Ok(())
+        validate_grid(self.values.len(), self.rows, self.cols)
     }
 }
 
@@ -118,6 +100,7 @@ impl<'a> Plot for HeatmapPlot<'a> {
             .as_ref()
             .map(|cstr| cstr.as_ptr())
             .unwrap_or(std::ptr::null());
+        let (scale_min, scale_max) = scale_range(self.scale_min, self.scale_max);
 
         unsafe {
             sys::ImPlot_PlotHeatmap_doublePtr(
@@ -125,8 +108,8 @@ impl<'a> Plot for HeatmapPlot<'a> {
                 self.values.as_ptr(),
                 self.rows,
                 self.cols,
-                self.scale_min,
-                self.scale_max,
+                scale_min,
+                scale_max,
                 label_fmt_ptr,
                 self.bounds_min,
                 self.bounds_max,
@@ -140,6 +123,43 @@ impl<'a> Plot for HeatmapPlot<'a> {
     }
 }
 
+/// Converts a row/column count for ImPlot; counts that don't fit fail validation
+fn grid_dim(n: usize) -> i32 {
+    i32::try_from(n).unwrap_or(i32::MAX)
+}
+
+/// Checks that `len` values exactly fill a `rows` x `cols` grid
+fn validate_grid(len: usize, rows: i32, cols: i32) -> Result<(), PlotError> {
+    if len == 0 {
+        return Err(PlotError::EmptyData);
+    }
+
+    if rows <= 0 || cols <= 0 {
+        return Err(PlotError::InvalidData(
+            "Rows and columns must be positive".to_string(),
+        ));
+    }
+
+    let expected_size = (rows as usize).checked_mul(cols as usize);
+    if expected_size != Some(len) {
+        return Err(PlotError::DataLengthMismatch {
+            x_len: expected_size.unwrap_or(usize::MAX),
+            y_len: len,
+        });
+    }
+
+    Ok(())
+}
+
+/// ImPlot auto-scales when min == max == 0; NaN bounds request the same
+fn scale_range(min: f64, max: f64) -> (f64, f64) {
+    if min.is_nan() || max.is_nan() {
+        (0.0, 0.0)
+    } else {
+        (min, max)
+    }
+}
+
 /// Float version of heatmap for better performance with f32 data
 pub struct HeatmapPlotF32<'a> {
     label: &'a str,
@@ -160,8 +180,8 @@ impl<'a> HeatmapPlotF32<'a> {
         Self {
             label,
             values,
-            rows: rows as i32,
-            cols: cols as i32,
+            rows: grid_dim(rows),
+            cols: grid_dim(cols),
             scale_min: 0.0,
             scale_max: 0.0,
             label_fmt: Some("%.1f"),
@@ -172,6 +192,7 @@ impl<'a> HeatmapPlotF32<'a> {
     }
 
     /// Set the color scale range (min, max)
+    /// If both are 0.0, or either is NaN, auto-scaling will be used
     pub fn with_scale(mut self, min: f64, max: f64) -> Self {
         self.scale_min = min;
         self.scale_max = max;
@@ -205,25 +226,7 @@ impl<'a> HeatmapPlotF32<'a> {
 
     /// Validate the plot data
     pub fn validate(&self) -> Result<(), PlotError> {
-        if self.values.is_empty() {
-            return Err(PlotError::EmptyData);
-        }
-
-        let expected_size = (self.rows * self.cols) as usize;
-        if self.values.len() != expected_size {
-            return Err(PlotError::DataLengthMismatch {
-                x_len: expected_size,
-                y_len: self.values.len(),
-            });
-        }
-
-        if self.rows <= 0 || self.cols <= 0 {
-            return Err(PlotError::InvalidData(
-                "Rows and columns must be positive".to_string(),
-            ));
-        }
-
-        Ok(())
+        validate_grid(self.values.len(), self.rows, self.cols)
     }
 }
 
@@ -240,6 +243,7 @@ impl<'a> Plot for HeatmapPlotF32<'a> {
             .as_ref()
             .map(|cstr| cstr.as_ptr())
             .unwrap_or(std::ptr::null());
+        let (scale_min, scale_max) = scale_range(self.scale_min, self.scale_max);
 
         unsafe {
             sys::ImPlot_PlotHeatmap_FloatPtr(
@@ -247,8 +251,8 @@ impl<'a> Plot for HeatmapPlotF32<'a> {
                 self.values.as_ptr(),
                 self.rows,
                 self.cols,
-                self.scale_min,
-                self.scale_max,
+                scale_min,
+                scale_max,
                 label_fmt_ptr,
                 self.bounds_min,
                 self.bounds_max,
@@ -264,6 +268,24 @@ impl<'a> Plot for HeatmapPlotF32<'a> {
 
 /// Convenience functions for quick heatmap plotting
 impl<'ui> crate::PlotUi<'ui> {
+    /// Plot a configured heatmap, e.g. a column-major matrix or one without cell labels
+    ///
+    /// ```no_run
+    /// # use dear_implot::*;
+    /// # fn f(plot_ui: &PlotUi, confusion: &[f64]) -> Result<(), PlotError> {
+    /// plot_ui.plot_heatmap(
+    ///     &HeatmapPlot::new("confusion", confusion, 3, 3)
+    ///         .with_scale(f64::NAN, f64::NAN)
+    ///         .with_label_format(None),
+    /// )
+    /// # }
+    /// ```
+    pub fn plot_heatmap(&self, plot: &HeatmapPlot<'_>) -> Result<(), PlotError> {
+        plot.validate()?;
+        plot.plot();
+        Ok(())
+    }
+
     /// Plot a heatmap with f64 data
     pub fn heatmap_plot(
         &self,
@@ -312,3 +334,44 @@ impl<'ui> crate::PlotUi<'ui> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_heatmap_validation() {
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+        assert!(HeatmapPlot::new("test", &values, 2, 3).validate().is_ok());
+        assert!(
+            HeatmapPlot::new("test", &values, 3, 2)
+                .column_major()
+                .validate()
+                .is_ok()
+        );
+        assert_eq!(
+            HeatmapPlot::new("test", &values[..5], 2, 3).validate(),
+            Err(PlotError::DataLengthMismatch { x_len: 6, y_len: 5 })
+        );
+        assert!(matches!(
+            HeatmapPlot::new("test", &values, 0, 6).validate(),
+            Err(PlotError::InvalidData(_))
+        ));
+        assert!(
+            HeatmapPlot::new("test", &values, usize::MAX, 1)
+                .validate()
+                .is_err()
+        );
+        assert_eq!(
+            HeatmapPlot::new("test", &[], 0, 0).validate(),
+            Err(PlotError::EmptyData)
+        );
+    }
+
+    #[test]
+    fn test_nan_scale_means_auto() {
+        assert_eq!(scale_range(f64::NAN, 1.0), (0.0, 0.0));
+        assert_eq!(scale_range(-1.0, f64::NAN), (0.0, 0.0));
+        assert_eq!(scale_range(-1.0, 1.0), (-1.0, 1.0));
+    }
+}
@@ -4,6 +4,9 @@ use super::{Plot, PlotError, safe_cstring};
 use crate::{InfLinesFlags, sys};
 
 /// Builder for infinite lines plots
+///
+/// Lines are vertical by default, with `positions` along the X axis. Use
+/// [`horizontal`](Self::horizontal) to draw horizontal lines at Y positions instead.
 pub struct InfLinesPlot<'a> {
     label: &'a str,
     positions: &'a [f64],
@@ -24,9 +27,9 @@ impl<'a> InfLinesPlot<'a> {
         }
     }
 
-    /// Make lines horizontal instead of vertical
-    pub fn horizontal(mut self) -> Self {
-        self.flags |= InfLinesFlags::HORIZONTAL;
+    /// Draw horizontal lines at Y positions instead of vertical lines at X positions
+    pub fn horizontal(mut self, horizontal: bool) -> Self {
+        self.flags.set(InfLinesFlags::HORIZONTAL, horizontal);
         self
     }
 
@@ -76,19 +79,51 @@ impl<'a> Plot for InfLinesPlot<'a> {
 
 /// Convenience functions for quick inf-lines plotting
 impl<'ui> crate::PlotUi<'ui> {
-    /// Plot vertical infinite lines at given x positions
-    pub fn inf_lines_vertical(&self, label: &str, xs: &[f64]) -> Result<(), PlotError> {
-        let plot = InfLinesPlot::new(label, xs);
+    /// Plot a configured set of infinite lines, e.g. event times or limit thresholds
+    ///
+    /// ```no_run
+    /// # use dear_implot::*;
+    /// # fn f(plot_ui: &PlotUi) -> Result<(), PlotError> {
+    /// plot_ui.inf_lines(&InfLinesPlot::new("limits", &[-1.0, 1.0]).horizontal(true))
+    /// # }
+    /// ```
+    pub fn inf_lines(&self, plot: &InfLinesPlot<'_>) -> Result<(), PlotError> {
         plot.validate()?;
         plot.plot();
         Ok(())
     }
 
+    /// Plot vertical infinite lines at given x positions
+    pub fn inf_lines_vertical(&self, label: &str, xs: &[f64]) -> Result<(), PlotError> {
+        self.inf_lines(&InfLinesPlot::new(label, xs))
+    }
+
     /// Plot horizontal infinite lines at given y positions
     pub fn inf_lines_horizontal(&self, label: &str, ys: &[f64]) -> Result<(), PlotError> {
-        let plot = InfLinesPlot::new(label, ys).horizontal();
-        plot.validate()?;
-        plot.plot();
-        Ok(())
+        self.inf_lines(&InfLinesPlot::new(label, ys).horizontal(true))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inf_lines_plot_orientation() {
+        let positions = [1.0, 2.5];
+        let plot = InfLinesPlot::new("events", &positions);
+        assert!(plot.validate().is_ok());
+        assert!(!plot.flags.contains(InfLinesFlags::HORIZONTAL));
+
+        let plot = plot.horizontal(true);
+        assert!(plot.flags.contains(InfLinesFlags::HORIZONTAL));
+        let plot = plot.horizontal(false);
+        assert!(!plot.flags.contains(InfLinesFlags::HORIZONTAL));
+    }
+
+    #[test]
+    fn test_inf_lines_plot_empty() {
+        let plot = InfLinesPlot::new("events", &[]);
+        assert!(matches!(plot.validate(), Err(PlotError::EmptyData)));
     }
 }
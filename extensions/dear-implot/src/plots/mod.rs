@@ -8,6 +8,7 @@ pub mod bar_groups;
 pub mod digital;
 pub mod dummy;
 pub mod error_bars;
+pub mod gantt;
 pub mod heatmap;
 pub mod histogram;
 pub mod image;
@@ -26,6 +27,7 @@ pub use bar_groups::*;
 pub use digital::*;
 pub use dummy::*;
 pub use error_bars::*;
+pub use gantt::*;
 pub use heatmap::*;
 pub use histogram::*;
 pub use image::*;
@@ -124,6 +126,18 @@ pub fn validate_data_lengths<T, U>(data1: &[T], data2: &[U]) -> Result<(), PlotE
     Ok(())
 }
 
+/// Helper function to count the values ImPlot reads from `len` contiguous `f64`s when
+/// stepping `stride` bytes at a time, rejecting strides that would read misaligned values
+pub(crate) fn strided_count(len: usize, stride: i32) -> Result<i32, PlotError> {
+    let size = std::mem::size_of::<f64>() as i32;
+    if stride <= 0 || stride % size != 0 {
+        return Err(PlotError::InvalidData(format!(
+            "Stride must be a positive multiple of {size} bytes, got {stride}"
+        )));
+    }
+    Ok(len.div_ceil((stride / size) as usize) as i32)
+}
+
 /// Helper function to create a CString safely
 /// This follows the pattern used in dear-imgui for safe string conversion
 pub fn safe_cstring(s: &str) -> std::ffi::CString {
@@ -20,20 +20,20 @@ impl<'a> PieChartPlot<'a> {
     /// Create a new pie chart plot
     ///
     /// # Arguments
-    /// * `label_ids` - Labels for each slice of the pie
+    /// * `label_ids` - Labels for each slice of the pie, e.g. a `&[&str]` or `Vec<&str>`
     /// * `values` - Values for each slice
     /// * `center_x` - X coordinate of the pie center in plot units
     /// * `center_y` - Y coordinate of the pie center in plot units
     /// * `radius` - Radius of the pie in plot units
     pub fn new(
-        label_ids: Vec<&'a str>,
+        label_ids: impl Into<Vec<&'a str>>,
         values: &'a [f64],
         center_x: f64,
         center_y: f64,
         radius: f64,
     ) -> Self {
         Self {
-            label_ids,
+            label_ids: label_ids.into(),
             values,
             center_x,
             center_y,
@@ -100,10 +100,10 @@ impl<'a> PieChartPlot<'a> {
             ));
         }
 
-        // Check for negative values
-        if self.values.iter().any(|&v| v < 0.0) {
+        // A NaN or infinite value would make every slice angle NaN
+        if self.values.iter().any(|&v| v < 0.0 || !v.is_finite()) {
             return Err(PlotError::InvalidData(
-                "Pie chart values cannot be negative".to_string(),
+                "Pie chart values must be finite and non-negative".to_string(),
             ));
         }
 
@@ -169,14 +169,14 @@ pub struct PieChartPlotF32<'a> {
 impl<'a> PieChartPlotF32<'a> {
     /// Create a new f32 pie chart plot
     pub fn new(
-        label_ids: Vec<&'a str>,
+        label_ids: impl Into<Vec<&'a str>>,
         values: &'a [f32],
         center_x: f64,
         center_y: f64,
         radius: f64,
     ) -> Self {
         Self {
-            label_ids,
+            label_ids: label_ids.into(),
             values,
             center_x,
             center_y,
@@ -242,9 +242,9 @@ impl<'a> PieChartPlotF32<'a> {
             ));
         }
 
-        if self.values.iter().any(|&v| v < 0.0) {
+        if self.values.iter().any(|&v| v < 0.0 || !v.is_finite()) {
             return Err(PlotError::InvalidData(
-                "Pie chart values cannot be negative".to_string(),
+                "Pie chart values must be finite and non-negative".to_string(),
             ));
         }
 
@@ -295,11 +295,18 @@ impl<'a> Plot for PieChartPlotF32<'a> {
 
 /// Convenience functions for quick pie chart plotting
 impl<'ui> crate::PlotUi<'ui> {
+    /// Plot a configured pie chart
+    pub fn pie_chart(&self, plot: &PieChartPlot<'_>) -> Result<(), PlotError> {
+        plot.validate()?;
+        plot.plot();
+        Ok(())
+    }
+
     /// Plot a pie chart with f64 data
-    pub fn pie_chart_plot(
+    pub fn pie_chart_plot<'a>(
         &self,
-        label_ids: Vec<&str>,
-        values: &[f64],
+        label_ids: impl Into<Vec<&'a str>>,
+        values: &'a [f64],
         center_x: f64,
         center_y: f64,
         radius: f64,
@@ -311,10 +318,10 @@ impl<'ui> crate::PlotUi<'ui> {
     }
 
     /// Plot a pie chart with f32 data
-    pub fn pie_chart_plot_f32(
+    pub fn pie_chart_plot_f32<'a>(
         &self,
-        label_ids: Vec<&str>,
-        values: &[f32],
+        label_ids: impl Into<Vec<&'a str>>,
+        values: &'a [f32],
         center_x: f64,
         center_y: f64,
         radius: f64,
@@ -326,10 +333,10 @@ impl<'ui> crate::PlotUi<'ui> {
     }
 
     /// Plot a centered pie chart (center at 0.5, 0.5 with radius 0.4)
-    pub fn centered_pie_chart(
+    pub fn centered_pie_chart<'a>(
         &self,
-        label_ids: Vec<&str>,
-        values: &[f64],
+        label_ids: impl Into<Vec<&'a str>>,
+        values: &'a [f64],
     ) -> Result<(), PlotError> {
         let plot = PieChartPlot::new(label_ids, values, 0.5, 0.5, 0.4);
         plot.validate()?;
@@ -337,3 +344,41 @@ impl<'ui> crate::PlotUi<'ui> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pie_chart_accepts_label_slices() {
+        let labels = ["cpu", "gpu", "io"];
+        let values = [0.5, 0.3, 0.2];
+
+        let plot = PieChartPlot::new(&labels[..], &values, 0.5, 0.5, 0.4);
+        assert!(plot.validate().is_ok());
+        let plot = PieChartPlot::new(vec!["cpu", "gpu", "io"], &values, 0.5, 0.5, 0.4);
+        assert!(plot.validate().is_ok());
+    }
+
+    #[test]
+    fn test_pie_chart_validation() {
+        let values = [0.5, 0.3, 0.2];
+
+        assert_eq!(
+            PieChartPlot::new(["cpu", "gpu"], &values, 0.5, 0.5, 0.4).validate(),
+            Err(PlotError::DataLengthMismatch { x_len: 2, y_len: 3 })
+        );
+        assert!(matches!(
+            PieChartPlot::new(["a", "b", "c"], &values, 0.5, 0.5, 0.0).validate(),
+            Err(PlotError::InvalidData(_))
+        ));
+        assert!(matches!(
+            PieChartPlot::new(["a", "b"], &[0.5, f64::NAN], 0.5, 0.5, 0.4).validate(),
+            Err(PlotError::InvalidData(_))
+        ));
+        assert!(matches!(
+            PieChartPlotF32::new(["a", "b"], &[0.5, -0.1], 0.5, 0.5, 0.4).validate(),
+            Err(PlotError::InvalidData(_))
+        ));
+    }
+}
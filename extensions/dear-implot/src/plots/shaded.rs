@@ -1,6 +1,6 @@
 //! Shaded area plot implementation
 
-use super::{Plot, PlotError, safe_cstring, validate_data_lengths};
+use super::{Plot, PlotError, safe_cstring, strided_count, validate_data_lengths};
 use crate::{ShadedFlags, sys};
 
 /// Builder for shaded area plots
@@ -30,6 +30,8 @@ impl<'a> ShadedPlot<'a> {
 
     /// Set the reference Y value for shading
     /// The area will be filled between the line and this Y value
+    ///
+    /// `f64::NEG_INFINITY` and `f64::INFINITY` fill down or up to the edge of the plot.
     pub fn with_y_ref(mut self, y_ref: f64) -> Self {
         self.y_ref = y_ref;
         self
@@ -47,7 +49,9 @@ impl<'a> ShadedPlot<'a> {
         self
     }
 
-    /// Set data stride for non-contiguous data
+    /// Set data stride in bytes for non-contiguous data
+    ///
+    /// Must be a multiple of `size_of::<f64>()`; every `stride / 8`-th value is plotted.
     pub fn with_stride(mut self, stride: i32) -> Self {
         self.stride = stride;
         self
@@ -55,7 +59,14 @@ impl<'a> ShadedPlot<'a> {
 
     /// Validate the plot data
     pub fn validate(&self) -> Result<(), PlotError> {
-        validate_data_lengths(self.x_data, self.y_data)
+        validate_data_lengths(self.x_data, self.y_data)?;
+        if self.y_ref.is_nan() {
+            return Err(PlotError::InvalidData(
+                "Shading reference must not be NaN".to_string(),
+            ));
+        }
+        strided_count(self.x_data.len(), self.stride)?;
+        Ok(())
     }
 }
 
@@ -65,6 +76,9 @@ impl<'a> Plot for ShadedPlot<'a> {
             return;
         }
 
+        let Ok(count) = strided_count(self.x_data.len(), self.stride) else {
+            return;
+        };
         let label_cstr = safe_cstring(self.label);
 
         unsafe {
@@ -72,7 +86,7 @@ impl<'a> Plot for ShadedPlot<'a> {
                 label_cstr.as_ptr(),
                 self.x_data.as_ptr(),
                 self.y_data.as_ptr(),
-                self.x_data.len() as i32,
+                count,
                 self.y_ref,
                 self.flags.bits() as sys::ImPlotShadedFlags,
                 self.offset,
@@ -93,6 +107,8 @@ pub struct ShadedBetweenPlot<'a> {
     y1_data: &'a [f64],
     y2_data: &'a [f64],
     flags: ShadedFlags,
+    offset: i32,
+    stride: i32,
 }
 
 impl<'a> ShadedBetweenPlot<'a> {
@@ -104,6 +120,8 @@ impl<'a> ShadedBetweenPlot<'a> {
             y1_data,
             y2_data,
             flags: ShadedFlags::NONE,
+            offset: 0,
+            stride: std::mem::size_of::<f64>() as i32,
         }
     }
 
@@ -113,10 +131,25 @@ impl<'a> ShadedBetweenPlot<'a> {
         self
     }
 
+    /// Set data offset for partial plotting
+    pub fn with_offset(mut self, offset: i32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set data stride in bytes for non-contiguous data
+    ///
+    /// Must be a multiple of `size_of::<f64>()`; every `stride / 8`-th value is plotted.
+    pub fn with_stride(mut self, stride: i32) -> Self {
+        self.stride = stride;
+        self
+    }
+
     /// Validate the plot data
     pub fn validate(&self) -> Result<(), PlotError> {
         validate_data_lengths(self.x_data, self.y1_data)?;
         validate_data_lengths(self.x_data, self.y2_data)?;
+        strided_count(self.x_data.len(), self.stride)?;
         Ok(())
     }
 }
@@ -127,20 +160,21 @@ impl<'a> Plot for ShadedBetweenPlot<'a> {
             return;
         }
 
+        let Ok(count) = strided_count(self.x_data.len(), self.stride) else {
+            return;
+        };
         let label_cstr = safe_cstring(self.label);
 
-        // Note: This would require a different wrapper function for shaded between two lines
-        // For now, we'll use the single line version with the first Y data
         unsafe {
             sys::ImPlot_PlotShaded_doublePtrdoublePtrdoublePtr(
                 label_cstr.as_ptr(),
                 self.x_data.as_ptr(),
                 self.y1_data.as_ptr(),
                 self.y2_data.as_ptr(),
-                self.x_data.len() as i32,
+                count,
                 self.flags.bits() as sys::ImPlotShadedFlags,
-                0,
-                std::mem::size_of::<f64>() as i32,
+                self.offset,
+                self.stride,
             );
         }
     }
@@ -224,6 +258,20 @@ impl<'a> Plot for SimpleShadedPlot<'a> {
 
 /// Convenience functions for quick shaded plotting
 impl<'ui> crate::PlotUi<'ui> {
+    /// Plot a configured shaded area between a line and its reference Y value
+    pub fn shaded(&self, plot: &ShadedPlot<'_>) -> Result<(), PlotError> {
+        plot.validate()?;
+        plot.plot();
+        Ok(())
+    }
+
+    /// Plot a configured shaded area between two lines, e.g. a confidence band
+    pub fn shaded_between(&self, plot: &ShadedBetweenPlot<'_>) -> Result<(), PlotError> {
+        plot.validate()?;
+        plot.plot();
+        Ok(())
+    }
+
     /// Plot a shaded area between a line and Y=0
     pub fn shaded_plot(
         &self,
@@ -308,5 +356,41 @@ mod tests {
         let plot = ShadedBetweenPlot::new("test", &x_data, &y1_data, &y2_data);
         assert_eq!(plot.label(), "test");
         assert!(plot.validate().is_ok());
+
+        let short = [1.0, 2.0];
+        let plot = ShadedBetweenPlot::new("test", &x_data, &y1_data, &short);
+        assert_eq!(
+            plot.validate(),
+            Err(PlotError::DataLengthMismatch { x_len: 4, y_len: 2 })
+        );
+    }
+
+    #[test]
+    fn test_shaded_reference_validation() {
+        let x_data = [1.0, 2.0, 3.0];
+        let y_data = [1.0, 4.0, 2.0];
+
+        let plot = ShadedPlot::new("test", &x_data, &y_data).with_y_ref(f64::NAN);
+        assert!(matches!(plot.validate(), Err(PlotError::InvalidData(_))));
+
+        // Infinite references fill to the plot edge
+        let plot = ShadedPlot::new("test", &x_data, &y_data).with_y_ref(f64::NEG_INFINITY);
+        assert!(plot.validate().is_ok());
+    }
+
+    #[test]
+    fn test_shaded_stride_validation() {
+        let x_data = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let y_data = [1.0, 4.0, 2.0, 3.0, 5.0];
+
+        let plot = ShadedPlot::new("test", &x_data, &y_data).with_stride(12);
+        assert!(matches!(plot.validate(), Err(PlotError::InvalidData(_))));
+        let plot = ShadedBetweenPlot::new("test", &x_data, &y_data, &y_data).with_stride(0);
+        assert!(matches!(plot.validate(), Err(PlotError::InvalidData(_))));
+
+        // Every other value is read, without running past the end
+        assert_eq!(strided_count(5, 16), Ok(3));
+        assert_eq!(strided_count(4, 16), Ok(2));
+        assert_eq!(strided_count(5, 8), Ok(5));
     }
 }
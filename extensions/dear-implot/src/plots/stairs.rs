@@ -33,7 +33,11 @@ impl<'a> StairsPlot<'a> {
         self
     }
 
-    /// Enable pre-step mode (step before the point instead of after)
+    /// Enable pre-step mode
+    ///
+    /// By default each value is held until the next point (post-step, like a
+    /// sample-and-hold signal). With pre-step, the line jumps to each value at the previous
+    /// point instead, so every step ends at its own point.
     pub fn pre_step(mut self) -> Self {
         self.flags |= StairsFlags::PRE_STEP;
         self
@@ -63,7 +67,12 @@ impl<'a> StairsPlot<'a> {
     }
 
     /// Plot the stairs
+    ///
+    /// Nothing is drawn if [`validate`](Self::validate) fails.
     pub fn plot(self) {
+        if self.validate().is_err() {
+            return;
+        }
         let label_cstring = safe_cstring(self.label);
 
         unsafe {
@@ -142,7 +151,12 @@ impl<'a> StairsPlotF32<'a> {
     }
 
     /// Plot the stairs
+    ///
+    /// Nothing is drawn if [`validate`](Self::validate) fails.
     pub fn plot(self) {
+        if self.validate().is_err() {
+            return;
+        }
         let label_cstring = safe_cstring(self.label);
 
         unsafe {
@@ -229,7 +243,12 @@ impl<'a> SimpleStairsPlot<'a> {
     }
 
     /// Plot the stairs
+    ///
+    /// Nothing is drawn if [`validate`](Self::validate) fails.
     pub fn plot(self) {
+        if self.validate().is_err() {
+            return;
+        }
         // Generate x data
         let x_data: Vec<f64> = (0..self.y_data.len())
             .map(|i| self.x_start + i as f64 * self.x_scale)
@@ -260,3 +279,68 @@ impl<'a> PlotData for SimpleStairsPlot<'a> {
         self.y_data.len()
     }
 }
+
+/// Convenience functions for quick stairs plotting
+impl<'ui> crate::PlotUi<'ui> {
+    /// Plot a stairs (step) plot with X and Y data
+    pub fn stairs_plot(
+        &self,
+        label: &str,
+        x_data: &[f64],
+        y_data: &[f64],
+    ) -> Result<(), PlotError> {
+        let plot = StairsPlot::new(label, x_data, y_data);
+        plot.validate()?;
+        plot.plot();
+        Ok(())
+    }
+
+    /// Plot a stairs (step) plot with Y values only (X will be indices)
+    pub fn simple_stairs_plot(&self, label: &str, values: &[f64]) -> Result<(), PlotError> {
+        let plot = SimpleStairsPlot::new(label, values);
+        plot.validate()?;
+        plot.plot();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stairs_flags_match_implot() {
+        assert_eq!(
+            StairsFlags::PRE_STEP.bits(),
+            sys::ImPlotStairsFlags_PreStep as u32
+        );
+        assert_eq!(
+            StairsFlags::SHADED.bits(),
+            sys::ImPlotStairsFlags_Shaded as u32
+        );
+
+        let x = [0.0, 1.0];
+        let plot = StairsPlot::new("cdf", &x, &x).pre_step().shaded();
+        assert_eq!(plot.flags, StairsFlags::PRE_STEP | StairsFlags::SHADED);
+    }
+
+    #[test]
+    fn test_stairs_validation() {
+        let x = [0.0, 1.0, 2.0];
+        let y = [1.0, 2.0];
+
+        assert!(StairsPlot::new("s", &x, &x).validate().is_ok());
+        assert_eq!(
+            StairsPlot::new("s", &x, &y).validate(),
+            Err(PlotError::DataLengthMismatch { x_len: 3, y_len: 2 })
+        );
+        assert_eq!(
+            SimpleStairsPlot::new("s", &[]).validate(),
+            Err(PlotError::EmptyData)
+        );
+        assert_eq!(
+            StairsPlotF32::new("s", &[0.0, 1.0], &[1.0]).validate(),
+            Err(PlotError::DataLengthMismatch { x_len: 2, y_len: 1 })
+        );
+    }
+}
@@ -53,9 +53,10 @@ impl<'a> StemPlot<'a> {
         self
     }
 
-    /// Validate the plot data
+    /// Validate the plot data and reference value
     pub fn validate(&self) -> Result<(), PlotError> {
-        validate_data_lengths(self.x_data, self.y_data)
+        validate_data_lengths(self.x_data, self.y_data)?;
+        validate_y_ref(self.y_ref)
     }
 }
 
@@ -124,11 +125,30 @@ impl<'a> SimpleStemPlot<'a> {
         self.x_start = start;
         self
     }
+
+    /// Validate the plot data and reference value
+    pub fn validate(&self) -> Result<(), PlotError> {
+        if self.values.is_empty() {
+            return Err(PlotError::EmptyData);
+        }
+        validate_y_ref(self.y_ref)
+    }
+}
+
+/// Stems are drawn from the reference line, so it must be a finite value
+fn validate_y_ref(y_ref: f64) -> Result<(), PlotError> {
+    if !y_ref.is_finite() {
+        return Err(PlotError::InvalidData(format!(
+            "Stem reference value must be finite, got {}",
+            y_ref
+        )));
+    }
+    Ok(())
 }
 
 impl<'a> Plot for SimpleStemPlot<'a> {
     fn plot(&self) {
-        if self.values.is_empty() {
+        if self.validate().is_err() {
             return;
         }
 
@@ -184,10 +204,8 @@ impl<'ui> crate::PlotUi<'ui> {
 
     /// Plot a simple stem plot with Y values only (X will be indices)
     pub fn simple_stem_plot(&self, label: &str, values: &[f64]) -> Result<(), PlotError> {
-        if values.is_empty() {
-            return Err(PlotError::EmptyData);
-        }
         let plot = SimpleStemPlot::new(label, values);
+        plot.validate()?;
         plot.plot();
         Ok(())
     }
@@ -199,10 +217,8 @@ impl<'ui> crate::PlotUi<'ui> {
         values: &[f64],
         y_ref: f64,
     ) -> Result<(), PlotError> {
-        if values.is_empty() {
-            return Err(PlotError::EmptyData);
-        }
         let plot = SimpleStemPlot::new(label, values).with_y_ref(y_ref);
+        plot.validate()?;
         plot.plot();
         Ok(())
     }
@@ -247,4 +263,24 @@ mod tests {
         assert_eq!(plot.y_ref, 1.0);
         assert!(plot.validate().is_ok());
     }
+
+    #[test]
+    fn test_stem_plot_rejects_non_finite_ref() {
+        let x_data = [1.0, 2.0, 3.0];
+        let y_data = [1.0, 4.0, 2.0];
+
+        for y_ref in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let plot = StemPlot::new("test", &x_data, &y_data).with_y_ref(y_ref);
+            assert!(matches!(plot.validate(), Err(PlotError::InvalidData(_))));
+
+            let simple = SimpleStemPlot::new("test", &y_data).with_y_ref(y_ref);
+            assert!(matches!(simple.validate(), Err(PlotError::InvalidData(_))));
+        }
+    }
+
+    #[test]
+    fn test_simple_stem_plot_rejects_empty() {
+        let plot = SimpleStemPlot::new("test", &[]);
+        assert!(matches!(plot.validate(), Err(PlotError::EmptyData)));
+    }
 }
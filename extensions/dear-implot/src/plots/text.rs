@@ -57,7 +57,13 @@ impl<'a> TextPlot<'a> {
     }
 
     /// Plot the text
+    ///
+    /// Nothing is drawn when either coordinate is NaN, so gaps in a data series can be
+    /// labelled without filtering them first.
     pub fn plot(self) {
+        if self.x.is_nan() || self.y.is_nan() {
+            return;
+        }
         let text_cstring = safe_cstring(self.text);
 
         let pix_offset = sys::ImVec2 {
@@ -240,7 +246,12 @@ impl FormattedTextPlot {
     }
 
     /// Plot the formatted text
+    ///
+    /// Nothing is drawn when either coordinate is NaN.
     pub fn plot(self) {
+        if self.x.is_nan() || self.y.is_nan() {
+            return;
+        }
         let text_cstring = safe_cstring(&self.text);
 
         let pix_offset = sys::ImVec2 {
@@ -333,3 +344,54 @@ impl<'a> TextAnnotation<'a> {
         text_plot.plot();
     }
 }
+
+impl<'ui> crate::PlotUi<'ui> {
+    /// Place `text` at plot coordinates `(x, y)`, shifted by `pixel_offset` in screen pixels
+    ///
+    /// The text is centered on the point unless offset; pass [`TextFlags::VERTICAL`] to
+    /// rotate it. Interior NUL bytes are stripped and NaN coordinates draw nothing.
+    pub fn plot_text(
+        &self,
+        text: &str,
+        x: f64,
+        y: f64,
+        pixel_offset: [f32; 2],
+        flags: TextFlags,
+    ) -> Result<(), PlotError> {
+        let plot = TextPlot::new(text, x, y)
+            .with_pixel_offset(pixel_offset[0] as f64, pixel_offset[1] as f64)
+            .with_flags(flags);
+        plot.validate()?;
+        plot.plot();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_flags_match_implot() {
+        assert_eq!(
+            TextFlags::VERTICAL.bits(),
+            sys::ImPlotTextFlags_Vertical as u32
+        );
+
+        let plot = TextPlot::new("peak", 1.0, 2.0).vertical();
+        assert_eq!(plot.flags, TextFlags::VERTICAL);
+    }
+
+    #[test]
+    fn test_text_validation() {
+        assert!(TextPlot::new("peak", 1.0, 2.0).validate().is_ok());
+        assert!(TextPlot::new("", 1.0, 2.0).validate().is_err());
+        // NaN coordinates are skipped at plot time rather than rejected
+        assert!(TextPlot::new("gap", f64::NAN, 2.0).validate().is_ok());
+        assert!(
+            FormattedTextPlot::new(String::new(), 0.0, 0.0)
+                .validate()
+                .is_err()
+        );
+    }
+}
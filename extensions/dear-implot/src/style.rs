@@ -136,6 +136,127 @@ pub fn push_style_color(element: crate::PlotColorElement, color: [f32; 4]) -> St
     StyleColorToken { was_popped: false }
 }
 
+/// Index of a colormap registered with ImPlot, built-in or added with
+/// [`PlotUi::add_colormap`](crate::PlotUi::add_colormap)
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ColormapId(pub sys::ImPlotColormap);
+
+impl From<crate::Colormap> for ColormapId {
+    fn from(preset: crate::Colormap) -> Self {
+        Self(preset as sys::ImPlotColormap)
+    }
+}
+
+/// Token for managing colormap changes
+pub struct ColormapToken {
+    was_popped: bool,
+}
+
+impl ColormapToken {
+    /// Pop this colormap from the stack
+    pub fn pop(mut self) {
+        if self.was_popped {
+            panic!("Attempted to pop a colormap token twice.");
+        }
+        self.was_popped = true;
+        unsafe {
+            sys::ImPlot_PopColormap(1);
+        }
+    }
+}
+
+impl Drop for ColormapToken {
+    fn drop(&mut self) {
+        if !self.was_popped {
+            unsafe {
+                sys::ImPlot_PopColormap(1);
+            }
+        }
+    }
+}
+
+impl crate::PlotUi<'_> {
+    /// Use `cmap` for subsequent plot colors and colormap widgets until the token is
+    /// popped or dropped
+    pub fn push_colormap(&self, cmap: impl Into<ColormapId>) -> ColormapToken {
+        unsafe {
+            sys::ImPlot_PushColormap_PlotColormap(cmap.into().0);
+        }
+        ColormapToken { was_popped: false }
+    }
+
+    /// Register a custom colormap, or look up the one already registered as `name`
+    ///
+    /// ImPlot keeps colormaps for the lifetime of its context and rejects duplicate
+    /// names, so calling this every frame returns the same id. A `qualitative`
+    /// colormap is sampled as discrete colors, otherwise colors are interpolated.
+    ///
+    /// # Panics
+    ///
+    /// Panics if fewer than two colors are given or `name` contains a null byte.
+    pub fn add_colormap(&self, name: &str, colors: &[[f32; 4]], qualitative: bool) -> ColormapId {
+        assert!(colors.len() >= 2, "a colormap needs at least two colors");
+        let name_cstr = std::ffi::CString::new(name).expect("colormap name contains a null byte");
+        let existing = unsafe { sys::ImPlot_GetColormapIndex(name_cstr.as_ptr()) };
+        if existing != -1 {
+            return ColormapId(existing);
+        }
+        let colors: Vec<sys::ImVec4> = colors
+            .iter()
+            .map(|&[r, g, b, a]| sys::ImVec4 {
+                x: r,
+                y: g,
+                z: b,
+                w: a,
+            })
+            .collect();
+        ColormapId(unsafe {
+            sys::ImPlot_AddColormap_Vec4Ptr(
+                name_cstr.as_ptr(),
+                colors.as_ptr(),
+                colors.len() as i32,
+                qualitative,
+            )
+        })
+    }
+
+    /// Draw a vertical color legend of the current colormap for values in
+    /// `scale_min..=scale_max`, e.g. next to a heatmap
+    ///
+    /// A zero `size` component uses ImPlot's default; `format` is a printf-style tick
+    /// label format (`None` for `"%g"`).
+    pub fn colormap_scale(
+        &self,
+        label: &str,
+        scale_min: f64,
+        scale_max: f64,
+        size: [f32; 2],
+        format: Option<&str>,
+        flags: crate::ColormapScaleFlags,
+    ) {
+        let label_cstr = std::ffi::CString::new(label).unwrap_or_default();
+        let fmt_cstr = format.and_then(|s| std::ffi::CString::new(s).ok());
+        let fmt_ptr = fmt_cstr
+            .as_ref()
+            .map(|cs| cs.as_ptr())
+            .unwrap_or(c"%g".as_ptr());
+        unsafe {
+            sys::ImPlot_ColormapScale(
+                label_cstr.as_ptr(),
+                scale_min,
+                scale_max,
+                sys::ImVec2 {
+                    x: size[0],
+                    y: size[1],
+                },
+                fmt_ptr,
+                flags.bits() as i32,
+                crate::IMPLOT_AUTO,
+            )
+        }
+    }
+}
+
 /// Push a colormap to the stack
 pub fn push_colormap(preset: crate::Colormap) {
     unsafe {
@@ -198,6 +319,17 @@ pub fn map_input_reverse() {
     unsafe { sys::ImPlot_MapInputReverse(sys::ImPlot_GetInputMap()) }
 }
 
+/// Set the mouse button and modifiers that start a box selection in all plots
+///
+/// Selections made this way can be read back with [`crate::PlotToken::selection`].
+pub fn set_select_input(button: dear_imgui_rs::MouseButton, modifiers: crate::InputModifiers) {
+    unsafe {
+        let map = sys::ImPlot_GetInputMap();
+        (*map).Select = button as i32;
+        (*map).SelectMod = modifiers.bits() as i32;
+    }
+}
+
 // Colormap widgets
 
 /// Draw a colormap scale widget
@@ -210,7 +342,8 @@ pub fn colormap_scale(
 ) {
     let c = std::ffi::CString::new(label).unwrap_or_default();
     let size = sys::ImVec2 { x: 0.0, y: height };
-    let fmt_ptr: *const i8 = std::ptr::null();
+    // ImPlot formats tick labels with this unconditionally, so it must not be null
+    let fmt_ptr = c"%g".as_ptr();
     let flags: i32 = 0; // ImPlotColormapScaleFlags_None
     unsafe {
         sys::ImPlot_ColormapScale(
@@ -220,7 +353,7 @@ pub fn colormap_scale(
             size,
             fmt_ptr,
             flags,
-            cmap.unwrap_or(0),
+            cmap.unwrap_or(crate::IMPLOT_AUTO),
         )
     }
 }
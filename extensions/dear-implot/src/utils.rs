@@ -18,6 +18,219 @@ pub fn is_legend_entry_hovered(label: &str) -> bool {
     unsafe { sys::ImPlot_IsLegendEntryHovered(c.as_ptr()) }
 }
 
+/// Check if a plot item is shown, i.e. not hidden by clicking its legend entry
+///
+/// Must be called between begin/end of a plot. Items the current plot has not seen yet,
+/// and calls made outside a plot, report `true` since ImPlot shows new items by default.
+pub fn is_legend_entry_visible(label: &str) -> bool {
+    let c = std::ffi::CString::new(label).unwrap_or_default();
+    unsafe {
+        if sys::ImPlot_GetCurrentPlot().is_null() {
+            return true;
+        }
+        let item = sys::ImPlot_GetItem(c.as_ptr());
+        item.is_null() || (*item).Show
+    }
+}
+
+/// Set the legend visibility of the next plotted item
+pub fn hide_next_item(hidden: bool, cond: crate::PlotCond) {
+    unsafe { sys::ImPlot_HideNextItem(hidden, cond as sys::ImPlotCond) }
+}
+
+/// A plotted series passed to [`PlotUi::export_visible_csv`](crate::PlotUi::export_visible_csv):
+/// `(label, xs, ys)`
+pub type CsvSeries<'a> = (&'a str, &'a [f64], &'a [f64]);
+
+impl crate::PlotUi<'_> {
+    /// Serialize the series currently shown in the legend to CSV
+    ///
+    /// ImPlot does not keep plotted data, so pass the same series (and labels) that were
+    /// plotted; those hidden through their legend entry are skipped. Each visible series
+    /// becomes an `x`/`y` column pair headed by its display label. Series of different
+    /// lengths are padded with empty cells.
+    ///
+    /// Call inside the plot, after plotting the items, e.g. from a "Copy data" button
+    /// in the plot's context menu.
+    pub fn export_visible_csv(&self, series: &[CsvSeries<'_>]) -> String {
+        series_to_csv(series, is_legend_entry_visible)
+    }
+}
+
+/// Screen-space rectangle as `(min, max)`
+pub type PixelRect = ([f32; 2], [f32; 2]);
+
+impl crate::PlotUi<'_> {
+    /// Plot area (inside the axes) of the current plot in framebuffer pixels
+    ///
+    /// Valid between begin/end of a plot; returns `None` outside one. The rectangle is
+    /// relative to the top-left of the viewport the plot is drawn in, scaled by its
+    /// framebuffer scale and rounded outwards to whole pixels, i.e. ready to pass to a
+    /// framebuffer readback. Reading the pixels back (e.g. `glReadPixels` or a wgpu
+    /// texture copy after the frame is rendered) is the backend's job.
+    pub fn plot_rect_pixels(&self) -> Option<PixelRect> {
+        current_plot_rect_pixels(|plot| &plot.PlotRect)
+    }
+
+    /// Whole frame of the current plot (title, axes, legend and plot area) in framebuffer
+    /// pixels
+    ///
+    /// Same conventions as [`plot_rect_pixels`](Self::plot_rect_pixels); use this one to
+    /// export the chart as it appears on screen.
+    pub fn plot_frame_rect_pixels(&self) -> Option<PixelRect> {
+        current_plot_rect_pixels(|plot| &plot.FrameRect)
+    }
+}
+
+fn current_plot_rect_pixels(rect: impl Fn(&sys::ImPlotPlot) -> &sys::ImRect) -> Option<PixelRect> {
+    let plot = unsafe { sys::ImPlot_GetCurrentPlot() };
+    if plot.is_null() {
+        return None;
+    }
+    // Plot rects are laid out when setup is locked (by the first item or an explicit query)
+    unsafe { sys::ImPlot_SetupLock() };
+    let rect = rect(unsafe { &*plot });
+    Some(to_framebuffer_pixels(
+        [rect.Min.x, rect.Min.y],
+        [rect.Max.x, rect.Max.y],
+    ))
+}
+
+fn to_framebuffer_pixels(min: [f32; 2], max: [f32; 2]) -> PixelRect {
+    let (origin, scale) = unsafe {
+        let viewport = &*dear_imgui_sys::igGetWindowViewport();
+        // The main viewport only receives io.DisplayFramebufferScale at render time
+        let scale = if viewport.FramebufferScale.x > 0.0 {
+            viewport.FramebufferScale
+        } else {
+            (*dear_imgui_sys::igGetIO_Nil()).DisplayFramebufferScale
+        };
+        (viewport.Pos, scale)
+    };
+    (
+        [
+            ((min[0] - origin.x) * scale.x).floor(),
+            ((min[1] - origin.y) * scale.y).floor(),
+        ],
+        [
+            ((max[0] - origin.x) * scale.x).ceil(),
+            ((max[1] - origin.y) * scale.y).ceil(),
+        ],
+    )
+}
+
+fn series_to_csv(series: &[CsvSeries<'_>], is_visible: impl Fn(&str) -> bool) -> String {
+    let visible: Vec<_> = series
+        .iter()
+        .filter(|(label, _, _)| is_visible(label))
+        .collect();
+
+    let mut out = String::new();
+    let header: Vec<String> = visible
+        .iter()
+        .flat_map(|(label, _, _)| {
+            // Text after "##" is an ID suffix that ImPlot doesn't display
+            let name = label.split("##").next().unwrap_or_default();
+            [
+                csv_field(&format!("{name} x")),
+                csv_field(&format!("{name} y")),
+            ]
+        })
+        .collect();
+    out.push_str(&header.join(","));
+    out.push('\n');
+
+    let rows = visible
+        .iter()
+        .map(|(_, xs, ys)| xs.len().max(ys.len()))
+        .max()
+        .unwrap_or(0);
+    for row in 0..rows {
+        let cells: Vec<String> = visible
+            .iter()
+            .flat_map(|(_, xs, ys)| {
+                [xs, ys].map(|v| v.get(row).map(f64::to_string).unwrap_or_default())
+            })
+            .collect();
+        out.push_str(&cells.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+impl crate::PlotUi<'_> {
+    /// Show a tooltip reading out every series at the X nearest to the mouse
+    ///
+    /// Does nothing unless the current plot is hovered. The mouse X snaps to the closest
+    /// sample of any visible series; each visible series is then linearly interpolated at
+    /// that X, so series sampled at different X positions can be mixed. Series whose range
+    /// doesn't cover the X are left out. `xs` must be sorted in ascending order.
+    ///
+    /// Pass the same `(label, xs, ys)` series that were plotted, after plotting them;
+    /// series hidden through their legend entry are skipped. Returns the snapped X, e.g.
+    /// to mark it with [`tag_x`]. Combine with [`PlotFlags::CROSSHAIRS`](crate::PlotFlags)
+    /// for the usual time-series hover readout.
+    pub fn crosshair_tooltip(&self, series: &[(&str, &[f64], &[f64])]) -> Option<f64> {
+        if !is_plot_hovered() {
+            return None;
+        }
+        let visible: Vec<_> = series
+            .iter()
+            .filter(|(label, _, _)| is_legend_entry_visible(label))
+            .collect();
+        let mouse_x = get_plot_mouse_position(None).x;
+        let x = visible
+            .iter()
+            .filter_map(|(_, xs, ys)| nearest_sample(&xs[..xs.len().min(ys.len())], mouse_x))
+            .min_by(|a, b| (a - mouse_x).abs().total_cmp(&(b - mouse_x).abs()))?;
+
+        self.ui.tooltip(|| {
+            self.ui.text(format!("x = {x:.3}"));
+            for (label, xs, ys) in &visible {
+                if let Some(y) = interpolate_at(xs, ys, x) {
+                    let name = label.split("##").next().unwrap_or_default();
+                    self.ui.text(format!("{name}: {y:.3}"));
+                }
+            }
+        });
+        Some(x)
+    }
+}
+
+/// Sample of the ascending `xs` closest to `target`
+fn nearest_sample(xs: &[f64], target: f64) -> Option<f64> {
+    let idx = xs.partition_point(|&x| x < target);
+    let after = xs.get(idx).copied();
+    let before = idx.checked_sub(1).map(|i| xs[i]);
+    match (before, after) {
+        (Some(b), Some(a)) => Some(if target - b <= a - target { b } else { a }),
+        (b, a) => b.or(a),
+    }
+}
+
+/// Linear interpolation of a series with ascending `xs` at `x`, `None` outside its range
+fn interpolate_at(xs: &[f64], ys: &[f64], x: f64) -> Option<f64> {
+    let n = xs.len().min(ys.len());
+    let idx = xs[..n].partition_point(|&v| v < x);
+    if idx < n && xs[idx] == x {
+        return Some(ys[idx]);
+    }
+    if idx == 0 || idx == n {
+        return None;
+    }
+    let (x0, x1) = (xs[idx - 1], xs[idx]);
+    let t = (x - x0) / (x1 - x0);
+    Some(ys[idx - 1] + (ys[idx] - ys[idx - 1]) * t)
+}
+
 /// Get the mouse position in plot coordinates
 pub fn get_plot_mouse_position(y_axis_choice: Option<crate::YAxisChoice>) -> sys::ImPlotPoint {
     let x_axis = 0; // ImAxis_X1
@@ -176,7 +389,9 @@ pub fn get_plot_selection_axes(x_axis: XAxis, y_axis: YAxis) -> Option<sys::ImPl
     Some(rect)
 }
 
-/// Draw a simple round annotation marker at (x,y)
+/// Draw an annotation at (x,y) labelled with the point's coordinates
+///
+/// See [`Annotation`] for how `pixel_offset` and `clamp` place the label.
 pub fn annotation_point(
     x: f64,
     y: f64,
@@ -198,6 +413,141 @@ pub fn annotation_point(
     unsafe { sys::ImPlot_Annotation_Bool(x, y, col, off, clamp, round) }
 }
 
+/// Default label offset for [`Annotation`]: up and to the right of the point
+pub const ANNOTATION_DEFAULT_OFFSET: [f32; 2] = [8.0, -8.0];
+
+/// Text label attached to a plot point
+///
+/// `pixel_offset` places the label relative to the point, per axis: `0` centers the
+/// label on the point, a positive value puts its near edge that many pixels to the
+/// right/below, and a negative value that many pixels to the left/above. A non-zero
+/// offset also draws a leader line from the point to the label, so the default
+/// ([`ANNOTATION_DEFAULT_OFFSET`]) keeps the label clear of the point.
+///
+/// With `clamp` enabled (the default), ImPlot shifts the label back inside the plot
+/// area when the offset would push it past an edge, so annotations on points near the
+/// axis limits stay visible. Without it the label is clipped by the plot rectangle.
+///
+/// ```no_run
+/// # use dear_implot::*;
+/// // inside begin_plot/end_plot
+/// Annotation::new(1.0, 1.0, "peak")
+///     .color([1.0, 0.5, 0.0, 1.0])
+///     .pixel_offset([10.0, -10.0])
+///     .plot();
+/// ```
+#[derive(Clone, Debug)]
+#[must_use]
+pub struct Annotation<'a> {
+    x: f64,
+    y: f64,
+    text: &'a str,
+    color: [f32; 4],
+    pixel_offset: [f32; 2],
+    clamp: bool,
+}
+
+impl<'a> Annotation<'a> {
+    /// Creates an annotation for the point (x,y)
+    pub fn new(x: f64, y: f64, text: &'a str) -> Self {
+        Self {
+            x,
+            y,
+            text,
+            color: [0.0, 0.0, 0.0, 0.0],
+            pixel_offset: ANNOTATION_DEFAULT_OFFSET,
+            clamp: true,
+        }
+    }
+
+    /// Background color of the label
+    ///
+    /// The text color is picked to contrast with it. A fully transparent color (the
+    /// default) draws no background and uses the inlay text color.
+    pub fn color(mut self, color: [f32; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Offset of the label from the point, in pixels
+    pub fn pixel_offset(mut self, pixel_offset: [f32; 2]) -> Self {
+        self.pixel_offset = pixel_offset;
+        self
+    }
+
+    /// Keep the label inside the plot area near its edges (default: true)
+    pub fn clamp(mut self, clamp: bool) -> Self {
+        self.clamp = clamp;
+        self
+    }
+
+    /// Submit the annotation (must be called between begin_plot and end_plot)
+    ///
+    /// Text after an interior null byte is dropped.
+    pub fn plot(self) {
+        let text = self.text.split('\0').next().unwrap_or_default();
+        let text = std::ffi::CString::new(text).expect("null bytes were stripped");
+        let [r, g, b, a] = self.color;
+        let [ox, oy] = self.pixel_offset;
+        unsafe {
+            sys::ImPlot_Annotation_Str(
+                self.x,
+                self.y,
+                sys::ImVec4 {
+                    x: r,
+                    y: g,
+                    z: b,
+                    w: a,
+                },
+                sys::ImVec2 { x: ox, y: oy },
+                self.clamp,
+                c"%s".as_ptr(),
+                text.as_ptr(),
+            )
+        }
+    }
+}
+
+impl crate::PlotUi<'_> {
+    /// Draw a text label attached to the plot point (x,y)
+    ///
+    /// `color` fills the label background, as in ImPlot's `Annotation` overload: the
+    /// text color is picked to contrast with it, and a fully transparent color draws no
+    /// background. See [`Annotation`] for how `pixel_offset` and `clamp` place the label.
+    /// Must be called between begin_plot and end_plot.
+    pub fn annotation(
+        &self,
+        x: f64,
+        y: f64,
+        color: [f32; 4],
+        pixel_offset: [f32; 2],
+        clamp: bool,
+        text: &str,
+    ) {
+        Annotation::new(x, y, text)
+            .color(color)
+            .pixel_offset(pixel_offset)
+            .clamp(clamp)
+            .plot();
+    }
+
+    /// Like [`annotation`](Self::annotation), with a label built from `format_args!`
+    pub fn annotation_fmt(
+        &self,
+        x: f64,
+        y: f64,
+        color: [f32; 4],
+        pixel_offset: [f32; 2],
+        clamp: bool,
+        args: std::fmt::Arguments<'_>,
+    ) {
+        match args.as_str() {
+            Some(text) => self.annotation(x, y, color, pixel_offset, clamp, text),
+            None => self.annotation(x, y, color, pixel_offset, clamp, &args.to_string()),
+        }
+    }
+}
+
 /// Tag the X axis at position x with a tick-like mark
 pub fn tag_x(x: f64, color: [f32; 4], round: bool) {
     let col = sys::ImVec4 {
@@ -429,3 +779,90 @@ pub fn drag_line_y(
         held,
     }
 }
+
+/// Draggable rectangle in plot coordinates
+///
+/// The four corners are written back when the rectangle is moved or resized.
+pub fn drag_rect(
+    id: i32,
+    x1: &mut f64,
+    y1: &mut f64,
+    x2: &mut f64,
+    y2: &mut f64,
+    color: [f32; 4],
+    flags: crate::DragToolFlags,
+) -> DragResult {
+    let mut clicked = false;
+    let mut hovered = false;
+    let mut held = false;
+    let changed = unsafe {
+        sys::ImPlot_DragRect(
+            id,
+            x1 as *mut f64,
+            y1 as *mut f64,
+            x2 as *mut f64,
+            y2 as *mut f64,
+            color4(color),
+            flags.bits() as i32,
+            &mut clicked as *mut bool,
+            &mut hovered as *mut bool,
+            &mut held as *mut bool,
+        )
+    };
+    DragResult {
+        changed,
+        clicked,
+        hovered,
+        held,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_skips_hidden_series_and_pads_ragged_ones() {
+        let series: [CsvSeries; 3] = [
+            ("sin##1", &[0.0, 1.0, 2.0], &[0.0, 0.5, 1.0]),
+            ("hidden", &[0.0], &[9.0]),
+            ("a, \"b\"", &[0.0], &[4.0]),
+        ];
+        let csv = series_to_csv(&series, |label| label != "hidden");
+        assert_eq!(
+            csv,
+            "sin x,sin y,\"a, \"\"b\"\" x\",\"a, \"\"b\"\" y\"\n\
+             0,0,0,4\n\
+             1,0.5,,\n\
+             2,1,,\n"
+        );
+    }
+
+    #[test]
+    fn csv_of_nothing_visible_is_empty_header() {
+        let series: [CsvSeries; 1] = [("a", &[1.0], &[2.0])];
+        assert_eq!(series_to_csv(&series, |_| false), "\n");
+    }
+
+    #[test]
+    fn nearest_sample_picks_closest_neighbour() {
+        let xs = [0.0, 1.0, 3.0];
+        assert_eq!(nearest_sample(&xs, -5.0), Some(0.0));
+        assert_eq!(nearest_sample(&xs, 1.9), Some(1.0));
+        assert_eq!(nearest_sample(&xs, 2.1), Some(3.0));
+        assert_eq!(nearest_sample(&xs, 9.0), Some(3.0));
+        assert_eq!(nearest_sample(&[], 1.0), None);
+    }
+
+    #[test]
+    fn interpolates_within_range_only() {
+        let xs = [0.0, 2.0, 4.0];
+        let ys = [10.0, 20.0, 0.0];
+        assert_eq!(interpolate_at(&xs, &ys, 2.0), Some(20.0));
+        assert_eq!(interpolate_at(&xs, &ys, 1.0), Some(15.0));
+        assert_eq!(interpolate_at(&xs, &ys, 3.5), Some(2.5));
+        assert_eq!(interpolate_at(&xs, &ys, 4.0), Some(0.0));
+        assert_eq!(interpolate_at(&xs, &ys, -0.1), None);
+        assert_eq!(interpolate_at(&xs, &ys, 4.1), None);
+    }
+}
@@ -0,0 +1,104 @@
+use dear_imgui_rs::Condition;
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_implot::{Annotation, PlotCond, PlotContext, PlotUi, get_plot_pos, get_plot_size};
+
+/// Label background; packed by ImGui as 0xAABBGGRR
+const LABEL_BG: [f32; 4] = [1.0, 0.0, 1.0, 1.0];
+const LABEL_BG_PACKED: u32 = 0xFF_FF_00_FF;
+/// Screen-space rectangle as (min, max)
+type Rect = ([f32; 2], [f32; 2]);
+
+/// Anti-aliased fringes of the leader line may stick out slightly
+const TOLERANCE: f32 = 2.0;
+
+/// Renders one frame with an annotation on the top-right corner of a [0,1]x[0,1] plot and
+/// returns the plot rectangle plus the positions of all label-background vertices
+fn render_corner_annotation(clamp: bool) -> (Rect, Vec<[f32; 2]>) {
+    render_annotations(|_| {
+        Annotation::new(1.0, 1.0, "a fairly wide corner label")
+            .color(LABEL_BG)
+            .pixel_offset([20.0, -20.0])
+            .clamp(clamp)
+            .plot();
+    })
+}
+
+/// Renders one frame of a [0,1]x[0,1] plot in which `annotate` adds annotations and
+/// returns the plot rectangle plus the positions of all label-background vertices
+fn render_annotations(annotate: impl FnOnce(&PlotUi<'_>)) -> (Rect, Vec<[f32; 2]>) {
+    let _guard = test_guard();
+
+    let mut ctx = headless_context();
+    let plot_ctx = PlotContext::create(&ctx);
+
+    let mut plot_rect: Rect = ([0.0; 2], [0.0; 2]);
+    {
+        let ui = ctx.frame();
+        ui.window("annotation")
+            .position([0.0, 0.0], Condition::Always)
+            .size([500.0, 400.0], Condition::Always)
+            .build(|| {
+                let plot_ui = plot_ctx.get_plot_ui(ui);
+                if let Some(_token) = plot_ui.begin_plot_with_size("##corner", [400.0, 300.0]) {
+                    plot_ui.setup_axes_limits(0.0, 1.0, 0.0, 1.0, PlotCond::Always);
+                    annotate(&plot_ui);
+                    let pos = get_plot_pos();
+                    let size = get_plot_size();
+                    plot_rect = (pos, [pos[0] + size[0], pos[1] + size[1]]);
+                }
+            });
+    }
+
+    let draw_data = ctx.render();
+    let verts = draw_data
+        .draw_lists()
+        .flat_map(|list| list.vtx_buffer().iter())
+        .filter(|v| v.col == LABEL_BG_PACKED)
+        .map(|v| v.pos)
+        .collect();
+    drop(plot_ctx);
+    (plot_rect, verts)
+}
+
+fn inside(rect: Rect, p: [f32; 2]) -> bool {
+    let (min, max) = rect;
+    (0..2).all(|i| p[i] >= min[i] - TOLERANCE && p[i] <= max[i] + TOLERANCE)
+}
+
+#[test]
+fn clamped_corner_annotation_stays_in_plot_rect() {
+    let (rect, verts) = render_corner_annotation(true);
+    assert!(!verts.is_empty(), "annotation label was not drawn");
+    for v in verts {
+        assert!(inside(rect, v), "vertex {v:?} outside plot rect {rect:?}");
+    }
+}
+
+#[test]
+fn unclamped_corner_annotation_overflows_plot_rect() {
+    let (rect, verts) = render_corner_annotation(false);
+    assert!(!verts.is_empty(), "annotation label was not drawn");
+    assert!(verts.iter().any(|&v| !inside(rect, v)));
+}
+
+#[test]
+fn plot_ui_annotation_fills_background_only_when_opaque() {
+    let (rect, verts) = render_annotations(|plot_ui| {
+        let peak = 0.5;
+        plot_ui.annotation_fmt(
+            0.5,
+            peak,
+            LABEL_BG,
+            [10.0, -10.0],
+            true,
+            format_args!("peak: {peak:.2}"),
+        );
+    });
+    assert!(!verts.is_empty(), "annotation background was not drawn");
+    assert!(verts.iter().all(|&v| inside(rect, v)));
+
+    let (_, verts) = render_annotations(|plot_ui| {
+        plot_ui.annotation(0.5, 0.5, [0.0; 4], [10.0, -10.0], true, "no background");
+    });
+    assert!(verts.is_empty());
+}
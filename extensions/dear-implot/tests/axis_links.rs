@@ -0,0 +1,36 @@
+use dear_imgui_rs::Condition;
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_implot::{PlotContext, XAxis, YAxis, get_plot_limits_axes};
+
+#[test]
+fn linked_plots_share_the_x_range() {
+    let _guard = test_guard();
+
+    let mut ctx = headless_context();
+    let plot_ctx = PlotContext::create(&ctx);
+
+    let mut range = (2.0, 5.0);
+    let mut seen = Vec::new();
+    {
+        let ui = ctx.frame();
+        ui.window("dashboard")
+            .position([0.0, 0.0], Condition::Always)
+            .size([500.0, 600.0], Condition::Always)
+            .build(|| {
+                let plot_ui = plot_ctx.get_plot_ui(ui);
+                for title in ["##voltage", "##current"] {
+                    if let Some(mut plot) = plot_ui.begin_plot_with_size(title, [400.0, 250.0]) {
+                        plot.setup_axis_links(XAxis::X1, Some(&mut range.0), Some(&mut range.1));
+                        plot_ui.setup_finish();
+                        let limits = get_plot_limits_axes(XAxis::X1, YAxis::Y1);
+                        seen.push((limits.X.Min, limits.X.Max));
+                    }
+                }
+            });
+    }
+    ctx.render();
+
+    assert_eq!(seen, vec![(2.0, 5.0), (2.0, 5.0)]);
+    // EndPlot writes the (unchanged) range back through the links
+    assert_eq!(range, (2.0, 5.0));
+}
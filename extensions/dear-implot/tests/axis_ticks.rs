@@ -0,0 +1,83 @@
+use dear_imgui_rs::Condition;
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_implot::{PlotContext, PlotError, PlotUi, XAxis};
+use dear_implot_sys as sys;
+use std::ffi::CStr;
+
+const DAYS: [&str; 3] = ["Mon", "Tue", "Wed"];
+
+/// Runs one frame with a plot, calling `setup` right after `begin_plot` and returning the
+/// X1 tick labels ImPlot holds once setup is finished
+fn x_tick_labels(setup: impl FnOnce(&PlotUi<'_>)) -> Vec<String> {
+    let mut ctx = headless_context();
+    let plot_ctx = PlotContext::create(&ctx);
+
+    let mut labels = Vec::new();
+    {
+        let ui = ctx.frame();
+        ui.window("chart")
+            .position([0.0, 0.0], Condition::Always)
+            .size([500.0, 400.0], Condition::Always)
+            .build(|| {
+                let plot_ui = plot_ctx.get_plot_ui(ui);
+                if let Some(_token) = plot_ui.begin_plot_with_size("##week", [400.0, 300.0]) {
+                    setup(&plot_ui);
+                    plot_ui.setup_finish();
+                    unsafe {
+                        let ticker = &(*sys::ImPlot_GetCurrentPlot()).Axes[0].Ticker;
+                        let text = ticker.TextBuffer.Buf.Data;
+                        for i in 0..ticker.Ticks.Size as usize {
+                            let tick = &*ticker.Ticks.Data.add(i);
+                            if tick.ShowLabel {
+                                let label = CStr::from_ptr(text.add(tick.TextOffset as usize));
+                                labels.push(label.to_string_lossy().into_owned());
+                            }
+                        }
+                    }
+                }
+            });
+    }
+    ctx.render();
+    labels
+}
+
+#[test]
+fn custom_ticks_replace_or_extend_defaults() {
+    let _guard = test_guard();
+
+    let replaced = x_tick_labels(|plot_ui| {
+        // Owned labels dropped before EndPlot must still be shown
+        let owned: Vec<String> = DAYS.iter().map(|d| d.to_string()).collect();
+        let refs: Vec<&str> = owned.iter().map(String::as_str).collect();
+        plot_ui
+            .setup_axis_ticks(XAxis::X1, &[0.0, 1.0, 2.0], Some(&refs), false)
+            .unwrap();
+    });
+    assert_eq!(replaced, DAYS);
+
+    let extended = x_tick_labels(|plot_ui| {
+        plot_ui
+            .setup_axis_ticks(XAxis::X1, &[0.0, 1.0, 2.0], Some(&DAYS), true)
+            .unwrap();
+    });
+    assert!(extended.len() > DAYS.len(), "{extended:?}");
+    assert_eq!(extended[..DAYS.len()], DAYS);
+}
+
+#[test]
+fn invalid_labels_are_rejected() {
+    let _guard = test_guard();
+
+    let mut results = Vec::new();
+    let labels = x_tick_labels(|plot_ui| {
+        results.push(plot_ui.setup_axis_ticks(XAxis::X1, &[0.0, 1.0], Some(&DAYS), false));
+        results.push(plot_ui.setup_axis_ticks(XAxis::X1, &[0.0], Some(&["a\0b"]), false));
+    });
+    assert!(matches!(
+        results[0],
+        Err(PlotError::DataLengthMismatch { x_len: 2, y_len: 3 })
+    ));
+    assert!(matches!(results[1], Err(PlotError::StringConversion(_))));
+    // Rejected calls leave the default ticks in place
+    assert!(!labels.is_empty());
+}
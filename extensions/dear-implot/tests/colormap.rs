@@ -0,0 +1,75 @@
+use dear_imgui_rs::Condition;
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_implot::{Colormap, ColormapId, ColormapScaleFlags, PlotContext};
+
+const TRAFFIC: [[f32; 4]; 3] = [
+    [0.0, 0.8, 0.0, 1.0],
+    [1.0, 0.8, 0.0, 1.0],
+    [0.9, 0.0, 0.0, 1.0],
+];
+
+#[test]
+fn custom_colormaps_are_registered_once_and_usable() {
+    let _guard = test_guard();
+
+    let mut ctx = headless_context();
+    let plot_ctx = PlotContext::create(&ctx);
+
+    let mut ids = Vec::new();
+    for _ in 0..2 {
+        {
+            let ui = ctx.frame();
+            ui.window("legend")
+                .position([0.0, 0.0], Condition::Always)
+                .size([500.0, 400.0], Condition::Always)
+                .build(|| {
+                    let plot_ui = plot_ctx.get_plot_ui(ui);
+                    let traffic = plot_ui.add_colormap("traffic", &TRAFFIC, false);
+                    let other = plot_ui.add_colormap("traffic-2", &TRAFFIC[..2], true);
+                    ids.push((traffic, other));
+
+                    let token = plot_ui.push_colormap(traffic);
+                    plot_ui.colormap_scale(
+                        "##load",
+                        0.0,
+                        100.0,
+                        [0.0, 200.0],
+                        Some("%.0f%%"),
+                        ColormapScaleFlags::NONE,
+                    );
+                    token.pop();
+
+                    let _viridis = plot_ui.push_colormap(Colormap::Viridis);
+                    plot_ui.colormap_scale(
+                        "##default",
+                        -1.0,
+                        1.0,
+                        [0.0, 0.0],
+                        None,
+                        ColormapScaleFlags::INVERT | ColormapScaleFlags::NO_LABEL,
+                    );
+                });
+        }
+        ctx.render();
+    }
+    drop(plot_ctx);
+
+    let (traffic, other) = ids[0];
+    assert_eq!(ids[1], ids[0], "re-adding a colormap returns the same id");
+    assert_ne!(traffic, other);
+    // Custom colormaps come after the built-in ones
+    assert!(traffic.0 > ColormapId::from(Colormap::Greys).0);
+}
+
+#[test]
+#[should_panic(expected = "at least two colors")]
+fn single_color_colormap_is_rejected() {
+    let _guard = test_guard();
+
+    let mut ctx = headless_context();
+    let plot_ctx = PlotContext::create(&ctx);
+    let ui = ctx.frame();
+    plot_ctx
+        .get_plot_ui(ui)
+        .add_colormap("solid", &TRAFFIC[..1], true);
+}
@@ -0,0 +1,52 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{Condition, WindowFlags};
+use dear_implot::{PlotContext, get_plot_pos, get_plot_size};
+
+#[test]
+fn crosshair_tooltip_snaps_to_nearest_sample_when_hovered() {
+    let _guard = test_guard();
+
+    let mut ctx = headless_context();
+    let plot_ctx = PlotContext::create(&ctx);
+
+    let dense_x = [0.0, 1.0, 2.0, 3.0, 4.0];
+    let dense_y = [0.0, 1.0, 2.0, 3.0, 4.0];
+    let sparse_x = [0.0, 4.0];
+    let sparse_y = [10.0, 50.0];
+
+    let mut plot_rect = ([0.0; 2], [0.0; 2]);
+    let mut snapped = Vec::new();
+    for frame in 0..3 {
+        if frame > 0 {
+            // Mouse over the middle of the plot area seen on the previous frame
+            let (pos, size) = plot_rect;
+            ctx.io_mut()
+                .add_mouse_pos_event([pos[0] + size[0] * 0.55, pos[1] + size[1] * 0.5]);
+        }
+        {
+            let ui = ctx.frame();
+            ui.window("chart")
+                .position([0.0, 0.0], Condition::Always)
+                .size([500.0, 400.0], Condition::Always)
+                .flags(WindowFlags::NO_DECORATION | WindowFlags::NO_MOVE)
+                .build(|| {
+                    let plot_ui = plot_ctx.get_plot_ui(ui);
+                    if let Some(_token) = plot_ui.begin_plot_with_size("##chart", [400.0, 300.0]) {
+                        plot_ui.plot_line("dense", &dense_x, &dense_y);
+                        plot_ui.plot_line("sparse", &sparse_x, &sparse_y);
+                        snapped.push(plot_ui.crosshair_tooltip(&[
+                            ("dense", &dense_x, &dense_y),
+                            ("sparse", &sparse_x, &sparse_y),
+                        ]));
+                        plot_rect = (get_plot_pos(), get_plot_size());
+                    }
+                });
+        }
+        ctx.render();
+    }
+    drop(plot_ctx);
+
+    assert_eq!(snapped[0], None, "not hovered before the mouse moves");
+    // Auto-fit limits span x in [0, 4], so the middle of the plot is nearest to x = 2
+    assert_eq!(snapped.last().copied().flatten(), Some(2.0));
+}
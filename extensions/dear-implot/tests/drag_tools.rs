@@ -0,0 +1,73 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{Condition, MouseButton, WindowFlags};
+use dear_implot::{DragResult, DragToolFlags, PlotCond, PlotContext, get_plot_pos, get_plot_size};
+
+#[test]
+fn drag_point_follows_the_mouse_while_held() {
+    let _guard = test_guard();
+
+    let mut ctx = headless_context();
+    let plot_ctx = PlotContext::create(&ctx);
+
+    let (mut x, mut y) = (0.5, 0.5);
+    let mut line = 0.25;
+    let mut plot_rect = ([0.0; 2], [0.0; 2]);
+    let mut results = Vec::new();
+    for frame in 0..6 {
+        let (pos, size) = plot_rect;
+        let center = [pos[0] + size[0] * 0.5, pos[1] + size[1] * 0.5];
+        match frame {
+            1 => ctx.io_mut().add_mouse_pos_event(center),
+            2 => ctx.io_mut().add_mouse_button_event(MouseButton::Left, true),
+            // Drag a quarter of the plot width to the right
+            3 => ctx
+                .io_mut()
+                .add_mouse_pos_event([center[0] + size[0] * 0.25, center[1]]),
+            5 => ctx
+                .io_mut()
+                .add_mouse_button_event(MouseButton::Left, false),
+            _ => {}
+        }
+        {
+            let ui = ctx.frame();
+            ui.window("editor")
+                .position([0.0, 0.0], Condition::Always)
+                .size([500.0, 400.0], Condition::Always)
+                .flags(WindowFlags::NO_DECORATION | WindowFlags::NO_MOVE)
+                .build(|| {
+                    let plot_ui = plot_ctx.get_plot_ui(ui);
+                    if let Some(_token) = plot_ui.begin_plot_with_size("##curve", [400.0, 300.0]) {
+                        plot_ui.setup_axes_limits(0.0, 1.0, 0.0, 1.0, PlotCond::Always);
+                        let result: DragResult = plot_ui.drag_point(
+                            0,
+                            &mut x,
+                            &mut y,
+                            [1.0, 0.0, 0.0, 1.0],
+                            8.0,
+                            DragToolFlags::NONE,
+                        );
+                        results.push(result);
+                        // Far from the mouse, so never grabbed
+                        plot_ui.drag_line_x(
+                            1,
+                            &mut line,
+                            [0.0, 1.0, 0.0, 1.0],
+                            1.0,
+                            DragToolFlags::NONE,
+                        );
+                        plot_rect = (get_plot_pos(), get_plot_size());
+                    }
+                });
+        }
+        ctx.render();
+    }
+    drop(plot_ctx);
+
+    assert!(!results[0].hovered && !results[0].held);
+    assert!(results[2].hovered, "point under the mouse is hovered");
+    assert!(results[4].held, "point is held while the button is down");
+    assert!(results.iter().any(|r| r.changed));
+    assert!((x - 0.75).abs() < 0.02, "x = {x}");
+    assert!((y - 0.5).abs() < 0.02, "y = {y}");
+    assert_eq!(line, 0.25);
+}
@@ -0,0 +1,39 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_implot::{PlotCond, PlotContext, hide_next_item, is_legend_entry_visible};
+
+#[test]
+fn export_skips_series_hidden_in_legend() {
+    let _guard = test_guard();
+
+    let mut ctx = headless_context();
+    let plot_ctx = PlotContext::create(&ctx);
+
+    let xs = [0.0, 1.0, 2.0];
+    let temp = [20.5, 21.0, 21.5];
+    let noise = [0.1, 0.2, 0.3];
+    let mut csv = None;
+    {
+        let ui = ctx.frame();
+        ui.window("plot").build(|| {
+            let plot_ui = plot_ctx.get_plot_ui(ui);
+            if let Some(_token) = plot_ui.begin_plot("##export") {
+                plot_ui.plot_line("temp", &xs, &temp);
+                hide_next_item(true, PlotCond::Always);
+                plot_ui.plot_line("noise", &xs, &noise);
+
+                assert!(is_legend_entry_visible("temp"));
+                assert!(!is_legend_entry_visible("noise"));
+                csv = Some(
+                    plot_ui.export_visible_csv(&[("temp", &xs, &temp), ("noise", &xs, &noise)]),
+                );
+            }
+        });
+    }
+    ctx.render();
+    drop(plot_ctx);
+
+    assert_eq!(
+        csv.as_deref(),
+        Some("temp x,temp y\n0,20.5\n1,21\n2,21.5\n")
+    );
+}
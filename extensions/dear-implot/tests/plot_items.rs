@@ -0,0 +1,330 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{Condition, Context, WindowFlags};
+use dear_implot::plots::bar::FloatingBarPlot;
+use dear_implot::plots::bar_groups::BarGroupsPlot;
+use dear_implot::{
+    BarPlot, ErrorBarsPlot, GanttInterval, GanttRow, HeatmapPlot, ImPlotPoint, ImPlotRange,
+    ImPlotRect, InfLinesPlot, PieChartPlot, Plot, PlotContext, PlotUi, ShadedBetweenPlot,
+    ShadedPlot, TextFlags, get_plot_limits, plot_to_pixels,
+};
+
+/// Runs one frame drawing `draw` in a 400x300 plot in an undecorated window at the
+/// origin. With `fit`, the axes are fitted to the plotted items from the next frame on.
+fn plot_frame(
+    ctx: &mut Context,
+    plot_ctx: &PlotContext,
+    fit: bool,
+    draw: impl FnOnce(&PlotUi<'_>),
+) {
+    let ui = ctx.frame();
+    ui.window("chart")
+        .position([0.0, 0.0], Condition::Always)
+        .size([500.0, 400.0], Condition::Always)
+        .flags(WindowFlags::NO_DECORATION | WindowFlags::NO_MOVE)
+        .build(|| {
+            let plot_ui = plot_ctx.get_plot_ui(ui);
+            if fit {
+                plot_ui.set_next_axes_to_fit();
+            }
+            if let Some(_token) = plot_ui.begin_plot_with_size("##plot", [400.0, 300.0]) {
+                draw(&plot_ui);
+            }
+        });
+    ctx.render();
+}
+
+/// Draws `draw` in a plot fitted to its items, returning the fitted axis limits
+fn fitted_limits(draw: impl Fn(&PlotUi<'_>)) -> ImPlotRect {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let plot_ctx = PlotContext::create(&ctx);
+    plot_frame(&mut ctx, &plot_ctx, true, &draw);
+    let mut limits = None;
+    plot_frame(&mut ctx, &plot_ctx, false, |plot_ui| {
+        draw(plot_ui);
+        limits = Some(get_plot_limits(None, None));
+    });
+    drop(plot_ctx);
+    limits.expect("plot was drawn")
+}
+
+fn assert_range(range: ImPlotRange, min: f64, max: f64) {
+    assert!(
+        (range.Min - min).abs() < 1e-6 && (range.Max - max).abs() < 1e-6,
+        "expected [{min}, {max}], got [{}, {}]",
+        range.Min,
+        range.Max
+    );
+}
+
+#[test]
+fn bars_with_a_base_start_from_it() {
+    let values = [3.0, 5.0];
+    let limits = fitted_limits(|_| BarPlot::new("bars", &values).with_base(1.0).plot());
+    assert_range(limits.X, -0.335, 1.335);
+    // Plain bars would fit down to 0
+    assert_range(limits.Y, 1.0, 5.0);
+}
+
+#[test]
+fn horizontal_floating_bars_span_along_x() {
+    let positions = [0.0, 2.0];
+    let lows = [10.0, 20.0];
+    let highs = [15.0, 30.0];
+    let limits = fitted_limits(|_| {
+        FloatingBarPlot::new("gantt", &positions, &lows, &highs)
+            .with_bar_size(0.5)
+            .horizontal(true)
+            .plot()
+    });
+    assert_range(limits.X, 10.0, 30.0);
+    assert_range(limits.Y, -0.25, 2.25);
+}
+
+#[test]
+fn gantt_fits_its_rows_and_reports_the_hovered_bar() {
+    let _guard = test_guard();
+    let mut ctx = headless_context();
+    let plot_ctx = PlotContext::create(&ctx);
+    let build = [GanttInterval::new(0.0, 2.0), GanttInterval::new(3.0, 5.0)];
+    let test = [GanttInterval::new(1.5, 4.0)];
+    let rows = [GanttRow::new("build", &build), GanttRow::new("test", &test)];
+
+    let mut limits = None;
+    let mut target = [0.0; 2];
+    plot_frame(&mut ctx, &plot_ctx, false, |plot_ui| {
+        assert_eq!(plot_ui.gantt(&rows), None);
+        limits = Some(get_plot_limits(None, None));
+        // Middle of the second "build" bar, on the top row
+        target = plot_to_pixels(ImPlotPoint { x: 4.0, y: 1.0 }, None);
+    });
+    let limits = limits.expect("plot was drawn");
+    assert_range(limits.X, 0.0, 5.0);
+    assert_range(limits.Y, -0.5, 1.5);
+
+    ctx.io_mut().add_mouse_pos_event(target);
+    let mut hovered = None;
+    for _ in 0..2 {
+        plot_frame(&mut ctx, &plot_ctx, false, |plot_ui| {
+            hovered = plot_ui.gantt(&rows);
+        });
+    }
+    drop(plot_ctx);
+    assert_eq!(hovered, Some((0, 1)));
+}
+
+#[test]
+fn stems_fit_down_to_their_reference_value() {
+    let limits = fitted_limits(|plot_ui| {
+        plot_ui
+            .stem_plot_with_ref("stems", &[0.0, 1.0], &[1.0, 3.0], -2.0)
+            .expect("valid stems");
+        assert!(
+            plot_ui
+                .simple_stem_plot_with_ref("nan", &[1.0], f64::NAN)
+                .is_err()
+        );
+    });
+    assert_range(limits.X, 0.0, 1.0);
+    assert_range(limits.Y, -2.0, 3.0);
+}
+
+#[test]
+fn digital_signals_fit_along_x_only() {
+    let limits = fitted_limits(|plot_ui| {
+        plot_ui.plot_line("line", &[0.0, 1.0], &[10.0, 20.0]);
+        plot_ui
+            .digital_plot("signal", &[2.0, 4.0, 6.0], &[0.0, 1.0, 0.0])
+            .expect("valid signal");
+        assert!(plot_ui.digital_plot("short", &[0.0, 1.0], &[1.0]).is_err());
+    });
+    assert_range(limits.X, 0.0, 6.0);
+    assert_range(limits.Y, 10.0, 20.0);
+}
+
+#[test]
+fn bar_groups_fit_each_layout() {
+    let values = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let limits = fitted_limits(|plot_ui| {
+        plot_ui
+            .bar_groups_plot(&["a", "b"], &values, 3)
+            .expect("two rows of three groups");
+        assert!(plot_ui.bar_groups_plot(&["a"], &values, 3).is_err());
+    });
+    assert_range(limits.X, -0.335, 2.335);
+    assert_range(limits.Y, 0.0, 6.0);
+
+    let limits = fitted_limits(|_| {
+        BarGroupsPlot::new(vec!["a", "b"], &values, 2, 3)
+            .stacked()
+            .horizontal()
+            .plot()
+    });
+    // The largest stack is 3 + 6
+    assert_range(limits.X, 0.0, 9.0);
+    assert_range(limits.Y, -0.335, 2.335);
+}
+
+#[test]
+fn horizontal_bars_run_along_x() {
+    let limits = fitted_limits(|_| BarPlot::new("bars", &[2.0, 4.0]).horizontal(true).plot());
+    assert_range(limits.X, 0.0, 4.0);
+    assert_range(limits.Y, -0.335, 1.335);
+}
+
+#[test]
+fn heatmaps_fit_their_bounds() {
+    let values = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    let limits = fitted_limits(|plot_ui| {
+        plot_ui
+            .plot_heatmap(
+                &HeatmapPlot::new("heat", &values, 2, 3)
+                    .with_scale(f64::NAN, f64::NAN)
+                    .with_label_format(None)
+                    .with_bounds(2.0, 3.0, 6.0, 9.0),
+            )
+            .expect("a 2x3 grid");
+        assert!(
+            plot_ui
+                .plot_heatmap(&HeatmapPlot::new("short", &values[..5], 2, 3))
+                .is_err()
+        );
+    });
+    assert_range(limits.X, 2.0, 6.0);
+    assert_range(limits.Y, 3.0, 9.0);
+}
+
+#[test]
+fn inf_lines_fit_the_axis_they_cross() {
+    let limits = fitted_limits(|plot_ui| {
+        plot_ui
+            .inf_lines(&InfLinesPlot::new("events", &[-3.0, 7.0]))
+            .expect("vertical lines");
+        plot_ui
+            .inf_lines(&InfLinesPlot::new("limits", &[2.0, 5.0]).horizontal(true))
+            .expect("horizontal lines");
+        assert!(plot_ui.inf_lines_vertical("none", &[]).is_err());
+    });
+    assert_range(limits.X, -3.0, 7.0);
+    assert_range(limits.Y, 2.0, 5.0);
+}
+
+#[test]
+fn shaded_areas_fit_their_reference_and_stride() {
+    // With a 16-byte stride only every other value is read
+    let xs = [10.0, 100.0, 12.0, 100.0];
+    let lower = [5.0, -100.0, 6.0, -100.0];
+    let upper = [8.0, 100.0, 9.0, 100.0];
+    let limits = fitted_limits(|plot_ui| {
+        plot_ui
+            .shaded(&ShadedPlot::new("area", &[0.0, 2.0], &[1.0, 3.0]).with_y_ref(-1.0))
+            .expect("valid area");
+        plot_ui
+            .shaded_between(&ShadedBetweenPlot::new("band", &xs, &lower, &upper).with_stride(16))
+            .expect("valid band");
+        assert!(
+            plot_ui
+                .shaded_between(
+                    &ShadedBetweenPlot::new("misaligned", &xs, &lower, &upper).with_stride(12)
+                )
+                .is_err()
+        );
+    });
+    assert_range(limits.X, 0.0, 12.0);
+    assert_range(limits.Y, -1.0, 9.0);
+}
+
+#[test]
+fn error_bars_fit_along_their_direction() {
+    let limits = fitted_limits(|plot_ui| {
+        plot_ui
+            .error_bars(&ErrorBarsPlot::new(
+                "v",
+                &[0.0, 2.0],
+                &[5.0, 6.0],
+                &[1.0, 2.0],
+            ))
+            .expect("valid error bars");
+        assert!(
+            plot_ui
+                .error_bars(&ErrorBarsPlot::new(
+                    "short",
+                    &[0.0, 2.0],
+                    &[5.0, 6.0],
+                    &[1.0]
+                ))
+                .is_err()
+        );
+    });
+    assert_range(limits.X, 0.0, 2.0);
+    assert_range(limits.Y, 4.0, 8.0);
+
+    let limits = fitted_limits(|plot_ui| {
+        plot_ui
+            .error_bars(
+                &ErrorBarsPlot::new("h", &[10.0, 20.0], &[1.0, 3.0], &[1.0, 2.0]).horizontal(),
+            )
+            .expect("valid error bars");
+    });
+    assert_range(limits.X, 9.0, 22.0);
+    assert_range(limits.Y, 1.0, 3.0);
+}
+
+#[test]
+fn stairs_fit_their_steps() {
+    let limits = fitted_limits(|plot_ui| {
+        plot_ui
+            .stairs_plot("steps", &[1.0, 2.0, 4.0], &[3.0, -1.0, 2.0])
+            .expect("valid steps");
+        plot_ui
+            .simple_stairs_plot("indexed", &[0.0, 5.0])
+            .expect("valid steps");
+        assert!(plot_ui.stairs_plot("short", &[1.0, 2.0], &[3.0]).is_err());
+        assert!(plot_ui.simple_stairs_plot("empty", &[]).is_err());
+    });
+    assert_range(limits.X, 0.0, 4.0);
+    assert_range(limits.Y, -1.0, 5.0);
+}
+
+#[test]
+fn pie_charts_fit_their_circle() {
+    let labels = ["a", "b", "c"];
+    let limits = fitted_limits(|plot_ui| {
+        plot_ui
+            .pie_chart(&PieChartPlot::new(
+                &labels[..],
+                &[1.0, 2.0, 3.0],
+                2.0,
+                3.0,
+                1.0,
+            ))
+            .expect("valid pie");
+        assert!(
+            plot_ui
+                .pie_chart_plot(&labels[..], &[1.0, f64::INFINITY, 3.0], 0.0, 0.0, 1.0)
+                .is_err()
+        );
+    });
+    assert_range(limits.X, 1.0, 3.0);
+    assert_range(limits.Y, 2.0, 4.0);
+}
+
+#[test]
+fn text_fits_its_anchor_and_skips_nan() {
+    let limits = fitted_limits(|plot_ui| {
+        plot_ui.plot_line("line", &[0.0, 1.0], &[0.0, 1.0]);
+        plot_ui
+            .plot_text("peak", 5.0, -2.0, [0.0, 0.0], TextFlags::NONE)
+            .expect("valid text");
+        plot_ui
+            .plot_text("gap", f64::NAN, 100.0, [0.0, 0.0], TextFlags::NONE)
+            .expect("NaN coordinates are skipped, not rejected");
+        assert!(
+            plot_ui
+                .plot_text("", 0.0, 0.0, [0.0, 0.0], TextFlags::NONE)
+                .is_err()
+        );
+    });
+    assert_range(limits.X, 0.0, 5.0);
+    assert_range(limits.Y, -2.0, 1.0);
+}
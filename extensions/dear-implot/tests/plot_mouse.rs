@@ -0,0 +1,50 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{Condition, WindowFlags};
+use dear_implot::{PlotContext, get_plot_pos, get_plot_size};
+
+#[test]
+fn token_reports_hover_and_mouse_position_in_data_coordinates() {
+    let _guard = test_guard();
+
+    let mut ctx = headless_context();
+    let plot_ctx = PlotContext::create(&ctx);
+
+    let xs = [0.0, 10.0];
+    let ys = [0.0, 100.0];
+
+    let mut plot_rect = ([0.0; 2], [0.0; 2]);
+    let mut frames = Vec::new();
+    for frame in 0..3 {
+        if frame > 0 {
+            // A quarter of the way across and up the plot area seen on the previous frame
+            let (pos, size) = plot_rect;
+            ctx.io_mut()
+                .add_mouse_pos_event([pos[0] + size[0] * 0.25, pos[1] + size[1] * 0.75]);
+        }
+        {
+            let ui = ctx.frame();
+            ui.window("chart")
+                .position([0.0, 0.0], Condition::Always)
+                .size([500.0, 400.0], Condition::Always)
+                .flags(WindowFlags::NO_DECORATION | WindowFlags::NO_MOVE)
+                .build(|| {
+                    let plot_ui = plot_ctx.get_plot_ui(ui);
+                    if let Some(token) = plot_ui.begin_plot_with_size("##chart", [400.0, 300.0]) {
+                        plot_ui.plot_line("line", &xs, &ys);
+                        frames.push((token.is_hovered(), token.mouse_pos(), token.limits()));
+                        plot_rect = (get_plot_pos(), get_plot_size());
+                    }
+                });
+        }
+        ctx.render();
+    }
+    drop(plot_ctx);
+
+    assert!(!frames[0].0, "not hovered before the mouse moves");
+    let (hovered, mouse, limits) = frames.last().copied().unwrap();
+    assert!(hovered);
+    let fx = (mouse.x - limits.X.Min) / (limits.X.Max - limits.X.Min);
+    let fy = (mouse.y - limits.Y.Min) / (limits.Y.Max - limits.Y.Min);
+    assert!((fx - 0.25).abs() < 0.02, "{fx}");
+    assert!((fy - 0.25).abs() < 0.02, "{fy}");
+}
@@ -0,0 +1,48 @@
+use dear_imgui_rs::Condition;
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_implot::{PixelRect, PlotContext, get_plot_pos, get_plot_size};
+
+#[test]
+fn plot_rect_pixels_applies_framebuffer_scale() {
+    let _guard = test_guard();
+
+    let mut ctx = headless_context();
+    ctx.io_mut().set_display_framebuffer_scale([2.0, 2.0]);
+    let plot_ctx = PlotContext::create(&ctx);
+
+    let mut logical: PixelRect = ([0.0; 2], [0.0; 2]);
+    let mut plot_px = None;
+    let mut frame_px = None;
+    let mut outside = Some(([0.0; 2], [0.0; 2]));
+    {
+        let ui = ctx.frame();
+        ui.window("chart")
+            .position([10.0, 20.0], Condition::Always)
+            .size([500.0, 400.0], Condition::Always)
+            .build(|| {
+                let plot_ui = plot_ctx.get_plot_ui(ui);
+                outside = plot_ui.plot_rect_pixels();
+                if let Some(_token) = plot_ui.begin_plot_with_size("##chart", [400.0, 300.0]) {
+                    // Query before any item so the rect must come from locking setup
+                    plot_px = plot_ui.plot_rect_pixels();
+                    frame_px = plot_ui.plot_frame_rect_pixels();
+                    plot_ui.plot_line("line", &[0.0, 1.0], &[0.0, 1.0]);
+                    let pos = get_plot_pos();
+                    let size = get_plot_size();
+                    logical = (pos, [pos[0] + size[0], pos[1] + size[1]]);
+                }
+            });
+    }
+    ctx.render();
+    drop(plot_ctx);
+
+    assert_eq!(outside, None);
+    let (min, max) = plot_px.expect("plot rect inside begin_plot");
+    for i in 0..2 {
+        assert_eq!(min[i], (logical.0[i] * 2.0).floor());
+        assert_eq!(max[i], (logical.1[i] * 2.0).ceil());
+    }
+    let (frame_min, frame_max) = frame_px.expect("frame rect inside begin_plot");
+    assert!(frame_min[0] <= min[0] && frame_min[1] <= min[1]);
+    assert!(frame_max[0] >= max[0] && frame_max[1] >= max[1]);
+}
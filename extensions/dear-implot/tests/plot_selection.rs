@@ -0,0 +1,63 @@
+use dear_imgui_rs::test_harness::{headless_context, test_guard};
+use dear_imgui_rs::{Condition, MouseButton, WindowFlags};
+use dear_implot::{PlotCond, PlotContext, get_plot_pos, get_plot_size};
+
+#[test]
+fn box_selection_is_read_back_and_cancelled() {
+    let _guard = test_guard();
+
+    let mut ctx = headless_context();
+    let plot_ctx = PlotContext::create(&ctx);
+
+    let mut plot_rect = ([0.0; 2], [0.0; 2]);
+    let mut selections = Vec::new();
+    for frame in 0..7 {
+        let (pos, size) = plot_rect;
+        let center = [pos[0] + size[0] * 0.5, pos[1] + size[1] * 0.5];
+        match frame {
+            1 => ctx.io_mut().add_mouse_pos_event(center),
+            // ImPlot box-selects with the right mouse button by default
+            2 => ctx
+                .io_mut()
+                .add_mouse_button_event(MouseButton::Right, true),
+            // Drag a quarter of the plot towards the bottom right
+            3 => ctx
+                .io_mut()
+                .add_mouse_pos_event([center[0] + size[0] * 0.25, center[1] + size[1] * 0.25]),
+            _ => {}
+        }
+        {
+            let ui = ctx.frame();
+            ui.window("editor")
+                .position([0.0, 0.0], Condition::Always)
+                .size([500.0, 400.0], Condition::Always)
+                .flags(WindowFlags::NO_DECORATION | WindowFlags::NO_MOVE)
+                .build(|| {
+                    let plot_ui = plot_ctx.get_plot_ui(ui);
+                    if let Some(token) = plot_ui.begin_plot_with_size("##range", [400.0, 300.0]) {
+                        plot_ui.setup_axes_limits(0.0, 1.0, 0.0, 1.0, PlotCond::Always);
+                        plot_ui.plot_line("line", &[0.0, 1.0], &[0.0, 1.0]);
+                        selections.push(token.selection());
+                        if frame == 5 {
+                            token.cancel_selection();
+                        }
+                        plot_rect = (get_plot_pos(), get_plot_size());
+                    }
+                });
+        }
+        ctx.render();
+    }
+    drop(plot_ctx);
+
+    assert!(
+        selections[..3].iter().all(Option::is_none),
+        "{selections:?}"
+    );
+    let rect = selections[4].expect("selection while dragging");
+    assert!((rect.X.Min - 0.5).abs() < 0.02, "{rect:?}");
+    assert!((rect.X.Max - 0.75).abs() < 0.02, "{rect:?}");
+    assert!((rect.Y.Min - 0.25).abs() < 0.02, "{rect:?}");
+    assert!((rect.Y.Max - 0.5).abs() < 0.02, "{rect:?}");
+    // Cancelled while the button is still held
+    assert_eq!(selections[6], None);
+}
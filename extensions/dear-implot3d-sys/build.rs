@@ -185,10 +185,13 @@ fn try_download_prebuilt(
     target_env: &str,
 ) -> Result<PathBuf, String> {
     let lib_name = expected_lib_name(target_env);
-    build_support::download_prebuilt(cache_root, url, lib_name, target_env)
+    let timeout = build_support::prebuilt_timeout_from_env("IMPLOT3D_SYS_PREBUILT_TIMEOUT_SECS");
+    build_support::download_prebuilt_with_timeout(cache_root, url, lib_name, target_env, timeout)
 }
 
-fn try_download_prebuilt_from_release(cfg: &BuildConfig) -> Option<PathBuf> {
+fn try_download_prebuilt_from_release(
+    cfg: &BuildConfig,
+) -> Option<(PathBuf, build_support::LibProvenance)> {
     if build_support::is_offline() {
         return None;
     }
@@ -229,7 +232,12 @@ fn try_download_prebuilt_from_release(cfg: &BuildConfig) -> Option<PathBuf> {
                     &cache_root,
                     expected_lib_name(&cfg.target_env),
                 ) {
-                    return Some(lib_dir);
+                    return Some((
+                        lib_dir,
+                        build_support::LibProvenance::PrebuiltLocal {
+                            origin: archive_path.display().to_string(),
+                        },
+                    ));
                 }
             }
         }
@@ -239,29 +247,40 @@ fn try_download_prebuilt_from_release(cfg: &BuildConfig) -> Option<PathBuf> {
     let urls = build_support::release_candidate_urls_env(&tags, &names);
     for url in urls {
         if let Ok(lib_dir) = try_download_prebuilt(&cache_root, &url, &cfg.target_env) {
-            return Some(lib_dir);
+            return Some((
+                lib_dir,
+                build_support::LibProvenance::PrebuiltDownloaded { origin: url },
+            ));
         }
     }
     None
 }
 
-fn try_link_prebuilt_all(cfg: &BuildConfig) -> bool {
+fn try_link_prebuilt_all(cfg: &BuildConfig) -> Option<build_support::LibProvenance> {
     let target_env = &cfg.target_env;
     if let Ok(dir) = env::var("IMPLOT3D_SYS_LIB_DIR") {
-        if try_link_prebuilt(PathBuf::from(dir.clone()), target_env) {
-            return true;
+        if try_link_prebuilt(PathBuf::from(&dir), target_env) {
+            return Some(build_support::LibProvenance::PrebuiltLocal { origin: dir });
         }
         println!(
             "cargo:warning=IMPLOT3D_SYS_LIB_DIR set but library not found in {}",
             dir
         );
     }
+    if env::var_os("IMPLOT3D_SYS_PREBUILT_URL").is_none()
+        && let Some(dir) = build_support::shared_prebuilt_lib_dir(expected_lib_name(target_env))
+        && try_link_prebuilt(dir.clone(), target_env)
+    {
+        return Some(build_support::LibProvenance::PrebuiltLocal {
+            origin: dir.display().to_string(),
+        });
+    }
     if let Ok(url) = env::var("IMPLOT3D_SYS_PREBUILT_URL") {
         let cache_root = prebuilt_cache_root(cfg);
         if let Ok(dir) = try_download_prebuilt(&cache_root, &url, target_env)
             && try_link_prebuilt(dir.clone(), target_env)
         {
-            return true;
+            return Some(build_support::LibProvenance::PrebuiltDownloaded { origin: url });
         }
     } else {
         let allow_feature = cfg!(feature = "prebuilt");
@@ -282,17 +301,22 @@ fn try_link_prebuilt_all(cfg: &BuildConfig) -> bool {
                 "cargo:warning=auto-prebuilt enabled (dear-implot3d-sys): source={}, repo={}/{}",
                 source, owner, repo
             );
-            if let Some(dir) = try_download_prebuilt_from_release(cfg)
-                && try_link_prebuilt(dir.clone(), target_env)
+            if let Some((dir, provenance)) = try_download_prebuilt_from_release(cfg)
+                && try_link_prebuilt(dir, target_env)
             {
-                return true;
+                return Some(provenance);
             }
         }
     }
-    false
+    None
 }
 
-fn build_with_cc(cfg: &BuildConfig, cimplot3d_root: &Path, imgui_src: &Path, cimgui_root: &Path) {
+fn build_with_cc(
+    cfg: &BuildConfig,
+    cimplot3d_root: &Path,
+    imgui_src: &Path,
+    cimgui_root: &Path,
+) -> usize {
     let mut build = cc::Build::new();
     if cfg.target_arch == "wasm32" {
         build.define("IMGUI_DISABLE_DEFAULT_SHELL_FUNCTIONS", "1");
@@ -337,7 +361,9 @@ fn build_with_cc(cfg: &BuildConfig, cimplot3d_root: &Path, imgui_src: &Path, cim
     build.file(cimplot3d_root.join("implot3d/implot3d_meshes.cpp"));
     build.file(cimplot3d_root.join("implot3d/implot3d_demo.cpp"));
 
+    let files = build.get_files().count();
     build.compile("dear_implot3d");
+    files
 }
 
 fn docsrs_build(cfg: &BuildConfig, cimplot3d_root: &Path, imgui_src: &Path, cimgui_root: &Path) {
@@ -370,8 +396,10 @@ fn main() {
     println!("cargo:rerun-if-changed=third-party/cimplot3d/implot3d/implot3d_demo.cpp");
     println!("cargo:rerun-if-changed=../../dear-imgui-sys");
     println!("cargo:rerun-if-env-changed=IMPLOT3D_SYS_LIB_DIR");
+    println!("cargo:rerun-if-env-changed=DEAR_IMGUI_PREBUILT_DIR");
     println!("cargo:rerun-if-env-changed=IMPLOT3D_SYS_SKIP_CC");
     println!("cargo:rerun-if-env-changed=IMPLOT3D_SYS_PREBUILT_URL");
+    println!("cargo:rerun-if-env-changed=IMPLOT3D_SYS_PREBUILT_TIMEOUT_SECS");
     println!("cargo:rerun-if-env-changed=IMPLOT3D_SYS_FORCE_BUILD");
 
     let (imgui_src, cimgui_root) = resolve_imgui_includes(&cfg);
@@ -396,14 +424,22 @@ fn main() {
 
     let force_build =
         cfg!(feature = "build-from-source") || env::var("IMPLOT3D_SYS_FORCE_BUILD").is_ok();
-    let linked_prebuilt = if force_build {
-        false
+    let mut provenance = if force_build {
+        None
     } else {
         try_link_prebuilt_all(&cfg)
     };
+    let linked_prebuilt = provenance.is_some();
     if !cfg.docs_rs && !linked_prebuilt && env::var("IMPLOT3D_SYS_SKIP_CC").is_err() {
-        build_with_cc(&cfg, &cimplot3d_root, &imgui_src, &cimgui_root);
+        let files = build_with_cc(&cfg, &cimplot3d_root, &imgui_src, &cimgui_root);
+        provenance = Some(build_support::LibProvenance::Source {
+            tool: "cc",
+            files: Some(files),
+        });
     } else if cfg.docs_rs {
         docsrs_build(&cfg, &cimplot3d_root, &imgui_src, &cimgui_root);
     }
+    if let Some(provenance) = &provenance {
+        build_support::report_provenance(&cfg.out_dir, "dear_implot3d", provenance);
+    }
 }
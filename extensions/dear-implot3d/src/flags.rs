@@ -143,6 +143,58 @@ pub enum Marker3D {
     Plus = sys::ImPlot3DMarker_Plus,
     Asterisk = sys::ImPlot3DMarker_Asterisk,
 }
+/// Built-in colormaps
+///
+/// Discriminants match `dear_implot::Colormap`, so the same colormap index selects the
+/// same palette in 2D and 3D plots. With the `implot` feature, the 2D enum converts into
+/// this one.
+#[repr(i32)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[allow(clippy::unnecessary_cast)] // Cast is necessary on some platforms where bindgen generates u32
+pub enum Colormap {
+    Deep = sys::ImPlot3DColormap_Deep as i32,
+    Dark = sys::ImPlot3DColormap_Dark as i32,
+    Pastel = sys::ImPlot3DColormap_Pastel as i32,
+    Paired = sys::ImPlot3DColormap_Paired as i32,
+    Viridis = sys::ImPlot3DColormap_Viridis as i32,
+    Plasma = sys::ImPlot3DColormap_Plasma as i32,
+    Hot = sys::ImPlot3DColormap_Hot as i32,
+    Cool = sys::ImPlot3DColormap_Cool as i32,
+    Pink = sys::ImPlot3DColormap_Pink as i32,
+    Jet = sys::ImPlot3DColormap_Jet as i32,
+    Twilight = sys::ImPlot3DColormap_Twilight as i32,
+    RdBu = sys::ImPlot3DColormap_RdBu as i32,
+    BrBG = sys::ImPlot3DColormap_BrBG as i32,
+    PiYG = sys::ImPlot3DColormap_PiYG as i32,
+    Spectral = sys::ImPlot3DColormap_Spectral as i32,
+    Greys = sys::ImPlot3DColormap_Greys as i32,
+}
+
+#[cfg(feature = "implot")]
+impl From<dear_implot::Colormap> for Colormap {
+    fn from(cmap: dear_implot::Colormap) -> Self {
+        use dear_implot::Colormap as C2;
+        match cmap {
+            C2::Deep => Colormap::Deep,
+            C2::Dark => Colormap::Dark,
+            C2::Pastel => Colormap::Pastel,
+            C2::Paired => Colormap::Paired,
+            C2::Viridis => Colormap::Viridis,
+            C2::Plasma => Colormap::Plasma,
+            C2::Hot => Colormap::Hot,
+            C2::Cool => Colormap::Cool,
+            C2::Pink => Colormap::Pink,
+            C2::Jet => Colormap::Jet,
+            C2::Twilight => Colormap::Twilight,
+            C2::RdBu => Colormap::RdBu,
+            C2::BrBG => Colormap::BrBG,
+            C2::PiYG => Colormap::PiYG,
+            C2::Spectral => Colormap::Spectral,
+            C2::Greys => Colormap::Greys,
+        }
+    }
+}
+
 /// 3D axis selector (X/Y/Z)
 #[repr(i32)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
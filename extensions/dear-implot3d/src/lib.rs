@@ -262,6 +262,103 @@ pub struct Plot3DUi<'ui> {
 /// `ImPlot3D_EndPlot()` when it goes out of scope, ensuring proper cleanup.
 pub struct Plot3DToken;
 
+/// Camera view of a 3D plot
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Plot3DView {
+    /// Elevation angle in degrees
+    pub elevation: f32,
+    /// Azimuth angle in degrees
+    pub azimuth: f32,
+    /// Relative viewing distance (1.0 = default framing)
+    pub distance: f32,
+}
+
+/// Rotates `v` by the unit quaternion `q`
+fn rotate_by_quat(q: &sys::ImPlot3DQuat, v: [f64; 3]) -> [f64; 3] {
+    let (qx, qy, qz, qw) = (q.x as f64, q.y as f64, q.z as f64, q.w as f64);
+    // v' = v + 2w(q × v) + 2 q × (q × v)
+    let t = [
+        2.0 * (qy * v[2] - qz * v[1]),
+        2.0 * (qz * v[0] - qx * v[2]),
+        2.0 * (qx * v[1] - qy * v[0]),
+    ];
+    [
+        v[0] + qw * t[0] + (qy * t[2] - qz * t[1]),
+        v[1] + qw * t[1] + (qz * t[0] - qx * t[2]),
+        v[2] + qw * t[2] + (qx * t[1] - qy * t[0]),
+    ]
+}
+
+/// Recovers `(elevation, azimuth)` in degrees from a plot rotation
+///
+/// ImPlot3D builds rotations as `X(elevation - 90°) * Z(azimuth)` and mouse rotation keeps
+/// that form, so the rotated Z axis yields the elevation and the X axis the azimuth.
+fn elevation_azimuth(q: &sys::ImPlot3DQuat) -> (f32, f32) {
+    let z = rotate_by_quat(q, [0.0, 0.0, 1.0]);
+    let tilt = (-z[1]).atan2(z[2]);
+    let x = rotate_by_quat(q, [1.0, 0.0, 0.0]);
+    // Undo the X rotation, leaving (cos(azimuth), sin(azimuth), 0)
+    let (sin, cos) = tilt.sin_cos();
+    let azimuth = (x[1] * cos + x[2] * sin).atan2(x[0]);
+    (
+        (tilt + std::f64::consts::FRAC_PI_2).to_degrees() as f32,
+        azimuth.to_degrees() as f32,
+    )
+}
+
+impl Plot3DToken {
+    /// Set the camera view programmatically
+    ///
+    /// Must be called right after `build()`, before any plotting. ImPlot3D uses an
+    /// orthographic projection, so `distance` is applied as a uniform box scale of
+    /// `1.0 / distance`; values above 1.0 shrink the box, values below enlarge it.
+    /// Calling this every frame locks the view; calling it once lets the user rotate from there.
+    pub fn set_view(&self, elevation: f32, azimuth: f32, distance: f32) {
+        debug_before_setup();
+        let distance = if distance > 0.0 { distance } else { 1.0 };
+        let scale = 1.0 / distance;
+        unsafe {
+            sys::ImPlot3D_SetupBoxRotation_Float(
+                elevation,
+                azimuth,
+                false,
+                Plot3DCond::Always as i32,
+            );
+            sys::ImPlot3D_SetupBoxScale(scale, scale, scale);
+        }
+    }
+
+    /// Current camera view of this plot, including rotation done with the mouse
+    ///
+    /// Read from the plot state, so it reflects [`set_view`](Self::set_view) as well as
+    /// user interaction. Returns `None` if no plot is current.
+    pub fn view(&self) -> Option<Plot3DView> {
+        let plot = unsafe { sys::ImPlot3D_GetCurrentPlot() };
+        if plot.is_null() {
+            return None;
+        }
+        let plot = unsafe { &*plot };
+        let (elevation, azimuth) = elevation_azimuth(&plot.Rotation);
+        let scale = plot.BoxScale.x as f32;
+        let distance = if scale > 0.0 { 1.0 / scale } else { 1.0 };
+        Some(Plot3DView {
+            elevation,
+            azimuth,
+            distance,
+        })
+    }
+
+    /// Current elevation angle of this plot, in degrees
+    pub fn elevation(&self) -> Option<f32> {
+        self.view().map(|v| v.elevation)
+    }
+
+    /// Current azimuth angle of this plot, in degrees
+    pub fn azimuth(&self) -> Option<f32> {
+        self.view().map(|v| v.azimuth)
+    }
+}
+
 impl<'ui> Plot3DUi<'ui> {
     /// Builder to configure and begin a 3D plot
     ///
@@ -290,6 +387,20 @@ impl<'ui> Plot3DUi<'ui> {
         }
     }
 
+    /// Push a colormap for subsequent plot items
+    ///
+    /// Surfaces with an automatic fill color are shaded by height using this colormap,
+    /// over the domain given by their `scale(min, max)`. Pair with [`pop_colormap`](Self::pop_colormap).
+    /// With the `implot` feature, a `dear_implot::Colormap` can be passed as well.
+    pub fn push_colormap(&self, cmap: impl Into<Colormap>) {
+        unsafe { sys::ImPlot3D_PushColormap_Plot3DColormap(cmap.into() as i32) }
+    }
+
+    /// Pop the colormap pushed by [`push_colormap`](Self::push_colormap)
+    pub fn pop_colormap(&self) {
+        unsafe { sys::ImPlot3D_PopColormap(1) }
+    }
+
     /// Convenience: plot a simple 3D line (f32)
     ///
     /// This is a quick way to plot a line without using the builder pattern.
@@ -345,6 +456,9 @@ impl<'ui> Plot3DUi<'ui> {
     }
 
     /// Raw line plot (f32) with offset/stride
+    ///
+    /// `stride` is in bytes (`0` for tightly packed data) and must be a multiple of
+    /// `size_of::<f32>()`; nothing is plotted otherwise.
     pub fn plot_line_f32_raw<S: AsRef<str>>(
         &self,
         label: S,
@@ -362,6 +476,10 @@ impl<'ui> Plot3DUi<'ui> {
             Ok(s) => s,
             Err(_) => return,
         };
+        let count = match plots::strided_count::<f32>(xs.len(), stride) {
+            Ok(count) => count,
+            Err(_) => return,
+        };
         let stride_bytes = if stride == 0 {
             std::mem::size_of::<f32>() as i32
         } else {
@@ -373,7 +491,7 @@ impl<'ui> Plot3DUi<'ui> {
                 xs.as_ptr(),
                 ys.as_ptr(),
                 zs.as_ptr(),
-                xs.len() as i32,
+                count,
                 flags.bits() as i32,
                 offset,
                 stride_bytes,
@@ -413,6 +531,9 @@ impl<'ui> Plot3DUi<'ui> {
     }
 
     /// Raw line plot (f64) with offset/stride
+    ///
+    /// `stride` is in bytes (`0` for tightly packed data) and must be a multiple of
+    /// `size_of::<f64>()`; nothing is plotted otherwise.
     pub fn plot_line_f64_raw<S: AsRef<str>>(
         &self,
         label: S,
@@ -430,6 +551,10 @@ impl<'ui> Plot3DUi<'ui> {
             Ok(s) => s,
             Err(_) => return,
         };
+        let count = match plots::strided_count::<f64>(xs.len(), stride) {
+            Ok(count) => count,
+            Err(_) => return,
+        };
         let stride_bytes = if stride == 0 {
             std::mem::size_of::<f64>() as i32
         } else {
@@ -441,7 +566,7 @@ impl<'ui> Plot3DUi<'ui> {
                 xs.as_ptr(),
                 ys.as_ptr(),
                 zs.as_ptr(),
-                xs.len() as i32,
+                count,
                 flags.bits() as i32,
                 offset,
                 stride_bytes,
@@ -481,6 +606,9 @@ impl<'ui> Plot3DUi<'ui> {
     }
 
     /// Raw scatter plot (f32) with offset/stride
+    ///
+    /// `stride` is in bytes (`0` for tightly packed data) and must be a multiple of
+    /// `size_of::<f32>()`; nothing is plotted otherwise.
     pub fn plot_scatter_f32_raw<S: AsRef<str>>(
         &self,
         label: S,
@@ -498,6 +626,10 @@ impl<'ui> Plot3DUi<'ui> {
             Ok(s) => s,
             Err(_) => return,
         };
+        let count = match plots::strided_count::<f32>(xs.len(), stride) {
+            Ok(count) => count,
+            Err(_) => return,
+        };
         let stride_bytes = if stride == 0 {
             std::mem::size_of::<f32>() as i32
         } else {
@@ -509,7 +641,7 @@ impl<'ui> Plot3DUi<'ui> {
                 xs.as_ptr(),
                 ys.as_ptr(),
                 zs.as_ptr(),
-                xs.len() as i32,
+                count,
                 flags.bits() as i32,
                 offset,
                 stride_bytes,
@@ -549,6 +681,9 @@ impl<'ui> Plot3DUi<'ui> {
     }
 
     /// Raw scatter plot (f64) with offset/stride
+    ///
+    /// `stride` is in bytes (`0` for tightly packed data) and must be a multiple of
+    /// `size_of::<f64>()`; nothing is plotted otherwise.
     pub fn plot_scatter_f64_raw<S: AsRef<str>>(
         &self,
         label: S,
@@ -566,6 +701,10 @@ impl<'ui> Plot3DUi<'ui> {
             Ok(s) => s,
             Err(_) => return,
         };
+        let count = match plots::strided_count::<f64>(xs.len(), stride) {
+            Ok(count) => count,
+            Err(_) => return,
+        };
         let stride_bytes = if stride == 0 {
             std::mem::size_of::<f64>() as i32
         } else {
@@ -577,7 +716,7 @@ impl<'ui> Plot3DUi<'ui> {
                 xs.as_ptr(),
                 ys.as_ptr(),
                 zs.as_ptr(),
-                xs.len() as i32,
+                count,
                 flags.bits() as i32,
                 offset,
                 stride_bytes,
@@ -1007,6 +1146,9 @@ pub struct Surface3DBuilder<'ui> {
 }
 
 impl<'ui> Surface3DBuilder<'ui> {
+    /// Colormap domain: heights at `min` and `max` map to the ends of the colormap
+    ///
+    /// Leave both at `0.0` (the default) to use the data's z range.
     pub fn scale(mut self, min: f64, max: f64) -> Self {
         self.scale_min = min;
         self.scale_max = max;
@@ -1017,30 +1159,47 @@ impl<'ui> Surface3DBuilder<'ui> {
         self
     }
     pub fn plot(self) {
-        let x_count = self.xs.len() as i32;
-        let y_count = self.ys.len() as i32;
-        let expected = (x_count as usize) * (y_count as usize);
-        if self.zs.len() != expected {
+        debug_before_plot();
+        let x_count = self.xs.len();
+        let y_count = self.ys.len();
+        if x_count.checked_mul(y_count) != Some(self.zs.len()) {
             return;
         }
+        let (xs_flat, ys_flat) = flatten_grid(self.xs, self.ys);
         unsafe {
             sys::ImPlot3D_PlotSurface_FloatPtr(
                 self.label.as_ptr(),
-                self.xs.as_ptr(),
-                self.ys.as_ptr(),
+                xs_flat.as_ptr(),
+                ys_flat.as_ptr(),
                 self.zs.as_ptr(),
-                x_count,
-                y_count,
+                x_count as i32,
+                y_count as i32,
                 self.scale_min,
                 self.scale_max,
                 self.flags.bits() as i32,
                 0,
-                0,
+                std::mem::size_of::<f32>() as i32,
             );
         }
     }
 }
 
+/// Expand per-axis grid coordinates into the per-vertex arrays expected by the C++ API
+///
+/// Vertices are laid out row-major (x varies fastest), matching the layout of `zs`.
+fn flatten_grid<T: Copy>(xs: &[T], ys: &[T]) -> (Vec<T>, Vec<T>) {
+    let count = xs.len() * ys.len();
+    let mut xs_flat = Vec::with_capacity(count);
+    let mut ys_flat = Vec::with_capacity(count);
+    for &y in ys {
+        for &x in xs {
+            xs_flat.push(x);
+            ys_flat.push(y);
+        }
+    }
+    (xs_flat, ys_flat)
+}
+
 impl<'ui> Plot3DUi<'ui> {
     /// Start a surface plot (f32)
     pub fn surface_f32<S: AsRef<str>>(
@@ -1058,13 +1217,16 @@ impl<'ui> Plot3DUi<'ui> {
             xs,
             ys,
             zs,
-            scale_min: f64::NAN,
-            scale_max: f64::NAN,
+            scale_min: 0.0,
+            scale_max: 0.0,
             flags: Surface3DFlags::NONE,
         }
     }
 
-    /// Raw surface plot (f32) with offset/stride
+    /// Raw surface plot (f32)
+    ///
+    /// `zs` holds `xs.len() * ys.len()` heights in row-major order (x varies fastest). The
+    /// per-vertex X/Y arrays are built internally, so all data must be tightly packed.
     pub fn surface_f32_raw<S: AsRef<str>>(
         &self,
         label: S,
@@ -1074,8 +1236,6 @@ impl<'ui> Plot3DUi<'ui> {
         scale_min: f64,
         scale_max: f64,
         flags: Surface3DFlags,
-        offset: i32,
-        stride: i32,
     ) {
         debug_before_plot();
         let x_count = xs.len();
@@ -1089,25 +1249,12 @@ impl<'ui> Plot3DUi<'ui> {
             return;
         }
 
-        // Flatten xs/ys to per-vertex arrays expected by the C++ API (length = x_count * y_count)
-        let mut xs_flat = Vec::with_capacity(expected);
-        let mut ys_flat = Vec::with_capacity(expected);
-        for yi in 0..y_count {
-            for xi in 0..x_count {
-                xs_flat.push(xs[xi]);
-                ys_flat.push(ys[yi]);
-            }
-        }
+        let (xs_flat, ys_flat) = flatten_grid(xs, ys);
 
         let label_c = match std::ffi::CString::new(label.as_ref()) {
             Ok(s) => s,
             Err(_) => return,
         };
-        let stride_bytes = if stride == 0 {
-            std::mem::size_of::<f32>() as i32
-        } else {
-            stride
-        };
         unsafe {
             sys::ImPlot3D_PlotSurface_FloatPtr(
                 label_c.as_ptr(),
@@ -1119,8 +1266,50 @@ impl<'ui> Plot3DUi<'ui> {
                 scale_min,
                 scale_max,
                 flags.bits() as i32,
-                offset,
-                stride_bytes,
+                0,
+                std::mem::size_of::<f32>() as i32,
+            );
+        }
+    }
+
+    /// Raw surface plot (f64)
+    ///
+    /// `zs` holds `xs.len() * ys.len()` heights in row-major order (x varies fastest).
+    pub fn surface_f64_raw<S: AsRef<str>>(
+        &self,
+        label: S,
+        xs: &[f64],
+        ys: &[f64],
+        zs: &[f64],
+        scale_min: f64,
+        scale_max: f64,
+        flags: Surface3DFlags,
+    ) {
+        debug_before_plot();
+        let x_count = xs.len();
+        let y_count = ys.len();
+        if x_count.checked_mul(y_count) != Some(zs.len()) {
+            return;
+        }
+        let (xs_flat, ys_flat) = flatten_grid(xs, ys);
+
+        let label_c = match std::ffi::CString::new(label.as_ref()) {
+            Ok(s) => s,
+            Err(_) => return,
+        };
+        unsafe {
+            sys::ImPlot3D_PlotSurface_doublePtr(
+                label_c.as_ptr(),
+                xs_flat.as_ptr(),
+                ys_flat.as_ptr(),
+                zs.as_ptr(),
+                x_count as i32,
+                y_count as i32,
+                scale_min,
+                scale_max,
+                flags.bits() as i32,
+                0,
+                std::mem::size_of::<f64>() as i32,
             );
         }
     }
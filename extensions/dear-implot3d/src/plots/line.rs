@@ -1,4 +1,4 @@
-use super::{Plot3D, Plot3DError, validate_lengths, validate_nonempty};
+use super::{Plot3D, Plot3DError, strided_count, validate_xyz};
 use crate::{Line3DFlags, Plot3DUi};
 
 pub struct Line3D<'a> {
@@ -63,15 +63,13 @@ impl<'a> Plot3D for Line3D<'a> {
     }
     fn try_plot(&self, ui: &Plot3DUi<'_>) -> Result<(), Plot3DError> {
         if let (Some(x), Some(y), Some(z)) = (self.xs_f32, self.ys_f32, self.zs_f32) {
-            validate_nonempty(x)?;
-            validate_lengths(x, y, "x/y")?;
-            validate_lengths(y, z, "y/z")?;
+            validate_xyz(x, y, z)?;
+            strided_count::<f32>(x.len(), self.stride)?;
             ui.plot_line_f32_raw(self.label, x, y, z, self.flags, self.offset, self.stride);
             Ok(())
         } else if let (Some(x), Some(y), Some(z)) = (self.xs_f64, self.ys_f64, self.zs_f64) {
-            validate_nonempty(x)?;
-            validate_lengths(x, y, "x/y")?;
-            validate_lengths(y, z, "y/z")?;
+            validate_xyz(x, y, z)?;
+            strided_count::<f64>(x.len(), self.stride)?;
             ui.plot_line_f64_raw(self.label, x, y, z, self.flags, self.offset, self.stride);
             Ok(())
         } else {
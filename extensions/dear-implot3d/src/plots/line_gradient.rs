@@ -0,0 +1,124 @@
+use super::{Plot3D, Plot3DError, validate_lengths, validate_nonempty};
+use crate::{Plot3DUi, debug_before_plot};
+use dear_imgui_sys as imgui_sys;
+
+/// Number of sub-segments used to approximate the color gradient of one segment
+const GRADIENT_SUBDIVISIONS: usize = 8;
+
+/// 3D polyline whose color interpolates between per-point colors
+///
+/// Colors are packed `u32` values in ImGui's `IM_COL32` layout (0xAABBGGRR), one per point.
+/// Each segment is drawn as a series of short lines on the plot draw list, so this is
+/// slower than the native single-color [`Line3D`](super::Line3D) and does not add a legend entry.
+pub struct LineGradient3D<'a> {
+    pub label: &'a str,
+    pub xs: &'a [f32],
+    pub ys: &'a [f32],
+    pub zs: &'a [f32],
+    pub colors: &'a [u32],
+    pub weight: f32,
+}
+
+impl<'a> LineGradient3D<'a> {
+    pub fn new(
+        label: &'a str,
+        xs: &'a [f32],
+        ys: &'a [f32],
+        zs: &'a [f32],
+        colors: &'a [u32],
+    ) -> Self {
+        Self {
+            label,
+            xs,
+            ys,
+            zs,
+            colors,
+            weight: 1.0,
+        }
+    }
+    /// Line thickness in pixels
+    pub fn weight(mut self, weight: f32) -> Self {
+        self.weight = weight;
+        self
+    }
+}
+
+/// Linearly interpolate two packed colors channel by channel
+fn lerp_color(a: u32, b: u32, t: f32) -> u32 {
+    let mut out = 0u32;
+    for shift in [0, 8, 16, 24] {
+        let ca = ((a >> shift) & 0xFF) as f32;
+        let cb = ((b >> shift) & 0xFF) as f32;
+        let c = (ca + (cb - ca) * t).round().clamp(0.0, 255.0) as u32;
+        out |= c << shift;
+    }
+    out
+}
+
+impl<'a> Plot3D for LineGradient3D<'a> {
+    fn label(&self) -> &str {
+        self.label
+    }
+    fn try_plot(&self, ui: &Plot3DUi<'_>) -> Result<(), Plot3DError> {
+        validate_nonempty(self.xs)?;
+        validate_lengths(self.xs, self.ys, "x/y")?;
+        validate_lengths(self.ys, self.zs, "y/z")?;
+        validate_lengths(self.xs, self.colors, "x/colors")?;
+        debug_before_plot();
+        let draw_list = ui.get_plot_draw_list();
+        if draw_list.is_null() {
+            return Ok(());
+        }
+        for i in 1..self.xs.len() {
+            let p0 = [self.xs[i - 1], self.ys[i - 1], self.zs[i - 1]];
+            let p1 = [self.xs[i], self.ys[i], self.zs[i]];
+            let (c0, c1) = (self.colors[i - 1], self.colors[i]);
+            let mut prev = ui.plot_to_pixels(p0);
+            for step in 1..=GRADIENT_SUBDIVISIONS {
+                let t = step as f32 / GRADIENT_SUBDIVISIONS as f32;
+                let p = [
+                    p0[0] + (p1[0] - p0[0]) * t,
+                    p0[1] + (p1[1] - p0[1]) * t,
+                    p0[2] + (p1[2] - p0[2]) * t,
+                ];
+                let next = ui.plot_to_pixels(p);
+                // Color the sub-segment by its midpoint along the segment
+                let col = lerp_color(c0, c1, t - 0.5 / GRADIENT_SUBDIVISIONS as f32);
+                unsafe {
+                    imgui_sys::ImDrawList_AddLine(
+                        draw_list,
+                        imgui_sys::ImVec2 {
+                            x: prev[0],
+                            y: prev[1],
+                        },
+                        imgui_sys::ImVec2 {
+                            x: next[0],
+                            y: next[1],
+                        },
+                        col,
+                        self.weight,
+                    );
+                }
+                prev = next;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<'ui> Plot3DUi<'ui> {
+    /// Plot a 3D polyline with per-point colors interpolated along each segment
+    ///
+    /// All four slices must share a length. This may be noticeably slower than
+    /// [`plot_line_f32`](Self::plot_line_f32) for large inputs.
+    pub fn line_plot_gradient<S: AsRef<str>>(
+        &self,
+        label: S,
+        xs: &[f32],
+        ys: &[f32],
+        zs: &[f32],
+        colors: &[u32],
+    ) -> Result<(), Plot3DError> {
+        LineGradient3D::new(label.as_ref(), xs, ys, zs, colors).try_plot(self)
+    }
+}
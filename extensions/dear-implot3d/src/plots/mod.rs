@@ -4,6 +4,7 @@
 
 pub mod image;
 pub mod line;
+pub mod line_gradient;
 pub mod mesh;
 pub mod quads;
 pub mod scatter;
@@ -12,6 +13,7 @@ pub mod triangles;
 
 pub use image::*;
 pub use line::*;
+pub use line_gradient::*;
 pub use mesh::*;
 pub use quads::*;
 pub use scatter::*;
@@ -38,6 +40,15 @@ pub enum Plot3DError {
         z_len: usize,
     },
     StringConversion(&'static str),
+    InvalidStride {
+        stride: i32,
+        elem_size: usize,
+    },
+    /// Offset/stride on data that must be tightly packed, such as surface grids
+    UnsupportedLayout {
+        offset: i32,
+        stride: i32,
+    },
 }
 
 impl std::fmt::Display for Plot3DError {
@@ -63,6 +74,16 @@ impl std::fmt::Display for Plot3DError {
                 z_len
             ),
             Plot3DError::StringConversion(what) => write!(f, "string conversion error: {}", what),
+            Plot3DError::InvalidStride { stride, elem_size } => write!(
+                f,
+                "stride {} is not a positive multiple of the element size {}",
+                stride, elem_size
+            ),
+            Plot3DError::UnsupportedLayout { offset, stride } => write!(
+                f,
+                "offset {} / stride {} not supported: data must be tightly packed",
+                offset, stride
+            ),
         }
     }
 }
@@ -100,6 +121,29 @@ pub fn validate_lengths<T, U>(a: &[T], b: &[U], what: &'static str) -> Result<()
     }
 }
 
+/// Validate that x/y/z coordinate slices are non-empty and of equal length
+#[inline]
+pub fn validate_xyz<T>(xs: &[T], ys: &[T], zs: &[T]) -> Result<(), Plot3DError> {
+    validate_nonempty(xs)?;
+    validate_lengths(xs, ys, "x/y")?;
+    validate_lengths(ys, zs, "y/z")
+}
+
+/// Number of points read from `len` elements of `T` spaced `stride` bytes apart
+///
+/// A stride of `0` means tightly packed. Other strides must be a positive multiple of
+/// `size_of::<T>()` so every point stays inside the slice.
+#[inline]
+pub fn strided_count<T>(len: usize, stride: i32) -> Result<i32, Plot3DError> {
+    let elem_size = std::mem::size_of::<T>();
+    let step = match stride {
+        0 => 1,
+        s if s > 0 && s as usize % elem_size == 0 => s as usize / elem_size,
+        _ => return Err(Plot3DError::InvalidStride { stride, elem_size }),
+    };
+    Ok(len.div_ceil(step) as i32)
+}
+
 #[inline]
 pub fn validate_multiple(len: usize, k: usize, what: &'static str) -> Result<(), Plot3DError> {
     if len % k != 0 {
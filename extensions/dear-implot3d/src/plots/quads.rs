@@ -147,3 +147,18 @@ impl<'a> Plot3D for Quads3D<'a> {
         Err(Plot3DError::EmptyData)
     }
 }
+
+impl<'ui> Plot3DUi<'ui> {
+    /// Plot quads where every 4 consecutive points form one face
+    ///
+    /// The slice length must be a multiple of 4. Use [`Quads3D`] to pass [`Quad3DFlags`].
+    pub fn quad_plot<S: AsRef<str>>(
+        &self,
+        label: S,
+        xs: &[f32],
+        ys: &[f32],
+        zs: &[f32],
+    ) -> Result<(), Plot3DError> {
+        Quads3D::f32(label.as_ref(), xs, ys, zs).try_plot(self)
+    }
+}
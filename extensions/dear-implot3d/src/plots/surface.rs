@@ -1,6 +1,10 @@
-use super::{Plot3D, Plot3DError};
+use super::{Plot3D, Plot3DError, validate_nonempty};
 use crate::{Plot3DUi, Surface3DFlags};
 
+/// Surface (grid) plot
+///
+/// When the fill color is automatic, vertices are colored by height using the
+/// active colormap (see [`Plot3DUi::push_colormap`]).
 pub struct Surface3D<'a> {
     pub label: &'a str,
     pub xs: &'a [f32],
@@ -20,13 +24,16 @@ impl<'a> Surface3D<'a> {
             xs,
             ys,
             zs,
-            scale_min: f64::NAN,
-            scale_max: f64::NAN,
+            scale_min: 0.0,
+            scale_max: 0.0,
             flags: Surface3DFlags::NONE,
             offset: 0,
             stride: 0,
         }
     }
+    /// Colormap domain: heights at `min` and `max` map to the ends of the colormap
+    ///
+    /// Leave both at `0.0` (the default) to use the data's z range.
     pub fn scale(mut self, min: f64, max: f64) -> Self {
         self.scale_min = min;
         self.scale_max = max;
@@ -36,10 +43,13 @@ impl<'a> Surface3D<'a> {
         self.flags = flags;
         self
     }
+    /// Only `0` is supported: the grid is expanded into tightly packed per-vertex arrays,
+    /// so any other value makes [`Plot3D::try_plot`] fail
     pub fn offset(mut self, o: i32) -> Self {
         self.offset = o;
         self
     }
+    /// Only `0` or `size_of::<f32>()` are supported, see [`offset`](Self::offset)
     pub fn stride(mut self, s: i32) -> Self {
         self.stride = s;
         self
@@ -51,6 +61,13 @@ impl<'a> Plot3D for Surface3D<'a> {
         self.label
     }
     fn try_plot(&self, ui: &Plot3DUi<'_>) -> Result<(), Plot3DError> {
+        let tight = std::mem::size_of::<f32>() as i32;
+        if self.offset != 0 || (self.stride != 0 && self.stride != tight) {
+            return Err(Plot3DError::UnsupportedLayout {
+                offset: self.offset,
+                stride: self.stride,
+            });
+        }
         let x_count = self.xs.len();
         let y_count = self.ys.len();
         let expected = x_count
@@ -75,8 +92,105 @@ impl<'a> Plot3D for Surface3D<'a> {
             self.scale_min,
             self.scale_max,
             self.flags,
-            self.offset,
-            self.stride,
+        );
+        Ok(())
+    }
+}
+
+/// Surface plot of a row-major height grid (f64)
+///
+/// `zs` holds `x_count * y_count` heights, one row of `x_count` values per y step. The grid
+/// spans `x_count` evenly spaced columns over the X bounds and `y_count` rows over the Y
+/// bounds, which default to the column and row indices. Use this for terrain or sampled
+/// `z = f(x, y)` functions where only the heights are stored.
+///
+/// Like [`Surface3D`], an automatic fill color shades vertices by height using the active
+/// colormap; [`scale`](Self::scale) pins which heights map to the colormap ends.
+pub struct HeightSurface3D<'a> {
+    pub label: &'a str,
+    pub zs: &'a [f64],
+    pub x_count: usize,
+    pub y_count: usize,
+    pub x_bounds: Option<(f64, f64)>,
+    pub y_bounds: Option<(f64, f64)>,
+    pub scale_min: f64,
+    pub scale_max: f64,
+    pub flags: Surface3DFlags,
+}
+
+impl<'a> HeightSurface3D<'a> {
+    pub fn new(label: &'a str, zs: &'a [f64], x_count: usize, y_count: usize) -> Self {
+        Self {
+            label,
+            zs,
+            x_count,
+            y_count,
+            x_bounds: None,
+            y_bounds: None,
+            scale_min: 0.0,
+            scale_max: 0.0,
+            flags: Surface3DFlags::NONE,
+        }
+    }
+    /// Place the first and last grid columns at `min` and `max` on the X axis
+    pub fn x_bounds(mut self, min: f64, max: f64) -> Self {
+        self.x_bounds = Some((min, max));
+        self
+    }
+    /// Place the first and last grid rows at `min` and `max` on the Y axis
+    pub fn y_bounds(mut self, min: f64, max: f64) -> Self {
+        self.y_bounds = Some((min, max));
+        self
+    }
+    /// Colormap domain: heights at `min` and `max` map to the ends of the colormap
+    ///
+    /// Leave both at `0.0` (the default) to use the data's z range.
+    pub fn scale(mut self, min: f64, max: f64) -> Self {
+        self.scale_min = min;
+        self.scale_max = max;
+        self
+    }
+    pub fn flags(mut self, flags: Surface3DFlags) -> Self {
+        self.flags = flags;
+        self
+    }
+}
+
+/// `count` evenly spaced values from `bounds.0` to `bounds.1`, or the indices `0..count`
+fn grid_axis(count: usize, bounds: Option<(f64, f64)>) -> Vec<f64> {
+    match bounds {
+        Some((min, max)) if count > 1 => {
+            let step = (max - min) / (count - 1) as f64;
+            (0..count).map(|i| min + step * i as f64).collect()
+        }
+        Some((min, _)) => vec![min; count],
+        None => (0..count).map(|i| i as f64).collect(),
+    }
+}
+
+impl<'a> Plot3D for HeightSurface3D<'a> {
+    fn label(&self) -> &str {
+        self.label
+    }
+    fn try_plot(&self, ui: &Plot3DUi<'_>) -> Result<(), Plot3DError> {
+        validate_nonempty(self.zs)?;
+        if self.x_count.checked_mul(self.y_count) != Some(self.zs.len()) {
+            return Err(Plot3DError::GridSizeMismatch {
+                x_count: self.x_count,
+                y_count: self.y_count,
+                z_len: self.zs.len(),
+            });
+        }
+        let xs = grid_axis(self.x_count, self.x_bounds);
+        let ys = grid_axis(self.y_count, self.y_bounds);
+        ui.surface_f64_raw(
+            self.label,
+            &xs,
+            &ys,
+            self.zs,
+            self.scale_min,
+            self.scale_max,
+            self.flags,
         );
         Ok(())
     }
@@ -147,3 +147,18 @@ impl<'a> Plot3D for Triangles3D<'a> {
         Err(Plot3DError::EmptyData)
     }
 }
+
+impl<'ui> Plot3DUi<'ui> {
+    /// Plot triangles where every 3 consecutive points form one face
+    ///
+    /// The slice length must be a multiple of 3. Use [`Triangles3D`] to pass [`Triangle3DFlags`].
+    pub fn triangle_plot<S: AsRef<str>>(
+        &self,
+        label: S,
+        xs: &[f32],
+        ys: &[f32],
+        zs: &[f32],
+    ) -> Result<(), Plot3DError> {
+        Triangles3D::f32(label.as_ref(), xs, ys, zs).try_plot(self)
+    }
+}
@@ -1,5 +1,6 @@
 use std::env;
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 pub fn parse_bool_env(key: &str) -> bool {
     match env::var(key) {
@@ -196,11 +197,48 @@ pub fn extract_archive_to_cache(
     Err("extracted archive did not contain expected library".into())
 }
 
+/// Default per-attempt timeout for prebuilt downloads
+pub const DEFAULT_DOWNLOAD_TIMEOUT_SECS: u64 = 300;
+/// Number of attempts made for each prebuilt download before giving up
+pub const DOWNLOAD_ATTEMPTS: u32 = 3;
+
+/// Read a download timeout (in seconds) from `env_var`, e.g. `IMGUI_SYS_PREBUILT_TIMEOUT_SECS`.
+///
+/// Falls back to [`DEFAULT_DOWNLOAD_TIMEOUT_SECS`] when unset or not a positive integer.
+pub fn prebuilt_timeout_from_env(env_var: &str) -> Duration {
+    let secs = env::var(env_var)
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .filter(|&v| v > 0)
+        .unwrap_or(DEFAULT_DOWNLOAD_TIMEOUT_SECS);
+    Duration::from_secs(secs)
+}
+
 pub fn download_prebuilt(
+    cache_root: &Path,
+    url: &str,
+    lib_name: &str,
+    target_env: &str,
+) -> Result<PathBuf, String> {
+    download_prebuilt_with_timeout(
+        cache_root,
+        url,
+        lib_name,
+        target_env,
+        Duration::from_secs(DEFAULT_DOWNLOAD_TIMEOUT_SECS),
+    )
+}
+
+/// Download a prebuilt archive or library, retrying transient failures with backoff.
+///
+/// `timeout` bounds each attempt. Data is written to a `.part` file and renamed once
+/// complete, so an interrupted download is never mistaken for a cached one.
+pub fn download_prebuilt_with_timeout(
     cache_root: &Path,
     url: &str,
     lib_name: &str,
     _target_env: &str,
+    timeout: Duration,
 ) -> Result<PathBuf, String> {
     let dl_dir = cache_root.join("download");
     let _ = std::fs::create_dir_all(&dl_dir);
@@ -209,20 +247,7 @@ pub fn download_prebuilt(
         let fname = url.split('/').next_back().unwrap_or("prebuilt.tar.gz");
         let archive_path = dl_dir.join(fname);
         if !archive_path.exists() {
-            let client = reqwest::blocking::Client::builder()
-                .timeout(std::time::Duration::from_secs(300))
-                .build()
-                .map_err(|e| format!("create http client: {}", e))?;
-            let resp = client
-                .get(url)
-                .send()
-                .map_err(|e| format!("http get: {}", e))?;
-            if !resp.status().is_success() {
-                return Err(format!("http status {}", resp.status()));
-            }
-            let bytes = resp.bytes().map_err(|e| format!("read body: {}", e))?;
-            std::fs::write(&archive_path, &bytes)
-                .map_err(|e| format!("write {}: {}", archive_path.display(), e))?;
+            fetch_to_file(url, &archive_path, timeout)?;
         }
         return extract_archive_to_cache(&archive_path, cache_root, lib_name);
     }
@@ -231,20 +256,132 @@ pub fn download_prebuilt(
     if dst.exists() {
         return Ok(dl_dir);
     }
+    fetch_to_file(url, &dst, timeout)?;
+    Ok(dl_dir)
+}
+
+fn fetch_to_file(url: &str, dst: &Path, timeout: Duration) -> Result<(), String> {
     let client = reqwest::blocking::Client::builder()
-        .timeout(std::time::Duration::from_secs(120))
+        .timeout(timeout)
+        .connect_timeout(timeout.min(Duration::from_secs(30)))
         .build()
-        .map_err(|e| format!("http client: {}", e))?;
+        .map_err(|e| format!("create http client: {}", e))?;
+    let mut attempt = 1;
+    loop {
+        match fetch_once(&client, url) {
+            Ok(bytes) => {
+                let mut part = dst.as_os_str().to_owned();
+                part.push(".part");
+                let part = PathBuf::from(part);
+                std::fs::write(&part, &bytes)
+                    .map_err(|e| format!("write {}: {}", part.display(), e))?;
+                return std::fs::rename(&part, dst)
+                    .map_err(|e| format!("rename to {}: {}", dst.display(), e));
+            }
+            Err((err, retryable)) => {
+                if !retryable || attempt >= DOWNLOAD_ATTEMPTS {
+                    return Err(err);
+                }
+                let backoff = Duration::from_secs(1 << attempt);
+                println!(
+                    "cargo:warning=prebuilt download attempt {}/{} failed ({}): {}; retrying in {}s",
+                    attempt,
+                    DOWNLOAD_ATTEMPTS,
+                    url,
+                    err,
+                    backoff.as_secs()
+                );
+                std::thread::sleep(backoff);
+                attempt += 1;
+            }
+        }
+    }
+}
+
+/// Perform a single GET; the error flag tells whether retrying could help.
+fn fetch_once(client: &reqwest::blocking::Client, url: &str) -> Result<Vec<u8>, (String, bool)> {
     let resp = client
         .get(url)
         .send()
-        .map_err(|e| format!("http get: {}", e))?;
-    if !resp.status().is_success() {
-        return Err(format!("http status {}", resp.status()));
+        .map_err(|e| (format!("http get: {}", e), true))?;
+    let status = resp.status();
+    if !status.is_success() {
+        // Missing assets (404 etc.) won't appear on retry; timeouts and server errors might.
+        let retryable = status.is_server_error()
+            || status == reqwest::StatusCode::REQUEST_TIMEOUT
+            || status == reqwest::StatusCode::TOO_MANY_REQUESTS;
+        return Err((format!("http status {}", status), retryable));
     }
-    let bytes = resp.bytes().map_err(|e| format!("read body: {}", e))?;
-    std::fs::write(&dst, &bytes).map_err(|e| format!("write {}: {}", dst.display(), e))?;
-    Ok(dl_dir)
+    resp.bytes()
+        .map(|b| b.to_vec())
+        .map_err(|e| (format!("read body: {}", e), true))
+}
+
+/// Shared fallback directory consulted by every -sys crate for prebuilt libraries
+pub const SHARED_PREBUILT_DIR_ENV: &str = "DEAR_IMGUI_PREBUILT_DIR";
+
+/// Locate `lib_name` under `DEAR_IMGUI_PREBUILT_DIR`, if set.
+///
+/// The directory itself, `lib/`, `<TARGET>/` and `<TARGET>/lib/` are searched in that order,
+/// so one directory can hold the libraries of all -sys crates (optionally per target).
+///
+/// Precedence used by the -sys build scripts:
+/// 1. `<CRATE>_SYS_LIB_DIR`
+/// 2. `<CRATE>_SYS_PREBUILT_URL` (the shared dir is skipped when this is set)
+/// 3. `DEAR_IMGUI_PREBUILT_DIR`
+/// 4. automatic release download (feature `prebuilt` or `<CRATE>_SYS_USE_PREBUILT`)
+/// 5. build from source
+pub fn shared_prebuilt_lib_dir(lib_name: &str) -> Option<PathBuf> {
+    let root = PathBuf::from(env::var_os(SHARED_PREBUILT_DIR_ENV)?);
+    let target = env::var("TARGET").unwrap_or_default();
+    let mut candidates = vec![root.clone(), root.join("lib")];
+    if !target.is_empty() {
+        candidates.push(root.join(&target));
+        candidates.push(root.join(&target).join("lib"));
+    }
+    candidates
+        .into_iter()
+        .find(|dir| dir.join(lib_name).exists())
+}
+
+/// Where the native static library linked by a -sys crate came from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LibProvenance {
+    /// Fetched over the network (explicit URL or GitHub release); `origin` is the URL
+    PrebuiltDownloaded { origin: String },
+    /// Linked from a local directory or package archive; `origin` is that path
+    PrebuiltLocal { origin: String },
+    /// Compiled from C/C++ sources with `tool` (`cc` or `cmake`)
+    Source {
+        tool: &'static str,
+        files: Option<usize>,
+    },
+}
+
+impl std::fmt::Display for LibProvenance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::PrebuiltDownloaded { origin } => write!(f, "prebuilt-downloaded ({})", origin),
+            Self::PrebuiltLocal { origin } => write!(f, "prebuilt-local ({})", origin),
+            Self::Source {
+                tool,
+                files: Some(n),
+            } => write!(f, "compiled-from-source ({}, {} files)", tool, n),
+            Self::Source { tool, files: None } => write!(f, "compiled-from-source ({})", tool),
+        }
+    }
+}
+
+/// Report how `lib_name` was obtained: emits a `cargo:warning` and writes
+/// `OUT_DIR/build_provenance.txt` so the decision can be inspected after the build.
+pub fn report_provenance(out_dir: &Path, lib_name: &str, provenance: &LibProvenance) {
+    println!("cargo:warning={}: {}", lib_name, provenance);
+    let target = env::var("TARGET").unwrap_or_default();
+    let contents = format!(
+        "lib={}\ntarget={}\nprovenance={}\n",
+        lib_name, target, provenance
+    );
+    let _ = std::fs::write(out_dir.join("build_provenance.txt"), contents);
 }
 
 pub fn prebuilt_cache_root_from_env_or_target(